@@ -313,6 +313,7 @@ pub enum Kind {
     Dist,
     Doc,
     Install,
+    CompareToolchains,
 }
 
 impl<'a> Builder<'a> {
@@ -362,6 +363,8 @@ impl<'a> Builder<'a> {
                 test::Codegen,
                 test::CodegenUnits,
                 test::Assembly,
+                test::StdarchAsm,
+                test::CodegenBackend,
                 test::Incremental,
                 test::Debuginfo,
                 test::UiFullDeps,
@@ -377,6 +380,7 @@ impl<'a> Builder<'a> {
                 test::Cargo,
                 test::Rls,
                 test::ErrorIndex,
+                test::FuzzSmoke,
                 test::Distcheck,
                 test::RunMakeFullDeps,
                 test::Nomicon,
@@ -428,6 +432,7 @@ impl<'a> Builder<'a> {
                 dist::Rustc,
                 dist::DebuggerScripts,
                 dist::Std,
+                dist::StdDebugAssertions,
                 dist::RustcDev,
                 dist::Analysis,
                 dist::Src,
@@ -454,6 +459,7 @@ impl<'a> Builder<'a> {
                 install::Src,
                 install::Rustc
             ),
+            Kind::CompareToolchains => describe!(test::CompareToolchains),
         }
     }
 
@@ -465,6 +471,7 @@ impl<'a> Builder<'a> {
             "bench" => Kind::Bench,
             "dist" => Kind::Dist,
             "install" => Kind::Install,
+            "compare-toolchains" => Kind::CompareToolchains,
             _ => return None,
         };
 
@@ -507,6 +514,9 @@ impl<'a> Builder<'a> {
             Subcommand::Bench { ref paths, .. } => (Kind::Bench, &paths[..]),
             Subcommand::Dist { ref paths } => (Kind::Dist, &paths[..]),
             Subcommand::Install { ref paths } => (Kind::Install, &paths[..]),
+            Subcommand::CompareToolchains { ref paths, .. } => {
+                (Kind::CompareToolchains, &paths[..])
+            }
             Subcommand::Format { .. } | Subcommand::Clean { .. } => panic!(),
         };
 
@@ -949,6 +959,12 @@ impl<'a> Builder<'a> {
             }
         }
 
+        // The debug sections are compressed by the linker, so this only
+        // affects the artifacts that are linked, not the rlibs.
+        if let Some(kind) = self.compress_debug_sections(target) {
+            rustflags.arg(&format!("-Clink-args=-Wl,--compress-debug-sections={}", kind));
+        }
+
         if let Some(host_linker) = self.linker(compiler.host) {
             cargo.env("RUSTC_HOST_LINKER", host_linker);
         }
@@ -364,6 +364,8 @@ fn test_with_no_doc_stage0() {
         bless: false,
         compare_mode: None,
         rustfix_coverage: false,
+        diagnostics_baseline: None,
+        fuzz_smoke: false,
         pass: None,
     };
 
@@ -402,6 +404,8 @@ fn test_exclude() {
         bless: false,
         compare_mode: None,
         rustfix_coverage: false,
+        diagnostics_baseline: None,
+        fuzz_smoke: false,
         pass: None,
     };
 
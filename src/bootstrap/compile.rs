@@ -267,6 +267,60 @@ impl Step for StdLink {
     }
 }
 
+/// A build of the standard library with debug assertions enabled, shipped as
+/// the optional `rust-std-debug-assertions` component.
+///
+/// This is built in a separate output directory and isn't linked into any
+/// sysroot, so it doesn't interfere with the standard library used by the
+/// rest of the build.
+#[derive(Debug, PartialOrd, Ord, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StdDebugAssertions {
+    pub target: Interned<String>,
+    pub compiler: Compiler,
+}
+
+impl Step for StdDebugAssertions {
+    type Output = ();
+
+    fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
+        run.never()
+    }
+
+    /// Builds the standard library with debug assertions enabled.
+    ///
+    /// The startup and third party objects of the regular standard library
+    /// are recorded in the stamp as well, so that the stamped files form a
+    /// complete `lib/rustlib/$target/lib` directory.
+    fn run(self, builder: &Builder<'_>) {
+        let target = self.target;
+        let compiler = self.compiler;
+
+        builder.ensure(Std { compiler, target });
+
+        let mut target_deps = builder.ensure(StartupObjects { compiler, target });
+        target_deps.extend(copy_third_party_objects(builder, &compiler, target).into_iter());
+
+        let mut cargo = builder.cargo(compiler, Mode::Std, target, "build");
+        std_cargo(builder, target, &mut cargo);
+        cargo
+            .env("CARGO_TARGET_DIR", std_debug_assertions_out(builder, compiler))
+            .env("RUSTC_DEBUG_ASSERTIONS", "true");
+
+        builder.info(&format!(
+            "Building stage{} std artifacts with debug assertions ({} -> {})",
+            compiler.stage, &compiler.host, target
+        ));
+        run_cargo(
+            builder,
+            cargo,
+            vec![],
+            &libstd_debug_assertions_stamp(builder, compiler, target),
+            target_deps,
+            false,
+        );
+    }
+}
+
 /// Copies sanitizer runtime libraries into target libdir.
 fn copy_sanitizers(
     builder: &Builder<'_>,
@@ -329,12 +383,16 @@ impl Step for StartupObjects {
     /// They don't require any library support as they're just plain old object
     /// files, so we just use the nightly snapshot compiler to always build them (as
     /// no other compilers are guaranteed to be available).
+    ///
+    /// The objects needed by each target family are listed in `STARTUP_OBJECTS`.
     fn run(self, builder: &Builder<'_>) -> Vec<PathBuf> {
         let for_compiler = self.compiler;
         let target = self.target;
-        if !target.contains("windows-gnu") {
-            return vec![];
-        }
+        let (rust_objects, c_objects) =
+            match STARTUP_OBJECTS.iter().find(|(family, _, _)| target.contains(family)) {
+                Some(&(_, rust_objects, c_objects)) => (rust_objects, c_objects),
+                None => return vec![],
+            };
 
         let mut target_deps = vec![];
 
@@ -343,10 +401,18 @@ impl Step for StartupObjects {
         let sysroot_dir = &builder.sysroot_libdir(for_compiler, target);
         t!(fs::create_dir_all(dst_dir));
 
-        for file in &["rsbegin", "rsend"] {
+        // The objects are built by the snapshot compiler, so they have to be
+        // rebuilt whenever it changes, even if the sources didn't.
+        let rustc_version =
+            output(Command::new(&builder.initial_rustc).arg("--version").arg("--verbose"));
+
+        for file in rust_objects {
             let src_file = &src_dir.join(file.to_string() + ".rs");
             let dst_file = &dst_dir.join(file.to_string() + ".o");
-            if !up_to_date(src_file, dst_file) {
+            let stamp = &dst_dir.join(file.to_string() + ".stamp");
+            let fresh = up_to_date(src_file, dst_file)
+                && fs::read_to_string(stamp).map(|s| s == rustc_version).unwrap_or(false);
+            if !fresh {
                 let mut cmd = Command::new(&builder.initial_rustc);
                 builder.run(
                     cmd.env("RUSTC_BOOTSTRAP", "1")
@@ -359,6 +425,9 @@ impl Step for StartupObjects {
                         .arg(dst_file)
                         .arg(src_file),
                 );
+                if !builder.config.dry_run {
+                    t!(fs::write(stamp, &rustc_version));
+                }
             }
 
             let target = sysroot_dir.join(file.to_string() + ".o");
@@ -366,7 +435,7 @@ impl Step for StartupObjects {
             target_deps.push(target);
         }
 
-        for obj in ["crt2.o", "dllcrt2.o"].iter() {
+        for obj in c_objects {
             let src = compiler_file(builder, builder.cc(target), target, obj);
             let target = sysroot_dir.join(obj);
             builder.copy(&src, &target);
@@ -377,6 +446,17 @@ impl Step for StartupObjects {
     }
 }
 
+/// The startup objects shipped in the sysroot of each target family: the
+/// objects compiled from `src/rtstartup`, and the ones copied from the C
+/// toolchain of the target.
+///
+/// UEFI images are linked by `rust-lld` without any C runtime, so they only
+/// get `rsbegin.o` and `rsend.o` to delimit the image sections.
+const STARTUP_OBJECTS: &[(&str, &[&str], &[&str])] = &[
+    ("windows-gnu", &["rsbegin", "rsend"], &["crt2.o", "dllcrt2.o"]),
+    ("-uefi", &["rsbegin", "rsend"], &[]),
+];
+
 #[derive(Debug, PartialOrd, Ord, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Rustc {
     pub target: Interned<String>,
@@ -621,6 +701,25 @@ pub fn libstd_stamp(
     builder.cargo_out(compiler, Mode::Std, target).join(".libstd.stamp")
 }
 
+/// Cargo's output directory for the build of libstd with debug assertions in
+/// a given stage, compiled by a particular compiler.
+fn std_debug_assertions_out(builder: &Builder<'_>, compiler: Compiler) -> PathBuf {
+    builder.host_out(compiler.host).join(format!("stage{}-std-debug-assertions", compiler.stage))
+}
+
+/// Cargo's output path for the build of libstd with debug assertions in a
+/// given stage, compiled by a particular compiler for the specified target.
+pub fn libstd_debug_assertions_stamp(
+    builder: &Builder<'_>,
+    compiler: Compiler,
+    target: Interned<String>,
+) -> PathBuf {
+    std_debug_assertions_out(builder, compiler)
+        .join(&*target)
+        .join(builder.cargo_dir())
+        .join(".libstd.stamp")
+}
+
 /// Cargo's output path for librustc in a given stage, compiled by a particular
 /// compiler for the specified target.
 pub fn librustc_stamp(
@@ -665,9 +764,9 @@ impl Step for Sysroot {
     fn run(self, builder: &Builder<'_>) -> Interned<PathBuf> {
         let compiler = self.compiler;
         let sysroot = if compiler.stage == 0 {
-            builder.out.join(&compiler.host).join("stage0-sysroot")
+            builder.host_out(compiler.host).join("stage0-sysroot")
         } else {
-            builder.out.join(&compiler.host).join(format!("stage{}", compiler.stage))
+            builder.host_out(compiler.host).join(format!("stage{}", compiler.stage))
         };
         let _ = fs::remove_dir_all(&sysroot);
         t!(fs::create_dir_all(&sysroot));
@@ -50,7 +50,9 @@ pub struct Config {
     pub exclude: Vec<PathBuf>,
     pub rustc_error_format: Option<String>,
     pub test_compare_mode: bool,
+    pub compare_modes: HashMap<String, Vec<String>>,
     pub llvm_libunwind: bool,
+    pub std_features: Vec<String>,
 
     pub skip_only_host_steps: bool,
 
@@ -62,6 +64,7 @@ pub struct Config {
     pub cmd: Subcommand,
     pub incremental: bool,
     pub dry_run: bool,
+    pub toolchain: Option<String>,
 
     pub deny_warnings: bool,
     pub backtrace_on_ice: bool,
@@ -110,6 +113,7 @@ pub struct Config {
     pub rust_verify_llvm_ir: bool,
     pub rust_thin_lto_import_instr_limit: Option<u32>,
     pub rust_remap_debuginfo: bool,
+    pub rust_compress_debug_sections: Option<String>,
 
     pub build: Interned<String>,
     pub hosts: Vec<Interned<String>>,
@@ -121,6 +125,7 @@ pub struct Config {
     pub dist_sign_folder: Option<PathBuf>,
     pub dist_upload_addr: Option<String>,
     pub dist_gpg_password_file: Option<PathBuf>,
+    pub dist_std_debug_assertions: bool,
 
     // libstd features
     pub backtrace: bool, // support for RUST_BACKTRACE
@@ -173,6 +178,8 @@ pub struct Target {
     pub musl_root: Option<PathBuf>,
     pub wasi_root: Option<PathBuf>,
     pub qemu_rootfs: Option<PathBuf>,
+    pub qemu_kernel: Option<PathBuf>,
+    pub qemu_initrd: Option<PathBuf>,
     pub no_std: bool,
 }
 
@@ -278,6 +285,7 @@ struct Dist {
     upload_addr: Option<String>,
     src_tarball: Option<bool>,
     missing_tools: Option<bool>,
+    std_debug_assertions: Option<bool>,
 }
 
 #[derive(Deserialize)]
@@ -329,9 +337,12 @@ struct Rust {
     verify_llvm_ir: Option<bool>,
     thin_lto_import_instr_limit: Option<u32>,
     remap_debuginfo: Option<bool>,
+    compress_debug_sections: Option<String>,
     jemalloc: Option<bool>,
     test_compare_mode: Option<bool>,
+    compare_modes: Option<HashMap<String, Vec<String>>>,
     llvm_libunwind: Option<bool>,
+    std_features: Option<Vec<String>>,
 }
 
 /// TOML representation of how each build target is configured.
@@ -350,6 +361,8 @@ struct TomlTarget {
     musl_root: Option<String>,
     wasi_root: Option<String>,
     qemu_rootfs: Option<String>,
+    qemu_kernel: Option<String>,
+    qemu_initrd: Option<String>,
 }
 
 impl Config {
@@ -409,6 +422,7 @@ impl Config {
         config.incremental = flags.incremental;
         config.dry_run = flags.dry_run;
         config.keep_stage = flags.keep_stage;
+        config.toolchain = flags.toolchain;
         config.bindir = "bin".into(); // default
         if let Some(value) = flags.deny_warnings {
             config.deny_warnings = value;
@@ -557,7 +571,9 @@ impl Config {
             set(&mut config.rust_rpath, rust.rpath);
             set(&mut config.jemalloc, rust.jemalloc);
             set(&mut config.test_compare_mode, rust.test_compare_mode);
+            set(&mut config.compare_modes, rust.compare_modes.clone());
             set(&mut config.llvm_libunwind, rust.llvm_libunwind);
+            set(&mut config.std_features, rust.std_features.clone());
             set(&mut config.backtrace, rust.backtrace);
             set(&mut config.channel, rust.channel.clone());
             set(&mut config.rust_dist_src, rust.dist_src);
@@ -578,6 +594,7 @@ impl Config {
             set(&mut config.rust_verify_llvm_ir, rust.verify_llvm_ir);
             config.rust_thin_lto_import_instr_limit = rust.thin_lto_import_instr_limit;
             set(&mut config.rust_remap_debuginfo, rust.remap_debuginfo);
+            config.rust_compress_debug_sections = rust.compress_debug_sections.clone();
 
             if let Some(ref backends) = rust.codegen_backends {
                 config.rust_codegen_backends =
@@ -610,6 +627,8 @@ impl Config {
                 target.musl_root = cfg.musl_root.clone().map(PathBuf::from);
                 target.wasi_root = cfg.wasi_root.clone().map(PathBuf::from);
                 target.qemu_rootfs = cfg.qemu_rootfs.clone().map(PathBuf::from);
+                target.qemu_kernel = cfg.qemu_kernel.clone().map(PathBuf::from);
+                target.qemu_initrd = cfg.qemu_initrd.clone().map(PathBuf::from);
 
                 config.target_config.insert(INTERNER.intern_string(triple.clone()), target);
             }
@@ -621,6 +640,7 @@ impl Config {
             config.dist_upload_addr = t.upload_addr.clone();
             set(&mut config.rust_dist_src, t.src_tarball);
             set(&mut config.missing_tools, t.missing_tools);
+            set(&mut config.dist_std_debug_assertions, t.std_debug_assertions);
         }
 
         // Now that we've reached the end of our configuration, infer the
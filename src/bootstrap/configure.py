@@ -59,6 +59,7 @@ o("full-tools", None, "enable all tools")
 o("lld", "rust.lld", "build lld")
 o("lldb", "rust.lldb", "build lldb")
 o("missing-tools", "dist.missing-tools", "allow failures when building tools")
+o("dist-std-debug-assertions", "dist.std-debug-assertions", "build a std component with debug assertions")
 o("use-libcxx", "llvm.use-libcxx", "build LLVM with libc++")
 
 o("cflags", "llvm.cflags", "build LLVM with these extra compiler flags")
@@ -79,6 +80,7 @@ v("debuginfo-level-rustc", "rust.debuginfo-level-rustc", "debuginfo level for th
 v("debuginfo-level-std", "rust.debuginfo-level-std", "debuginfo level for the standard library")
 v("debuginfo-level-tools", "rust.debuginfo-level-tools", "debuginfo level for the tools")
 v("debuginfo-level-tests", "rust.debuginfo-level-tests", "debuginfo level for the test suites run with compiletest")
+v("compress-debug-sections", "rust.compress-debug-sections", "compress debug sections with zlib or zstd")
 v("save-toolstates", "rust.save-toolstates", "save build and test status of external tools into this file")
 
 v("prefix", "install.prefix", "set installation prefix")
@@ -141,6 +143,10 @@ v("qemu-armhf-rootfs", "target.arm-unknown-linux-gnueabihf.qemu-rootfs",
   "rootfs in qemu testing, you probably don't want to use this")
 v("qemu-aarch64-rootfs", "target.aarch64-unknown-linux-gnu.qemu-rootfs",
   "rootfs in qemu testing, you probably don't want to use this")
+v("qemu-riscv64gc-kernel", "target.riscv64gc-unknown-linux-gnu.qemu-kernel",
+  "kernel in qemu testing, you probably don't want to use this")
+v("qemu-riscv64gc-initrd", "target.riscv64gc-unknown-linux-gnu.qemu-initrd",
+  "initrd in qemu testing, you probably don't want to use this")
 v("experimental-targets", "llvm.experimental-targets",
   "experimental LLVM targets to build")
 v("release-channel", "rust.channel", "the name of the release channel to build")
@@ -720,6 +720,84 @@ impl Step for Std {
     }
 }
 
+#[derive(Debug, PartialOrd, Ord, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct StdDebugAssertions {
+    pub compiler: Compiler,
+    pub target: Interned<String>,
+}
+
+impl Step for StdDebugAssertions {
+    type Output = PathBuf;
+    const DEFAULT: bool = true;
+
+    fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
+        let builder = run.builder;
+        run.path("rust-std-debug-assertions")
+            .default_condition(builder.config.dist_std_debug_assertions)
+    }
+
+    fn make_run(run: RunConfig<'_>) {
+        run.builder.ensure(StdDebugAssertions {
+            compiler: run.builder.compiler_for(
+                run.builder.top_stage,
+                run.builder.config.build,
+                run.target,
+            ),
+            target: run.target,
+        });
+    }
+
+    /// Creates the `rust-std-debug-assertions` installer component.
+    ///
+    /// The libraries are installed into a sysroot of their own, at
+    /// `lib/rustlib/debug-assertions`, so that they don't conflict with the
+    /// ones from `rust-std`. They're used by passing
+    /// `--sysroot $(rustc --print sysroot)/lib/rustlib/debug-assertions`.
+    fn run(self, builder: &Builder<'_>) -> PathBuf {
+        let compiler = self.compiler;
+        let target = self.target;
+
+        let name = pkgname(builder, "rust-std-debug-assertions");
+        let archive = distdir(builder).join(format!("{}-{}.tar.gz", name, target));
+        if skip_host_target_lib(builder, compiler) {
+            return archive;
+        }
+
+        let compiler_to_use = builder.compiler_for(compiler.stage, compiler.host, target);
+        builder.ensure(compile::StdDebugAssertions { compiler: compiler_to_use, target });
+
+        let image = tmpdir(builder).join(format!("{}-{}-image", name, target));
+        let _ = fs::remove_dir_all(&image);
+
+        let stamp = compile::libstd_debug_assertions_stamp(builder, compiler_to_use, target);
+        copy_target_libs(builder, &target, &image.join("lib/rustlib/debug-assertions"), &stamp);
+
+        let mut cmd = rust_installer(builder);
+        cmd.arg("generate")
+            .arg("--product-name=Rust")
+            .arg("--rel-manifest-dir=rustlib")
+            .arg("--success-message=std-with-debug-assertions-is-standing-at-the-ready.")
+            .arg("--image-dir")
+            .arg(&image)
+            .arg("--work-dir")
+            .arg(&tmpdir(builder))
+            .arg("--output-dir")
+            .arg(&distdir(builder))
+            .arg(format!("--package-name={}-{}", name, target))
+            .arg(format!("--component-name=rust-std-debug-assertions-{}", target))
+            .arg("--legacy-manifest-dirs=rustlib,cargo");
+
+        builder.info(&format!(
+            "Dist std with debug assertions stage{} ({} -> {})",
+            compiler.stage, &compiler.host, target
+        ));
+        let _time = timeit(builder);
+        builder.run(&mut cmd);
+        builder.remove_dir(&image);
+        archive
+    }
+}
+
 #[derive(Debug, PartialOrd, Ord, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct RustcDev {
     pub compiler: Compiler,
@@ -40,6 +40,10 @@ pub struct Flags {
     pub deny_warnings: Option<bool>,
 
     pub llvm_skip_rebuild: Option<bool>,
+
+    // The toolchain slot that the stages are built in, so that several
+    // toolchains can be kept in the same build directory.
+    pub toolchain: Option<String>,
 }
 
 pub enum Subcommand {
@@ -72,6 +76,8 @@ pub enum Subcommand {
         fail_fast: bool,
         doc_tests: DocTests,
         rustfix_coverage: bool,
+        diagnostics_baseline: Option<String>,
+        fuzz_smoke: bool,
     },
     Bench {
         paths: Vec<PathBuf>,
@@ -86,6 +92,10 @@ pub enum Subcommand {
     Install {
         paths: Vec<PathBuf>,
     },
+    CompareToolchains {
+        paths: Vec<PathBuf>,
+        baseline: String,
+    },
 }
 
 impl Default for Subcommand {
@@ -113,6 +123,8 @@ Subcommands:
     clean       Clean out build directories
     dist        Build distribution artifacts
     install     Install distribution artifacts
+    compare-toolchains
+                Compare the behavior of two toolchains on some tests
 
 To learn more about a subcommand, run `./x.py <subcommand> -h`",
         );
@@ -160,6 +172,13 @@ To learn more about a subcommand, run `./x.py <subcommand> -h`",
              VALUE overrides the skip-rebuild option in config.toml.",
             "VALUE",
         );
+        opts.optopt(
+            "",
+            "as-toolchain",
+            "build the stages in the named toolchain slot instead of the default one, \
+             keeping the toolchains of other slots intact",
+            "NAME",
+        );
 
         // fn usage()
         let usage =
@@ -188,6 +207,7 @@ To learn more about a subcommand, run `./x.py <subcommand> -h`",
                 || (s == "clean")
                 || (s == "dist")
                 || (s == "install")
+                || (s == "compare-toolchains")
         });
         let subcommand = match subcommand {
             Some(s) => s,
@@ -218,7 +238,8 @@ To learn more about a subcommand, run `./x.py <subcommand> -h`",
                 opts.optopt(
                     "",
                     "compare-mode",
-                    "mode describing what file the actual ui output will be compared to",
+                    "mode describing what file the actual ui output will be compared to: \
+                     nll, polonius, chalk or one of the `rust.compare-modes` of config.toml",
                     "COMPARE MODE",
                 );
                 opts.optopt(
@@ -233,6 +254,19 @@ To learn more about a subcommand, run `./x.py <subcommand> -h`",
                     "enable this to generate a Rustfix coverage file, which is saved in \
                         `/<build_base>/rustfix_missing_coverage.txt`",
                 );
+                opts.optopt(
+                    "",
+                    "diagnostics-baseline",
+                    "compare the diagnostics of the ui tests with the ones of another compiler \
+                     instead of the expected output, `beta` being the bootstrap compiler",
+                    "beta | PATH",
+                );
+                opts.optflag(
+                    "",
+                    "fuzz-smoke",
+                    "also run the fuzz targets of the lexer, the parser and the constant \
+                     evaluator on the smoke corpus of `src/tools/rustc-fuzz`",
+                );
             }
             "bench" => {
                 opts.optmulti("", "test-args", "extra arguments", "ARGS");
@@ -243,6 +277,14 @@ To learn more about a subcommand, run `./x.py <subcommand> -h`",
             "fmt" => {
                 opts.optflag("", "check", "check formatting instead of applying.");
             }
+            "compare-toolchains" => {
+                opts.optopt(
+                    "",
+                    "baseline",
+                    "toolchain slot that the toolchain being built is compared to",
+                    "NAME",
+                );
+            }
             _ => {}
         };
 
@@ -367,6 +409,8 @@ Arguments:
         ./x.py test src/libstd --stage 0 --no-doc
         ./x.py test src/test/ui --bless
         ./x.py test src/test/ui --compare-mode nll
+        ./x.py test src/test/ui --diagnostics-baseline beta
+        ./x.py test src/tools/rustc-fuzz
 
     Note that `test src/test/* --stage N` does NOT depend on `build src/rustc --stage N`;
     just like `build src/libstd --stage N` it tests the compiler produced by the previous
@@ -376,7 +420,8 @@ Arguments:
     compiled and tested.
 
         ./x.py test
-        ./x.py test --stage 1",
+        ./x.py test --stage 1
+        ./x.py test --fuzz-smoke",
                 );
             }
             "doc" => {
@@ -396,6 +441,21 @@ Arguments:
         ./x.py doc --stage 1",
                 );
             }
+            "compare-toolchains" => {
+                subcommand_help.push_str(
+                    "\n
+Arguments:
+    This subcommand accepts a number of paths to test files, or directories of
+    test files, under `src/test`. Each test is compiled and run with both the
+    toolchain being built and the `--baseline` one, which must have been built
+    with `--as-toolchain` before, and any difference in the output of the
+    compiler or of the program is reported. For example:
+
+        ./x.py build --stage 2 --as-toolchain known-good
+        ./x.py compare-toolchains --stage 2 --baseline known-good src/test/ui/issues
+        ./x.py compare-toolchains --as-toolchain experiment --baseline known-good src/test/ui",
+                );
+            }
             _ => {}
         };
         // Get any optional paths which occur after the subcommand
@@ -443,6 +503,8 @@ Arguments:
                 rustc_args: matches.opt_strs("rustc-args"),
                 fail_fast: !matches.opt_present("no-fail-fast"),
                 rustfix_coverage: matches.opt_present("rustfix-coverage"),
+                diagnostics_baseline: matches.opt_str("diagnostics-baseline"),
+                fuzz_smoke: matches.opt_present("fuzz-smoke"),
                 doc_tests: if matches.opt_present("doc") {
                     DocTests::Only
                 } else if matches.opt_present("no-doc") {
@@ -464,6 +526,17 @@ Arguments:
             "fmt" => Subcommand::Format { check: matches.opt_present("check") },
             "dist" => Subcommand::Dist { paths },
             "install" => Subcommand::Install { paths },
+            "compare-toolchains" => {
+                if paths.is_empty() {
+                    println!("\ncompare-toolchains requires the paths of the tests to compare\n");
+                    usage(1, &opts, &subcommand_help, &extra_help);
+                }
+                let baseline = matches.opt_str("baseline").unwrap_or_else(|| {
+                    println!("\ncompare-toolchains requires a `--baseline` toolchain\n");
+                    usage(1, &opts, &subcommand_help, &extra_help);
+                });
+                Subcommand::CompareToolchains { paths, baseline: parse_toolchain(baseline) }
+            }
             _ => {
                 usage(1, &opts, &subcommand_help, &extra_help);
             }
@@ -500,6 +573,7 @@ Arguments:
             llvm_skip_rebuild: matches.opt_str("llvm-skip-rebuild").map(|s| s.to_lowercase()).map(
                 |s| s.parse::<bool>().expect("`llvm-skip-rebuild` should be either true or false"),
             ),
+            toolchain: matches.opt_str("as-toolchain").map(parse_toolchain),
         }
     }
 }
@@ -564,6 +638,22 @@ impl Subcommand {
             _ => None,
         }
     }
+
+    pub fn diagnostics_baseline(&self) -> Option<&str> {
+        match *self {
+            Subcommand::Test { ref diagnostics_baseline, .. } => {
+                diagnostics_baseline.as_ref().map(|s| &s[..])
+            }
+            _ => None,
+        }
+    }
+
+    pub fn fuzz_smoke(&self) -> bool {
+        match *self {
+            Subcommand::Test { fuzz_smoke, .. } => fuzz_smoke,
+            _ => false,
+        }
+    }
 }
 
 fn split(s: &[String]) -> Vec<String> {
@@ -581,3 +671,11 @@ fn parse_deny_warnings(matches: &getopts::Matches) -> Option<bool> {
         None => None,
     }
 }
+
+fn parse_toolchain(name: String) -> String {
+    if name.is_empty() || name == "." || name == ".." || name.contains(&['/', '\\'][..]) {
+        eprintln!("invalid toolchain name: {:?}, expected a plain directory name", name);
+        process::exit(1);
+    }
+    name
+}
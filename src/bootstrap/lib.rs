@@ -490,6 +490,10 @@ impl Build {
         if self.config.profiler {
             features.push_str(" profiler");
         }
+        for feature in &self.config.std_features {
+            features.push_str(" ");
+            features.push_str(feature);
+        }
         features
     }
 
@@ -511,8 +515,24 @@ impl Build {
         if self.config.rust_optimize { "release" } else { "debug" }
     }
 
+    /// Returns the directory holding the output of all stages built for `host`
+    /// in the toolchain slot `toolchain`, or in the default one if it is `None`.
+    fn toolchain_out(&self, host: Interned<String>, toolchain: Option<&str>) -> PathBuf {
+        let out = self.out.join(&*host);
+        match toolchain {
+            Some(name) => out.join("toolchains").join(name),
+            None => out,
+        }
+    }
+
+    /// Returns the directory holding the output of all stages built for `host`
+    /// in the toolchain slot selected with `--as-toolchain`.
+    fn host_out(&self, host: Interned<String>) -> PathBuf {
+        self.toolchain_out(host, self.config.toolchain.as_deref())
+    }
+
     fn tools_dir(&self, compiler: Compiler) -> PathBuf {
-        let out = self.out.join(&*compiler.host).join(format!("stage{}-tools-bin", compiler.stage));
+        let out = self.host_out(compiler.host).join(format!("stage{}-tools-bin", compiler.stage));
         t!(fs::create_dir_all(&out));
         out
     }
@@ -529,7 +549,7 @@ impl Build {
             Mode::ToolBootstrap => "-bootstrap-tools",
             Mode::ToolStd | Mode::ToolRustc => "-tools",
         };
-        self.out.join(&*compiler.host).join(format!("stage{}{}", compiler.stage, suffix))
+        self.host_out(compiler.host).join(format!("stage{}{}", compiler.stage, suffix))
     }
 
     /// Returns the root output directory for all Cargo output in a given stage,
@@ -824,6 +844,23 @@ impl Build {
         }
     }
 
+    /// Returns the compression to apply to the debug sections of the artifacts
+    /// linked for `target`, if `rust.compress-debug-sections` is set and the
+    /// target is linked with a linker that produces ELF files.
+    fn compress_debug_sections(&self, target: Interned<String>) -> Option<&str> {
+        let kind = self.config.rust_compress_debug_sections.as_ref()?;
+        if util::use_host_linker(&target)
+            && !target.contains("windows")
+            && !target.contains("apple")
+            && !target.contains("uefi")
+            && !target.contains("-none")
+        {
+            Some(kind)
+        } else {
+            None
+        }
+    }
+
     /// Returns if this target should statically link the C runtime, if specified
     fn crt_static(&self, target: Interned<String>) -> Option<bool> {
         if target.contains("pc-windows-msvc") {
@@ -857,6 +894,7 @@ impl Build {
     /// and `remote-test-server` binaries.
     fn remote_tested(&self, target: Interned<String>) -> bool {
         self.qemu_rootfs(target).is_some()
+            || self.qemu_kernel(target).is_some()
             || target.contains("android")
             || env::var_os("TEST_DEVICE_ADDR").is_some()
     }
@@ -870,6 +908,18 @@ impl Build {
         self.config.target_config.get(&target).and_then(|t| t.qemu_rootfs.as_ref()).map(|p| &**p)
     }
 
+    /// Returns the kernel that QEMU boots to emulate this target, if one was
+    /// configured.
+    fn qemu_kernel(&self, target: Interned<String>) -> Option<&Path> {
+        self.config.target_config.get(&target).and_then(|t| t.qemu_kernel.as_ref()).map(|p| &**p)
+    }
+
+    /// Returns the initrd image that QEMU boots to emulate this target when it
+    /// has no rootfs, if one was configured.
+    fn qemu_initrd(&self, target: Interned<String>) -> Option<&Path> {
+        self.config.target_config.get(&target).and_then(|t| t.qemu_initrd.as_ref()).map(|p| &**p)
+    }
+
     /// Path to the python interpreter to use
     fn python(&self) -> &Path {
         self.config.python.as_ref().unwrap()
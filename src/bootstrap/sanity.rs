@@ -17,7 +17,8 @@ use std::process::Command;
 
 use build_helper::{output, t};
 
-use crate::Build;
+use crate::cache::Interned;
+use crate::{Build, GitRepo};
 
 struct Finder {
     cache: HashMap<OsString, Option<PathBuf>>,
@@ -180,6 +181,23 @@ pub fn check(build: &mut Build) {
         }
     }
 
+    if let Some(ref kind) = build.config.rust_compress_debug_sections {
+        if kind != "zlib" && kind != "zstd" {
+            panic!(
+                "unknown value `{}` for `rust.compress-debug-sections`, \
+                 expected `zlib` or `zstd`",
+                kind
+            );
+        }
+        if !build.config.dry_run {
+            for target in &build.targets {
+                if build.compress_debug_sections(*target).is_some() {
+                    check_compress_debug_sections(build, *target, kind);
+                }
+            }
+        }
+    }
+
     // Externally configured LLVM requires FileCheck to exist
     let filecheck = build.llvm_filecheck(build.build);
     if !filecheck.starts_with(&build.out) && !filecheck.exists() && build.config.codegen_tests {
@@ -254,6 +272,26 @@ $ pacman -R cmake && pacman -S mingw-w64-x86_64-cmake
         cmd_finder.must_have(s);
     }
 
+    // The standard library is built through `libtest`, so its features are the ones that
+    // `rust.std-features` may enable.
+    if !build.config.std_features.is_empty() {
+        let manifest = t!(fs::read_to_string(build.src.join("src/libtest/Cargo.toml")));
+        let manifest: toml::Value = t!(toml::from_str(&manifest));
+        let known = manifest.get("features").and_then(|features| features.as_table());
+        for feature in &build.config.std_features {
+            if !known.map_or(false, |known| known.contains_key(feature)) {
+                let known = known.map_or(String::new(), |known| {
+                    known.keys().map(|k| &k[..]).collect::<Vec<_>>().join(", ")
+                });
+                panic!(
+                    "unknown feature `{}` in `rust.std-features`, the standard library \
+                     has the features: {}",
+                    feature, known
+                );
+            }
+        }
+    }
+
     if build.config.channel == "stable" {
         let stage0 = t!(fs::read_to_string(build.src.join("src/stage0.txt")));
         if stage0.contains("\ndev:") {
@@ -264,3 +302,34 @@ $ pacman -R cmake && pacman -S mingw-w64-x86_64-cmake
         }
     }
 }
+
+/// Checks that the linker for `target` can compress debug sections with
+/// `kind`, by linking a small C program with debuginfo, so that an old linker
+/// is reported before the build starts rather than when linking the compiler.
+fn check_compress_debug_sections(build: &Build, target: Interned<String>, kind: &str) {
+    let dir = build.out.join("tmp").join("compress-debug-sections").join(&*target);
+    t!(fs::create_dir_all(&dir));
+    let src = dir.join("probe.c");
+    t!(fs::write(&src, "int main(void) { return 0; }\n"));
+
+    let linker = build.linker(target).unwrap_or_else(|| build.cc(target));
+    let output = t!(Command::new(linker)
+        .args(&build.cflags(target, GitRepo::Rustc))
+        .arg("-g")
+        .arg(&src)
+        .arg("-o")
+        .arg(dir.join("probe"))
+        .arg(format!("-Wl,--compress-debug-sections={}", kind))
+        .output());
+    if !output.status.success() {
+        panic!(
+            "\n\nthe linker for `{}` ({}) doesn't support \
+             `--compress-debug-sections={}`, which `rust.compress-debug-sections` \
+             needs. Use a newer version of binutils or lld, or unset the option.\n\n{}",
+            target,
+            linker.display(),
+            kind,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
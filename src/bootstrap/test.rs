@@ -920,6 +920,14 @@ host_test!(RunMakeFullDeps {
 
 default_test!(Assembly { path: "src/test/assembly", mode: "assembly", suite: "assembly" });
 
+// The conformance suite of the codegen backends, which runs against another backend with
+// `x.py test src/test/codegen-backend --rustc-args -Zcodegen-backend=path/to/backend.so`.
+default_test!(CodegenBackend {
+    path: "src/test/codegen-backend",
+    mode: "ui",
+    suite: "codegen-backend"
+});
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 struct Compiletest {
     compiler: Compiler,
@@ -1255,6 +1263,23 @@ impl Step for Compiletest {
             cmd.arg("--rustfix-coverage");
         }
 
+        // The tests are not checked against their expected output when they are
+        // compared with another compiler, so there is no point in also running
+        // them in the compare mode.
+        let diagnostics_baseline = match builder.config.cmd.diagnostics_baseline() {
+            Some(baseline) if mode == "ui" => {
+                let rustc = if baseline == "beta" {
+                    builder.initial_rustc.clone()
+                } else {
+                    PathBuf::from(baseline)
+                };
+                cmd.arg("--baseline-rustc-path").arg(rustc);
+                true
+            }
+            _ => false,
+        };
+        let compare_mode = if diagnostics_baseline { None } else { compare_mode };
+
         builder.ci_env.force_coloring_in_ci(&mut cmd);
 
         builder.info(&format!(
@@ -1264,8 +1289,21 @@ impl Step for Compiletest {
         let _time = util::timeit(&builder);
         try_run(builder, &mut cmd);
 
+        if diagnostics_baseline {
+            let report = testdir(builder, compiler.host).join(suite);
+            let report = report.join("diagnostics_baseline_diff.txt");
+            if report.exists() {
+                builder.info(&format!("Changed diagnostics are listed in {}", report.display()));
+            } else {
+                builder.info("No diagnostics changed");
+            }
+        }
+
         if let Some(compare_mode) = compare_mode {
             cmd.arg("--compare-mode").arg(compare_mode);
+            if let Some(flags) = builder.config.compare_modes.get(compare_mode) {
+                cmd.arg("--compare-mode-flags").arg(flags.join(" "));
+            }
             builder.info(&format!(
                 "Check compiletest suite={} mode={} compare_mode={} ({} -> {})",
                 suite, mode, compare_mode, &compiler.host, target
@@ -1436,6 +1474,47 @@ impl Step for ErrorIndex {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FuzzSmoke {
+    compiler: Compiler,
+}
+
+impl Step for FuzzSmoke {
+    type Output = ();
+    const DEFAULT: bool = true;
+    const ONLY_HOSTS: bool = true;
+
+    fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
+        let builder = run.builder;
+        run.path("src/tools/rustc-fuzz").default_condition(builder.config.cmd.fuzz_smoke())
+    }
+
+    fn make_run(run: RunConfig<'_>) {
+        run.builder
+            .ensure(FuzzSmoke { compiler: run.builder.compiler(run.builder.top_stage, run.host) });
+    }
+
+    /// Runs the fuzz targets of `src/tools/rustc-fuzz` on the inputs of its smoke corpus, and
+    /// on a few mutations of each of them.
+    ///
+    /// This is not fuzzing as such, but it catches the compiler panics and the non-determinism
+    /// that these inputs are known to exercise, quickly enough to run with the other tests.
+    fn run(self, builder: &Builder<'_>) {
+        let compiler = self.compiler;
+
+        // The constant evaluator compiles its inputs against the standard library.
+        builder.ensure(compile::Std { compiler, target: compiler.host });
+
+        let mut tool = tool::RustcFuzz::command(builder, compiler);
+        tool.arg("--sysroot").arg(builder.sysroot(compiler));
+        tool.arg(builder.src.join("src/tools/rustc-fuzz/corpus"));
+
+        builder.info(&format!("Fuzz smoke test stage{} ({})", compiler.stage, compiler.host));
+        let _time = util::timeit(&builder);
+        try_run(builder, &mut tool);
+    }
+}
+
 fn markdown_test(builder: &Builder<'_>, compiler: Compiler, markdown: &Path) -> bool {
     match fs::read_to_string(markdown) {
         Ok(contents) => {
@@ -1841,6 +1920,12 @@ impl Step for RemoteCopyLibs {
         if let Some(rootfs) = builder.qemu_rootfs(target) {
             cmd.arg(rootfs);
         }
+        if let Some(kernel) = builder.qemu_kernel(target) {
+            cmd.env("TEST_QEMU_KERNEL", kernel);
+        }
+        if let Some(initrd) = builder.qemu_initrd(target) {
+            cmd.env("TEST_QEMU_INITRD", initrd);
+        }
         builder.run(&mut cmd);
 
         // Push all our dylibs to the emulator
@@ -1960,3 +2045,359 @@ impl Step for Bootstrap {
         run.builder.ensure(Bootstrap);
     }
 }
+
+/// Checks the assembly that the `core::arch` intrinsics lower to against the
+/// expectations checked in next to the tests, to catch the regressions in their
+/// lowering when LLVM is updated.
+///
+/// The tests of a target are in `src/test/stdarch-asm/<target>`: they are keyed
+/// by the whole target triple rather than by the architecture, as the object
+/// format and the calling convention also show in the assembly.
+/// Each one is a library whose `#[no_mangle]` functions call the intrinsics
+/// being checked, and which lists the target features to build it with in
+/// `// features: ...` headers, one line per entry of the matrix, where an empty
+/// list stands for the default features of the target. A test without headers
+/// is only built with the default features. The assembly
+/// of each build is compared with `<test>.<features>.s`, which `--bless`
+/// updates.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StdarchAsm {
+    compiler: Compiler,
+    target: Interned<String>,
+}
+
+impl Step for StdarchAsm {
+    type Output = ();
+    const DEFAULT: bool = true;
+
+    fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
+        run.path("src/test/stdarch-asm")
+    }
+
+    fn make_run(run: RunConfig<'_>) {
+        let compiler = run.builder.compiler(run.builder.top_stage, run.host);
+        run.builder.ensure(StdarchAsm { compiler, target: run.target });
+    }
+
+    fn run(self, builder: &Builder<'_>) {
+        let compiler = self.compiler;
+        let target = self.target;
+        let arch = target.split('-').next().unwrap();
+        let dir = builder.src.join("src/test/stdarch-asm").join(&*target);
+        if !dir.is_dir() {
+            builder.info(&format!("No stdarch assembly tests for {}, skipping", target));
+            return;
+        }
+
+        builder.ensure(compile::Std { compiler, target });
+        if builder.config.dry_run {
+            return;
+        }
+
+        let comment = match arch {
+            "x86_64" | "i686" | "i586" => "#",
+            "arm" | "armv7" => "@",
+            _ => "//",
+        };
+        let mut files = t!(dir.read_dir())
+            .map(|p| t!(p).path())
+            .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("rs"))
+            .collect::<Vec<_>>();
+        files.sort();
+
+        builder.info(&format!("Checking stdarch assembly stage{} ({})", compiler.stage, target));
+        let _time = util::timeit(&builder);
+
+        let out_dir = testdir(builder, compiler.host).join("stdarch-asm");
+        t!(fs::create_dir_all(&out_dir));
+        let bless = builder.config.cmd.bless();
+        let mut checked = 0;
+        let mut mismatches = Vec::new();
+        for file in &files {
+            let src = t!(fs::read_to_string(file));
+            let stem = file.file_stem().unwrap().to_str().unwrap();
+            let symbols = src
+                .lines()
+                .zip(src.lines().skip(1))
+                .filter(|(attr, _)| attr.trim() == "#[no_mangle]")
+                .filter_map(|(_, item)| {
+                    let name = item.split("fn ").nth(1)?.split(|c| c == '(' || c == '<').next()?;
+                    Some(name.trim().to_string())
+                })
+                .collect::<Vec<_>>();
+            let mut matrix = src
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| l.starts_with("// features:"))
+                .map(|l| l["// features:".len()..].trim().to_string())
+                .collect::<Vec<_>>();
+            if matrix.is_empty() {
+                matrix.push(String::new());
+            }
+
+            for features in &matrix {
+                let name = if features.is_empty() {
+                    "default".to_string()
+                } else {
+                    features.replace('+', "").replace(',', "-")
+                };
+                let asm = out_dir.join(format!("{}.{}.s", stem, name));
+                let mut cmd = Command::new(builder.rustc(compiler));
+                cmd.env("RUSTC_BOOTSTRAP", "1")
+                    .arg("--target")
+                    .arg(target)
+                    .arg("--crate-type=lib")
+                    .arg("--emit=asm")
+                    .arg("-Copt-level=3")
+                    .arg("-Ccodegen-units=1")
+                    .arg("-Cpanic=abort")
+                    .arg("-o")
+                    .arg(&asm)
+                    .arg(file);
+                if !features.is_empty() {
+                    cmd.arg(format!("-Ctarget-feature={}", features));
+                }
+                util::add_lib_path(vec![builder.rustc_libdir(compiler)], &mut cmd);
+                try_run(builder, &mut cmd);
+
+                let actual = normalize_asm(&t!(fs::read_to_string(&asm)), &symbols, comment);
+                let expected_path = dir.join(format!("{}.{}.s", stem, name));
+                let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+                checked += 1;
+                if actual == expected {
+                    continue;
+                }
+                if bless {
+                    t!(fs::write(&expected_path, &actual));
+                    continue;
+                }
+                let path = file.strip_prefix(&builder.src).unwrap_or(file);
+                println!("\n{} with `{}`:", path.display(), name);
+                println!("--- expected\n{}--- actual\n{}", expected, actual);
+                mismatches.push(format!("{}.{}", stem, name));
+            }
+        }
+
+        println!("checked the assembly of {} builds, {} differ", checked, mismatches.len());
+        if !mismatches.is_empty() {
+            let message = format!(
+                "the assembly of the intrinsics differs from the expectations in {}: {}\n\
+                 run with `--bless` to update the expectations if the change is intended",
+                dir.display(),
+                mismatches.join(", ")
+            );
+            if builder.fail_fast {
+                panic!("{}", message);
+            }
+            builder.delayed_failures.borrow_mut().push(message);
+        }
+    }
+}
+
+/// Extracts the bodies of the functions named `symbols` from the assembly
+/// `asm`, and normalizes them so that they only change with the instructions:
+/// the comments, which start with `comment`, and the directives are removed,
+/// the whitespace is collapsed and the local labels are numbered in the order
+/// of their appearance in each function.
+fn normalize_asm(asm: &str, symbols: &[String], comment: &str) -> String {
+    let mut out = String::new();
+    for symbol in symbols {
+        let label = format!("{}:", symbol);
+        let mut lines = asm.lines().map(|l| l.trim()).skip_while(|l| *l != label);
+        if lines.next().is_none() {
+            out.push_str(&format!("{}: not found\n\n", symbol));
+            continue;
+        }
+        out.push_str(&label);
+        out.push('\n');
+
+        let mut labels: Vec<String> = Vec::new();
+        for line in lines {
+            if line.starts_with(".Lfunc_end") || line == ".cfi_endproc" {
+                break;
+            }
+            let line = line.split(comment).next().unwrap();
+            let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if line.is_empty() || (line.starts_with('.') && !line.ends_with(':')) {
+                continue;
+            }
+            // Rename the local labels, like `.LBB0_2`, which are numbered over
+            // the whole crate.
+            let mut rest = &line[..];
+            out.push_str("    ");
+            while let Some(start) = rest.find(".L") {
+                out.push_str(&rest[..start]);
+                let len = rest[start + 2..]
+                    .find(|c: char| !c.is_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len() - start - 2);
+                let local = &rest[start..start + 2 + len];
+                let idx = match labels.iter().position(|l| l == local) {
+                    Some(idx) => idx,
+                    None => {
+                        labels.push(local.to_string());
+                        labels.len() - 1
+                    }
+                };
+                out.push_str(&format!(".L{}", idx));
+                rest = &rest[start + 2 + len..];
+            }
+            out.push_str(rest);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Compares the toolchain being built with a baseline toolchain, built before
+/// in another slot with `--as-toolchain`.
+///
+/// Each test is compiled by the rustc of both toolchains and, if that succeeds,
+/// run. A test fails if the exit status or the output of the compiler or of the
+/// program differs between the two toolchains.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompareToolchains {
+    compiler: Compiler,
+    target: Interned<String>,
+}
+
+impl Step for CompareToolchains {
+    type Output = ();
+    const DEFAULT: bool = true;
+    const ONLY_HOSTS: bool = true;
+
+    fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
+        run.suite_path("src/test")
+    }
+
+    fn make_run(run: RunConfig<'_>) {
+        let compiler = run.builder.compiler(run.builder.top_stage, run.host);
+        run.builder.ensure(CompareToolchains { compiler, target: run.target });
+    }
+
+    fn run(self, builder: &Builder<'_>) {
+        let compiler = self.compiler;
+        let target = self.target;
+        let (paths, baseline) = match builder.config.cmd {
+            Subcommand::CompareToolchains { ref paths, ref baseline } => (paths, baseline),
+            _ => unreachable!(),
+        };
+        if builder.config.toolchain.as_ref() == Some(baseline) {
+            panic!("cannot compare the `{}` toolchain with itself", baseline);
+        }
+
+        builder.ensure(compile::Std { compiler, target });
+
+        let sysroot = builder
+            .toolchain_out(compiler.host, Some(baseline))
+            .join(format!("stage{}", compiler.stage));
+        let baseline_rustc = sysroot.join("bin").join(util::exe("rustc", &compiler.host));
+        let baseline_libdir = sysroot.join(builder.libdir_relative(compiler));
+        let toolchain = builder.config.toolchain.as_ref().map_or("default", |s| &s[..]);
+        let toolchains = [
+            ("current", builder.rustc(compiler), builder.rustc_libdir(compiler)),
+            ("baseline", baseline_rustc, baseline_libdir),
+        ];
+
+        if builder.config.dry_run {
+            return;
+        }
+        if !toolchains[1].1.exists() {
+            panic!(
+                "the stage{} compiler of the `{}` toolchain was not found at {}, \
+                 build it first with `./x.py build --stage {} --as-toolchain {}`",
+                compiler.stage,
+                baseline,
+                toolchains[1].1.display(),
+                compiler.stage,
+                baseline,
+            );
+        }
+
+        // Collect all the test files in the given paths, skipping the auxiliary
+        // crates which are not tests on their own.
+        let mut stack = paths.iter().map(|p| builder.src.join(p)).collect::<Vec<_>>();
+        let mut files = Vec::new();
+        while let Some(p) = stack.pop() {
+            if p.is_dir() {
+                if p.file_name().and_then(|s| s.to_str()) != Some("auxiliary") {
+                    stack.extend(t!(p.read_dir()).map(|p| t!(p).path()));
+                }
+                continue;
+            }
+            if p.extension().and_then(|s| s.to_str()) == Some("rs") {
+                files.push(p);
+            }
+        }
+        files.sort();
+        files.dedup();
+
+        builder.info(&format!(
+            "Comparing stage{} toolchains `{}` and `{}` ({} -> {})",
+            compiler.stage, toolchain, baseline, compiler.host, target
+        ));
+        let _time = util::timeit(&builder);
+
+        let out_dir = testdir(builder, compiler.host).join("compare-toolchains");
+        let mut differences = 0;
+        for file in &files {
+            let name = file.strip_prefix(&builder.src).unwrap_or(file);
+            let outputs = toolchains
+                .iter()
+                .map(|(slot, rustc, libdir)| {
+                    let dir = out_dir.join(slot);
+                    t!(fs::create_dir_all(&dir));
+                    let exe = dir.join(util::exe(
+                        &name.to_string_lossy().replace(&['/', '\\', '.'][..], "-"),
+                        &target,
+                    ));
+                    let _ = fs::remove_file(&exe);
+
+                    let mut cmd = Command::new(rustc);
+                    cmd.env("RUSTC_BOOTSTRAP", "1")
+                        .arg("--target")
+                        .arg(target)
+                        .arg("-o")
+                        .arg(&exe)
+                        .arg(file);
+                    util::add_lib_path(vec![libdir.clone()], &mut cmd);
+                    let compiled = t!(cmd.output());
+                    let ran = if compiled.status.success() && exe.exists() {
+                        Some(t!(Command::new(&exe).current_dir(&dir).output()))
+                    } else {
+                        None
+                    };
+                    (compiled, ran)
+                })
+                .collect::<Vec<_>>();
+
+            let describe = |output: &std::process::Output| {
+                (output.status.code(), output.stdout.clone(), output.stderr.clone())
+            };
+            let (current, base) = (&outputs[0], &outputs[1]);
+            let compiler_differs = describe(&current.0) != describe(&base.0);
+            let program_differs = current.1.as_ref().map(describe) != base.1.as_ref().map(describe);
+            if compiler_differs || program_differs {
+                differences += 1;
+                let what = if compiler_differs {
+                    "output of the compiler"
+                } else {
+                    "behavior of the test"
+                };
+                println!("{}: the {} differs between the toolchains", name.display(), what);
+            }
+        }
+
+        println!("compared {} tests, {} differ", files.len(), differences);
+        if differences > 0 {
+            let message = format!(
+                "{} tests behave differently with the `{}` and `{}` toolchains",
+                differences, toolchain, baseline
+            );
+            if builder.fail_fast {
+                panic!("{}", message);
+            }
+            builder.delayed_failures.borrow_mut().push(message);
+        }
+    }
+}
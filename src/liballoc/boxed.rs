@@ -138,7 +138,8 @@ use core::iter::{FromIterator, FusedIterator, Iterator};
 use core::marker::{Unpin, Unsize};
 use core::mem;
 use core::ops::{
-    CoerceUnsized, Deref, DerefMut, DispatchFromDyn, Generator, GeneratorState, Receiver,
+    CoerceUnsized, Deref, DerefMut, DerefPure, DispatchFromDyn, Generator, GeneratorState,
+    Receiver,
 };
 use core::pin::Pin;
 use core::ptr::{self, NonNull, Unique};
@@ -433,8 +434,9 @@ impl<T: ?Sized> Box<T> {
     /// [memory layout]: index.html#memory-layout
     /// [`Box::from_raw`]: struct.Box.html#method.from_raw
     #[stable(feature = "box_raw", since = "1.4.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
     #[inline]
-    pub fn into_raw(b: Box<T>) -> *mut T {
+    pub const fn into_raw(b: Box<T>) -> *mut T {
         Box::into_raw_non_null(b).as_ptr()
     }
 
@@ -467,16 +469,19 @@ impl<T: ?Sized> Box<T> {
     /// let x = unsafe { Box::from_raw(ptr.as_ptr()) };
     /// ```
     #[unstable(feature = "box_into_raw_non_null", issue = "47336")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
     #[inline]
-    pub fn into_raw_non_null(b: Box<T>) -> NonNull<T> {
-        Box::into_unique(b).into()
+    pub const fn into_raw_non_null(b: Box<T>) -> NonNull<T> {
+        // Not `into`, as trait methods cannot be called in constants.
+        unsafe { NonNull::new_unchecked(Box::into_unique(b).as_ptr()) }
     }
 
     #[unstable(feature = "ptr_internals", issue = "none", reason = "use into_raw_non_null instead")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
     #[inline]
     #[doc(hidden)]
-    pub fn into_unique(b: Box<T>) -> Unique<T> {
-        let mut unique = b.0;
+    pub const fn into_unique(b: Box<T>) -> Unique<T> {
+        let unique = b.0;
         mem::forget(b);
         // Box is kind-of a library type, but recognized as a "unique pointer" by
         // Stacked Borrows.  This function here corresponds to "reborrowing to
@@ -484,7 +489,7 @@ impl<T: ?Sized> Box<T> {
         // without some care, the pointer we are returning here still carries
         // the tag of `b`, with `Unique` permission.
         // We round-trip through a mutable reference to avoid that.
-        unsafe { Unique::new_unchecked(unique.as_mut() as *mut T) }
+        unsafe { Unique::new_unchecked(&mut *unique.as_ptr() as *mut T) }
     }
 
     /// Consumes and leaks the `Box`, returning a mutable reference,
@@ -503,6 +508,9 @@ impl<T: ?Sized> Box<T> {
     /// to call it as `Box::leak(b)` instead of `b.leak()`. This
     /// is so that there is no conflict with a method on the inner type.
     ///
+    /// With `#![feature(const_heap)]`, boxes can also be created and leaked
+    /// in constants, whose final value then holds the leaked memory.
+    ///
     /// [`Box::from_raw`]: struct.Box.html#method.from_raw
     ///
     /// # Examples
@@ -525,8 +533,9 @@ impl<T: ?Sized> Box<T> {
     /// assert_eq!(*static_ref, [4, 2, 3]);
     /// ```
     #[stable(feature = "box_leak", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
     #[inline]
-    pub fn leak<'a>(b: Box<T>) -> &'a mut T
+    pub const fn leak<'a>(b: Box<T>) -> &'a mut T
     where
         T: 'a, // Technically not needed, but kept to be explicit.
     {
@@ -939,6 +948,9 @@ impl<T: ?Sized> DerefMut for Box<T> {
     }
 }
 
+#[unstable(feature = "deref_pure_trait", issue = "none")]
+unsafe impl<T: ?Sized> DerefPure for Box<T> {}
+
 #[unstable(feature = "receiver_trait", issue = "none")]
 impl<T: ?Sized> Receiver for Box<T> {}
 
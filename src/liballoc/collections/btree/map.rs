@@ -119,6 +119,14 @@ use UnderflowResult::*;
 /// let stat = player_stats.entry("attack").or_insert(100);
 /// *stat += random_stat_buff();
 /// ```
+#[cfg_attr(
+    not(bootstrap),
+    rustc_on_misuse(on(
+        op = "index",
+        note = "`{Self}` is indexed by a reference to any borrowed form of its key type `{K}`, \
+                such as `&{K}`"
+    ))
+)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct BTreeMap<K, V> {
     root: node::Root<K, V>,
@@ -566,6 +566,9 @@ use crate::string;
 /// [`format!`]: ../../std/macro.format.html
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn format(args: Arguments<'_>) -> string::String {
+    if let Some(s) = args.as_statically_known_str() {
+        return string::String::from(s);
+    }
     let capacity = args.estimated_capacity();
     let mut output = string::String::with_capacity(capacity);
     output.write_fmt(args).expect("a formatting trait implementation returned an error");
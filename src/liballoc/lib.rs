@@ -81,16 +81,23 @@
 #![feature(box_syntax)]
 #![feature(cfg_target_has_atomic)]
 #![feature(coerce_unsized)]
+#![feature(const_forget)]
 #![feature(const_generic_impls_guard)]
 #![feature(const_generics)]
 #![feature(const_in_array_repeat_expressions)]
 #![feature(const_if_match)]
+#![feature(const_mut_refs)]
+#![feature(const_raw_ptr_deref)]
 #![feature(cow_is_borrowed)]
 #![feature(dispatch_from_dyn)]
 #![feature(core_intrinsics)]
 #![feature(container_error_extra)]
+#![feature(deref_pure_trait)]
 #![feature(dropck_eyepatch)]
+#![cfg_attr(not(bootstrap), feature(error_generic_member_access))]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
 #![feature(exact_size_is_empty)]
+#![feature(fmt_as_str)]
 #![feature(fmt_internals)]
 #![feature(fn_traits)]
 #![feature(fundamental)]
@@ -151,6 +158,8 @@ mod boxed {
 }
 pub mod borrow;
 pub mod collections;
+#[cfg(not(any(bootstrap, test)))]
+mod error;
 pub mod fmt;
 pub mod prelude;
 pub mod raw_vec;
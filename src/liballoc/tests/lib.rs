@@ -12,6 +12,10 @@
 #![feature(binary_heap_into_iter_sorted)]
 #![feature(binary_heap_drain_sorted)]
 #![feature(vec_remove_item)]
+#![feature(deque_make_contiguous)]
+#![feature(vecdeque_binary_search)]
+#![feature(round_char_boundary)]
+#![feature(str_from_raw_parts)]
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -715,6 +715,92 @@ fn test_is_char_boundary() {
     }
 }
 
+#[test]
+fn test_floor_char_boundary() {
+    fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(
+                s.floor_char_boundary(idx),
+                ret,
+                "{:?}.floor_char_boundary({:?}) != {:?}",
+                s,
+                idx,
+                ret
+            );
+        }
+    }
+
+    // edge case
+    check_many("", [0, 1, isize::max_value() as usize, usize::max_value()].iter().cloned(), 0);
+
+    // basic check
+    check_many("x", [0].iter().cloned(), 0);
+    check_many("x", [1, isize::max_value() as usize, usize::max_value()].iter().cloned(), 1);
+
+    // 1-byte chars
+    check_many("jp", [0].iter().cloned(), 0);
+    check_many("jp", [1].iter().cloned(), 1);
+    check_many("jp", 2..4, 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..2, 0);
+    check_many("ĵƥ", 2..4, 2);
+    check_many("ĵƥ", 4..6, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..3, 0);
+    check_many("日本", 3..6, 3);
+    check_many("日本", 6..8, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..4, 0);
+    check_many("🇯🇵", 4..8, 4);
+    check_many("🇯🇵", 8..10, 8);
+}
+
+#[test]
+fn test_ceil_char_boundary() {
+    fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(
+                s.ceil_char_boundary(idx),
+                ret,
+                "{:?}.ceil_char_boundary({:?}) != {:?}",
+                s,
+                idx,
+                ret
+            );
+        }
+    }
+
+    // edge case
+    check_many("", [0, 1, usize::max_value()].iter().cloned(), 0);
+
+    // basic check
+    check_many("x", [0].iter().cloned(), 0);
+    check_many("x", [1, usize::max_value()].iter().cloned(), 1);
+
+    // 1-byte chars
+    check_many("jp", [0].iter().cloned(), 0);
+    check_many("jp", [1].iter().cloned(), 1);
+    check_many("jp", [2].iter().cloned(), 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..=0, 0);
+    check_many("ĵƥ", 1..=2, 2);
+    check_many("ĵƥ", 3..=4, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..=0, 0);
+    check_many("日本", 1..=3, 3);
+    check_many("日本", 4..=6, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..=0, 0);
+    check_many("🇯🇵", 1..=4, 4);
+    check_many("🇯🇵", 5..=8, 8);
+}
+
 #[test]
 fn test_trim_start_matches() {
     let v: &[char] = &[];
@@ -836,6 +922,22 @@ fn from_utf8_mostly_ascii() {
     }
 }
 
+#[test]
+fn test_from_raw_parts() {
+    use std::ptr::NonNull;
+    use std::str::{from_raw_parts, from_raw_parts_mut};
+
+    let s = "ประเทศไทย中华Việt Nam";
+    assert_eq!(unsafe { from_raw_parts(s.as_ptr(), s.len()) }, s);
+    assert_eq!(unsafe { from_raw_parts(s.as_ptr().add(27), 6) }, "中华");
+    assert_eq!(unsafe { from_raw_parts(NonNull::dangling().as_ptr(), 0) }, "");
+
+    let mut owned = String::from("hello world");
+    let tail = unsafe { from_raw_parts_mut(owned.as_mut_ptr().add(6), 5) };
+    tail.make_ascii_uppercase();
+    assert_eq!(owned, "hello WORLD");
+}
+
 #[test]
 fn from_utf8_error() {
     macro_rules! test {
@@ -1475,6 +1475,69 @@ fn test_rotate_right_random() {
     }
 }
 
+#[test]
+fn test_make_contiguous() {
+    // Start from every possible position of the front within the buffer, for every length.
+    let cap = VecDeque::<usize>::with_capacity(15).capacity();
+    for len in 0..=cap {
+        for start in 0..=cap {
+            let mut v = VecDeque::with_capacity(15);
+            for _ in 0..start {
+                v.push_back(0);
+                v.pop_front();
+            }
+            v.extend(0..len);
+            assert_eq!(v.capacity(), cap);
+
+            let expected: Vec<_> = (0..len).collect();
+            assert_eq!(v.make_contiguous(), &expected[..]);
+            assert_eq!(v.as_slices(), (&expected[..], &[][..]));
+
+            // The deque keeps working once its storage was rearranged.
+            v.push_back(len);
+            v.push_front(usize::max_value());
+            assert_eq!(v.len(), len + 2);
+            assert_eq!(v.pop_front(), Some(usize::max_value()));
+            assert_eq!(v.pop_back(), Some(len));
+            assert!(v.iter().cloned().eq(0..len));
+        }
+    }
+}
+
+#[test]
+fn test_binary_search() {
+    // Contiguous (front only) search:
+    let deque: VecDeque<_> = vec![1, 2, 3, 5, 6].into();
+    assert!(deque.as_slices().1.is_empty());
+    assert_eq!(deque.binary_search(&3), Ok(2));
+    assert_eq!(deque.binary_search(&4), Err(3));
+
+    // Split search (both front & back non-empty):
+    let mut deque = VecDeque::with_capacity(8);
+    deque.push_back(5);
+    deque.push_back(6);
+    deque.push_front(3);
+    deque.push_front(2);
+    deque.push_front(1);
+    deque.push_back(10);
+    assert!(!deque.as_slices().0.is_empty());
+    assert!(!deque.as_slices().1.is_empty());
+
+    assert_eq!(deque.binary_search(&0), Err(0));
+    assert_eq!(deque.binary_search(&1), Ok(0));
+    assert_eq!(deque.binary_search(&5), Ok(3));
+    assert_eq!(deque.binary_search(&7), Err(5));
+    assert_eq!(deque.binary_search(&20), Err(6));
+}
+
+#[test]
+fn test_binary_search_by_key() {
+    let deque: VecDeque<_> = vec![(0, 'a'), (1, 'b'), (2, 'c')].into();
+    assert_eq!(deque.binary_search_by_key(&1, |&(n, _)| n), Ok(1));
+    assert_eq!(deque.binary_search_by_key(&3, |&(n, _)| n), Err(3));
+    assert_eq!(deque.binary_search_by(|&(_, c)| c.cmp(&'c')), Ok(2));
+}
+
 #[test]
 fn test_try_fold_empty() {
     assert_eq!(Some(0), VecDeque::<u32>::new().iter().try_fold(0, |_, _| None));
@@ -1905,6 +1905,9 @@ impl<T> ops::Deref for Vec<T> {
     }
 }
 
+#[unstable(feature = "deref_pure_trait", issue = "none")]
+unsafe impl<T> ops::DerefPure for Vec<T> {}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> ops::DerefMut for Vec<T> {
     fn deref_mut(&mut self) -> &mut [T] {
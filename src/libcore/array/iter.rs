@@ -77,6 +77,15 @@ where
         Self { data, alive: 0..N }
     }
 
+    /// Creates an iterator over the elements `data[alive]`.
+    ///
+    /// # Safety
+    ///
+    /// The elements `data[alive]` must be initialized, and `alive` must be in bounds.
+    pub(crate) unsafe fn new_unchecked(data: [MaybeUninit<T>; N], alive: Range<usize>) -> Self {
+        Self { data, alive }
+    }
+
     /// Returns an immutable slice of all elements that have not been yielded
     /// yet.
     fn as_slice(&self) -> &[T] {
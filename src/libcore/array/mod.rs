@@ -12,6 +12,8 @@ use crate::convert::{Infallible, TryFrom};
 use crate::fmt;
 use crate::hash::{self, Hash};
 use crate::marker::Unsize;
+use crate::mem::{self, MaybeUninit};
+use crate::ptr;
 use crate::slice::{Iter, IterMut};
 
 mod iter;
@@ -91,6 +93,122 @@ impl From<Infallible> for TryFromSliceError {
     }
 }
 
+/// Creates an array `[T; N]` whose element at each index `i` is the value of `cb(i)`, unless
+/// `cb` returns an error.
+///
+/// The elements are created in order, starting at index 0. The first error that `cb` returns is
+/// returned as is, after dropping the elements created so far, and `cb` is not called again.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_try_from_fn)]
+/// use std::array;
+///
+/// let words = ["1", "2", "3"];
+/// let numbers: Result<[u32; 3], _> = array::try_from_fn(|i| words[i].parse());
+/// assert_eq!(numbers, Ok([1, 2, 3]));
+///
+/// let words = ["1", "two", "3"];
+/// let numbers: Result<[u32; 3], _> = array::try_from_fn(|i| words[i].parse());
+/// assert!(numbers.is_err());
+/// ```
+#[unstable(feature = "array_try_from_fn", issue = "none")]
+pub fn try_from_fn<T, E, F, const N: usize>(mut cb: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut guard = Guard::<T, { N }>::new();
+    while guard.initialized < N {
+        let elem = cb(guard.initialized)?;
+        // SAFETY: the loop condition checks that the array is not fully initialized.
+        unsafe { guard.push(elem) };
+    }
+    // SAFETY: the loop initialized all the elements.
+    Ok(unsafe { guard.into_array() })
+}
+
+/// Pulls the next `N` items of `iter` into an array, or returns an iterator over the items that
+/// it pulled if `iter` runs out first. This implements `Iterator::next_chunk`.
+pub(crate) fn iter_next_chunk<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, { N }>>
+where
+    I: Iterator,
+    [I::Item; N]: LengthAtMost32,
+{
+    let mut guard = Guard::<I::Item, { N }>::new();
+    while guard.initialized < N {
+        match iter.next() {
+            // SAFETY: the loop condition checks that the array is not fully initialized.
+            Some(item) => unsafe { guard.push(item) },
+            None => return Err(guard.into_iter()),
+        }
+    }
+    // SAFETY: the loop initialized all the elements.
+    Ok(unsafe { guard.into_array() })
+}
+
+/// An array being initialized in order, which drops the elements that are initialized if it is
+/// dropped itself, after an error or a panic.
+struct Guard<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    /// The number of elements at the start of `data` that are initialized.
+    initialized: usize,
+}
+
+impl<T, const N: usize> Guard<T, { N }> {
+    fn new() -> Self {
+        // SAFETY: an array of `MaybeUninit`s does not need to be initialized.
+        Guard { data: unsafe { MaybeUninit::uninit().assume_init() }, initialized: 0 }
+    }
+
+    /// Initializes the next element of the array.
+    ///
+    /// # Safety
+    ///
+    /// The array must not be fully initialized yet.
+    unsafe fn push(&mut self, elem: T) {
+        *self.data.get_unchecked_mut(self.initialized) = MaybeUninit::new(elem);
+        self.initialized += 1;
+    }
+
+    /// Returns the array.
+    ///
+    /// # Safety
+    ///
+    /// The array must be fully initialized.
+    unsafe fn into_array(self) -> [T; N] {
+        // FIXME: use `mem::transmute` once it works with const generics, see `IntoIter::new`.
+        let array = ptr::read(&self.data as *const [MaybeUninit<T>; N] as *const [T; N]);
+        mem::forget(self);
+        array
+    }
+
+    /// Returns an iterator over the initialized elements.
+    fn into_iter(self) -> IntoIter<T, { N }>
+    where
+        [T; N]: LengthAtMost32,
+    {
+        let alive = 0..self.initialized;
+        // SAFETY: `self` is forgotten right after, so the elements are only owned by the copy.
+        let data = unsafe { ptr::read(&self.data) };
+        mem::forget(self);
+        // SAFETY: the first `alive.end` elements are initialized.
+        unsafe { IntoIter::new_unchecked(data, alive) }
+    }
+}
+
+impl<T, const N: usize> Drop for Guard<T, { N }> {
+    fn drop(&mut self) {
+        // SAFETY: the first `initialized` elements are initialized, and are not used again.
+        unsafe {
+            let initialized = self.data.get_unchecked_mut(..self.initialized);
+            ptr::drop_in_place(initialized as *mut [MaybeUninit<T>] as *mut [T]);
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, const N: usize> AsRef<[T]> for [T; N]
 where
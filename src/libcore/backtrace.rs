@@ -0,0 +1,81 @@
+//! The `Backtrace` type that errors return from [`Error::backtrace`].
+//!
+//! `core` cannot capture a backtrace, so a `Backtrace` only owns a capture
+//! that `std` made. `std` provides the constructors and the accessors of the
+//! type, see `std::backtrace`, and the functions formatting and dropping the
+//! capture through the `BacktraceVTable` stored next to it.
+//!
+//! [`Error::backtrace`]: ../error/trait.Error.html#method.backtrace
+
+#![unstable(feature = "backtrace", issue = "53487")]
+
+use crate::fmt;
+
+/// A captured OS thread stack backtrace.
+///
+/// This type represents a stack backtrace for an OS thread captured at a
+/// previous point in time. It is re-exported as `std::backtrace::Backtrace`,
+/// which provides the functions to capture one.
+#[lang = "backtrace"]
+pub struct Backtrace {
+    data: *mut (),
+    vtable: &'static BacktraceVTable,
+}
+
+/// The functions with which `std` formats and drops the capture of a
+/// `Backtrace`.
+#[doc(hidden)]
+#[unstable(feature = "backtrace_internals", issue = "none")]
+pub struct BacktraceVTable {
+    /// Formats the capture at the given address, for both `Debug` and `Display`.
+    pub fmt: unsafe fn(*mut (), &mut fmt::Formatter<'_>) -> fmt::Result,
+    /// Drops the capture at the given address.
+    pub drop: unsafe fn(*mut ()),
+}
+
+// SAFETY: `from_raw_parts` requires the capture to be `Send` and `Sync`.
+unsafe impl Send for Backtrace {}
+// SAFETY: `from_raw_parts` requires the capture to be `Send` and `Sync`.
+unsafe impl Sync for Backtrace {}
+
+impl Backtrace {
+    /// Creates a backtrace owning the capture at `data`, which the functions
+    /// of `vtable` format and drop.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to a capture that is `Send` and `Sync`, and that the
+    /// functions of `vtable` can use until its `drop` is called.
+    #[doc(hidden)]
+    #[unstable(feature = "backtrace_internals", issue = "none")]
+    pub unsafe fn from_raw_parts(data: *mut (), vtable: &'static BacktraceVTable) -> Backtrace {
+        Backtrace { data, vtable }
+    }
+
+    /// Returns the address of the capture owned by this backtrace.
+    #[doc(hidden)]
+    #[unstable(feature = "backtrace_internals", issue = "none")]
+    pub fn as_raw(&self) -> *mut () {
+        self.data
+    }
+}
+
+impl Drop for Backtrace {
+    fn drop(&mut self) {
+        // SAFETY: the capture is dropped only once, and not used afterwards.
+        unsafe { (self.vtable.drop)(self.data) }
+    }
+}
+
+impl fmt::Debug for Backtrace {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: the capture is valid until the backtrace is dropped.
+        unsafe { (self.vtable.fmt)(self.data, fmt) }
+    }
+}
+
+impl fmt::Display for Backtrace {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, fmt)
+    }
+}
@@ -561,7 +561,9 @@ where
 
 // From (and thus Into) is reflexive
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> From<T> for T {
+impl<T> const From<T> for T {
+    // Const so that `?` can be used in const fns when the error types are the same.
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn from(t: T) -> T {
         t
     }
@@ -81,6 +81,7 @@
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_diagnostic_item = "Default"]
 pub trait Default: Sized {
     /// Returns the "default value" for a type.
     ///
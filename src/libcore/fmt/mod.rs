@@ -355,6 +355,44 @@ impl<'a> Arguments<'a> {
             pieces_length.checked_mul(2).unwrap_or(0)
         }
     }
+
+    /// Gets the formatted string, if it is known at compile time.
+    ///
+    /// This is the case when the format string has no placeholders, or when its only
+    /// arguments are string literals formatted with `{}`, which `format_args!` writes into
+    /// the format string. Code that formats the arguments can then skip the formatting
+    /// machinery entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(fmt_as_str)]
+    ///
+    /// use std::fmt::Arguments;
+    ///
+    /// fn write_str(args: Arguments<'_>) -> String {
+    ///     match args.as_statically_known_str() {
+    ///         Some(s) => s.to_string(),
+    ///         None => std::fmt::format(args),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(format_args!("hello").as_statically_known_str(), Some("hello"));
+    /// assert_eq!(format_args!("{} {}", "hello", "world").as_statically_known_str(),
+    ///            Some("hello world"));
+    /// assert_eq!(format_args!("{:?}", "hello").as_statically_known_str(), None);
+    /// assert_eq!(format_args!("{}", 1).as_statically_known_str(), None);
+    /// assert_eq!(write_str(format_args!("{}", 1)), "1");
+    /// ```
+    #[unstable(feature = "fmt_as_str", issue = "none")]
+    #[inline]
+    pub fn as_statically_known_str(&self) -> Option<&'a str> {
+        match (self.pieces, self.args) {
+            ([], []) => Some(""),
+            ([s], []) => Some(s),
+            _ => None,
+        }
+    }
 }
 
 /// This structure represents a safely precompiled version of a format string
@@ -1488,7 +1526,10 @@ impl<'a> Formatter<'a> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn write_fmt(&mut self, fmt: Arguments<'_>) -> Result {
-        write(self.buf, fmt)
+        match fmt.as_statically_known_str() {
+            Some(s) => self.buf.write_str(s),
+            None => write(self.buf, fmt),
+        }
     }
 
     /// Flags for formatting
@@ -717,8 +717,18 @@ extern "rust-intrinsic" {
     #[rustc_const_unstable(feature = "const_type_id", issue = "none")]
     pub fn type_id<T: ?Sized + 'static>() -> u64;
 
+    /// Returns the number of variants of the type `T` cast to a `usize`;
+    /// if `T` has no variants, returns 0. Uninhabited variants will be counted.
+    ///
+    /// The to-be-stabilized version of this intrinsic is
+    /// [`std::mem::variant_count`](../../std/mem/fn.variant_count.html).
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "variant_count", issue = "none")]
+    pub fn variant_count<T>() -> usize;
+
     /// A guard for unsafe functions that cannot ever be executed if `T` is uninhabited:
     /// This will statically either panic, or do nothing.
+    #[rustc_const_unstable(feature = "const_maybe_uninit_assume_init", issue = "none")]
     pub fn panic_if_uninhabited<T>();
 
     /// Gets a reference to a static `Location` indicating where it was called.
@@ -993,6 +1003,7 @@ extern "rust-intrinsic" {
     /// byte past the end of an allocated object. If either pointer is out of
     /// bounds or arithmetic overflow occurs then any further use of the
     /// returned value will result in undefined behavior.
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     pub fn offset<T>(dst: *const T, offset: isize) -> *const T;
 
     /// Calculates the offset from a pointer, potentially wrapping.
@@ -1006,6 +1017,7 @@ extern "rust-intrinsic" {
     /// resulting pointer to point into or one byte past the end of an allocated
     /// object, and it wraps with two's complement arithmetic. The resulting
     /// value is not necessarily valid to be used to actually access memory.
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     pub fn arith_offset<T>(dst: *const T, offset: isize) -> *const T;
 
     /// Equivalent to the appropriate `llvm.memcpy.p0i8.0i8.*` intrinsic, with
@@ -1047,8 +1059,10 @@ extern "rust-intrinsic" {
     pub fn unaligned_volatile_store<T>(dst: *mut T, val: T);
 
     /// Returns the square root of an `f32`
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn sqrtf32(x: f32) -> f32;
     /// Returns the square root of an `f64`
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn sqrtf64(x: f64) -> f64;
 
     /// Raises an `f32` to an integer power.
@@ -1097,59 +1111,81 @@ extern "rust-intrinsic" {
     pub fn log2f64(x: f64) -> f64;
 
     /// Returns `a * b + c` for `f32` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn fmaf32(a: f32, b: f32, c: f32) -> f32;
     /// Returns `a * b + c` for `f64` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn fmaf64(a: f64, b: f64, c: f64) -> f64;
 
     /// Returns the absolute value of an `f32`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn fabsf32(x: f32) -> f32;
     /// Returns the absolute value of an `f64`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn fabsf64(x: f64) -> f64;
 
     /// Returns the minimum of two `f32` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn minnumf32(x: f32, y: f32) -> f32;
     /// Returns the minimum of two `f64` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn minnumf64(x: f64, y: f64) -> f64;
     /// Returns the maximum of two `f32` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn maxnumf32(x: f32, y: f32) -> f32;
     /// Returns the maximum of two `f64` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn maxnumf64(x: f64, y: f64) -> f64;
 
     /// Copies the sign from `y` to `x` for `f32` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn copysignf32(x: f32, y: f32) -> f32;
     /// Copies the sign from `y` to `x` for `f64` values.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn copysignf64(x: f64, y: f64) -> f64;
 
     /// Returns the largest integer less than or equal to an `f32`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn floorf32(x: f32) -> f32;
     /// Returns the largest integer less than or equal to an `f64`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn floorf64(x: f64) -> f64;
 
     /// Returns the smallest integer greater than or equal to an `f32`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn ceilf32(x: f32) -> f32;
     /// Returns the smallest integer greater than or equal to an `f64`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn ceilf64(x: f64) -> f64;
 
     /// Returns the integer part of an `f32`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn truncf32(x: f32) -> f32;
     /// Returns the integer part of an `f64`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn truncf64(x: f64) -> f64;
 
     /// Returns the nearest integer to an `f32`. May raise an inexact floating-point exception
     /// if the argument is not an integer.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn rintf32(x: f32) -> f32;
     /// Returns the nearest integer to an `f64`. May raise an inexact floating-point exception
     /// if the argument is not an integer.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn rintf64(x: f64) -> f64;
 
     /// Returns the nearest integer to an `f32`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn nearbyintf32(x: f32) -> f32;
     /// Returns the nearest integer to an `f64`.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn nearbyintf64(x: f64) -> f64;
 
     /// Returns the nearest integer to an `f32`. Rounds half-way cases away from zero.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn roundf32(x: f32) -> f32;
     /// Returns the nearest integer to an `f64`. Rounds half-way cases away from zero.
+    #[rustc_const_unstable(feature = "const_float_intrinsics", issue = "none")]
     pub fn roundf64(x: f64) -> f64;
 
     /// Float addition that allows optimizations based on algebraic rules.
@@ -1305,9 +1341,11 @@ extern "rust-intrinsic" {
 
     /// Performs an unchecked division, resulting in undefined behavior
     /// where y = 0 or x = `T::min_value()` and y = -1
+    #[rustc_const_unstable(feature = "const_int_unchecked_arith", issue = "none")]
     pub fn unchecked_div<T>(x: T, y: T) -> T;
     /// Returns the remainder of an unchecked division, resulting in
     /// undefined behavior where y = 0 or x = `T::min_value()` and y = -1
+    #[rustc_const_unstable(feature = "const_int_unchecked_arith", issue = "none")]
     pub fn unchecked_rem<T>(x: T, y: T) -> T;
 
     /// Performs an unchecked left shift, resulting in undefined behavior when
@@ -1321,14 +1359,17 @@ extern "rust-intrinsic" {
 
     /// Returns the result of an unchecked addition, resulting in
     /// undefined behavior when `x + y > T::max_value()` or `x + y < T::min_value()`.
+    #[rustc_const_unstable(feature = "const_int_unchecked_arith", issue = "none")]
     pub fn unchecked_add<T>(x: T, y: T) -> T;
 
     /// Returns the result of an unchecked subtraction, resulting in
     /// undefined behavior when `x - y > T::max_value()` or `x - y < T::min_value()`.
+    #[rustc_const_unstable(feature = "const_int_unchecked_arith", issue = "none")]
     pub fn unchecked_sub<T>(x: T, y: T) -> T;
 
     /// Returns the result of an unchecked multiplication, resulting in
     /// undefined behavior when `x * y > T::max_value()` or `x * y < T::min_value()`.
+    #[rustc_const_unstable(feature = "const_int_unchecked_arith", issue = "none")]
     pub fn unchecked_mul<T>(x: T, y: T) -> T;
 
     /// Performs rotate left.
@@ -1399,6 +1440,35 @@ extern "rust-intrinsic" {
     #[rustc_const_unstable(feature = "const_ptr_offset_from", issue = "none")]
     pub fn ptr_offset_from<T>(ptr: *const T, base: *const T) -> isize;
 
+    /// Compares two pointers in a way that is deterministic at compile time.
+    ///
+    /// Returns `1` if the pointers are equal, `0` if they are unequal, and `2` if it cannot
+    /// be known during const evaluation whether they are equal. At runtime the result is
+    /// always `0` or `1`.
+    ///
+    /// This intrinsic is exposed as `<*const T>::guaranteed_eq` and
+    /// `<*const T>::guaranteed_ne`.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "none")]
+    pub fn ptr_guaranteed_cmp<T>(ptr: *const T, other: *const T) -> u8;
+
+    /// Allocates a block of memory at compile time.
+    /// At runtime, just returns a null pointer.
+    ///
+    /// Allocations that are still live at the end of const evaluation become part of the
+    /// final value of the constant.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
+    pub fn const_allocate(size: usize, align: usize) -> *mut u8;
+
+    /// Deallocates a memory which allocated by `intrinsics::const_allocate` at compile time.
+    /// At runtime, does nothing.
+    ///
+    /// `size` and `align` must be the same values that were passed to `const_allocate`.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
+    pub fn const_deallocate(ptr: *mut u8, size: usize, align: usize);
+
     /// Internal hook used by Miri to implement unwinding.
     /// Compiles to a NOP during non-Miri codegen.
     ///
@@ -129,6 +129,38 @@ pub trait Iterator {
     #[stable(feature = "rust1", since = "1.0.0")]
     fn next(&mut self) -> Option<Self::Item>;
 
+    /// Advances the iterator and returns an array containing the next `N` values.
+    ///
+    /// If the iterator runs out before `N` values, `Err` is returned with an iterator over the
+    /// values that were pulled from it, and which are not lost.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// let mut iter = "lorem".chars();
+    ///
+    /// assert_eq!(iter.next_chunk::<2>().unwrap(), ['l', 'o']);
+    /// assert_eq!(iter.next_chunk::<2>().unwrap(), ['r', 'e']);
+    ///
+    /// // Only 'm' is left.
+    /// let rest = iter.next_chunk::<2>().unwrap_err();
+    /// assert_eq!(rest.collect::<Vec<_>>(), ['m']);
+    /// ```
+    #[unstable(feature = "iter_next_chunk", issue = "none")]
+    fn next_chunk<const N: usize>(
+        &mut self,
+    ) -> Result<[Self::Item; N], crate::array::IntoIter<Self::Item, { N }>>
+    where
+        Self: Sized,
+        [Self::Item; N]: crate::array::LengthAtMost32,
+    {
+        crate::array::iter_next_chunk(self)
+    }
+
     /// Returns the bounds on the remaining length of the iterator.
     ///
     /// Specifically, `size_hint()` returns a tuple where the first element
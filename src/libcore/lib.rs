@@ -71,12 +71,25 @@
 #![feature(cfg_target_has_atomic)]
 #![feature(concat_idents)]
 #![feature(const_alloc_layout)]
+#![feature(const_ascii_whitespace)]
+#![feature(const_align_offset)]
+#![feature(const_cttz)]
 #![feature(const_if_match)]
 #![feature(const_panic)]
+#![feature(const_trait_impl)]
 #![feature(const_fn_union)]
 #![feature(const_generics)]
+#![feature(const_int_unchecked_arith)]
+#![feature(const_loop)]
+#![feature(const_maybe_uninit_as_ptr)]
+#![cfg_attr(not(bootstrap), feature(const_maybe_uninit_assume_init))]
+#![feature(const_mut_refs)]
+#![feature(const_ptr_offset)]
 #![feature(const_ptr_offset_from)]
+#![cfg_attr(not(bootstrap), feature(const_raw_ptr_comparison))]
+#![feature(const_raw_ptr_to_usize_cast)]
 #![feature(const_result)]
+#![feature(const_slice_split)]
 #![feature(const_type_name)]
 #![feature(custom_inner_attributes)]
 #![feature(decl_macro)]
@@ -97,6 +110,7 @@
 #![feature(optin_builtin_traits)]
 #![feature(prelude_import)]
 #![feature(repr_simd, platform_intrinsics)]
+#![cfg_attr(not(bootstrap), feature(repr_align_cache_line))]
 #![feature(rustc_attrs)]
 #![feature(simd_ffi)]
 #![feature(specialization)]
@@ -133,6 +147,7 @@
 #![feature(associated_type_bounds)]
 #![feature(const_type_id)]
 #![feature(const_caller_location)]
+#![cfg_attr(not(bootstrap), feature(variant_count))]
 #![cfg_attr(bootstrap, feature(slice_patterns))]
 
 #[prelude_import]
@@ -219,8 +234,12 @@ pub mod any;
 #[cfg(not(test))] // See #65860
 pub mod array;
 pub mod ascii;
+#[cfg(not(bootstrap))]
+pub mod backtrace;
 pub mod cell;
 pub mod char;
+#[cfg(not(bootstrap))]
+pub mod error;
 pub mod ffi;
 #[cfg(not(test))] // See #65860
 pub mod iter;
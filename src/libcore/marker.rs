@@ -90,6 +90,7 @@ impl<T: ?Sized> !Send for *mut T {}
           ch19-04-advanced-types.html#dynamically-sized-types-and-the-sized-trait>"
 )]
 #[fundamental] // for Default, for example, which requires that `[T]: !Default` be evaluatable
+#[cfg_attr(not(bootstrap), rustc_specialization_trait)]
 pub trait Sized {
     // Empty.
 }
@@ -362,6 +363,13 @@ pub trait StructuralEq {
 /// [impls]: #implementors
 #[stable(feature = "rust1", since = "1.0.0")]
 #[lang = "copy"]
+// FIXME: this allows copying a type that doesn't implement
+// `Copy` because of unsatisfied lifetime bounds (copying `A<'_>` when only
+// `A<'static>: Copy` and `A<'_>: Clone`).
+// We have this attribute here for now only because there are quite a few
+// existing specializations on `Copy` that already exist in the standard
+// library, and there's no way to safely have this behavior right now.
+#[cfg_attr(not(bootstrap), rustc_unsafe_specialization_marker)]
 pub trait Copy: Clone {
     // Empty.
 }
@@ -374,6 +382,17 @@ pub macro Copy($item:item) {
     /* compiler built-in */
 }
 
+/// Derive macro generating the impls of `CoerceUnsized` and `DispatchFromDyn` for a
+/// `#[repr(transparent)]` smart pointer, so that it can be unsized like `Box` and be the receiver
+/// of the methods of a trait object.
+#[rustc_builtin_macro]
+#[unstable(feature = "derive_smart_pointer", issue = "none")]
+#[allow_internal_unstable(coerce_unsized, dispatch_from_dyn, unsize)]
+#[cfg(not(bootstrap))]
+pub macro SmartPointer($item:item) {
+    /* compiler built-in */
+}
+
 /// Types for which it is safe to share references between threads.
 ///
 /// This trait is automatically implemented when the compiler determines
@@ -110,7 +110,8 @@ pub use crate::intrinsics::transmute;
 /// [`ManuallyDrop`]: struct.ManuallyDrop.html
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
-pub fn forget<T>(t: T) {
+#[rustc_const_unstable(feature = "const_forget", issue = "none")]
+pub const fn forget<T>(t: T) {
     ManuallyDrop::new(t);
 }
 
@@ -282,6 +283,10 @@ pub const fn size_of<T>() -> usize {
 /// statically-known size, e.g., a slice [`[T]`][slice] or a [trait object],
 /// then `size_of_val` can be used to get the dynamically-known size.
 ///
+/// The size of [extern types] is not known to Rust, so calling `size_of_val` with a value whose
+/// type is or ends in an extern type is a compile error.
+///
+/// [extern types]: ../../unstable-book/language-features/extern-types.html
 /// [slice]: ../../std/primitive.slice.html
 /// [trait object]: ../../book/ch17-02-trait-objects.html
 ///
@@ -298,6 +303,7 @@ pub const fn size_of<T>() -> usize {
 /// ```
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_diagnostic_item = "mem_size_of_val"]
 pub fn size_of_val<T: ?Sized>(val: &T) -> usize {
     intrinsics::size_of_val(val)
 }
@@ -342,6 +348,7 @@ pub fn min_align_of<T>() -> usize {
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_deprecated(reason = "use `align_of_val` instead", since = "1.2.0")]
+#[rustc_diagnostic_item = "mem_min_align_of_val"]
 pub fn min_align_of_val<T: ?Sized>(val: &T) -> usize {
     intrinsics::min_align_of_val(val)
 }
@@ -373,7 +380,11 @@ pub const fn align_of<T>() -> usize {
 ///
 /// Every reference to a value of the type `T` must be a multiple of this number.
 ///
+/// Like for [`size_of_val`], calling `align_of_val` with a value whose type is or ends in an
+/// extern type is a compile error.
+///
 /// [ABI]: https://en.wikipedia.org/wiki/Application_binary_interface
+/// [`size_of_val`]: fn.size_of_val.html
 ///
 /// # Examples
 ///
@@ -385,10 +396,33 @@ pub const fn align_of<T>() -> usize {
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[allow(deprecated)]
+#[rustc_diagnostic_item = "mem_align_of_val"]
 pub fn align_of_val<T: ?Sized>(val: &T) -> usize {
     min_align_of_val(val)
 }
 
+#[cfg_attr(not(bootstrap), repr(align(cache_line)))]
+#[cfg_attr(bootstrap, repr(align(64)))]
+struct CacheLineAligned;
+
+/// The size in bytes of the cache lines of the target, as given by its specification.
+///
+/// Aligning the data that threads access concurrently to this value keeps it from sharing a
+/// cache line with unrelated data, which avoids false sharing. It is 128 on the AArch64 Apple
+/// and the PowerPC64 targets, 256 on s390x, and 64 on the others. Types can be aligned to it
+/// with `#[repr(align(cache_line))]`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cache_line_align)]
+/// use std::mem;
+///
+/// assert!(mem::CACHE_LINE_ALIGN.is_power_of_two());
+/// ```
+#[unstable(feature = "cache_line_align", issue = "none")]
+pub const CACHE_LINE_ALIGN: usize = align_of::<CacheLineAligned>();
+
 /// Returns `true` if dropping values of type `T` matters.
 ///
 /// This is purely an optimization hint, and may be implemented conservatively:
@@ -866,3 +900,34 @@ impl<T> fmt::Debug for Discriminant<T> {
 pub fn discriminant<T>(v: &T) -> Discriminant<T> {
     Discriminant(intrinsics::discriminant_value(v), PhantomData)
 }
+
+/// Returns the number of variants in the enum type `T`.
+///
+/// If `T` is not an enum, calling this function will not result in undefined behavior, but the
+/// return value is unspecified. Equally, if `T` is an enum with more variants than `usize::MAX`
+/// the return value is unspecified. Uninhabited variants will be counted.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(never_type)]
+/// # #![feature(variant_count)]
+///
+/// use std::mem;
+///
+/// enum Void {}
+/// enum Foo { A(&'static str), B(i32), C(i32) }
+///
+/// assert_eq!(mem::variant_count::<Void>(), 0);
+/// assert_eq!(mem::variant_count::<Foo>(), 3);
+///
+/// assert_eq!(mem::variant_count::<Option<!>>(), 2);
+/// assert_eq!(mem::variant_count::<Result<!, !>>(), 2);
+/// ```
+#[cfg(not(bootstrap))]
+#[inline(always)]
+#[unstable(feature = "variant_count", issue = "none")]
+#[rustc_const_unstable(feature = "variant_count", issue = "none")]
+pub const fn variant_count<T>() -> usize {
+    intrinsics::variant_count::<T>()
+}
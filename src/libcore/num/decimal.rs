@@ -0,0 +1,236 @@
+//! Conversions of numbers to their decimal representation that don't go through
+//! `fmt::Formatter`, for the programs where its machinery is too large.
+//!
+//! The `Display` implementations of the integers delegate to the same routines.
+
+// ignore-tidy-undocumented-unsafe
+
+use crate::fmt;
+use crate::mem::MaybeUninit;
+use crate::num::flt2dec;
+use crate::ptr;
+use crate::slice;
+use crate::str;
+
+/// The length of the longest representation that `DecimalBuffer` holds: the sign and the 39
+/// digits of `i128::MIN`. The shortest representations of the floats are shorter.
+pub(crate) const MAX_LEN: usize = 40;
+
+/// The representation of the floats is only plain decimal for the values in
+/// `10^DEC_BOUNDS.0 <= |v| < 10^DEC_BOUNDS.1`, and uses an exponent otherwise.
+const DEC_BOUNDS: (i16, i16) = (-5, 17);
+
+static DEC_DIGITS_LUT: &[u8; 200] = b"0001020304050607080910111213141516171819\
+      2021222324252627282930313233343536373839\
+      4041424344454647484950515253545556575859\
+      6061626364656667686970717273747576777879\
+      8081828384858687888990919293949596979899";
+
+macro_rules! decimal_digits {
+    ($($(#[$attr:meta])* $u:ident named $name:ident),*) => {$(
+        $(#[$attr])*
+        /// Writes the decimal digits of `n` at the end of `buf`, and returns the index of the
+        /// first one.
+        pub(crate) fn $name(mut n: $u, buf: &mut [MaybeUninit<u8>; MAX_LEN]) -> usize {
+            let mut curr = buf.len() as isize;
+            let buf_ptr = MaybeUninit::first_ptr_mut(buf);
+            let lut_ptr = DEC_DIGITS_LUT.as_ptr();
+
+            unsafe {
+                // need at least 16 bits for the 4-characters-at-a-time to work.
+                assert!(crate::mem::size_of::<$u>() >= 2);
+
+                // eagerly decode 4 characters at a time
+                while n >= 10000 {
+                    let rem = (n % 10000) as isize;
+                    n /= 10000;
+
+                    let d1 = (rem / 100) << 1;
+                    let d2 = (rem % 100) << 1;
+                    curr -= 4;
+                    ptr::copy_nonoverlapping(lut_ptr.offset(d1), buf_ptr.offset(curr), 2);
+                    ptr::copy_nonoverlapping(lut_ptr.offset(d2), buf_ptr.offset(curr + 2), 2);
+                }
+
+                // if we reach here numbers are <= 9999, so at most 4 chars long
+                let mut n = n as isize; // possibly reduce 64bit math
+
+                // decode 2 more chars, if > 2 chars
+                if n >= 100 {
+                    let d1 = (n % 100) << 1;
+                    n /= 100;
+                    curr -= 2;
+                    ptr::copy_nonoverlapping(lut_ptr.offset(d1), buf_ptr.offset(curr), 2);
+                }
+
+                // decode last 1 or 2 chars
+                if n < 10 {
+                    curr -= 1;
+                    *buf_ptr.offset(curr) = (n as u8) + b'0';
+                } else {
+                    let d1 = n << 1;
+                    curr -= 2;
+                    ptr::copy_nonoverlapping(lut_ptr.offset(d1), buf_ptr.offset(curr), 2);
+                }
+            }
+            curr as usize
+        }
+    )*};
+}
+
+decimal_digits!(
+    #[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+    u32 named u32_digits,
+    u64 named u64_digits,
+    u128 named u128_digits
+);
+
+/// A buffer to write the decimal representation of numbers in, without `fmt::Formatter`.
+///
+/// Writing an integer never panics. The floats are written in their shortest representation,
+/// which uses an exponent for very large and very small values.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(num_decimal)]
+///
+/// use std::num::DecimalBuffer;
+///
+/// let mut buf = DecimalBuffer::new();
+/// assert_eq!(buf.format(-1234i32), "-1234");
+/// assert_eq!(buf.format(u128::max_value()), "340282366920938463463374607431768211455");
+/// assert_eq!(buf.format(0.3f64), "0.3");
+/// assert_eq!(buf.format(1e100f64), "1e100");
+/// ```
+#[unstable(feature = "num_decimal", issue = "none")]
+#[derive(Clone, Copy)]
+pub struct DecimalBuffer {
+    bytes: [MaybeUninit<u8>; MAX_LEN],
+}
+
+impl DecimalBuffer {
+    /// Creates an empty buffer.
+    #[unstable(feature = "num_decimal", issue = "none")]
+    #[inline]
+    pub const fn new() -> DecimalBuffer {
+        DecimalBuffer { bytes: [MaybeUninit::uninit(); MAX_LEN] }
+    }
+
+    /// Writes the decimal representation of `n` in the buffer and returns it.
+    #[unstable(feature = "num_decimal", issue = "none")]
+    pub fn format<N: Decimal>(&mut self, n: N) -> &str {
+        let start = n.write_decimal(&mut self.bytes);
+        unsafe {
+            let start_ptr = MaybeUninit::first_ptr(&self.bytes).add(start);
+            str::from_utf8_unchecked(slice::from_raw_parts(start_ptr, MAX_LEN - start))
+        }
+    }
+}
+
+#[unstable(feature = "num_decimal", issue = "none")]
+impl Default for DecimalBuffer {
+    fn default() -> DecimalBuffer {
+        DecimalBuffer::new()
+    }
+}
+
+#[unstable(feature = "num_decimal", issue = "none")]
+impl fmt::Debug for DecimalBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("DecimalBuffer { .. }")
+    }
+}
+
+/// The numbers that `DecimalBuffer` can write: the integers and the floats.
+///
+/// This trait is sealed and cannot be implemented outside of `core`.
+#[unstable(feature = "num_decimal", issue = "none")]
+pub trait Decimal: private::Sealed {}
+
+mod private {
+    use crate::mem::MaybeUninit;
+
+    pub trait Sealed {
+        /// Writes the decimal representation of `self` at the end of `buf`, and returns the
+        /// index of its first byte.
+        fn write_decimal(self, buf: &mut [MaybeUninit<u8>; super::MAX_LEN]) -> usize;
+    }
+}
+
+macro_rules! impl_Decimal {
+    ($($t:ident),* as $u:ident via $digits:ident) => {$(
+        impl private::Sealed for $t {
+            #[allow(unused_comparisons)]
+            fn write_decimal(self, buf: &mut [MaybeUninit<u8>; MAX_LEN]) -> usize {
+                let is_nonnegative = self >= 0;
+                let n = if is_nonnegative {
+                    self as $u
+                } else {
+                    // convert the negative num to positive by summing 1 to it's 2 complement
+                    (!(self as $u)).wrapping_add(1)
+                };
+                let mut start = $digits(n, buf);
+                if !is_nonnegative {
+                    // There are at most 39 digits, so there is room for the sign.
+                    start -= 1;
+                    unsafe { *MaybeUninit::first_ptr_mut(buf).add(start) = b'-' };
+                }
+                start
+            }
+        }
+
+        #[unstable(feature = "num_decimal", issue = "none")]
+        impl Decimal for $t {}
+    )*};
+}
+
+// Like the `Display` implementations, format the integers that fit in 64 bits with 64-bit math
+// where it is native, and on wasm32, which often cares strongly about code size.
+#[cfg(any(target_pointer_width = "64", target_arch = "wasm32"))]
+impl_Decimal!(i8, u8, i16, u16, i32, u32, i64, u64, usize, isize as u64 via u64_digits);
+
+#[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+impl_Decimal!(i8, u8, i16, u16, i32, u32, isize, usize as u32 via u32_digits);
+#[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+impl_Decimal!(i64, u64 as u64 via u64_digits);
+
+impl_Decimal!(i128, u128 as u128 via u128_digits);
+
+macro_rules! impl_Decimal_float {
+    ($($t:ident)*) => {$(
+        impl private::Sealed for $t {
+            fn write_decimal(self, buf: &mut [MaybeUninit<u8>; MAX_LEN]) -> usize {
+                unsafe {
+                    let mut digits = MaybeUninit::<[u8; flt2dec::MAX_SIG_DIGITS]>::uninit();
+                    let mut parts = MaybeUninit::<[flt2dec::Part<'_>; 6]>::uninit();
+                    // FIXME(#53491): like `fmt::float`, this calls `get_mut` on uninitialized
+                    // `MaybeUninit`s.
+                    let formatted = flt2dec::to_shortest_exp_str(
+                        flt2dec::strategy::grisu::format_shortest,
+                        self,
+                        flt2dec::Sign::Minus,
+                        DEC_BOUNDS,
+                        false,
+                        digits.get_mut(),
+                        parts.get_mut(),
+                    );
+                    // The shortest representation of `f64` is at most 24 bytes long with these
+                    // bounds, so it always fits.
+                    let start = MAX_LEN - formatted.len();
+                    let out = slice::from_raw_parts_mut(
+                        MaybeUninit::first_ptr_mut(buf).add(start),
+                        MAX_LEN - start,
+                    );
+                    formatted.write(out);
+                    start
+                }
+            }
+        }
+
+        #[unstable(feature = "num_decimal", issue = "none")]
+        impl Decimal for $t {}
+    )*};
+}
+
+impl_Decimal_float!(f32 f64);
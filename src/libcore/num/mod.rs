@@ -215,8 +215,12 @@ pub mod dec2flt;
 pub mod diy_float;
 pub mod flt2dec;
 
+pub(crate) mod decimal;
 mod wrapping;
 
+#[unstable(feature = "num_decimal", issue = "none")]
+pub use decimal::{Decimal, DecimalBuffer};
+
 macro_rules! usize_isize_to_xe_bytes_doc {
     () => {
         "
@@ -4567,8 +4571,9 @@ impl u8 {
     /// assert!(!esc.is_ascii_whitespace());
     /// ```
     #[stable(feature = "ascii_ctype_on_intrinsics", since = "1.24.0")]
+    #[rustc_const_unstable(feature = "const_ascii_whitespace", issue = "none")]
     #[inline]
-    pub fn is_ascii_whitespace(&self) -> bool {
+    pub const fn is_ascii_whitespace(&self) -> bool {
         matches!(*self, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
     }
 
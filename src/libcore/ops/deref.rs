@@ -174,6 +174,27 @@ impl<T: ?Sized> DerefMut for &mut T {
     }
 }
 
+/// Indicates that the [`Deref`] implementation of a type is pure: `deref` always returns a
+/// reference to the same value, does not have side effects and cannot panic. Such types can be
+/// looked through by deref patterns (`#![feature(deref_patterns)]`), which call `deref` while
+/// matching.
+///
+/// Constructor and literal patterns that are matched against a value of a type implementing
+/// this trait are always matched against its pointee, so a type implementing it cannot be
+/// matched by its own constructor.
+///
+/// [`Deref`]: trait.Deref.html
+#[cfg_attr(not(bootstrap), lang = "deref_pure")]
+#[unstable(feature = "deref_pure_trait", issue = "none")]
+#[doc(hidden)]
+pub unsafe trait DerefPure: Deref {}
+
+#[unstable(feature = "deref_pure_trait", issue = "none")]
+unsafe impl<T: ?Sized> DerefPure for &T {}
+
+#[unstable(feature = "deref_pure_trait", issue = "none")]
+unsafe impl<T: ?Sized> DerefPure for &mut T {}
+
 /// Indicates that a struct can be used as a method receiver, without the
 /// `arbitrary_self_types` feature. This is implemented by stdlib pointer types like `Box<T>`,
 /// `Rc<T>`, `&T`, and `Pin<P>`.
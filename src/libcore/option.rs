@@ -151,6 +151,33 @@ use crate::{
 
 /// The `Option` type. See [the module level documentation](index.html) for more.
 #[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(
+    not(bootstrap),
+    rustc_on_misuse(
+        on(
+            all(
+                any(op = "method", op = "index"),
+                any(
+                    T = "std::vec::Vec<T>",
+                    T = "std::collections::VecDeque<T>",
+                    T = "std::collections::HashMap<K, V, S>",
+                    T = "std::collections::HashSet<T, S>",
+                    T = "std::collections::BTreeMap<K, V>",
+                    T = "std::collections::BTreeSet<T>"
+                )
+            ),
+            note = "`{Self}` is not a collection, but the value it may contain is: handle the \
+                    `None` case first, for example with `if let Some(inner) = ...`, and call \
+                    `.iter()` on the inner value to iterate over its elements"
+        ),
+        on(
+            op = "deref",
+            note = "`{Self}` may not contain a value: handle the `None` case first, for example \
+                    with `if let Some(inner) = ...`, or use `Option::as_deref` to borrow the \
+                    dereferenced inner value"
+        )
+    )
+)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub enum Option<T> {
     /// No value
@@ -1570,21 +1597,27 @@ impl<A, V: FromIterator<A>> FromIterator<Option<A>> for Option<V> {
 pub struct NoneError;
 
 #[unstable(feature = "try_trait", issue = "42327")]
-impl<T> ops::Try for Option<T> {
+impl<T> const ops::Try for Option<T> {
     type Ok = T;
     type Error = NoneError;
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn into_result(self) -> Result<T, NoneError> {
-        self.ok_or(NoneError)
+        match self {
+            Some(v) => Ok(v),
+            None => Err(NoneError),
+        }
     }
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn from_ok(v: T) -> Self {
         Some(v)
     }
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn from_error(_: NoneError) -> Self {
         None
     }
@@ -29,11 +29,12 @@ use crate::fmt;
 /// ```
 #[lang = "panic_info"]
 #[stable(feature = "panic_hooks", since = "1.10.0")]
-#[derive(Debug)]
 pub struct PanicInfo<'a> {
     payload: &'a (dyn Any + Send),
+    payload_str: Option<&'a str>,
     message: Option<&'a fmt::Arguments<'a>>,
     location: &'a Location<'a>,
+    can_unwind: bool,
 }
 
 impl<'a> PanicInfo<'a> {
@@ -48,9 +49,10 @@ impl<'a> PanicInfo<'a> {
     pub fn internal_constructor(
         message: Option<&'a fmt::Arguments<'a>>,
         location: &'a Location<'a>,
+        can_unwind: bool,
     ) -> Self {
         struct NoPayload;
-        PanicInfo { location, message, payload: &NoPayload }
+        PanicInfo { location, message, payload: &NoPayload, payload_str: None, can_unwind }
     }
 
     #[unstable(
@@ -63,6 +65,21 @@ impl<'a> PanicInfo<'a> {
     #[inline]
     pub fn set_payload(&mut self, info: &'a (dyn Any + Send)) {
         self.payload = info;
+        self.payload_str = None;
+    }
+
+    /// Sets the string that `payload_as_str` returns for a payload that is a `String`, which
+    /// libcore cannot downcast to.
+    #[unstable(
+        feature = "panic_internals",
+        reason = "internal details of the implementation of the `panic!` \
+                  and related macros",
+        issue = "none"
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_payload_str(&mut self, payload_str: &'a str) {
+        self.payload_str = Some(payload_str);
     }
 
     /// Returns the payload associated with the panic.
@@ -87,11 +104,62 @@ impl<'a> PanicInfo<'a> {
         self.payload
     }
 
-    /// If the `panic!` macro from the `core` crate (not from `std`)
-    /// was used with a formatting string and some additional arguments,
-    /// returns that message ready to be used for example with [`fmt::write`]
+    /// Returns the payload associated with the panic, if it is a `&'static str` or a
+    /// [`String`].
+    ///
+    /// [`String`]: ../../std/string/struct.String.html
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_payload_as_str)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     if let Some(s) = panic_info.payload_as_str() {
+    ///         println!("panic occurred: {:?}", s);
+    ///     } else {
+    ///         println!("panic occurred");
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[unstable(feature = "panic_payload_as_str", issue = "none")]
+    pub fn payload_as_str(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&'static str>() {
+            Some(s)
+        } else {
+            self.payload_str
+        }
+    }
+
+    /// Returns the message of the panic, if it was given one, ready to be used
+    /// for example with [`fmt::write`].
+    ///
+    /// This is `Some` for all the panics of the `core` crate, so a
+    /// `#[panic_handler]` can always format the message of a panic in its own
+    /// buffer. With the `panic!` macro of `std`, this is `None` if the panic was
+    /// given a payload that is neither a string literal nor a formatting string,
+    /// including an owned `String`: use [`payload_as_str`] to get at that one.
     ///
     /// [`fmt::write`]: ../fmt/fn.write.html
+    /// [`payload_as_str`]: #method.payload_as_str
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_info_message)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     if let Some(message) = panic_info.message() {
+    ///         println!("panic occurred: {}", message);
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic: {}", 42);
+    /// ```
     #[unstable(feature = "panic_info_message", issue = "66745")]
     pub fn message(&self) -> Option<&fmt::Arguments<'_>> {
         self.message
@@ -127,6 +195,53 @@ impl<'a> PanicInfo<'a> {
         // deal with that case in std::panicking::default_hook and std::panicking::begin_panic_fmt.
         Some(&self.location)
     }
+
+    /// Returns whether the panic handler is allowed to unwind the stack from
+    /// the point where the panic occurred.
+    ///
+    /// This is `false` when the panic will abort the process regardless of
+    /// what the handler does, for example when the standard library sees a
+    /// panic while the thread is already unwinding from another panic. A
+    /// `#[panic_handler]` must not unwind when this returns `false`.
+    ///
+    /// This is also `false` when the program is linked with the panic runtime
+    /// of `-C panic=abort`, and for the panics in code that cannot unwind:
+    /// anywhere in a crate compiled with `-C panic=abort`, in the cleanup code
+    /// the compiler emits to drop values during unwinding, and in a function
+    /// with `#[unwind(aborts)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_can_unwind)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     if !panic_info.can_unwind() {
+    ///         println!("the process is about to abort");
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[unstable(feature = "panic_can_unwind", issue = "none")]
+    pub fn can_unwind(&self) -> bool {
+        self.can_unwind
+    }
+}
+
+#[stable(feature = "panic_hooks", since = "1.10.0")]
+impl fmt::Debug for PanicInfo<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `payload_str` only caches the contents of a `String` payload, so it is left out.
+        formatter
+            .debug_struct("PanicInfo")
+            .field("payload", &self.payload)
+            .field("message", &self.message)
+            .field("location", &self.location)
+            .field("can_unwind", &self.can_unwind)
+            .finish()
+    }
 }
 
 #[stable(feature = "panic_hook_display", since = "1.26.0")]
@@ -170,12 +285,20 @@ impl fmt::Display for PanicInfo<'_> {
 /// panic!("Normal panic");
 /// ```
 #[lang = "panic_location"]
-#[derive(Debug)]
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub struct Location<'a> {
     file: &'a str,
     line: u32,
     col: u32,
+    // The fields below are 0 when unknown. The compiler always fills `offset` and `len`, and fills
+    // `col_end` if the call ends on the line it starts on.
+    col_end: u32,
+    offset: u32,
+    len: u32,
+    // Whether a panic at this location can unwind out of the calling function, as far as the
+    // compiler knows when compiling the call: not under `-C panic=abort`, in a cleanup block or
+    // in a function with `#[unwind(aborts)]`.
+    can_unwind: bool,
 }
 
 impl<'a> Location<'a> {
@@ -242,7 +365,14 @@ impl<'a> Location<'a> {
     )]
     #[doc(hidden)]
     pub const fn internal_constructor(file: &'a str, line: u32, col: u32) -> Self {
-        Location { file, line, col }
+        Location { file, line, col, col_end: 0, offset: 0, len: 0, can_unwind: true }
+    }
+
+    /// Returns whether a panic at this location can unwind out of the calling
+    /// function, which the panic runtime may still not do.
+    #[doc(hidden)]
+    pub fn can_unwind(&self) -> bool {
+        self.can_unwind
     }
 
     /// Returns the name of the source file from which the panic originated.
@@ -310,6 +440,68 @@ impl<'a> Location<'a> {
     pub fn column(&self) -> u32 {
         self.col
     }
+
+    /// Returns the column just past the end of the expression from which the
+    /// panic originated, if it ends on the line where it starts.
+    ///
+    /// Together with [`column`], this can be used to underline the expression.
+    /// For a panic in a macro, this is the end of the outermost macro
+    /// invocation, like [`column`] is its start.
+    ///
+    /// [`column`]: #method.column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(track_caller, panic_location_span)]
+    /// use core::panic::Location;
+    ///
+    /// #[track_caller]
+    /// fn get_caller_location(_: u32) -> &'static Location<'static> {
+    ///     Location::caller()
+    /// }
+    ///
+    /// let location = get_caller_location(1 + 2);
+    /// assert_eq!(location.column(), 16);
+    /// assert_eq!(location.column_end(), Some(42));
+    /// ```
+    #[unstable(feature = "panic_location_span", issue = "none")]
+    pub fn column_end(&self) -> Option<u32> {
+        if self.col_end != 0 { Some(self.col_end) } else { None }
+    }
+
+    /// Returns the byte offset in [`file`] of the expression from which the
+    /// panic originated, if it is known.
+    ///
+    /// This is always known for the locations created by the compiler.
+    ///
+    /// [`file`]: #method.file
+    #[unstable(feature = "panic_location_span", issue = "none")]
+    pub fn byte_offset(&self) -> Option<u32> {
+        if self.len != 0 { Some(self.offset) } else { None }
+    }
+
+    /// Returns the length in bytes of the expression from which the panic
+    /// originated, if it is known.
+    ///
+    /// This is always known for the locations created by the compiler.
+    #[unstable(feature = "panic_location_span", issue = "none")]
+    pub fn byte_len(&self) -> Option<u32> {
+        if self.len != 0 { Some(self.len) } else { None }
+    }
+}
+
+#[stable(feature = "panic_hooks", since = "1.10.0")]
+impl fmt::Debug for Location<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The span fields are left out so that the output stays as it was before they existed.
+        formatter
+            .debug_struct("Location")
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("col", &self.col)
+            .finish()
+    }
 }
 
 #[stable(feature = "panic_hook_display", since = "1.26.0")]
@@ -81,6 +81,6 @@ pub fn panic_fmt(fmt: fmt::Arguments<'_>, location: &Location<'_>) -> ! {
         fn panic_impl(pi: &PanicInfo<'_>) -> !;
     }
 
-    let pi = PanicInfo::internal_constructor(Some(&fmt), location);
+    let pi = PanicInfo::internal_constructor(Some(&fmt), location, location.can_unwind());
     unsafe { panic_impl(&pi) }
 }
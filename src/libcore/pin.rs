@@ -822,3 +822,137 @@ impl<P, U> CoerceUnsized<Pin<U>> for Pin<P> where P: CoerceUnsized<U> {}
 
 #[stable(feature = "pin", since = "1.33.0")]
 impl<P, U> DispatchFromDyn<Pin<U>> for Pin<P> where P: DispatchFromDyn<U> {}
+
+/// A wrapper for the data of a self-referential or intrusive data structure, which may be
+/// aliased even while there is a `&mut` to it.
+///
+/// Normally, the compiler assumes that nothing else accesses the memory behind a `&mut T` (or a
+/// `Box<T>`) during its lifetime, and optimizes on this basis. That assumption breaks once the
+/// value keeps pointers to itself, or is linked into a list whose other nodes point to it: the
+/// `&mut` created to poll a future or to update a node then aliases these pointers.
+/// `UnsafePinned<T>` opts out of the assumption: `&mut` and `Box` pointers to a type that
+/// contains an `UnsafePinned<T>` are not treated as unique, much as `UnsafeCell<T>` opts out of
+/// the immutability of `&T`. `UnsafePinned<T>` does not make mutation through a `&T` allowed;
+/// combine it with `UnsafeCell<T>` for that.
+///
+/// `UnsafePinned<T>` is [`!Unpin`], as its contents are only aliased once it is pinned. It has
+/// the same in-memory representation as `T`.
+///
+/// [`!Unpin`]: ../marker/trait.Unpin.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(unsafe_pinned)]
+/// use std::pin::{Pin, UnsafePinned};
+///
+/// struct SelfRef {
+///     value: UnsafePinned<i32>,
+///     ptr: *mut i32,
+/// }
+///
+/// let mut s = Box::pin(SelfRef { value: UnsafePinned::new(5), ptr: std::ptr::null_mut() });
+/// unsafe {
+///     let s = Pin::get_unchecked_mut(s.as_mut());
+///     s.ptr = s.value.get_mut_unchecked();
+///     // Writing through `s.ptr` does not invalidate the `&mut SelfRef`.
+///     *s.ptr = 6;
+///     assert_eq!(*s.value.get(), 6);
+/// }
+/// ```
+#[unstable(feature = "unsafe_pinned", issue = "none")]
+#[lang = "unsafe_pinned"]
+#[repr(transparent)]
+pub struct UnsafePinned<T: ?Sized> {
+    value: T,
+}
+
+#[unstable(feature = "unsafe_pinned", issue = "none")]
+impl<T: ?Sized> !Unpin for UnsafePinned<T> {}
+
+impl<T> UnsafePinned<T> {
+    /// Constructs a new `UnsafePinned` which wraps the given value.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        UnsafePinned { value }
+    }
+
+    /// Unwraps the value.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> UnsafePinned<T> {
+    /// Gets a pointer to the wrapped value, which may not be used for writes unless `T`
+    /// contains an `UnsafeCell`.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub const fn get(&self) -> *const T {
+        // `UnsafePinned` is `repr(transparent)`.
+        self as *const UnsafePinned<T> as *const T
+    }
+
+    /// Gets a mutable pointer to the wrapped value, which stays valid for as long as the
+    /// `UnsafePinned` is not moved.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub fn get_mut_pinned(self: Pin<&mut Self>) -> *mut T {
+        // SAFETY: the value is not moved out of the reference.
+        unsafe { self.get_unchecked_mut().get_mut_unchecked() }
+    }
+
+    /// Gets a mutable pointer to the wrapped value, without requiring the `UnsafePinned` to be
+    /// pinned.
+    ///
+    /// The pointer is only valid for as long as the `UnsafePinned` is not moved; this is not
+    /// enforced, as with [`Pin::get_unchecked_mut`].
+    ///
+    /// [`Pin::get_unchecked_mut`]: struct.Pin.html#method.get_unchecked_mut
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub fn get_mut_unchecked(&mut self) -> *mut T {
+        self as *mut UnsafePinned<T> as *mut T
+    }
+
+    /// Gets a pointer to the wrapped value from a pointer to the `UnsafePinned`, without
+    /// creating a reference, which is useful for values that are not initialized yet.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub const fn raw_get(this: *const Self) -> *const T {
+        this as *const T
+    }
+
+    /// Gets a mutable pointer to the wrapped value from a pointer to the `UnsafePinned`,
+    /// without creating a reference.
+    #[unstable(feature = "unsafe_pinned", issue = "none")]
+    #[inline]
+    pub const fn raw_get_mut(this: *mut Self) -> *mut T {
+        this as *mut T
+    }
+}
+
+#[unstable(feature = "unsafe_pinned", issue = "none")]
+impl<T: Default> Default for UnsafePinned<T> {
+    /// Creates an `UnsafePinned`, with the `Default` value for T.
+    fn default() -> Self {
+        UnsafePinned::new(Default::default())
+    }
+}
+
+#[unstable(feature = "unsafe_pinned", issue = "none")]
+impl<T> From<T> for UnsafePinned<T> {
+    fn from(value: T) -> Self {
+        UnsafePinned::new(value)
+    }
+}
+
+#[unstable(feature = "unsafe_pinned", issue = "none")]
+impl<T: ?Sized> fmt::Debug for UnsafePinned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("UnsafePinned { .. }")
+    }
+}
@@ -1041,9 +1041,13 @@ pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
 /// power-of-two, it will probably be more prudent to just change to a naive implementation rather
 /// than trying to adapt this to accommodate that change.
 ///
+/// During CTFE, calls to this function are evaluated by the interpreter without looking at its
+/// body, as the address of `p` is not known at compile time.
+///
 /// Any questions go to @nagisa.
 #[lang = "align_offset"]
-pub(crate) unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
+#[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+pub(crate) const unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
     /// Calculate multiplicative modular inverse of `x` modulo `m`.
     ///
     /// This implementation is tailored for align_offset and has following preconditions:
@@ -1053,7 +1057,8 @@ pub(crate) unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
     ///
     /// Implementation of this function shall not panic. Ever.
     #[inline]
-    fn mod_inv(x: usize, m: usize) -> usize {
+    #[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+    const fn mod_inv(x: usize, m: usize) -> usize {
         /// Multiplicative modular inverse table modulo 2⁴ = 16.
         ///
         /// Note, that this table does not contain values where inverse does not exist (i.e., for
@@ -1113,7 +1118,10 @@ pub(crate) unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
 
     let smoda = stride & a_minus_one;
     // a is power-of-two so cannot be 0. stride = 0 is handled above.
-    let gcdpow = intrinsics::cttz_nonzero(stride).min(intrinsics::cttz_nonzero(a));
+    let stridepow = intrinsics::cttz_nonzero(stride);
+    let apow = intrinsics::cttz_nonzero(a);
+    // `Ord::min` cannot be called in a `const fn`.
+    let gcdpow = if stridepow < apow { stridepow } else { apow };
     let gcd = 1usize << gcdpow;
 
     if p as usize & (gcd - 1) == 0 {
@@ -38,6 +38,46 @@ impl<T: ?Sized> *mut T {
         self as _
     }
 
+    /// Returns whether two pointers are guaranteed to be equal.
+    ///
+    /// At runtime this function behaves like `self == other`. During const evaluation,
+    /// however, it is not always possible to determine whether two pointers are equal, so
+    /// this function may return `None` for pointers that later turn out to be equal or
+    /// unequal. When it returns `Some`, the result is guaranteed to be the same at runtime.
+    #[unstable(feature = "const_raw_ptr_comparison", issue = "none")]
+    #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "none")]
+    #[cfg(not(bootstrap))]
+    #[inline]
+    pub const fn guaranteed_eq(self, other: *mut T) -> Option<bool>
+    where
+        T: Sized,
+    {
+        match unsafe { intrinsics::ptr_guaranteed_cmp(self as *const T, other as *const T) } {
+            2 => None,
+            cmp => Some(cmp == 1),
+        }
+    }
+
+    /// Returns whether two pointers are guaranteed to be unequal.
+    ///
+    /// This is the negation of [`guaranteed_eq`]: it returns `None` whenever
+    /// `guaranteed_eq` does.
+    ///
+    /// [`guaranteed_eq`]: #method.guaranteed_eq
+    #[unstable(feature = "const_raw_ptr_comparison", issue = "none")]
+    #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "none")]
+    #[cfg(not(bootstrap))]
+    #[inline]
+    pub const fn guaranteed_ne(self, other: *mut T) -> Option<bool>
+    where
+        T: Sized,
+    {
+        match self.guaranteed_eq(other) {
+            None => None,
+            Some(eq) => Some(!eq),
+        }
+    }
+
     /// Returns `None` if the pointer is null, or else returns a reference to
     /// the value wrapped in `Some`.
     ///
@@ -146,8 +186,9 @@ impl<T: ?Sized> *mut T {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub unsafe fn offset(self, count: isize) -> *mut T
+    pub const unsafe fn offset(self, count: isize) -> *mut T
     where
         T: Sized,
     {
@@ -203,8 +244,9 @@ impl<T: ?Sized> *mut T {
     /// assert_eq!(&data, &[0, 2, 0, 4, 0]);
     /// ```
     #[stable(feature = "ptr_wrapping_offset", since = "1.16.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub fn wrapping_offset(self, count: isize) -> *mut T
+    pub const fn wrapping_offset(self, count: isize) -> *mut T
     where
         T: Sized,
     {
@@ -437,8 +479,9 @@ impl<T: ?Sized> *mut T {
     /// }
     /// ```
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub unsafe fn add(self, count: usize) -> Self
+    pub const unsafe fn add(self, count: usize) -> Self
     where
         T: Sized,
     {
@@ -498,8 +541,9 @@ impl<T: ?Sized> *mut T {
     /// }
     /// ```
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub unsafe fn sub(self, count: usize) -> Self
+    pub const unsafe fn sub(self, count: usize) -> Self
     where
         T: Sized,
     {
@@ -553,8 +597,9 @@ impl<T: ?Sized> *mut T {
     /// }
     /// ```
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub fn wrapping_add(self, count: usize) -> Self
+    pub const fn wrapping_add(self, count: usize) -> Self
     where
         T: Sized,
     {
@@ -608,8 +653,9 @@ impl<T: ?Sized> *mut T {
     /// }
     /// ```
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "none")]
     #[inline]
-    pub fn wrapping_sub(self, count: usize) -> Self
+    pub const fn wrapping_sub(self, count: usize) -> Self
     where
         T: Sized,
     {
@@ -883,7 +929,8 @@ impl<T: ?Sized> *mut T {
     /// # } }
     /// ```
     #[stable(feature = "align_offset", since = "1.36.0")]
-    pub fn align_offset(self, align: usize) -> usize
+    #[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+    pub const fn align_offset(self, align: usize) -> usize
     where
         T: Sized,
     {
@@ -1492,21 +1492,24 @@ impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
 }
 
 #[unstable(feature = "try_trait", issue = "42327")]
-impl<T, E> ops::Try for Result<T, E> {
+impl<T, E> const ops::Try for Result<T, E> {
     type Ok = T;
     type Error = E;
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn into_result(self) -> Self {
         self
     }
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn from_ok(v: T) -> Self {
         Ok(v)
     }
 
     #[inline]
+    #[rustc_const_unstable(feature = "const_try", issue = "none")]
     fn from_error(v: E) -> Self {
         Err(v)
     }
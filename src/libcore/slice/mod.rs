@@ -499,6 +499,84 @@ impl<T> [T] {
         start..end
     }
 
+    /// Returns the index of the element that a reference points to.
+    ///
+    /// Returns `None` if `element` does not point into the slice, or does not point to the
+    /// start of one of its elements. The comparison is on the addresses: an element that is
+    /// only equal to one of the slice is not found, unlike with [`iter().position`].
+    ///
+    /// [`iter().position`]: iter/trait.Iterator.html#method.position
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(substr_range)]
+    ///
+    /// let nums = [0, 1, 2, 3];
+    /// let two = &nums[2];
+    ///
+    /// assert_eq!(nums.element_offset(two), Some(2));
+    /// assert_eq!(nums.element_offset(&2), None);
+    /// ```
+    #[unstable(feature = "substr_range", issue = "none")]
+    pub fn element_offset(&self, element: &T) -> Option<usize> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            panic!("elements are zero-sized");
+        }
+        let byte_offset = (element as *const T as usize).wrapping_sub(self.as_ptr() as usize);
+        if byte_offset % size != 0 {
+            return None;
+        }
+        let offset = byte_offset / size;
+        if offset < self.len() { Some(offset) } else { None }
+    }
+
+    /// Returns the range of indices that a subslice points to.
+    ///
+    /// Returns `None` if `subslice` does not point within the slice, or does not start at one
+    /// of its elements. The comparison is on the addresses, so the result can be used to
+    /// index the slice again, like one would use offsets in a parser.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(substr_range)]
+    ///
+    /// let nums = [0, 1, 2, 3, 4];
+    /// let middle = &nums[1..3];
+    ///
+    /// assert_eq!(nums.subslice_range(middle), Some(1..3));
+    /// assert_eq!(nums.subslice_range(&nums[5..]), Some(5..5));
+    /// assert_eq!(nums.subslice_range(&[1, 2]), None);
+    /// ```
+    #[unstable(feature = "substr_range", issue = "none")]
+    pub fn subslice_range(&self, subslice: &[T]) -> Option<Range<usize>> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            panic!("elements are zero-sized");
+        }
+        let byte_start = (subslice.as_ptr() as usize).wrapping_sub(self.as_ptr() as usize);
+        if byte_start % size != 0 {
+            return None;
+        }
+        let start = byte_start / size;
+        let end = start.wrapping_add(subslice.len());
+        if start <= self.len() && end <= self.len() && start <= end {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
     /// Swaps two elements in the slice.
     ///
     /// # Arguments
@@ -1008,6 +1086,152 @@ impl<T> [T] {
         RChunksExactMut { v: snd, rem: fst, chunk_size }
     }
 
+    /// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the
+    /// slice, and a remainder slice with length strictly less than `N`.
+    ///
+    /// The arrays can be processed with a fixed size known to the compiler, like the chunks of
+    /// [`chunks_exact`], but without iterating over them.
+    ///
+    /// See [`as_rchunks`] for the variant that starts at the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (chunks, remainder) = slice.as_chunks::<2>();
+    /// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+    /// assert_eq!(remainder, &['m']);
+    /// ```
+    ///
+    /// [`chunks_exact`]: #method.chunks_exact
+    /// [`as_rchunks`]: #method.as_rchunks
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0);
+        let len = self.len() / N;
+        let (multiple_of_n, remainder) = self.split_at(len * N);
+        // SAFETY: `multiple_of_n` holds `len * N` elements, and `[T; N]` has the layout of `N`
+        // consecutive `T`s.
+        let array_slice = unsafe { from_raw_parts(multiple_of_n.as_ptr() as *const [T; N], len) };
+        (array_slice, remainder)
+    }
+
+    /// Splits the slice into a slice of `N`-element arrays, starting at the end of the slice,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// See [`as_chunks`] for the variant that starts at the beginning of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (remainder, chunks) = slice.as_rchunks::<2>();
+    /// assert_eq!(remainder, &['l']);
+    /// assert_eq!(chunks, &[['o', 'r'], ['e', 'm']]);
+    /// ```
+    ///
+    /// [`as_chunks`]: #method.as_chunks
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
+        assert!(N != 0);
+        let len = self.len() / N;
+        let (remainder, multiple_of_n) = self.split_at(self.len() - len * N);
+        // SAFETY: `multiple_of_n` holds `len * N` elements, and `[T; N]` has the layout of `N`
+        // consecutive `T`s.
+        let array_slice = unsafe { from_raw_parts(multiple_of_n.as_ptr() as *const [T; N], len) };
+        (remainder, array_slice)
+    }
+
+    /// Splits the slice into a slice of mutable `N`-element arrays, starting at the beginning
+    /// of the slice, and a mutable remainder slice with length strictly less than `N`.
+    ///
+    /// See [`as_rchunks_mut`] for the variant that starts at the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// let (chunks, remainder) = v.as_chunks_mut::<2>();
+    /// remainder[0] = 9;
+    /// for chunk in chunks {
+    ///     *chunk = [count; 2];
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 9]);
+    /// ```
+    ///
+    /// [`as_rchunks_mut`]: #method.as_rchunks_mut
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert!(N != 0);
+        let len = self.len() / N;
+        let (multiple_of_n, remainder) = self.split_at_mut(len * N);
+        // SAFETY: `multiple_of_n` holds `len * N` elements, and `[T; N]` has the layout of `N`
+        // consecutive `T`s.
+        let array_slice =
+            unsafe { from_raw_parts_mut(multiple_of_n.as_mut_ptr() as *mut [T; N], len) };
+        (array_slice, remainder)
+    }
+
+    /// Splits the slice into a slice of mutable `N`-element arrays, starting at the end of the
+    /// slice, and a mutable remainder slice with length strictly less than `N`.
+    ///
+    /// See [`as_chunks_mut`] for the variant that starts at the beginning of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// let (remainder, chunks) = v.as_rchunks_mut::<2>();
+    /// remainder[0] = 9;
+    /// for chunk in chunks {
+    ///     *chunk = [count; 2];
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[9, 1, 1, 2, 2]);
+    /// ```
+    ///
+    /// [`as_chunks_mut`]: #method.as_chunks_mut
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_rchunks_mut<const N: usize>(&mut self) -> (&mut [T], &mut [[T; N]]) {
+        assert!(N != 0);
+        let len = self.len() / N;
+        let (remainder, multiple_of_n) = self.split_at_mut(self.len() - len * N);
+        // SAFETY: `multiple_of_n` holds `len * N` elements, and `[T; N]` has the layout of `N`
+        // consecutive `T`s.
+        let array_slice =
+            unsafe { from_raw_parts_mut(multiple_of_n.as_mut_ptr() as *mut [T; N], len) };
+        (remainder, array_slice)
+    }
+
     /// Divides one slice into two at an index.
     ///
     /// The first will contain all indices from `[0, mid)` (excluding
@@ -1125,8 +1349,9 @@ impl<T> [T] {
     /// assert!(iter.next().is_none());
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_slice_split", issue = "none")]
     #[inline]
-    pub fn split<F>(&self, pred: F) -> Split<'_, T, F>
+    pub const fn split<F>(&self, pred: F) -> Split<'_, T, F>
     where
         F: FnMut(&T) -> bool,
     {
@@ -1327,6 +1552,54 @@ impl<T> [T] {
         RSplitNMut { inner: GenericSplitN { iter: self.rsplit_mut(pred), count: n } }
     }
 
+    /// Splits the slice on the first element that matches `pred`, returning the subslices
+    /// before and after it. The matched element is not contained in either of them.
+    ///
+    /// Returns `None` if no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    ///
+    /// let s = [1, 2, 3, 2, 4];
+    /// assert_eq!(s.split_once(|&x| x == 2), Some((&[1][..], &[3, 2, 4][..])));
+    /// assert_eq!(s.split_once(|&x| x == 0), None);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn split_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(pred)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+
+    /// Splits the slice on the last element that matches `pred`, returning the subslices
+    /// before and after it. The matched element is not contained in either of them.
+    ///
+    /// Returns `None` if no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    ///
+    /// let s = [1, 2, 3, 2, 4];
+    /// assert_eq!(s.rsplit_once(|&x| x == 2), Some((&[1, 2, 3][..], &[4][..])));
+    /// assert_eq!(s.rsplit_once(|&x| x == 0), None);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn rsplit_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(pred)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+
     /// Returns `true` if the slice contains an element with the given value.
     ///
     /// # Examples
@@ -694,6 +694,13 @@ where
         return;
     }
 
+    // Heapsort, which is otherwise the fallback for imbalanced partitions, is much smaller
+    // than the whole quicksort.
+    if cfg!(feature = "optimize_for_size") {
+        heapsort(v, &mut is_less);
+        return;
+    }
+
     // Limit the number of imbalanced partitions to `floor(log2(len)) + 1`.
     let limit = mem::size_of::<usize>() * 8 - v.len().leading_zeros() as usize;
 
@@ -445,6 +445,93 @@ pub unsafe fn from_utf8_unchecked_mut(v: &mut [u8]) -> &mut str {
     &mut *(v as *mut [u8] as *mut str)
 }
 
+/// Forms a string slice from a pointer and a length, without checking that the bytes are valid
+/// UTF-8.
+///
+/// The `len` argument is the number of **bytes**, not the number of characters.
+///
+/// This is the equivalent of [`slice::from_raw_parts`] followed by [`from_utf8_unchecked`],
+/// for the FFI code receiving a string as a pointer and a length.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `ptr` and `len` must meet all the requirements of [`slice::from_raw_parts`]: the `len`
+///   bytes must be valid for reads, within a single allocated object, and `ptr` must be
+///   non-null even for zero-length strings.
+///
+/// * The memory referenced by the returned string slice must not be mutated for the duration
+///   of lifetime `'a`.
+///
+/// * The bytes must be valid UTF-8, or the returned string slice breaks the invariant of `str`
+///   that the rest of the standard library relies on.
+///
+/// # Caveat
+///
+/// The lifetime for the returned string slice is inferred from its usage. To prevent accidental
+/// misuse, it's suggested to tie the lifetime to whichever source lifetime is safe in the
+/// context.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_from_raw_parts)]
+///
+/// use std::str;
+///
+/// let story = "Once upon a time...";
+/// let ptr = story.as_ptr();
+/// let len = story.len();
+///
+/// // We can rebuild a str out of ptr and len. This is all unsafe because
+/// // we are responsible for making sure the two components are valid:
+/// let s = unsafe { str::from_raw_parts(ptr, len) };
+///
+/// assert_eq!(s, story);
+/// ```
+///
+/// [`slice::from_raw_parts`]: ../../std/slice/fn.from_raw_parts.html
+/// [`from_utf8_unchecked`]: fn.from_utf8_unchecked.html
+#[inline]
+#[unstable(feature = "str_from_raw_parts", issue = "none")]
+pub unsafe fn from_raw_parts<'a>(ptr: *const u8, len: usize) -> &'a str {
+    from_utf8_unchecked(slice::from_raw_parts(ptr, len))
+}
+
+/// Forms a mutable string slice from a pointer and a length, without checking that the bytes are
+/// valid UTF-8.
+///
+/// This is the mutable version of [`from_raw_parts`], with the safety requirements of
+/// [`slice::from_raw_parts_mut`]: in particular, the memory must not be accessed through any
+/// other pointer for the duration of lifetime `'a`, and the bytes must stay valid UTF-8 when the
+/// string slice is released.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_from_raw_parts)]
+///
+/// use std::str;
+///
+/// let mut story = String::from("once upon a time...");
+/// let ptr = story.as_mut_ptr();
+/// let len = story.len();
+///
+/// let s = unsafe { str::from_raw_parts_mut(ptr, len) };
+/// s.make_ascii_uppercase();
+///
+/// assert_eq!(story, "ONCE UPON A TIME...");
+/// ```
+///
+/// [`from_raw_parts`]: fn.from_raw_parts.html
+/// [`slice::from_raw_parts_mut`]: ../../std/slice/fn.from_raw_parts_mut.html
+#[inline]
+#[unstable(feature = "str_from_raw_parts", issue = "none")]
+pub unsafe fn from_raw_parts_mut<'a>(ptr: *mut u8, len: usize) -> &'a mut str {
+    from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, len))
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl fmt::Display for Utf8Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -500,6 +587,14 @@ fn utf8_is_cont_byte(byte: u8) -> bool {
     (byte & !CONT_MASK) == TAG_CONT_U8
 }
 
+/// Checks whether the byte is the first byte of a UTF-8 character, i.e., is not
+/// a continuation byte.
+#[inline]
+fn is_utf8_char_boundary(byte: u8) -> bool {
+    // This is bit magic equivalent to: b < 128 || b >= 192
+    (byte as i8) >= -0x40
+}
+
 #[inline]
 fn unwrap_or_0(opt: Option<&u8>) -> u8 {
     match opt {
@@ -1840,13 +1935,13 @@ mod traits {
         unsafe fn get_unchecked(self, slice: &str) -> &Self::Output {
             let ptr = slice.as_ptr().add(self.start);
             let len = self.end - self.start;
-            super::from_utf8_unchecked(slice::from_raw_parts(ptr, len))
+            super::from_raw_parts(ptr, len)
         }
         #[inline]
         unsafe fn get_unchecked_mut(self, slice: &mut str) -> &mut Self::Output {
             let ptr = slice.as_mut_ptr().add(self.start);
             let len = self.end - self.start;
-            super::from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, len))
+            super::from_raw_parts_mut(ptr, len)
         }
         #[inline]
         fn index(self, slice: &str) -> &Self::Output {
@@ -1908,12 +2003,12 @@ mod traits {
         #[inline]
         unsafe fn get_unchecked(self, slice: &str) -> &Self::Output {
             let ptr = slice.as_ptr();
-            super::from_utf8_unchecked(slice::from_raw_parts(ptr, self.end))
+            super::from_raw_parts(ptr, self.end)
         }
         #[inline]
         unsafe fn get_unchecked_mut(self, slice: &mut str) -> &mut Self::Output {
             let ptr = slice.as_mut_ptr();
-            super::from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, self.end))
+            super::from_raw_parts_mut(ptr, self.end)
         }
         #[inline]
         fn index(self, slice: &str) -> &Self::Output {
@@ -1972,13 +2067,13 @@ mod traits {
         unsafe fn get_unchecked(self, slice: &str) -> &Self::Output {
             let ptr = slice.as_ptr().add(self.start);
             let len = slice.len() - self.start;
-            super::from_utf8_unchecked(slice::from_raw_parts(ptr, len))
+            super::from_raw_parts(ptr, len)
         }
         #[inline]
         unsafe fn get_unchecked_mut(self, slice: &mut str) -> &mut Self::Output {
             let ptr = slice.as_mut_ptr().add(self.start);
             let len = slice.len() - self.start;
-            super::from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, len))
+            super::from_raw_parts_mut(ptr, len)
         }
         #[inline]
         fn index(self, slice: &str) -> &Self::Output {
@@ -2238,8 +2333,86 @@ impl str {
         }
         match self.as_bytes().get(index) {
             None => false,
-            // This is bit magic equivalent to: b < 128 || b >= 192
-            Some(&b) => (b as i8) >= -0x40,
+            Some(&b) => is_utf8_char_boundary(b),
+        }
+    }
+
+    /// Finds the closest `x` not exceeding `index` where `is_char_boundary(x)` is `true`.
+    ///
+    /// This method can help you truncate a string so that it's still valid UTF-8, but doesn't
+    /// exceed a given number of bytes. Note that this is done purely at the character level
+    /// and can still visually split graphemes, even though the underlying characters aren't
+    /// split.
+    ///
+    /// If `index` is greater than the length of the string, this returns the length of the
+    /// string. This looks at no more than 4 bytes of the string, so it takes constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.floor_char_boundary(13);
+    /// assert_eq!(closest, 10);
+    /// assert_eq!(&s[..closest], "❤️🧡");
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "none")]
+    #[inline]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            self.len()
+        } else {
+            // A character is at most 4 bytes long, so one of the 4 bytes ending at `index` is
+            // the start of the character containing it.
+            let lower_bound = index.saturating_sub(3);
+            let new_index = self.as_bytes()[lower_bound..=index]
+                .iter()
+                .rposition(|&b| is_utf8_char_boundary(b));
+
+            match new_index {
+                Some(new_index) => lower_bound + new_index,
+                // SAFETY: `self` is valid UTF-8, so one of these bytes starts a character.
+                None => unsafe { crate::hint::unreachable_unchecked() },
+            }
+        }
+    }
+
+    /// Finds the closest `x` not below `index` where `is_char_boundary(x)` is `true`.
+    ///
+    /// If `index` is greater than the length of the string, this returns the length of the
+    /// string. This looks at no more than 4 bytes of the string, so it takes constant time.
+    ///
+    /// This method is the natural complement to [`floor_char_boundary`]. See that method
+    /// for more details.
+    ///
+    /// [`floor_char_boundary`]: #method.floor_char_boundary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.ceil_char_boundary(13);
+    /// assert_eq!(closest, 14);
+    /// assert_eq!(&s[..closest], "❤️🧡💛");
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "none")]
+    #[inline]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        if index > self.len() {
+            self.len()
+        } else {
+            let upper_bound = Ord::min(index + 4, self.len());
+            self.as_bytes()[index..upper_bound]
+                .iter()
+                .position(|&b| is_utf8_char_boundary(b))
+                .map_or(upper_bound, |pos| pos + index)
         }
     }
 
@@ -2640,12 +2813,7 @@ impl str {
             let len = self.len();
             let ptr = self.as_mut_ptr();
             // SAFETY: just checked that `mid` is on a char boundary.
-            unsafe {
-                (
-                    from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, mid)),
-                    from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr.add(mid), len - mid)),
-                )
-            }
+            unsafe { (from_raw_parts_mut(ptr, mid), from_raw_parts_mut(ptr.add(mid), len - mid)) }
         } else {
             slice_error_fail(self, 0, mid)
         }
@@ -2859,11 +3027,24 @@ impl str {
     ///
     /// assert_eq!(None, iter.next());
     /// ```
+    ///
+    /// The iterator can be created in a constant:
+    ///
+    /// ```
+    /// #![feature(const_ascii_whitespace)]
+    /// use std::str::SplitAsciiWhitespace;
+    ///
+    /// const WORDS: SplitAsciiWhitespace<'static> = "debug = true".split_ascii_whitespace();
+    /// assert_eq!(WORDS.collect::<Vec<_>>(), ["debug", "=", "true"]);
+    /// ```
     #[stable(feature = "split_ascii_whitespace", since = "1.34.0")]
+    #[rustc_const_unstable(feature = "const_ascii_whitespace", issue = "none")]
     #[inline]
-    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
-        let inner =
-            self.as_bytes().split(IsAsciiWhitespace).filter(BytesIsNotEmpty).map(UnsafeBytesToStr);
+    pub const fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
+        // The adapters are built directly, as the methods of `Iterator` cannot be called in a
+        // constant.
+        let split = self.as_bytes().split(IsAsciiWhitespace);
+        let inner = Map::new(Filter::new(split, BytesIsNotEmpty), UnsafeBytesToStr);
         SplitAsciiWhitespace { inner }
     }
 
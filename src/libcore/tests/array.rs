@@ -241,3 +241,73 @@ fn iterator_drops() {
     }
     assert_eq!(i.get(), 5);
 }
+
+#[test]
+fn array_try_from_fn() {
+    let array: Result<[usize; 4], ()> = core::array::try_from_fn(|i| Ok(i * 2));
+    assert_eq!(array, Ok([0, 2, 4, 6]));
+
+    let mut calls = 0;
+    let array: Result<[usize; 4], usize> = core::array::try_from_fn(|i| {
+        calls += 1;
+        if i == 2 { Err(i) } else { Ok(i) }
+    });
+    assert_eq!(array, Err(2));
+    assert_eq!(calls, 3);
+
+    let array: Result<[String; 0], ()> = core::array::try_from_fn(|_| panic!());
+    assert_eq!(array, Ok([]));
+}
+
+#[test]
+fn array_try_from_fn_drops() {
+    use core::cell::Cell;
+
+    struct Foo<'a>(&'a Cell<usize>);
+
+    impl Drop for Foo<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    // The elements created before the error are dropped.
+    let i = Cell::new(0);
+    let array: Result<[Foo<'_>; 5], ()> =
+        core::array::try_from_fn(|n| if n < 3 { Ok(Foo(&i)) } else { Err(()) });
+    assert!(array.is_err());
+    assert_eq!(i.get(), 3);
+
+    // And the elements created before a panic.
+    let i = Cell::new(0);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _: Result<[Foo<'_>; 5], ()> =
+            core::array::try_from_fn(|n| if n < 2 { Ok(Foo(&i)) } else { panic!() });
+    }));
+    assert!(result.is_err());
+    assert_eq!(i.get(), 2);
+
+    // None are dropped when the array is returned.
+    let i = Cell::new(0);
+    let array: Result<[Foo<'_>; 5], ()> = core::array::try_from_fn(|_| Ok(Foo(&i)));
+    assert_eq!(i.get(), 0);
+    drop(array);
+    assert_eq!(i.get(), 5);
+}
+
+#[test]
+fn iter_next_chunk() {
+    let mut iter = 0..7;
+    assert_eq!(iter.next_chunk::<3>().unwrap(), [0, 1, 2]);
+    assert_eq!(iter.next_chunk::<3>().unwrap(), [3, 4, 5]);
+    let rest = iter.next_chunk::<3>().unwrap_err();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.collect::<Vec<_>>(), [6]);
+    assert_eq!(iter.next_chunk::<0>().unwrap(), []);
+    assert_eq!(iter.next_chunk::<1>().unwrap_err().len(), 0);
+
+    let mut iter = vec![String::from("a"), String::from("b")].into_iter();
+    let rest = iter.next_chunk::<4>().unwrap_err();
+    assert_eq!(rest.collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(iter.next(), None);
+}
@@ -1,4 +1,5 @@
 use core::char::from_u32;
+use core::str::SplitAsciiWhitespace;
 
 #[test]
 fn test_is_ascii() {
@@ -343,3 +344,22 @@ fn test_is_ascii_control() {
         " ",
     );
 }
+
+#[test]
+fn test_is_ascii_whitespace_const() {
+    const SPACE: bool = b' '.is_ascii_whitespace();
+    const LETTER: bool = 'a'.is_ascii_whitespace();
+    const NON_ASCII: bool = '\u{a0}'.is_ascii_whitespace();
+    assert!(SPACE);
+    assert!(!LETTER);
+    assert!(!NON_ASCII);
+}
+
+#[test]
+fn test_split_ascii_whitespace_const() {
+    const WORDS: SplitAsciiWhitespace<'static> =
+        " Mary   had\ta\u{2009}little  \n\t lamb".split_ascii_whitespace();
+    assert_eq!(WORDS.collect::<Vec<_>>(), ["Mary", "had", "a\u{2009}little", "lamb"]);
+    const EMPTY: SplitAsciiWhitespace<'static> = " \t\n".split_ascii_whitespace();
+    assert_eq!(EMPTY.count(), 0);
+}
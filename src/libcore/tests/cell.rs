@@ -1,6 +1,7 @@
 use core::cell::*;
 use core::default::Default;
 use std::mem::drop;
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
 
 #[test]
 fn smoketest_cell() {
@@ -367,3 +368,98 @@ fn refcell_replace_borrows() {
     let _b = x.borrow();
     x.replace(1);
 }
+
+#[test]
+fn once_cell() {
+    let c = OnceCell::new();
+    assert!(c.get().is_none());
+    c.get_or_init(|| 92);
+    assert_eq!(c.get(), Some(&92));
+
+    c.get_or_init(|| panic!("Kabom!"));
+    assert_eq!(c.get(), Some(&92));
+    assert_eq!(c.set(62), Err(62));
+}
+
+#[test]
+fn once_cell_get_mut() {
+    let mut c = OnceCell::new();
+    assert!(c.get_mut().is_none());
+    c.set(90).unwrap();
+    *c.get_mut().unwrap() += 2;
+    assert_eq!(c.get_mut(), Some(&mut 92));
+    assert_eq!(c.take(), Some(92));
+    assert!(c.get().is_none());
+}
+
+#[test]
+fn once_cell_drop() {
+    static DROP_CNT: AtomicUsize = AtomicUsize::new(0);
+    struct Dropper;
+    impl Drop for Dropper {
+        fn drop(&mut self) {
+            DROP_CNT.fetch_add(1, SeqCst);
+        }
+    }
+
+    let x = OnceCell::new();
+    x.get_or_init(|| Dropper);
+    assert_eq!(DROP_CNT.load(SeqCst), 0);
+    drop(x);
+    assert_eq!(DROP_CNT.load(SeqCst), 1);
+}
+
+#[test]
+#[should_panic(expected = "reentrant init")]
+fn once_cell_reentrant_init() {
+    let x: OnceCell<Box<i32>> = OnceCell::new();
+    x.get_or_init(|| {
+        let r = x.get_or_init(|| Box::new(92));
+        Box::new(**r)
+    });
+}
+
+#[test]
+fn once_cell_traits() {
+    let c: OnceCell<u32> = OnceCell::default();
+    assert_eq!(format!("{:?}", c), "OnceCell(Uninit)");
+    let d = OnceCell::from(1);
+    assert_eq!(format!("{:?}", d), "OnceCell(1)");
+    assert_eq!(d.clone(), d);
+    assert!(c != d);
+}
+
+#[test]
+fn lazy_cell() {
+    let called = Cell::new(0);
+    let x = LazyCell::new(|| {
+        called.set(called.get() + 1);
+        92
+    });
+
+    assert_eq!(called.get(), 0);
+
+    let y = *x - 30;
+    assert_eq!(y, 62);
+    assert_eq!(called.get(), 1);
+
+    let y = *x - 30;
+    assert_eq!(y, 62);
+    assert_eq!(called.get(), 1);
+}
+
+#[test]
+fn lazy_cell_default() {
+    let x: LazyCell<Vec<u8>> = LazyCell::default();
+    assert_eq!(format!("{:?}", x), "LazyCell(Uninit)");
+    assert!(LazyCell::force(&x).is_empty());
+    assert_eq!(format!("{:?}", x), "LazyCell([])");
+}
+
+#[test]
+#[should_panic(expected = "previously been poisoned")]
+fn lazy_cell_poisoned() {
+    let x: LazyCell<String> = LazyCell::new(|| panic!("kaboom"));
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| x.len()));
+    let _ = &*x;
+}
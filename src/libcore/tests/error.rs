@@ -0,0 +1,52 @@
+use core::error::Error;
+use core::fmt;
+
+#[derive(Debug, PartialEq)]
+struct A;
+
+#[derive(Debug, PartialEq)]
+struct B(A);
+
+impl fmt::Display for A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("A")
+    }
+}
+
+impl fmt::Display for B {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("B")
+    }
+}
+
+impl Error for A {}
+
+impl Error for B {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn downcasting() {
+    let mut a = A;
+    let a = &mut a as &mut (dyn Error + 'static);
+    assert!(a.is::<A>());
+    assert_eq!(a.downcast_ref::<A>(), Some(&A));
+    assert_eq!(a.downcast_ref::<B>(), None);
+    assert_eq!(a.downcast_mut::<A>(), Some(&mut A));
+    assert_eq!(a.downcast_mut::<B>(), None);
+
+    let b = &B(A) as &(dyn Error + Send + Sync + 'static);
+    assert_eq!(b.downcast_ref::<B>(), Some(&B(A)));
+    assert_eq!(b.source().and_then(|a| a.downcast_ref::<A>()), Some(&A));
+}
+
+#[test]
+fn chain() {
+    let b = B(A);
+    let mut chain = (&b as &(dyn Error + 'static)).chain();
+    assert_eq!(chain.next().and_then(|e| e.downcast_ref::<B>()), Some(&b));
+    assert_eq!(chain.next().and_then(|e| e.downcast_ref::<A>()), Some(&A));
+    assert!(chain.next().is_none());
+}
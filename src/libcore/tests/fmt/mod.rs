@@ -21,12 +21,43 @@ fn test_pointer_formats_data_pointer() {
 
 #[test]
 fn test_estimated_capacity() {
+    // String literal arguments are inlined into the pieces, so use other strings.
+    let (empty, world) = (String::new(), String::from("World"));
     assert_eq!(format_args!("").estimated_capacity(), 0);
-    assert_eq!(format_args!("{}", "").estimated_capacity(), 0);
+    assert_eq!(format_args!("{}", empty).estimated_capacity(), 0);
     assert_eq!(format_args!("Hello").estimated_capacity(), 5);
-    assert_eq!(format_args!("Hello, {}!", "").estimated_capacity(), 16);
-    assert_eq!(format_args!("{}, hello!", "World").estimated_capacity(), 0);
-    assert_eq!(format_args!("{}. 16-bytes piece", "World").estimated_capacity(), 32);
+    assert_eq!(format_args!("Hello, {}!", empty).estimated_capacity(), 16);
+    assert_eq!(format_args!("{}, hello!", world).estimated_capacity(), 0);
+    assert_eq!(format_args!("{}. 16-bytes piece", world).estimated_capacity(), 32);
+}
+
+#[test]
+fn test_as_statically_known_str() {
+    let world = "World";
+    assert_eq!(format_args!("").as_statically_known_str(), Some(""));
+    assert_eq!(format_args!("Hello").as_statically_known_str(), Some("Hello"));
+    assert_eq!(format_args!("{}", "").as_statically_known_str(), Some(""));
+    assert_eq!(
+        format_args!("{}, {}!", "Hello", "World").as_statically_known_str(),
+        Some("Hello, World!")
+    );
+    assert_eq!(format_args!("{1} {0} {1}", "a", "b").as_statically_known_str(), Some("b a b"));
+    assert_eq!(format_args!("{x}{{}}", x = "{}").as_statically_known_str(), Some("{}{}"));
+    assert_eq!(
+        format_args!(concat!("Hello", ", {}!"), "World").as_statically_known_str(),
+        Some("Hello, World!")
+    );
+
+    assert_eq!(format_args!("Hello, {}!", world).as_statically_known_str(), None);
+    assert_eq!(format_args!("{:?}", "Hello").as_statically_known_str(), None);
+    assert_eq!(format_args!("{:>6}", "Hello").as_statically_known_str(), None);
+    assert_eq!(format_args!("{} {:?}", "Hello", "Hello").as_statically_known_str(), None);
+    assert_eq!(format_args!("{}, {}!", "Hello", 1).as_statically_known_str(), None);
+
+    // Mixing inlined literals and formatted arguments keeps the arguments in their place.
+    assert_eq!(format!("{}, {}! {}", "Hello", world, 1), "Hello, World! 1");
+    assert_eq!(format!("{0}{1}{0}{2:?}", "-", world, "a"), "-World-\"a\"");
+    assert_eq!(format!("{:2$}|{}", world, "a", 7), "World  |a");
 }
 
 #[test]
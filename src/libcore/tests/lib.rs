@@ -10,6 +10,7 @@
 #![feature(exact_size_is_empty)]
 #![feature(fixed_size_array)]
 #![feature(flt2dec)]
+#![feature(fmt_as_str)]
 #![feature(fmt_internals)]
 #![feature(hashmap_internals)]
 #![feature(try_find)]
@@ -42,6 +43,17 @@
 #![feature(const_raw_ptr_deref)]
 #![feature(never_type)]
 #![feature(unwrap_infallible)]
+#![feature(num_decimal)]
+#![feature(slice_split_once)]
+#![feature(substr_range)]
+#![feature(const_ascii_whitespace)]
+#![feature(error_in_core)]
+#![feature(error_iter)]
+#![feature(once_cell)]
+#![feature(lazy_cell)]
+#![feature(slice_as_chunks)]
+#![feature(array_try_from_fn)]
+#![feature(iter_next_chunk)]
 
 extern crate test;
 
@@ -55,6 +67,7 @@ mod cell;
 mod char;
 mod clone;
 mod cmp;
+mod error;
 mod fmt;
 mod hash;
 mod intrinsics;
@@ -0,0 +1,52 @@
+use core::num::DecimalBuffer;
+use core::{f32, f64, i128, i16, i32, i64, i8, u128, u16, u32, u64, u8};
+
+#[test]
+fn test_format_int() {
+    let mut buf = DecimalBuffer::new();
+    assert_eq!(buf.format(0u8), "0");
+    assert_eq!(buf.format(9u16), "9");
+    assert_eq!(buf.format(10u32), "10");
+    assert_eq!(buf.format(12345u64), "12345");
+    assert_eq!(buf.format(-1i32), "-1");
+    assert_eq!(buf.format(-12345isize), "-12345");
+    assert_eq!(buf.format(u8::MAX), "255");
+    assert_eq!(buf.format(i8::MIN), "-128");
+    assert_eq!(buf.format(u16::MAX), "65535");
+    assert_eq!(buf.format(i16::MIN), "-32768");
+    assert_eq!(buf.format(u32::MAX), "4294967295");
+    assert_eq!(buf.format(i32::MIN), "-2147483648");
+    assert_eq!(buf.format(u64::MAX), "18446744073709551615");
+    assert_eq!(buf.format(i64::MIN), "-9223372036854775808");
+    assert_eq!(buf.format(u128::MAX), "340282366920938463463374607431768211455");
+    assert_eq!(buf.format(i128::MIN), "-170141183460469231731687303715884105728");
+}
+
+#[test]
+fn test_format_int_matches_display() {
+    let mut buf = DecimalBuffer::new();
+    for &n in &[0i64, 7, -7, 99, 100, -101, 9999, 10000, 123456789, i64::MAX] {
+        assert_eq!(buf.format(n), format!("{}", n));
+    }
+}
+
+#[test]
+fn test_format_float() {
+    let mut buf = DecimalBuffer::new();
+    assert_eq!(buf.format(0.0f64), "0");
+    assert_eq!(buf.format(-0.0f64), "0");
+    assert_eq!(buf.format(1.0f64), "1");
+    assert_eq!(buf.format(-1.5f64), "-1.5");
+    assert_eq!(buf.format(0.1f32), "0.1");
+    assert_eq!(buf.format(0.3f64), "0.3");
+    assert_eq!(buf.format(0.00001f64), "0.00001");
+    assert_eq!(buf.format(0.000001f64), "1e-6");
+    assert_eq!(buf.format(1e16f64), "10000000000000000");
+    assert_eq!(buf.format(1e17f64), "1e17");
+    assert_eq!(buf.format(f64::MAX), "1.7976931348623157e308");
+    assert_eq!(buf.format(-f64::MIN_POSITIVE), "-2.2250738585072014e-308");
+    assert_eq!(buf.format(f32::MAX), "3.4028235e38");
+    assert_eq!(buf.format(f64::INFINITY), "inf");
+    assert_eq!(buf.format(f64::NEG_INFINITY), "-inf");
+    assert_eq!(buf.format(f64::NAN), "NaN");
+}
@@ -25,6 +25,7 @@ mod u8;
 
 mod bignum;
 mod dec2flt;
+mod decimal;
 mod flt2dec;
 
 /// Adds the attribute to all items in the block.
@@ -18,6 +18,51 @@ fn test_rposition() {
     assert_eq!(b.iter().rposition(|&v| v == 0), None);
 }
 
+#[test]
+fn test_split_once() {
+    let v = [1, 2, 3, 2, 4];
+    assert_eq!(v.split_once(|&x| x == 2), Some((&[1][..], &[3, 2, 4][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 2), Some((&[1, 2, 3][..], &[4][..])));
+    assert_eq!(v.split_once(|&x| x == 4), Some((&[1, 2, 3, 2][..], &[][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 1), Some((&[][..], &[2, 3, 2, 4][..])));
+    assert_eq!(v.split_once(|&x| x == 5), None);
+    assert_eq!(v.rsplit_once(|&x| x == 5), None);
+}
+
+#[test]
+fn test_element_offset() {
+    let v = [1u16, 2, 3];
+    assert_eq!(v.element_offset(&v[0]), Some(0));
+    assert_eq!(v.element_offset(&v[2]), Some(2));
+    assert_eq!(v.element_offset(&1), None);
+
+    // A reference into the middle of an element is not the start of one.
+    let pairs = [[1u8, 2], [3, 4]];
+    let middle = unsafe { &*(&pairs[0][1] as *const u8 as *const [u8; 2]) };
+    assert_eq!(pairs.element_offset(&pairs[1]), Some(1));
+    assert_eq!(pairs.element_offset(middle), None);
+}
+
+#[test]
+fn test_subslice_range() {
+    let v = [1, 2, 3, 4, 5];
+    assert_eq!(v.subslice_range(&v[..]), Some(0..5));
+    assert_eq!(v.subslice_range(&v[1..3]), Some(1..3));
+    assert_eq!(v.subslice_range(&v[5..]), Some(5..5));
+    assert_eq!(v.subslice_range(&[1, 2]), None);
+
+    let (first, rest) = v.split_at(2);
+    assert_eq!(v.subslice_range(rest), Some(2..5));
+    assert_eq!(rest.subslice_range(first), None);
+}
+
+#[test]
+#[should_panic]
+fn test_element_offset_zst() {
+    let v = [(), ()];
+    v.element_offset(&v[1]);
+}
+
 #[test]
 fn test_binary_search() {
     let b: [i32; 0] = [];
@@ -709,6 +754,64 @@ fn test_rchunks_exact_mut_zip() {
     assert_eq!(v1, [0, 16, 17, 22, 23]);
 }
 
+#[test]
+fn test_as_chunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4];
+    let (chunks, remainder) = v.as_chunks::<2>();
+    assert_eq!(chunks, &[[0, 1], [2, 3]]);
+    assert_eq!(remainder, &[4]);
+
+    let (chunks, remainder) = v.as_chunks::<5>();
+    assert_eq!(chunks, &[[0, 1, 2, 3, 4]]);
+    assert!(remainder.is_empty());
+
+    let (chunks, remainder) = v.as_chunks::<6>();
+    assert!(chunks.is_empty());
+    assert_eq!(remainder, v);
+}
+
+#[test]
+fn test_as_rchunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4];
+    let (remainder, chunks) = v.as_rchunks::<2>();
+    assert_eq!(remainder, &[0]);
+    assert_eq!(chunks, &[[1, 2], [3, 4]]);
+
+    let v: &[i32] = &[];
+    let (remainder, chunks) = v.as_rchunks::<3>();
+    assert!(remainder.is_empty());
+    assert!(chunks.is_empty());
+}
+
+#[test]
+fn test_as_chunks_mut() {
+    let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5, 6];
+    let (chunks, remainder) = v.as_chunks_mut::<3>();
+    for chunk in chunks.iter_mut() {
+        chunk.reverse();
+    }
+    remainder[0] = 10;
+    assert_eq!(v, [2, 1, 0, 5, 4, 3, 10]);
+}
+
+#[test]
+fn test_as_rchunks_mut() {
+    let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5, 6];
+    let (remainder, chunks) = v.as_rchunks_mut::<3>();
+    for chunk in chunks.iter_mut() {
+        chunk.reverse();
+    }
+    remainder[0] = 10;
+    assert_eq!(v, [10, 3, 2, 1, 6, 5, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_as_chunks_zero() {
+    let v: &[i32] = &[0, 1, 2];
+    let _ = v.as_chunks::<0>();
+}
+
 #[test]
 fn test_windows_count() {
     let v: &[i32] = &[0, 1, 2, 3, 4, 5];
@@ -31,6 +31,13 @@ pub unsafe extern "C" fn __rust_maybe_catch_panic(
     0
 }
 
+// Panics never unwind with this runtime, even in the crates compiled with
+// `-C panic=unwind`.
+#[rustc_std_internal_symbol]
+pub extern "C" fn __rust_panic_can_unwind() -> bool {
+    false
+}
+
 // "Leak" the payload and shim to the relevant abort on the platform in
 // question.
 //
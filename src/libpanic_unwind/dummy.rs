@@ -6,6 +6,9 @@ use alloc::boxed::Box;
 use core::any::Any;
 use core::intrinsics;
 
+/// Panics abort the process here, instead of unwinding.
+pub const CAN_UNWIND: bool = false;
+
 pub fn payload() -> *mut u8 {
     core::ptr::null_mut()
 }
@@ -48,6 +48,8 @@ static EXCEPTION_TYPE_INFO: TypeInfo = TypeInfo {
     name: b"rust_panic\0".as_ptr(),
 };
 
+pub const CAN_UNWIND: bool = true;
+
 pub fn payload() -> *mut u8 {
     ptr::null_mut()
 }
@@ -60,6 +60,8 @@ struct Exception {
     cause: Box<dyn Any + Send>,
 }
 
+pub const CAN_UNWIND: bool = true;
+
 pub unsafe fn panic(data: Box<dyn Any + Send>) -> u32 {
     let exception = Box::new(Exception {
         _uwe: uw::_Unwind_Exception {
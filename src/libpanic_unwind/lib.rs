@@ -110,3 +110,10 @@ pub unsafe extern "C" fn __rust_start_panic(payload: usize) -> u32 {
 
     imp::panic(Box::from_raw(payload))
 }
+
+// Entry point for asking whether `__rust_start_panic` unwinds, which it does
+// not on the targets where the implementation only has stubs.
+#[no_mangle]
+pub extern "C" fn __rust_panic_can_unwind() -> bool {
+    imp::CAN_UNWIND
+}
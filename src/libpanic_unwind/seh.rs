@@ -248,6 +248,8 @@ cfg_if::cfg_if! {
    }
 }
 
+pub const CAN_UNWIND: bool = true;
+
 pub unsafe fn panic(data: Box<dyn Any + Send>) -> u32 {
     use core::intrinsics::atomic_store;
 
@@ -116,6 +116,7 @@ macro_rules! arena_types {
             [few] visible_parent_map: rustc_hir::def_id::DefIdMap<rustc_hir::def_id::DefId>,
             [few] foreign_module: rustc::middle::cstore::ForeignModule,
             [few] foreign_modules: Vec<rustc::middle::cstore::ForeignModule>,
+            [few] repr_c_layout: rustc::ty::layout::ReprCLayout,
             [few] reachable_non_generics: rustc_hir::def_id::DefIdMap<
                 rustc::middle::exported_symbols::SymbolExportLevel
             >,
@@ -90,6 +90,10 @@ struct DepGraphData {
     previous_work_products: FxHashMap<WorkProductId, WorkProduct>,
 
     dep_node_debug: Lock<FxHashMap<DepNode, String>>,
+
+    /// The nodes whose results were loaded from the on-disk cache, recorded with
+    /// `-Z query-dep-graph` for `#[rustc_clean(loaded_from_disk = "...")]`.
+    debug_loaded_from_disk: Lock<FxHashSet<DepNode>>,
 }
 
 pub fn hash_result<R>(hcx: &mut StableHashingContext<'_>, result: &R) -> Option<Fingerprint>
@@ -113,6 +117,7 @@ impl DepGraph {
             data: Some(Lrc::new(DepGraphData {
                 previous_work_products: prev_work_products,
                 dep_node_debug: Default::default(),
+                debug_loaded_from_disk: Default::default(),
                 current: CurrentDepGraph::new(prev_graph_node_count),
                 emitting_diagnostics: Default::default(),
                 emitting_diagnostics_cond_var: Condvar::new(),
@@ -488,6 +493,16 @@ impl DepGraph {
         self.data.as_ref()?.dep_node_debug.borrow().get(&dep_node).cloned()
     }
 
+    pub fn mark_debug_loaded_from_disk(&self, dep_node: DepNode) {
+        self.data.as_ref().unwrap().debug_loaded_from_disk.borrow_mut().insert(dep_node);
+    }
+
+    pub fn debug_was_loaded_from_disk(&self, dep_node: DepNode) -> bool {
+        self.data
+            .as_ref()
+            .map_or(false, |data| data.debug_loaded_from_disk.borrow().contains(&dep_node))
+    }
+
     pub fn edge_deduplication_data(&self) -> Option<(u64, u64)> {
         if cfg!(debug_assertions) {
             let current_dep_graph = &self.data.as_ref().unwrap().current;
@@ -275,7 +275,7 @@ impl CheckAttrVisitor<'tcx> {
                 .emit();
                 false
             }
-            Target::Fn | Target::Method(MethodKind::Inherent) => true,
+            Target::Fn | Target::Method(MethodKind::Inherent) | Target::Closure => true,
             Target::Method(_) => {
                 struct_span_err!(
                     self.tcx.sess,
@@ -395,6 +395,9 @@ impl CheckAttrVisitor<'tcx> {
                     is_simd = true;
                     if target != Target::Struct { ("a", "struct") } else { continue }
                 }
+                sym::scalable => {
+                    if target != Target::Struct { ("a", "struct") } else { continue }
+                }
                 sym::transparent => {
                     is_transparent = true;
                     match target {
@@ -504,6 +507,38 @@ impl CheckAttrVisitor<'tcx> {
                     "not defining a struct, enum, or union",
                 );
             }
+            if attr.check_name(sym::track_caller) {
+                if self.is_generator(expr) {
+                    struct_span_err!(
+                        self.tcx.sess,
+                        attr.span,
+                        E0739,
+                        "`#[track_caller]` may not be used on generators or async blocks",
+                    )
+                    .note(
+                        "generators are resumed through `Generator::resume` and `Future::poll`, \
+                         which cannot forward the location of their caller",
+                    )
+                    .emit();
+                } else {
+                    self.check_track_caller(&attr.span, &expr.attrs, &expr.span, target);
+                }
+            }
+        }
+    }
+
+    /// Whether `expr` defines a generator, either directly or as the body of an async block.
+    fn is_generator(&self, expr: &hir::Expr<'_>) -> bool {
+        match expr.kind {
+            hir::ExprKind::Closure(.., Some(_)) => true,
+            hir::ExprKind::Call(_, [arg]) => match arg.kind {
+                hir::ExprKind::Closure(.., body, _, Some(_)) => {
+                    let kind = self.tcx.hir().body(body).generator_kind;
+                    kind == Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block))
+                }
+                _ => false,
+            },
+            _ => false,
         }
     }
 
@@ -5,7 +5,10 @@ use crate::infer::error_reporting::nice_region_error::util::AnonymousParamInfo;
 use crate::infer::error_reporting::nice_region_error::NiceRegionError;
 use crate::util::common::ErrorReported;
 
-use rustc_errors::struct_span_err;
+use rustc_errors::{struct_span_err, DiagnosticBuilder};
+use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_span::symbol::kw;
 
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
     /// Print the error message for lifetime errors when both the concerned regions are anonymous.
@@ -92,6 +95,8 @@ impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
             None => String::new(),
         };
 
+        // The function whose return type is involved in the conflict, if any.
+        let mut ret_fn = None;
         let (span_1, span_2, main_label, span_label) = match (sup_is_ret_type, sub_is_ret_type) {
             (None, None) => {
                 let (main_label_1, span_label_1) = if ty_sup.hir_id == ty_sub.hir_id {
@@ -108,29 +113,97 @@ impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
                 (ty_sup.span, ty_sub.span, main_label_1, span_label_1)
             }
 
-            (Some(ret_span), _) => (
-                ty_sub.span,
-                ret_span,
-                "this parameter and the return type are declared \
-                 with different lifetimes..."
-                    .to_owned(),
-                format!("...but data{} is returned here", span_label_var1),
-            ),
-            (_, Some(ret_span)) => (
-                ty_sup.span,
-                ret_span,
-                "this parameter and the return type are declared \
-                 with different lifetimes..."
-                    .to_owned(),
-                format!("...but data{} is returned here", span_label_var1),
-            ),
+            (Some(ret_span), _) => {
+                ret_fn = Some((scope_def_id_sub, ty_fndecl_sub));
+                (
+                    ty_sub.span,
+                    ret_span,
+                    "this parameter and the return type are declared \
+                     with different lifetimes..."
+                        .to_owned(),
+                    format!("...but data{} is returned here", span_label_var1),
+                )
+            }
+            (_, Some(ret_span)) => {
+                ret_fn = Some((scope_def_id_sup, ty_fndecl_sup));
+                (
+                    ty_sup.span,
+                    ret_span,
+                    "this parameter and the return type are declared \
+                     with different lifetimes..."
+                        .to_owned(),
+                    format!("...but data{} is returned here", span_label_var1),
+                )
+            }
         };
 
-        struct_span_err!(self.tcx().sess, span, E0623, "lifetime mismatch")
-            .span_label(span_1, main_label)
+        let mut err = struct_span_err!(self.tcx().sess, span, E0623, "lifetime mismatch");
+        err.span_label(span_1, main_label)
             .span_label(span_2, String::new())
-            .span_label(span, span_label)
-            .emit();
+            .span_label(span, span_label);
+        if let Some((scope_def_id, fndecl)) = ret_fn {
+            self.note_smart_pointer_receiver_elision(&mut err, scope_def_id, fndecl);
+        }
+        err.emit();
         return Some(ErrorReported);
     }
+
+    /// Explains where the elided lifetimes of the return type come from when they are taken
+    /// from a reference nested in a `self` receiver like `self: Box<&mut Self>` or
+    /// `self: Pin<&Self>`, which is easy to overlook.
+    fn note_smart_pointer_receiver_elision(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        scope_def_id: DefId,
+        fndecl: &hir::FnDecl<'_>,
+    ) {
+        let hir = self.tcx().hir();
+        let has_self_param = hir
+            .as_local_hir_id(scope_def_id)
+            .and_then(|hir_id| hir.maybe_body_owned_by(hir_id))
+            .and_then(|body_id| hir.body(body_id).params.first())
+            .and_then(|param| param.pat.simple_ident())
+            .map_or(false, |ident| ident.name == kw::SelfLower);
+        let receiver = match fndecl.inputs.first() {
+            Some(receiver) if has_self_param => receiver,
+            _ => return,
+        };
+        // Plain `&self` and `self: &Self` receivers are not worth pointing out.
+        if let hir::TyKind::Rptr(..) = receiver.kind {
+            return;
+        }
+        if !has_reference_to_path(receiver) {
+            return;
+        }
+        if let Ok(snippet) = self.tcx().sess.source_map().span_to_snippet(receiver.span) {
+            err.note(&format!(
+                "the elided lifetime in the return type is taken from the reference in the \
+                 type of `self`, `{}`",
+                snippet,
+            ));
+        }
+    }
+}
+
+/// Whether `ty` is a reference to a path like `&Self` or `&mut Foo`, or a path with such a
+/// reference among its type arguments, like `Pin<Box<&Self>>`.
+fn has_reference_to_path(ty: &hir::Ty<'_>) -> bool {
+    match ty.kind {
+        hir::TyKind::Rptr(_, ref mt) => {
+            if let hir::TyKind::Path(hir::QPath::Resolved(None, _)) = mt.ty.kind {
+                true
+            } else {
+                false
+            }
+        }
+        hir::TyKind::Path(hir::QPath::Resolved(None, ref path)) => {
+            path.segments.iter().filter_map(|segment| segment.args).any(|args| {
+                args.args.iter().any(|arg| match arg {
+                    hir::GenericArg::Type(ty) => has_reference_to_path(ty),
+                    _ => false,
+                })
+            })
+        }
+        _ => false,
+    }
 }
@@ -296,6 +296,10 @@ language_item_table! {
     F64ImplItem,                 "f64",                f64_impl,                Target::Impl;
     F32RuntimeImplItem,          "f32_runtime",        f32_runtime_impl,        Target::Impl;
     F64RuntimeImplItem,          "f64_runtime",        f64_runtime_impl,        Target::Impl;
+    ErrorAllocImplItem,          "error_alloc",        error_alloc_impl,        Target::Impl;
+    ErrorSendAllocImplItem,      "error_send_alloc",   error_send_alloc_impl,   Target::Impl;
+    ErrorSendSyncAllocImplItem,  "error_send_sync_alloc", error_send_sync_alloc_impl, Target::Impl;
+    BacktraceImplItem,           "backtrace_impl",     backtrace_impl,          Target::Impl;
 
     SizedTraitLangItem,          "sized",              sized_trait,             Target::Trait;
     UnsizeTraitLangItem,         "unsize",             unsize_trait,            Target::Trait;
@@ -340,9 +344,11 @@ language_item_table! {
 
     UnsafeCellTypeLangItem,      "unsafe_cell",        unsafe_cell_type,        Target::Struct;
     VaListTypeLangItem,          "va_list",            va_list,                 Target::Struct;
+    BacktraceTypeLangItem,       "backtrace",          backtrace_type,          Target::Struct;
 
     DerefTraitLangItem,          "deref",              deref_trait,             Target::Trait;
     DerefMutTraitLangItem,       "deref_mut",          deref_mut_trait,         Target::Trait;
+    DerefPureTraitLangItem,      "deref_pure",         deref_pure_trait,        Target::Trait;
     ReceiverTraitLangItem,       "receiver",           receiver_trait,          Target::Trait;
 
     FnTraitLangItem,             "fn",                 fn_trait,                Target::Trait;
@@ -354,6 +360,7 @@ language_item_table! {
     GeneratorTraitLangItem,      "generator",          gen_trait,               Target::Trait;
     UnpinTraitLangItem,          "unpin",              unpin_trait,             Target::Trait;
     PinTypeLangItem,             "pin",                pin_type,                Target::Struct;
+    UnsafePinnedTypeLangItem,    "unsafe_pinned",      unsafe_pinned_type,      Target::Struct;
 
     // Don't be fooled by the naming here: this lang item denotes `PartialEq`, not `Eq`.
     EqTraitLangItem,             "eq",                 eq_trait,                Target::Trait;
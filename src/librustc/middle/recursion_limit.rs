@@ -14,6 +14,24 @@ use rustc_data_structures::sync::Once;
 pub fn update_limits(sess: &Session, krate: &ast::Crate) {
     update_limit(krate, &sess.recursion_limit, sym::recursion_limit, 128);
     update_limit(krate, &sess.type_length_limit, sym::type_length_limit, 1048576);
+    // There is no step limit by default, so that crates with long-running constants keep
+    // compiling.
+    let const_eval_limit = krate.attrs.iter().find(|attr| attr.check_name(sym::const_eval_limit));
+    sess.const_eval_limit
+        .set(const_eval_limit.and_then(|attr| const_eval_limit_value(sess, attr)).unwrap_or(0));
+}
+
+/// Returns the value of a `#[const_eval_limit = "N"]` attribute, reporting an error if it is not
+/// a non-negative integer.
+pub fn const_eval_limit_value(sess: &Session, attr: &ast::Attribute) -> Option<usize> {
+    let value = attr.value_str()?;
+    match value.as_str().parse() {
+        Ok(limit) => Some(limit),
+        Err(_) => {
+            sess.span_err(attr.span, "`#[const_eval_limit]` must be a non-negative integer");
+            None
+        }
+    }
 }
 
 fn update_limit(krate: &ast::Crate, limit: &Once<usize>, name: Symbol, default: usize) {
@@ -349,6 +349,7 @@ impl<'tcx> TyCtxt<'tcx> {
                     return EvalResult::Allow;
                 }
                 if self.stability().active_features.contains(&feature) {
+                    self.sess.parse_sess.record_feature_use(feature, span);
                     return EvalResult::Allow;
                 }
 
@@ -7,30 +7,25 @@ use super::{
 use crate::ty::layout::{Align, Size};
 
 use rustc_data_structures::sorted_map::SortedMap;
+use rustc_data_structures::sync::Once;
+use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
 use rustc_target::abi::HasDataLayout;
 use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter;
+use std::mem;
 use std::ops::{Deref, DerefMut, Range};
 use syntax::ast::Mutability;
 
 // NOTE: When adding new fields, make sure to adjust the `Snapshot` impl in
 // `src/librustc_mir/interpret/snapshot.rs`.
-#[derive(
-    Clone,
-    Debug,
-    Eq,
-    PartialEq,
-    PartialOrd,
-    Ord,
-    Hash,
-    RustcEncodable,
-    RustcDecodable,
-    HashStable
-)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, HashStable)]
 pub struct Allocation<Tag = (), Extra = ()> {
     /// The actual bytes of the allocation.
     /// Note that the bytes of a pointer represent the offset of the pointer.
-    bytes: Vec<u8>,
+    bytes: AllocBytes,
     /// Maps from byte addresses to extra data for each pointer.
     /// Only the first byte of a pointer is inserted into the map; i.e.,
     /// every entry in this map applies to `pointer_size` consecutive bytes starting
@@ -50,6 +45,190 @@ pub struct Allocation<Tag = (), Extra = ()> {
     pub extra: Extra,
 }
 
+/// The bytes of an allocation.
+///
+/// The bytes of allocations that are all zero, like those of large zeroed or uninitialized
+/// statics, are not stored until they are written to or read in a slice longer than `ZEROS`.
+/// Interned allocations always store zero bytes this way, see `Allocation::compact`.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, HashStable)]
+enum AllocBytes {
+    Bytes(Vec<u8>),
+    /// This many zero bytes.
+    Zeroed(usize, #[stable_hasher(ignore)] ZeroedCache),
+}
+
+/// The slices of zero bytes handed out for short reads of `AllocBytes::Zeroed`.
+static ZEROS: [u8; 1024] = [0; 1024];
+
+/// The zero bytes of an `AllocBytes::Zeroed`, once they are read in a slice longer than `ZEROS`.
+/// They are not part of the value of the allocation.
+struct ZeroedCache(Once<Vec<u8>>);
+
+impl Clone for ZeroedCache {
+    fn clone(&self) -> Self {
+        ZeroedCache(Once::new())
+    }
+}
+
+impl PartialEq for ZeroedCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ZeroedCache {}
+
+impl PartialOrd for ZeroedCache {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ZeroedCache {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for ZeroedCache {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl fmt::Debug for ZeroedCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "..")
+    }
+}
+
+impl AllocBytes {
+    fn zeroed(len: usize) -> Self {
+        AllocBytes::Zeroed(len, ZeroedCache(Once::new()))
+    }
+
+    fn get(&self, range: Range<usize>) -> &[u8] {
+        match self {
+            AllocBytes::Bytes(bytes) => &bytes[range],
+            AllocBytes::Zeroed(len, cache) => {
+                assert!(range.start <= range.end && range.end <= *len);
+                if range.len() <= ZEROS.len() {
+                    &ZEROS[..range.len()]
+                } else {
+                    &cache.0.init_locking(|| vec![0; *len])[range]
+                }
+            }
+        }
+    }
+
+    fn get_mut(&mut self, range: Range<usize>) -> &mut [u8] {
+        if let AllocBytes::Zeroed(len, cache) = self {
+            let bytes = mem::replace(&mut cache.0, Once::new()).into_inner();
+            *self = AllocBytes::Bytes(bytes.unwrap_or_else(|| vec![0; *len]));
+        }
+        match self {
+            AllocBytes::Bytes(bytes) => &mut bytes[range],
+            AllocBytes::Zeroed(..) => bug!("the zero bytes of an allocation were not stored"),
+        }
+    }
+}
+
+impl Encodable for AllocBytes {
+    fn encode<E: Encoder>(&self, s: &mut E) -> Result<(), E::Error> {
+        match self {
+            AllocBytes::Bytes(bytes) => encode_runs(bytes, s),
+            AllocBytes::Zeroed(len, _) => vec![(&[][..], &0u8, *len)].encode(s),
+        }
+    }
+}
+
+impl Decodable for AllocBytes {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Self, D::Error> {
+        let chunks: Vec<(Vec<u8>, u8, usize)> = Decodable::decode(d)?;
+        if let [(single, 0, len)] = &chunks[..] {
+            if single.is_empty() {
+                return Ok(AllocBytes::zeroed(*len));
+            }
+        }
+        Ok(AllocBytes::Bytes(expand_runs(chunks)))
+    }
+}
+
+/// Runs of at least this many equal elements of the bytes and the undef mask of an allocation are
+/// serialized as the element and the length of the run, so that e.g. large zeroed statics do not
+/// bloat the metadata.
+const MIN_SERIALIZED_RUN: usize = 16;
+
+/// Splits `elems` into chunks that each consist of elements stored one by one, followed by a run
+/// of one element repeated at least `MIN_SERIALIZED_RUN` times. The run of the last chunk may be
+/// empty, in which case its element is `elems[0]`.
+fn serialized_runs<T: PartialEq>(elems: &[T]) -> Vec<(&[T], &T, usize)> {
+    let mut chunks = vec![];
+    let mut chunk_start = 0;
+    let mut i = 0;
+    while i < elems.len() {
+        let elem = &elems[i];
+        let run = elems[i..].iter().take_while(|other| *other == elem).count();
+        if run >= MIN_SERIALIZED_RUN {
+            chunks.push((&elems[chunk_start..i], elem, run));
+            chunk_start = i + run;
+        }
+        i += run;
+    }
+    if chunk_start < elems.len() {
+        chunks.push((&elems[chunk_start..], &elems[0], 0));
+    }
+    chunks
+}
+
+fn encode_runs<T: PartialEq + Encodable, E: Encoder>(
+    elems: &[T],
+    s: &mut E,
+) -> Result<(), E::Error> {
+    serialized_runs(elems).encode(s)
+}
+
+fn decode_runs<T: Clone + Decodable, D: Decoder>(d: &mut D) -> Result<Vec<T>, D::Error> {
+    Ok(expand_runs(Decodable::decode(d)?))
+}
+
+fn expand_runs<T: Clone>(chunks: Vec<(Vec<T>, T, usize)>) -> Vec<T> {
+    let mut elems = vec![];
+    for (single, elem, run) in chunks {
+        elems.extend(single);
+        elems.extend(iter::repeat(elem).take(run));
+    }
+    elems
+}
+
+impl<Tag: Encodable, Extra: Encodable> Encodable for Allocation<Tag, Extra> {
+    fn encode<E: Encoder>(&self, s: &mut E) -> Result<(), E::Error> {
+        s.emit_struct("Allocation", 7, |s| {
+            s.emit_struct_field("bytes", 0, |s| self.bytes.encode(s))?;
+            s.emit_struct_field("relocations", 1, |s| self.relocations.encode(s))?;
+            s.emit_struct_field("undef_mask", 2, |s| self.undef_mask.encode(s))?;
+            s.emit_struct_field("size", 3, |s| self.size.encode(s))?;
+            s.emit_struct_field("align", 4, |s| self.align.encode(s))?;
+            s.emit_struct_field("mutability", 5, |s| self.mutability.encode(s))?;
+            s.emit_struct_field("extra", 6, |s| self.extra.encode(s))
+        })
+    }
+}
+
+impl<Tag: Decodable, Extra: Decodable> Decodable for Allocation<Tag, Extra> {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Self, D::Error> {
+        d.read_struct("Allocation", 7, |d| {
+            Ok(Allocation {
+                bytes: d.read_struct_field("bytes", 0, Decodable::decode)?,
+                relocations: d.read_struct_field("relocations", 1, Decodable::decode)?,
+                undef_mask: d.read_struct_field("undef_mask", 2, Decodable::decode)?,
+                size: d.read_struct_field("size", 3, Decodable::decode)?,
+                align: d.read_struct_field("align", 4, Decodable::decode)?,
+                mutability: d.read_struct_field("mutability", 5, Decodable::decode)?,
+                extra: d.read_struct_field("extra", 6, Decodable::decode)?,
+            })
+        })
+    }
+}
+
 pub trait AllocationExtra<Tag>: ::std::fmt::Debug + Clone {
     // There is no constructor in here because the constructor's type depends
     // on `MemoryKind`, and making things sufficiently generic leads to painful
@@ -101,7 +280,7 @@ impl<Tag> Allocation<Tag> {
         let bytes = slice.into().into_owned();
         let size = Size::from_bytes(bytes.len() as u64);
         Self {
-            bytes,
+            bytes: AllocBytes::Bytes(bytes),
             relocations: Relocations::new(),
             undef_mask: UndefMask::new(size, true),
             size,
@@ -118,7 +297,7 @@ impl<Tag> Allocation<Tag> {
     pub fn undef(size: Size, align: Align) -> Self {
         assert_eq!(size.bytes() as usize as u64, size.bytes());
         Allocation {
-            bytes: vec![0; size.bytes() as usize],
+            bytes: AllocBytes::zeroed(size.bytes() as usize),
             relocations: Relocations::new(),
             undef_mask: UndefMask::new(size, false),
             size,
@@ -156,6 +335,17 @@ impl Allocation<(), ()> {
             extra,
         }
     }
+
+    /// Replaces the `AllocId` of every relocation with `f(alloc_id)`.
+    pub fn map_relocations(self, mut f: impl FnMut(AllocId) -> AllocId) -> Self {
+        let relocations = Relocations::from_presorted(
+            self.relocations
+                .iter()
+                .map(|&(offset, ((), alloc))| (offset, ((), f(alloc))))
+                .collect(),
+        );
+        Allocation { relocations, ..self }
+    }
 }
 
 /// Raw accessors. Provide access to otherwise private bytes.
@@ -169,7 +359,25 @@ impl<Tag, Extra> Allocation<Tag, Extra> {
     /// edges) at all. It further ignores `AllocationExtra` callbacks.
     /// This must not be used for reads affecting the interpreter execution.
     pub fn inspect_with_undef_and_ptr_outside_interpreter(&self, range: Range<usize>) -> &[u8] {
-        &self.bytes[range]
+        self.bytes.get(range)
+    }
+
+    /// Returns whether the bytes of this allocation are all zero and not stored, which is always
+    /// the case for interned allocations whose bytes are all zero.
+    pub fn is_zeroed(&self) -> bool {
+        match self.bytes {
+            AllocBytes::Zeroed(..) => true,
+            AllocBytes::Bytes(_) => false,
+        }
+    }
+
+    /// Stops storing the bytes of this allocation if they are all zero.
+    pub fn compact(&mut self) {
+        if let AllocBytes::Bytes(bytes) = &self.bytes {
+            if bytes.iter().all(|&byte| byte == 0) {
+                self.bytes = AllocBytes::zeroed(bytes.len());
+            }
+        }
     }
 
     /// Returns the undef mask.
@@ -214,7 +422,7 @@ impl<'tcx, Tag: Copy, Extra: AllocationExtra<Tag>> Allocation<Tag, Extra> {
     ///
     /// This function also guarantees that the resulting pointer will remain stable
     /// even when new allocations are pushed to the `HashMap`. `copy_repeatedly` relies
-    /// on that.
+    /// on that. For bytes that are all zero and not stored, it may point to `ZEROS`.
     ///
     /// It is the caller's responsibility to check bounds and alignment beforehand.
     fn get_bytes_internal(
@@ -236,7 +444,7 @@ impl<'tcx, Tag: Copy, Extra: AllocationExtra<Tag>> Allocation<Tag, Extra> {
 
         AllocationExtra::memory_read(self, ptr, size)?;
 
-        Ok(&self.bytes[range])
+        Ok(self.bytes.get(range))
     }
 
     /// Checks that these bytes are initialized and not pointer bytes, and then return them
@@ -288,7 +496,7 @@ impl<'tcx, Tag: Copy, Extra: AllocationExtra<Tag>> Allocation<Tag, Extra> {
 
         AllocationExtra::memory_written(self, ptr, size)?;
 
-        Ok(&mut self.bytes[range])
+        Ok(self.bytes.get_mut(range))
     }
 }
 
@@ -305,7 +513,7 @@ impl<'tcx, Tag: Copy, Extra: AllocationExtra<Tag>> Allocation<Tag, Extra> {
     ) -> InterpResult<'tcx, &[u8]> {
         assert_eq!(ptr.offset.bytes() as usize as u64, ptr.offset.bytes());
         let offset = ptr.offset.bytes() as usize;
-        Ok(match self.bytes[offset..].iter().position(|&c| c == 0) {
+        Ok(match self.bytes.get(offset..self.len()).iter().position(|&c| c == 0) {
             Some(size) => {
                 let size_with_null = Size::from_bytes((size + 1) as u64);
                 // Go through `get_bytes` for checks and AllocationExtra hooks.
@@ -758,23 +966,32 @@ type Block = u64;
 
 /// A bitmask where each bit refers to the byte with the same index. If the bit is `true`, the byte
 /// is defined. If it is `false` the byte is undefined.
-#[derive(
-    Clone,
-    Debug,
-    Eq,
-    PartialEq,
-    PartialOrd,
-    Ord,
-    Hash,
-    RustcEncodable,
-    RustcDecodable,
-    HashStable
-)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, HashStable)]
 pub struct UndefMask {
     blocks: Vec<Block>,
     len: Size,
 }
 
+impl Encodable for UndefMask {
+    fn encode<E: Encoder>(&self, s: &mut E) -> Result<(), E::Error> {
+        s.emit_struct("UndefMask", 2, |s| {
+            s.emit_struct_field("blocks", 0, |s| encode_runs(&self.blocks, s))?;
+            s.emit_struct_field("len", 1, |s| self.len.encode(s))
+        })
+    }
+}
+
+impl Decodable for UndefMask {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Self, D::Error> {
+        d.read_struct("UndefMask", 2, |d| {
+            Ok(UndefMask {
+                blocks: d.read_struct_field("blocks", 0, decode_runs)?,
+                len: d.read_struct_field("len", 1, Decodable::decode)?,
+            })
+        })
+    }
+}
+
 impl UndefMask {
     pub const BLOCK_SIZE: u64 = 64;
 
@@ -8,6 +8,7 @@ use crate::ty::{self, layout, Ty};
 
 use backtrace::Backtrace;
 use hir::GeneratorKind;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{struct_span_err, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_macros::HashStable;
@@ -59,6 +60,18 @@ pub struct FrameInfo<'tcx> {
     pub lint_root: Option<hir::HirId>,
 }
 
+/// Where an allocation of a const evaluation was created. The CTFE machine records this to point
+/// at the allocation in errors about the pointers to it that remain in the final value.
+#[derive(Clone, Debug)]
+pub struct AllocOrigin<'tcx> {
+    /// The span of the local, or of the call to `const_allocate`, that created the allocation.
+    pub span: Span,
+    /// The function that created the allocation, or `None` if it is the item being evaluated.
+    pub instance: Option<ty::Instance<'tcx>>,
+    /// Whether this is a heap allocation of `const_allocate` rather than a local.
+    pub is_heap: bool,
+}
+
 impl<'tcx> fmt::Display for FrameInfo<'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         ty::tls::with(|tcx| {
@@ -78,6 +91,9 @@ impl<'tcx> fmt::Display for FrameInfo<'tcx> {
     }
 }
 
+/// The number of distinct frames shown when the evaluation reaches the stack frame limit.
+const STACK_FRAME_LIMIT_SHOWN_FRAMES: usize = 5;
+
 impl<'tcx> ConstEvalErr<'tcx> {
     pub fn struct_error(
         &self,
@@ -165,12 +181,59 @@ impl<'tcx> ConstEvalErr<'tcx> {
         if !must_error {
             err.span_label(self.span, self.error.to_string());
         }
+        if let err_unsup!(ReadPointerAsBytes) = self.error {
+            err.note(
+                "the absolute address of a pointer is not known at compile-time, so such \
+                 operations are not supported",
+            );
+        }
+        if let err_unsup!(DanglingPointerInFinalValue(Some(ref origin))) = self.error {
+            let what = if origin.is_heap { "heap allocation" } else { "local variable" };
+            let inside = match origin.instance {
+                Some(instance) => format!(" inside call to `{}`", instance),
+                None => String::new(),
+            };
+            err.span_note(
+                origin.span,
+                &format!(
+                    "the pointer points to this {}{}, which was deallocated before the end of \
+                     the evaluation",
+                    what, inside
+                ),
+            );
+        }
         // Skip the last, which is just the environment of the constant.  The stacktrace
         // is sometimes empty because we create "fake" eval contexts in CTFE to do work
         // on constant values.
         if self.stacktrace.len() > 0 {
-            for frame_info in &self.stacktrace[..self.stacktrace.len() - 1] {
-                err.span_label(frame_info.call_site, frame_info.to_string());
+            let frames = &self.stacktrace[..self.stacktrace.len() - 1];
+            if let err_exhaust!(StackFrameLimitReached { limit }) = self.error {
+                // The stack of a runaway recursion is the same few frames over and over, so
+                // only show the innermost distinct ones.
+                let mut distinct = FxHashSet::default();
+                let shown: Vec<_> = frames
+                    .iter()
+                    .filter(|frame| distinct.insert((frame.call_site, frame.instance)))
+                    .take(STACK_FRAME_LIMIT_SHOWN_FRAMES)
+                    .collect();
+                for frame_info in &shown {
+                    err.span_label(frame_info.call_site, frame_info.to_string());
+                }
+                if shown.len() < frames.len() {
+                    err.note(&format!(
+                        "repeated frames, and the distinct frames after the first {}, are not shown",
+                        STACK_FRAME_LIMIT_SHOWN_FRAMES
+                    ));
+                }
+                err.help(&format!(
+                    "the limit of {} stack frames can be raised with \
+                     `#[const_eval_stack_limit = \"N\"]` or `-Z const-eval-stack-limit=N`",
+                    limit
+                ));
+            } else {
+                for frame_info in frames {
+                    err.span_label(frame_info.call_site, frame_info.to_string());
+                }
             }
         }
         Ok(err)
@@ -417,6 +480,9 @@ pub enum UnsupportedOpInfo<'tcx> {
         has: Align,
     },
     ValidationFailure(String),
+    /// A pointer in the final value of a constant points to an allocation that was deallocated
+    /// during its evaluation. Contains where that allocation was created, if it is known.
+    DanglingPointerInFinalValue(Option<AllocOrigin<'tcx>>),
     VtableForArgumentlessMethod,
     ModifiedConstantMemory,
     ModifiedStatic,
@@ -447,6 +513,9 @@ impl fmt::Debug for UnsupportedOpInfo<'tcx> {
                 allocation_size.bytes()
             ),
             ValidationFailure(ref err) => write!(f, "type validation failed: {}", err),
+            DanglingPointerInFinalValue(_) => {
+                write!(f, "type validation failed: encountered dangling pointer in final constant")
+            }
             NoMirFor(ref func) => write!(f, "no MIR for `{}`", func),
             FunctionAbiMismatch(caller_abi, callee_abi) => write!(
                 f,
@@ -507,11 +576,7 @@ impl fmt::Debug for UnsupportedOpInfo<'tcx> {
             }
             InvalidBool => write!(f, "invalid boolean value read"),
             InvalidNullPointerUsage => write!(f, "invalid use of NULL pointer"),
-            ReadPointerAsBytes => write!(
-                f,
-                "a raw memory access tried to access part of a pointer value as raw \
-                    bytes"
-            ),
+            ReadPointerAsBytes => write!(f, "unable to turn pointer into raw bytes"),
             ReadBytesAsPointer => {
                 write!(f, "a memory access tried to interpret some bytes as a pointer")
             }
@@ -576,17 +641,22 @@ impl fmt::Debug for UnsupportedOpInfo<'tcx> {
 /// Error information for when the program exhausted the resources granted to it
 /// by the interpreter.
 pub enum ResourceExhaustionInfo {
-    /// The stack grew too big.
-    StackFrameLimitReached,
+    /// The stack grew to more than `limit` frames.
+    StackFrameLimitReached { limit: usize },
     /// The program ran into an infinite loop.
     InfiniteLoop,
+    /// The evaluation of the named constant took more than the configured number of steps.
+    StepLimitReached { name: String, limit: usize },
+    /// The evaluation of the named constant had more than the configured number of bytes
+    /// allocated.
+    MemoryLimitReached { name: String, limit: u64 },
 }
 
 impl fmt::Debug for ResourceExhaustionInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use ResourceExhaustionInfo::*;
         match self {
-            StackFrameLimitReached => {
+            StackFrameLimitReached { .. } => {
                 write!(f, "reached the configured maximum number of stack frames")
             }
             InfiniteLoop => write!(
@@ -594,6 +664,18 @@ impl fmt::Debug for ResourceExhaustionInfo {
                 "duplicate interpreter state observed here, const evaluation will never \
                     terminate"
             ),
+            StepLimitReached { name, limit } => write!(
+                f,
+                "evaluation of `{}` exceeded the limit of {} interpreter steps \
+                    (see `#[const_eval_limit]`)",
+                name, limit
+            ),
+            MemoryLimitReached { name, limit } => write!(
+                f,
+                "memory limit exceeded in evaluation of `{}`: more than {} bytes are allocated \
+                    (see `-Z const-eval-memory-limit`)",
+                name, limit
+            ),
         }
     }
 }
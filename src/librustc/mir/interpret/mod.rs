@@ -104,9 +104,9 @@ mod queries;
 mod value;
 
 pub use self::error::{
-    struct_error, AssertMessage, ConstEvalErr, ConstEvalRawResult, ConstEvalResult, ErrorHandled,
-    FrameInfo, InterpError, InterpErrorInfo, InterpResult, InvalidProgramInfo, PanicInfo,
-    ResourceExhaustionInfo, UndefinedBehaviorInfo, UnsupportedOpInfo,
+    struct_error, AllocOrigin, AssertMessage, ConstEvalErr, ConstEvalRawResult, ConstEvalResult,
+    ErrorHandled, FrameInfo, InterpError, InterpErrorInfo, InterpResult, InvalidProgramInfo,
+    PanicInfo, ResourceExhaustionInfo, UndefinedBehaviorInfo, UnsupportedOpInfo,
 };
 
 pub use self::value::{get_slice_bytes, ConstValue, RawConst, Scalar, ScalarMaybeUndef};
@@ -127,11 +127,14 @@ use rustc_data_structures::tiny_list::TinyList;
 use rustc_hir::def_id::DefId;
 use rustc_macros::HashStable;
 use rustc_serialize::{Decodable, Encodable, Encoder};
+use rustc_span::{Span, Symbol};
+use rustc_target::spec::PanicStrategy;
 use std::fmt;
 use std::io;
 use std::num::NonZeroU32;
 use std::sync::atomic::{AtomicU32, Ordering};
-use syntax::ast::LitKind;
+use syntax::ast::{LitKind, Mutability};
+use syntax::attr::{self, UnwindAttr};
 
 /// Uniquely identifies one of the following:
 /// - A constant
@@ -166,6 +169,54 @@ pub enum LitToConstError {
     Reported,
 }
 
+/// Input argument for `tcx.const_caller_location`: the position in the source of a call to a
+/// `#[track_caller]` function, which its `core::panic::Location` describes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, HashStable)]
+pub struct CallerLocation {
+    pub file: Symbol,
+    pub line: u32,
+    pub col: u32,
+    /// The column just past the end of the call, or 0 if the call ends on another line.
+    pub col_end: u32,
+    /// The offset of the start of the call in its file, in bytes.
+    pub offset: u32,
+    /// The length of the call, in bytes.
+    pub len: u32,
+    /// Whether a panic in the call can unwind out of the calling function, see `can_unwind_from`.
+    pub can_unwind: bool,
+}
+
+impl CallerLocation {
+    /// Returns the location of the call at `span`, or of the outermost macro invocation that
+    /// expanded to it, from which a panic can unwind if `can_unwind`.
+    pub fn for_span(tcx: TyCtxt<'_>, span: Span, can_unwind: bool) -> CallerLocation {
+        let topmost = span.ctxt().outer_expn().expansion_cause().unwrap_or(span);
+        let source_map = tcx.sess.source_map();
+        let lo = source_map.lookup_char_pos(topmost.lo());
+        let hi = source_map.lookup_char_pos(topmost.hi());
+        CallerLocation {
+            file: Symbol::intern(&lo.file.name.to_string()),
+            line: lo.line as u32,
+            col: lo.col_display as u32 + 1,
+            col_end: if hi.line == lo.line { hi.col_display as u32 + 1 } else { 0 },
+            offset: (topmost.lo() - lo.file.start_pos).0,
+            len: (topmost.hi() - topmost.lo()).0,
+            can_unwind,
+        }
+    }
+
+    /// Returns whether a panic in a call made by the body of `caller`, in one of its cleanup
+    /// blocks if `in_cleanup`, can unwind out of `caller`. It cannot under `-C panic=abort`,
+    /// from a cleanup block, whose calls have no landing pads, nor from a function with
+    /// `#[unwind(aborts)]`, which aborts when a panic reaches it.
+    pub fn can_unwind_from(tcx: TyCtxt<'_>, caller: DefId, in_cleanup: bool) -> bool {
+        if tcx.sess.panic_strategy() != PanicStrategy::Unwind || in_cleanup {
+            return false;
+        }
+        attr::find_unwind_attr(None, &tcx.get_attrs(caller)) != Some(UnwindAttr::Aborts)
+    }
+}
+
 #[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
 pub struct AllocId(pub u64);
 
@@ -384,6 +435,10 @@ pub struct AllocMap<'tcx> {
     // FIXME: Should we just have two separate dedup maps for statics and functions each?
     dedup: FxHashMap<GlobalAlloc<'tcx>, AllocId>,
 
+    /// Used to share one `AllocId` between all immutable allocations of constants that have the
+    /// same contents. See `dedup_immutable_memory`.
+    memory_dedup: FxHashMap<&'tcx Allocation, AllocId>,
+
     /// The `AllocId` to assign to the next requested ID.
     /// Always incremented; never gets smaller.
     next_id: AllocId,
@@ -391,7 +446,12 @@ pub struct AllocMap<'tcx> {
 
 impl<'tcx> AllocMap<'tcx> {
     pub fn new() -> Self {
-        AllocMap { alloc_map: Default::default(), dedup: Default::default(), next_id: AllocId(0) }
+        AllocMap {
+            alloc_map: Default::default(),
+            dedup: Default::default(),
+            memory_dedup: Default::default(),
+            next_id: AllocId(0),
+        }
     }
 
     /// Obtains a new allocation ID that can be referenced but does not
@@ -503,6 +563,25 @@ impl<'tcx> AllocMap<'tcx> {
         }
     }
 
+    /// Returns the `AllocId` to use instead of `id` for the immutable allocation `mem`: the first
+    /// `AllocId` passed to this function with an identical `Allocation`, or `id` itself.
+    /// Only use this for allocations whose address cannot be observed, as all `AllocId`s with the
+    /// same contents end up at the same address.
+    pub fn dedup_immutable_memory(&mut self, id: AllocId, mem: &'tcx Allocation) -> AllocId {
+        assert_eq!(mem.mutability, Mutability::Not);
+        *self.memory_dedup.entry(mem).or_insert(id)
+    }
+
+    /// Points an `AllocId` that was frozen with `set_alloc_id_memory` at another `Allocation`.
+    /// This must only be done while the allocation is interned, before anything outside of the
+    /// interner can have seen the old `Allocation`.
+    pub fn reset_alloc_id_memory(&mut self, id: AllocId, mem: &'tcx Allocation) {
+        match self.alloc_map.insert(id, GlobalAlloc::Memory(mem)) {
+            Some(GlobalAlloc::Memory(_)) => {}
+            old => bug!("tried to reset allocation ID {}, but it was {:#?}", id, old),
+        }
+    }
+
     /// Freezes an `AllocId` created with `reserve` by pointing it at an `Allocation`. May be called
     /// twice for the same `(AllocId, Allocation)` pair.
     fn set_alloc_id_same_memory(&mut self, id: AllocId, mem: &'tcx Allocation) {
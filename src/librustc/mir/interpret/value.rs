@@ -13,7 +13,7 @@ use crate::ty::{
 use super::{sign_extend, truncate, AllocId, Allocation, InterpResult, Pointer, PointerArithmetic};
 
 /// Represents the result of a raw const operation, pre-validation.
-#[derive(Clone, HashStable)]
+#[derive(Clone, HashStable, RustcEncodable, RustcDecodable)]
 pub struct RawConst<'tcx> {
     // the value lives here, at offset 0, and that allocation definitely is a `AllocKind::Memory`
     // (so you can use `AllocMap::unwrap_memory`).
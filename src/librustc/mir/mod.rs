@@ -2476,7 +2476,14 @@ impl<'tcx> Display for Constant<'tcx> {
         if let ty::RawPtr(_) = self.literal.ty.kind {
             write!(fmt, "{:?} : {}", self.literal.val, self.literal.ty)
         } else {
-            write!(fmt, "{}", self.literal)
+            // The MIR dumps print aggregates and references by their representation.
+            ty::tls::with(|tcx| {
+                let literal = tcx.lift(&self.literal).expect("could not lift for printing");
+                let mut cx = FmtPrinter::new(tcx, fmt, Namespace::ValueNS);
+                cx.print_const_contents = false;
+                cx.print_const(literal)?;
+                Ok(())
+            })
         }
     }
 }
@@ -17,6 +17,12 @@ pub enum UnsafetyViolationKind {
     /// Permitted both in `const fn`s and regular `fn`s.
     GeneralAndConstFn,
     BorrowPacked(hir::HirId),
+    /// Unsafe operation in an `unsafe fn` but outside an `unsafe` block, linted by
+    /// `unsafe_op_in_unsafe_fn` at the given lint root.
+    UnsafeFn(hir::HirId),
+    /// Call to an `unsafe fn` that was safe to call before the edition of the call, linted by
+    /// `deprecated_safe` at the given lint root.
+    DeprecatedSafe(hir::HirId),
 }
 
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable, HashStable)]
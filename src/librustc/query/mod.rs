@@ -474,6 +474,13 @@ rustc_queries! {
                 "const-evaluating `{}`",
                 tcx.def_path_str(key.value.instance.def.def_id())
             }
+            // The result only depends on the MIR and the layouts that the evaluation consulted,
+            // so the evaluation of the statics that other globals read is not redone just
+            // because something unrelated in the crate changed.
+            cache_on_disk_if(_, opt_result) {
+                // Only store results without errors
+                opt_result.map_or(true, |r| r.is_ok())
+            }
         }
 
         /// Results of evaluating const items or constants embedded in
@@ -514,7 +521,16 @@ rustc_queries! {
             desc { "destructure constant" }
         }
 
-        query const_caller_location(key: (rustc_span::Symbol, u32, u32)) -> &'tcx ty::Const<'tcx> {
+        /// Reads the value that a constant reference points to, if it is in the memory of the
+        /// constant.
+        query deref_const(
+            key: ty::ParamEnvAnd<'tcx, &'tcx ty::Const<'tcx>>
+        ) -> Option<&'tcx ty::Const<'tcx>> {
+            no_force
+            desc { "dereference constant" }
+        }
+
+        query const_caller_location(key: mir::interpret::CallerLocation) -> &'tcx ty::Const<'tcx> {
             no_force
             desc { "get a &core::panic::Location referring to a span" }
         }
@@ -705,6 +721,11 @@ rustc_queries! {
             fatal_cycle
             desc { "checking if the crate has_panic_handler" }
         }
+        /// Whether the crate defines statics whose types have destructors, which never run.
+        query has_static_dtors(_: CrateNum) -> bool {
+            fatal_cycle
+            desc { "checking if the crate has statics with destructors" }
+        }
         query is_profiler_runtime(_: CrateNum) -> bool {
             fatal_cycle
             desc { "query a crate is `#![profiler_runtime]`" }
@@ -790,6 +811,12 @@ rustc_queries! {
             desc { "looking up the foreign modules of a linked crate" }
         }
 
+        /// The layouts of the `#[repr(C)]` structs and unions of a crate. For another crate,
+        /// these are only available if it was compiled with `-Z encode-repr-c-layouts`.
+        query repr_c_layouts(_: CrateNum) -> &'tcx [ty::layout::ReprCLayout] {
+            desc { "computing the layouts of the `#[repr(C)]` types of a crate" }
+        }
+
         /// Identifies the entry-point (e.g., the `main` function) for a given
         /// crate, returning `None` if there is no entry point (such as for library crates).
         query entry_fn(_: CrateNum) -> Option<(DefId, EntryFnType)> {
@@ -38,6 +38,29 @@ fn in_environment(
     InEnvironment { environment, goal: obligation }
 }
 
+/// Warns if the old trait solver disagrees with Chalk about whether `obligation` holds, for
+/// `-Z chalk-cross-check`. `chalk_result` is `None` if Chalk found the obligation ambiguous.
+fn cross_check_with_old_solver(
+    infcx: &InferCtxt<'_, 'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+    chalk_result: Option<bool>,
+) {
+    let disagreement = match chalk_result {
+        Some(true) if !infcx.predicate_may_hold(obligation) => "Chalk proves it",
+        Some(false) if infcx.predicate_must_hold_modulo_regions(obligation) => {
+            "the old solver proves it"
+        }
+        _ => return,
+    };
+    infcx.tcx.sess.span_warn(
+        obligation.cause.span,
+        &format!(
+            "trait solver discrepancy for `{}`: {}, the other one does not",
+            obligation.predicate, disagreement
+        ),
+    );
+}
+
 impl TraitEngine<'tcx> for FulfillmentContext<'tcx> {
     fn normalize_projection_type(
         &mut self,
@@ -102,7 +125,17 @@ impl TraitEngine<'tcx> for FulfillmentContext<'tcx> {
                     &mut orig_values,
                 );
 
-                match infcx.tcx.evaluate_goal(canonical_goal) {
+                let result = infcx.tcx.evaluate_goal(canonical_goal);
+                if infcx.tcx.sess.opts.debugging_opts.chalk_cross_check {
+                    let chalk_result = match &result {
+                        Ok(response) if response.is_proven() => Some(true),
+                        Ok(_) => None,
+                        Err(NoSolution) => Some(false),
+                    };
+                    cross_check_with_old_solver(infcx, &obligation.goal, chalk_result);
+                }
+
+                match result {
                     Ok(response) => {
                         if response.is_proven() {
                             making_progress = true;
@@ -624,6 +624,14 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
                             // If it has a custom `#[rustc_on_unimplemented]` note, let's display it
                             err.note(s.as_str());
                         }
+                        if let Some(indexed_ty) = self.indexed_ty(trait_ref, obligation) {
+                            // The indexed type may describe how it is indexed with its
+                            // `#[rustc_on_misuse]`.
+                            let indexed_ty = self.resolve_vars_if_possible(&indexed_ty);
+                            if let Some(s) = self.on_misuse_note(indexed_ty, "index", None).note {
+                                err.note(&s);
+                            }
+                        }
                         if let Some(ref s) = enclosing_scope {
                             let enclosing_scope_span = tcx.def_span(
                                 tcx.hir()
@@ -642,6 +650,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
                         self.suggest_fn_call(&obligation, &mut err, &trait_ref, points_at_arg);
                         self.suggest_remove_reference(&obligation, &mut err, &trait_ref);
                         self.suggest_semicolon_removal(&obligation, &mut err, span, &trait_ref);
+                        self.suggest_derive(&obligation, &mut err, &trait_ref);
                         self.note_version_mismatch(&mut err, &trait_ref);
                         if self.suggest_impl_trait(&mut err, span, &obligation, &trait_ref) {
                             err.emit();
@@ -3,10 +3,10 @@ use super::{
 };
 use crate::infer::InferCtxt;
 use crate::ty::subst::Subst;
-use crate::ty::{self, GenericParamDefKind};
+use crate::ty::{self, GenericParamDefKind, Ty};
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{kw, sym, Symbol};
 
 impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
     fn impl_similar_to(
@@ -196,4 +196,79 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
             OnUnimplementedNote::default()
         }
     }
+
+    /// Evaluates the `#[rustc_on_misuse]` attribute of the type of `self_ty` for an error about
+    /// the operation `op` that the type does not support: `"index"`, `"deref"`, or `"method"`
+    /// for a call of the method `from_method` that it does not have.
+    pub fn on_misuse_note(
+        &self,
+        self_ty: Ty<'tcx>,
+        op: &str,
+        from_method: Option<Symbol>,
+    ) -> OnUnimplementedNote {
+        let (def, substs) = match self_ty.kind {
+            ty::Adt(def, substs) => (def, substs),
+            _ => return OnUnimplementedNote::default(),
+        };
+        let directive = match OnUnimplementedDirective::of_type(self.tcx, def.did) {
+            Ok(Some(directive)) => directive,
+            _ => return OnUnimplementedNote::default(),
+        };
+
+        let mut flags = vec![];
+        flags.push((sym::op, Some(op.to_owned())));
+        if let Some(method) = from_method {
+            flags.push((sym::from_method, None));
+            flags.push((sym::from_method, Some(method.to_string())));
+        }
+        // Unlike for a trait, `Self` is not one of the generic parameters of the type.
+        flags.push((kw::SelfUpper, Some(self_ty.to_string())));
+        flags.push((sym::_Self, Some(self_ty.to_string())));
+        flags.push((sym::_Self, Some(self.tcx.type_of(def.did).to_string())));
+        for param in self.tcx.generics_of(def.did).params.iter() {
+            if let GenericParamDefKind::Type { .. } = param.kind {
+                let arg = substs.type_at(param.index as usize);
+                flags.push((param.name, Some(arg.to_string())));
+                // Also allow selecting the argument's type with no type arguments resolved
+                if let Some(arg_def) = arg.ty_adt_def() {
+                    flags.push((param.name, Some(self.tcx.type_of(arg_def.did).to_string())));
+                }
+            }
+        }
+
+        // The format strings substitute the generic parameters of the type like the ones of a
+        // trait.
+        directive.evaluate(self.tcx, ty::TraitRef::new(def.did, substs), &flags[..])
+    }
+
+    /// The type being indexed, if `trait_ref` is the `Index` bound of an indexing expression,
+    /// or a requirement of the `Index` impl that it selected.
+    crate fn indexed_ty(
+        &self,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        obligation: &PredicateObligation<'tcx>,
+    ) -> Option<Ty<'tcx>> {
+        let is_index = |trait_ref: &ty::PolyTraitRef<'tcx>| {
+            let lang_items = self.tcx.lang_items();
+            let def_id = Some(trait_ref.def_id());
+            def_id == lang_items.index_trait() || def_id == lang_items.index_mut_trait()
+        };
+        if is_index(&trait_ref) {
+            return Some(trait_ref.skip_binder().self_ty());
+        }
+        let mut code = &obligation.cause.code;
+        loop {
+            match code {
+                ObligationCauseCode::BuiltinDerivedObligation(data)
+                | ObligationCauseCode::ImplDerivedObligation(data) => {
+                    let parent_trait_ref = self.resolve_vars_if_possible(&data.parent_trait_ref);
+                    if is_index(&parent_trait_ref) {
+                        return Some(parent_trait_ref.skip_binder().self_ty());
+                    }
+                    code = &*data.parent_code;
+                }
+                _ => return None,
+            }
+        }
+    }
 }
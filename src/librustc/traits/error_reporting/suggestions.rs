@@ -15,9 +15,10 @@ use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::Visitor;
 use rustc_hir::Node;
+use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::SourceMap;
-use rustc_span::symbol::{kw, sym};
-use rustc_span::{MultiSpan, Span, DUMMY_SP};
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::{BytePos, MultiSpan, Span, DUMMY_SP};
 use std::fmt;
 
 impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
@@ -508,6 +509,175 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         }
     }
 
+    /// Suggests deriving the trait of `trait_ref` on its self type, if that is a struct or enum
+    /// of the local crate which could derive it: the trait must be one of the built-in derives,
+    /// and the fields of the type must implement it, as well as the traits that it requires to be
+    /// derived alongside it.
+    crate fn suggest_derive(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut DiagnosticBuilder<'tcx>,
+        trait_ref: &ty::PolyTraitRef<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let trait_ref = self.resolve_vars_if_possible(trait_ref);
+        if trait_ref.has_infer_types() {
+            return;
+        }
+        let trait_ref = trait_ref.skip_binder();
+        let self_ty = trait_ref.self_ty();
+        let (adt, substs) = match self_ty.kind {
+            ty::Adt(adt, substs) if !adt.is_union() => (adt, substs),
+            _ => return,
+        };
+        let adt_span = match tcx.hir().span_if_local(adt.did) {
+            Some(span) if !span.from_expansion() => span,
+            _ => return,
+        };
+        let derivable = self.derivable_traits();
+        let name = match derivable.iter().find(|&&(_, def_id)| def_id == Some(trait_ref.def_id)) {
+            Some(&(name, _)) => name,
+            None => return,
+        };
+        // `PartialEq` and `PartialOrd` are only derived for the comparison with the type itself,
+        // `Default` only for structs, and `Copy` only for the types without a destructor.
+        if trait_ref.substs.types().skip(1).any(|ty| ty != self_ty)
+            || (name == sym::Default && adt.is_enum())
+            || (name == sym::Copy && adt.has_dtor(tcx))
+        {
+            return;
+        }
+
+        let required: &[Symbol] = match name {
+            sym::Copy => &[sym::Clone],
+            sym::Eq | sym::PartialOrd => &[sym::PartialEq],
+            sym::Ord => &[sym::PartialEq, sym::Eq, sym::PartialOrd],
+            _ => &[],
+        };
+        let mut to_derive = vec![];
+        for &(required_name, def_id) in derivable.iter().filter(|(n, _)| required.contains(n)) {
+            match def_id {
+                Some(def_id) if self.implements_trait(def_id, self_ty, obligation.param_env) => {}
+                Some(def_id) => to_derive.push((required_name, def_id)),
+                None => return,
+            }
+        }
+        to_derive.push((name, trait_ref.def_id));
+
+        // The derived impls require the fields to implement the traits, except for the fields
+        // that contain the type itself, which get them from the derived impls.
+        for &(_, def_id) in &to_derive {
+            for field in adt.all_fields() {
+                let field_ty = field.ty(tcx, substs);
+                if !field_ty.walk().any(|ty| ty == self_ty)
+                    && !self.implements_trait(def_id, field_ty, obligation.param_env)
+                {
+                    return;
+                }
+            }
+        }
+
+        // If the type already derives some traits, extend the last list of derives rather than
+        // adding another one. The impls that a derive expands to are attributed to the path of the
+        // trait in the list, which must be followed by the next one or the end of the list.
+        let source_map = tcx.sess.source_map();
+        let mut last_derive: Option<Span> = None;
+        for &(_, def_id) in &derivable {
+            let def_id = match def_id {
+                Some(def_id) => def_id,
+                None => continue,
+            };
+            tcx.for_each_relevant_impl(def_id, self_ty, |impl_def_id| {
+                if tcx.type_of(impl_def_id).ty_adt_def().map(|def| def.did) != Some(adt.did)
+                    || !tcx.has_attr(impl_def_id, sym::automatically_derived)
+                {
+                    return;
+                }
+                let span = match tcx.hir().span_if_local(impl_def_id) {
+                    Some(span) => span,
+                    None => return,
+                };
+                let expn_data = span.ctxt().outer_expn_data();
+                if let ExpnKind::Macro(MacroKind::Derive, _) = expn_data.kind {
+                    let path_span = expn_data.call_site;
+                    let next = source_map.span_to_snippet(source_map.next_point(path_span));
+                    if !path_span.from_expansion()
+                        && path_span.hi() <= adt_span.lo()
+                        && next.map_or(false, |next| next == "," || next == ")")
+                        && last_derive.map_or(true, |last| last.hi() < path_span.hi())
+                    {
+                        last_derive = Some(path_span);
+                    }
+                }
+            });
+        }
+
+        let names = to_derive.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+        let names = names.join(", ");
+        let (span, suggestion) = match last_derive {
+            Some(span) => (span.shrink_to_hi(), format!(", {}", names)),
+            None => {
+                // Put the attribute on its own line, unless the type doesn't start its line.
+                let prev_source = source_map.span_to_prev_source(adt_span);
+                let indent = prev_source.as_ref().ok().and_then(|s| s.rsplit('\n').next());
+                match indent {
+                    Some(indent) if indent.trim().is_empty() => {
+                        let line_start = adt_span.lo() - BytePos(indent.len() as u32);
+                        let span = adt_span.with_lo(line_start).shrink_to_lo();
+                        (span, format!("{}#[derive({})]\n", indent, names))
+                    }
+                    _ => (adt_span.shrink_to_lo(), format!("#[derive({})] ", names)),
+                }
+            }
+        };
+        err.span_suggestion_verbose(
+            span,
+            &format!(
+                "consider annotating `{}` with `#[derive({})]`",
+                tcx.def_path_str(adt.did),
+                names
+            ),
+            suggestion,
+            Applicability::MachineApplicable,
+        );
+    }
+
+    /// The traits that have a built-in derive, with their `DefId`s if they are known.
+    fn derivable_traits(&self) -> [(Symbol, Option<DefId>); 9] {
+        let lang_items = self.tcx.lang_items();
+        let diagnostic_item = |name| self.tcx.get_diagnostic_item(name);
+        [
+            (sym::Clone, lang_items.clone_trait()),
+            (sym::Copy, lang_items.copy_trait()),
+            (sym::Debug, diagnostic_item(sym::debug_trait)),
+            (sym::Default, diagnostic_item(sym::Default)),
+            (sym::PartialEq, lang_items.eq_trait()),
+            (sym::Eq, diagnostic_item(sym::Eq)),
+            (sym::PartialOrd, lang_items.partial_ord_trait()),
+            (sym::Ord, diagnostic_item(sym::Ord)),
+            (sym::Hash, diagnostic_item(sym::Hash)),
+        ]
+    }
+
+    /// Whether `ty` may implement the trait `trait_def_id`, compared with `ty` itself if the
+    /// trait has a type parameter.
+    fn implements_trait(
+        &self,
+        trait_def_id: DefId,
+        ty: Ty<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+    ) -> bool {
+        let substs = if self.tcx.generics_of(trait_def_id).count() > 1 {
+            self.tcx.mk_substs_trait(ty, &[ty.into()])
+        } else {
+            self.tcx.mk_substs_trait(ty, &[])
+        };
+        let trait_ref = ty::TraitRef::new(trait_def_id, substs);
+        let obligation =
+            Obligation::new(ObligationCause::dummy(), param_env, trait_ref.to_predicate());
+        self.predicate_may_hold(&obligation)
+    }
+
     crate fn suggest_semicolon_removal(
         &self,
         obligation: &PredicateObligation<'tcx>,
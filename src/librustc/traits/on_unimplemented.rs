@@ -164,10 +164,26 @@ impl<'tcx> OnUnimplementedDirective {
         tcx: TyCtxt<'tcx>,
         trait_def_id: DefId,
         impl_def_id: DefId,
+    ) -> Result<Option<Self>, ErrorReported> {
+        Self::of_attr(tcx, sym::rustc_on_unimplemented, trait_def_id, impl_def_id)
+    }
+
+    /// The `#[rustc_on_misuse]` directive of the type `adt_def_id`, which adds notes to the
+    /// errors about the operations that the type does not support. Its format strings can use
+    /// the generic parameters of the type.
+    pub fn of_type(tcx: TyCtxt<'tcx>, adt_def_id: DefId) -> Result<Option<Self>, ErrorReported> {
+        Self::of_attr(tcx, sym::rustc_on_misuse, adt_def_id, adt_def_id)
+    }
+
+    fn of_attr(
+        tcx: TyCtxt<'tcx>,
+        attr_name: Symbol,
+        trait_def_id: DefId,
+        impl_def_id: DefId,
     ) -> Result<Option<Self>, ErrorReported> {
         let attrs = tcx.get_attrs(impl_def_id);
 
-        let attr = if let Some(item) = attr::find_by_name(&attrs, sym::rustc_on_unimplemented) {
+        let attr = if let Some(item) = attr::find_by_name(&attrs, attr_name) {
             item
         } else {
             return Ok(None);
@@ -192,7 +208,7 @@ impl<'tcx> OnUnimplementedDirective {
         } else {
             return Err(ErrorReported);
         };
-        debug!("of_item({:?}/{:?}) = {:?}", trait_def_id, impl_def_id, result);
+        debug!("of_attr({:?}/{:?}) = {:?}", trait_def_id, impl_def_id, result);
         result
     }
 
@@ -1319,6 +1319,10 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
 
         if let Some(conflict) = self.is_knowable(stack) {
             debug!("coherence stage: not knowable");
+            if self.strict_coherence_negative_impl_applies(&stack.obligation) {
+                debug!("coherence stage: a negative impl rules out future impls");
+                return Err(Unimplemented);
+            }
             if self.intercrate_ambiguity_causes.is_some() {
                 debug!("evaluate_stack: intercrate_ambiguity_causes is some");
                 // Heuristics: show the diagnostics when there are no candidates in crate.
@@ -1466,6 +1470,38 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         self.filter_negative_and_reservation_impls(candidates.pop().unwrap().candidate)
     }
 
+    /// Whether the obligation is covered by a negative impl of a `#[rustc_strict_coherence]`
+    /// trait. Such an impl is a promise of the crate of the trait that it will never implement
+    /// the trait for these types, so coherence may rely on them not implementing it even though
+    /// the trait reference is not knowable.
+    fn strict_coherence_negative_impl_applies(
+        &mut self,
+        obligation: &TraitObligation<'tcx>,
+    ) -> bool {
+        let tcx = self.tcx();
+        if !tcx.has_attr(obligation.predicate.def_id(), sym::rustc_strict_coherence) {
+            return false;
+        }
+        // The negative impl must cover the obligation as it is, without constraining its
+        // inference variables.
+        let predicate = self.infcx().resolve_vars_if_possible(&obligation.predicate);
+        let obligation = obligation.with(predicate);
+        let trait_ref = obligation.predicate.skip_binder().trait_ref;
+        if trait_ref.input_types().any(|ty| ty.has_infer_types()) {
+            return false;
+        }
+
+        let mut applies = false;
+        tcx.for_each_relevant_impl(trait_ref.def_id, trait_ref.self_ty(), |impl_def_id| {
+            if tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Negative {
+                applies |= self
+                    .infcx
+                    .probe(|snapshot| self.match_impl(impl_def_id, &obligation, snapshot).is_ok());
+            }
+        });
+        applies
+    }
+
     fn is_knowable<'o>(&mut self, stack: &TraitObligationStack<'o, 'tcx>) -> Option<Conflict> {
         debug!("is_knowable(intercrate={:?})", self.intercrate);
 
@@ -1054,7 +1054,8 @@ impl<'tcx> TyCtxt<'tcx> {
         self.arena.alloc(def)
     }
 
-    pub fn intern_const_alloc(self, alloc: Allocation) -> &'tcx Allocation {
+    pub fn intern_const_alloc(self, mut alloc: Allocation) -> &'tcx Allocation {
+        alloc.compact();
         self.allocation_interner.intern(alloc, |alloc| self.arena.alloc(alloc))
     }
 
@@ -2784,4 +2785,20 @@ pub fn provide(providers: &mut ty::query::Providers<'_>) {
         // We want to check if the panic handler was defined in this crate
         tcx.lang_items().panic_impl().map_or(false, |did| did.is_local())
     };
+    providers.has_static_dtors = |tcx, cnum| {
+        assert_eq!(cnum, LOCAL_CRATE);
+        // The standard library is exempt, its statics with destructors only hold memory that it
+        // leaks on purpose.
+        if tcx.features().staged_api {
+            return false;
+        }
+        tcx.hir().krate().items.values().any(|item| match item.kind {
+            ItemKind::Static(..) => {
+                let def_id = tcx.hir().local_def_id(item.hir_id);
+                !tcx.has_attr(def_id, sym::thread_local)
+                    && tcx.type_of(def_id).needs_drop(tcx, ty::ParamEnv::reveal_all())
+            }
+            _ => false,
+        })
+    };
 }
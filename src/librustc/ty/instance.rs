@@ -50,8 +50,12 @@ pub enum InstanceDef<'tcx> {
     Virtual(DefId, usize),
 
     /// `<[mut closure] as FnOnce>::call_once`
+    ///
+    /// `track_caller` is set when the closure is `#[track_caller]`, in which case the shim
+    /// forwards its own caller location to the closure.
     ClosureOnceShim {
         call_once: DefId,
+        track_caller: bool,
     },
 
     /// `drop_in_place::<T>; None` for empty drop glue.
@@ -103,7 +107,7 @@ impl<'tcx> InstanceDef<'tcx> {
             | InstanceDef::FnPtrShim(def_id, _)
             | InstanceDef::Virtual(def_id, _)
             | InstanceDef::Intrinsic(def_id)
-            | InstanceDef::ClosureOnceShim { call_once: def_id }
+            | InstanceDef::ClosureOnceShim { call_once: def_id, track_caller: _ }
             | InstanceDef::DropGlue(def_id, _)
             | InstanceDef::CloneShim(def_id, _) => def_id,
         }
@@ -141,7 +145,12 @@ impl<'tcx> InstanceDef<'tcx> {
     }
 
     pub fn requires_caller_location(&self, tcx: TyCtxt<'_>) -> bool {
-        tcx.codegen_fn_attrs(self.def_id()).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+        match *self {
+            InstanceDef::ClosureOnceShim { track_caller, .. } => track_caller,
+            _ => {
+                tcx.codegen_fn_attrs(self.def_id()).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+            }
+        }
     }
 }
 
@@ -310,6 +319,21 @@ impl<'tcx> Instance<'tcx> {
         }
     }
 
+    /// Resolves the function that the closure `def_id`, which captures nothing, is coerced to
+    /// when it is cast to a `fn` pointer. That function cannot take the caller location of a
+    /// `#[track_caller]` closure, so its shim calls the closure with its own location instead.
+    pub fn resolve_closure_for_fn_ptr(
+        tcx: TyCtxt<'tcx>,
+        def_id: DefId,
+        substs: ty::SubstsRef<'tcx>,
+    ) -> Instance<'tcx> {
+        let mut instance = Instance::resolve_closure(tcx, def_id, substs, ty::ClosureKind::FnOnce);
+        if let InstanceDef::ClosureOnceShim { ref mut track_caller, .. } = instance.def {
+            *track_caller = false;
+        }
+        instance
+    }
+
     pub fn resolve_drop_in_place(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> ty::Instance<'tcx> {
         let def_id = tcx.require_lang_item(DropInPlaceFnLangItem, None);
         let substs = tcx.intern_substs(&[ty.into()]);
@@ -328,7 +352,9 @@ impl<'tcx> Instance<'tcx> {
             .find(|it| it.kind == ty::AssocKind::Method)
             .unwrap()
             .def_id;
-        let def = ty::InstanceDef::ClosureOnceShim { call_once };
+        let track_caller =
+            tcx.codegen_fn_attrs(closure_did).flags.contains(CodegenFnAttrFlags::TRACK_CALLER);
+        let def = ty::InstanceDef::ClosureOnceShim { call_once, track_caller };
 
         let self_ty = tcx.mk_closure(closure_did, substs);
 
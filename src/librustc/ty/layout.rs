@@ -1,6 +1,9 @@
 use crate::session::{self, DataTypeKind};
 use crate::ty::{self, subst::SubstsRef, ReprOptions, Ty, TyCtxt, TypeFoldable};
 
+use rustc_hir::def_id::{CrateNum, LOCAL_CRATE};
+use rustc_macros::HashStable;
+use rustc_span::symbol::Symbol;
 use rustc_span::DUMMY_SP;
 use syntax::ast::{self, Ident, IntTy, UintTy};
 use syntax::attr;
@@ -210,8 +213,67 @@ fn layout_raw<'tcx>(
     })
 }
 
+/// The layout of a non-generic `#[repr(C)]` struct or union, which `-Z encode-repr-c-layouts`
+/// records in the crate metadata so that tools checking it against a C declaration do not have
+/// to compute it themselves.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable, HashStable)]
+pub struct ReprCLayout {
+    /// The path of the type, as printed by `TyCtxt::def_path_str`.
+    pub path: String,
+    pub size: u64,
+    pub align: u64,
+    /// The fields, in declaration order.
+    pub fields: Vec<ReprCField>,
+}
+
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable, HashStable)]
+pub struct ReprCField {
+    pub name: Symbol,
+    pub offset: u64,
+    pub size: u64,
+}
+
+fn repr_c_layouts(tcx: TyCtxt<'_>, cnum: CrateNum) -> &[ReprCLayout] {
+    assert_eq!(cnum, LOCAL_CRATE);
+    let cx = LayoutCx { tcx, param_env: ty::ParamEnv::reveal_all() };
+    tcx.arena.alloc_from_iter(tcx.hir().krate().items.values().filter_map(|item| {
+        match item.kind {
+            hir::ItemKind::Struct(_, ref generics) | hir::ItemKind::Union(_, ref generics)
+                if generics.params.is_empty() => {}
+            _ => return None,
+        }
+        let def_id = tcx.hir().local_def_id(item.hir_id);
+        let adt = tcx.adt_def(def_id);
+        if !adt.repr.c() {
+            return None;
+        }
+        // Types whose layout cannot be computed are reported where they are used.
+        let layout = cx.layout_of(tcx.type_of(def_id)).ok()?;
+        let fields = adt
+            .non_enum_variant()
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                Ok(ReprCField {
+                    name: field.ident.name,
+                    offset: layout.fields.offset(i).bytes(),
+                    size: layout.field(&cx, i)?.size.bytes(),
+                })
+            })
+            .collect::<Result<_, LayoutError<'_>>>()
+            .ok()?;
+        Some(ReprCLayout {
+            path: tcx.def_path_str(def_id),
+            size: layout.size.bytes(),
+            align: layout.align.abi.bytes(),
+            fields,
+        })
+    }))
+}
+
 pub fn provide(providers: &mut ty::query::Providers<'_>) {
-    *providers = ty::query::Providers { layout_raw, ..*providers };
+    *providers = ty::query::Providers { layout_raw, repr_c_layouts, ..*providers };
 }
 
 pub struct LayoutCx<'tcx, C> {
@@ -655,6 +717,37 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
                 )?
             }
 
+            // Scalable vector types.
+            ty::Adt(def, ..) if def.repr.simd() && def.repr.scalable() => {
+                let element = self.layout_of(ty.simd_type(tcx))?;
+                let elt = def.repr.scalable.unwrap() as u64;
+                let scalar = match element.abi {
+                    Abi::Scalar(ref scalar) => scalar.clone(),
+                    _ => {
+                        tcx.sess.fatal(&format!(
+                            "monomorphising scalable vector type `{}` with \
+                             a non-machine element type `{}`",
+                            ty, element.ty
+                        ));
+                    }
+                };
+                // The number of elements is only known at runtime, so this is the size of the
+                // smallest vector, the one with `elt` elements.
+                let size =
+                    element.size.checked_mul(elt, dl).ok_or(LayoutError::SizeOverflow(ty))?;
+                let align = dl.vector_align(size);
+                let size = size.align_to(align.abi);
+
+                tcx.intern_layout(LayoutDetails {
+                    variants: Variants::Single { index: VariantIdx::new(0) },
+                    fields: FieldPlacement::Array { stride: element.size, count: 1 },
+                    abi: Abi::ScalableVector { element: scalar, elt },
+                    largest_niche: None,
+                    size,
+                    align,
+                })
+            }
+
             // SIMD vector types.
             ty::Adt(def, ..) if def.repr.simd() => {
                 let element = self.layout_of(ty.simd_type(tcx))?;
@@ -730,9 +823,9 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
                                 Abi::Vector { element: x, count } => {
                                     Abi::Vector { element: scalar_unit(x.value), count: *count }
                                 }
-                                Abi::Uninhabited | Abi::Aggregate { .. } => {
-                                    Abi::Aggregate { sized: true }
-                                }
+                                Abi::Uninhabited
+                                | Abi::ScalableVector { .. }
+                                | Abi::Aggregate { .. } => Abi::Aggregate { sized: true },
                             };
 
                             if size == Size::ZERO {
@@ -2004,6 +2097,35 @@ impl ty::query::TyCtxtAt<'tcx> {
     }
 }
 
+/// Whether `ty` contains an `UnsafePinned` by value, in which case the unique pointers to it
+/// (`&mut` and `Box`) do not get `noalias`: the contents of an `UnsafePinned` may be aliased
+/// by the pointers that the data structure built around it keeps to itself.
+fn contains_unsafe_pinned<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+) -> bool {
+    match ty.kind {
+        ty::Adt(def, _) if Some(def.did) == tcx.lang_items().unsafe_pinned_type() => true,
+        ty::Adt(def, substs) => def.all_fields().any(|field| {
+            let field_ty = tcx.normalize_erasing_regions(param_env, field.ty(tcx, substs));
+            contains_unsafe_pinned(tcx, param_env, field_ty)
+        }),
+        ty::Tuple(_) => {
+            ty.tuple_fields().any(|field| contains_unsafe_pinned(tcx, param_env, field))
+        }
+        ty::Array(elem, _) | ty::Slice(elem) => contains_unsafe_pinned(tcx, param_env, elem),
+        ty::Closure(def_id, substs) => substs
+            .as_closure()
+            .upvar_tys(def_id, tcx)
+            .any(|upvar| contains_unsafe_pinned(tcx, param_env, upvar)),
+        // Immovable generators may keep references to their own saved locals across a yield,
+        // so their contents may be aliased in the same way (see #63818).
+        ty::Generator(_, _, hir::Movability::Static) => true,
+        _ => false,
+    }
+}
+
 impl<'tcx, C> TyLayoutMethods<'tcx, C> for Ty<'tcx>
 where
     C: LayoutOf<Ty = Ty<'tcx>, TyLayout: MaybeResult<TyLayout<'tcx>>>
@@ -2200,7 +2322,7 @@ where
                         // issue is being figured out.
                         let mutable_noalias =
                             tcx.sess.opts.debugging_opts.mutable_noalias.unwrap_or(false);
-                        if mutable_noalias {
+                        if mutable_noalias && !contains_unsafe_pinned(tcx, cx.param_env(), ty) {
                             PointerKind::UniqueBorrowed
                         } else {
                             PointerKind::Shared
@@ -2270,9 +2392,15 @@ where
 
                 // FIXME(eddyb) This should be for `ptr::Unique<T>`, not `Box<T>`.
                 if let Some(ref mut pointee) = result {
-                    if let ty::Adt(def, _) = this.ty.kind {
+                    if let ty::Adt(def, substs) = this.ty.kind {
                         if def.is_box() && offset.bytes() == 0 {
-                            pointee.safe = Some(PointerKind::UniqueOwned);
+                            let tcx = cx.tcx();
+                            pointee.safe =
+                                if contains_unsafe_pinned(tcx, cx.param_env(), substs.type_at(0)) {
+                                    Some(PointerKind::UniqueOwnedAliasable)
+                                } else {
+                                    Some(PointerKind::UniqueOwned)
+                                };
                         }
                     }
                 }
@@ -2571,7 +2699,7 @@ where
                     // for the entire duration of the function as they can be deallocated
                     // any time. Set their valid size to 0.
                     attrs.pointee_size = match kind {
-                        PointerKind::UniqueOwned => Size::ZERO,
+                        PointerKind::UniqueOwned | PointerKind::UniqueOwnedAliasable => Size::ZERO,
                         _ => pointee.size,
                     };
 
@@ -2584,7 +2712,7 @@ where
                     // LLVM's definition of `noalias` is based solely on memory
                     // dependencies rather than pointer equality
                     let no_alias = match kind {
-                        PointerKind::Shared => false,
+                        PointerKind::Shared | PointerKind::UniqueOwnedAliasable => false,
                         PointerKind::UniqueOwned => true,
                         PointerKind::Frozen | PointerKind::UniqueBorrowed => !is_return,
                     };
@@ -2701,6 +2829,11 @@ where
                         return;
                     }
 
+                    // Scalable vectors have no size known at compile time to copy them to
+                    // memory with, so they are always immediates, whatever the target features
+                    // of the caller and the callee.
+                    Abi::ScalableVector { .. } => return,
+
                     _ => return,
                 }
 
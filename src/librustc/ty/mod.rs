@@ -2001,6 +2001,8 @@ pub struct ReprOptions {
     pub int: Option<attr::IntType>,
     pub align: Option<Align>,
     pub pack: Option<Align>,
+    /// The minimum number of elements of a scalable vector, from `#[repr(scalable(N))]`.
+    pub scalable: Option<u32>,
     pub flags: ReprFlags,
 }
 
@@ -2010,6 +2012,7 @@ impl ReprOptions {
         let mut size = None;
         let mut max_align: Option<Align> = None;
         let mut min_pack: Option<Align> = None;
+        let mut scalable = None;
         for attr in tcx.get_attrs(did).iter() {
             for r in attr::find_repr_attrs(&tcx.sess.parse_sess, attr) {
                 flags.insert(match r {
@@ -2033,6 +2036,16 @@ impl ReprOptions {
                         max_align = max_align.max(Some(Align::from_bytes(align as u64).unwrap()));
                         ReprFlags::empty()
                     }
+                    attr::ReprAlignCacheLine => {
+                        // The cache line size is checked when the target spec is loaded.
+                        let align = tcx.sess.target.target.options.cache_line_size;
+                        max_align = max_align.max(Some(Align::from_bytes(align).unwrap()));
+                        ReprFlags::empty()
+                    }
+                    attr::ReprScalable(elt) => {
+                        scalable = Some(elt);
+                        ReprFlags::empty()
+                    }
                 });
             }
         }
@@ -2041,7 +2054,7 @@ impl ReprOptions {
         if !tcx.consider_optimizing(|| format!("Reorder fields of {:?}", tcx.def_path_str(did))) {
             flags.insert(ReprFlags::IS_LINEAR);
         }
-        ReprOptions { int: size, align: max_align, pack: min_pack, flags: flags }
+        ReprOptions { int: size, align: max_align, pack: min_pack, scalable, flags: flags }
     }
 
     #[inline]
@@ -2049,6 +2062,10 @@ impl ReprOptions {
         self.flags.contains(ReprFlags::IS_SIMD)
     }
     #[inline]
+    pub fn scalable(&self) -> bool {
+        self.scalable.is_some()
+    }
+    #[inline]
     pub fn c(&self) -> bool {
         self.flags.contains(ReprFlags::IS_C)
     }
@@ -6,7 +6,7 @@ use crate::ty::layout::{Integer, IntegerExt, Size};
 use crate::ty::subst::{GenericArg, GenericArgKind, Subst};
 use crate::ty::{self, DefIdTree, ParamConst, Ty, TyCtxt, TypeFoldable};
 use rustc_hir as hir;
-use rustc_hir::def::{DefKind, Namespace};
+use rustc_hir::def::{CtorKind, DefKind, Namespace};
 use rustc_hir::def_id::{CrateNum, DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 
 use rustc_apfloat::ieee::{Double, Single};
@@ -709,6 +709,12 @@ pub trait PrettyPrinter<'tcx>:
         None
     }
 
+    /// Whether constant aggregates and references are printed by their contents, e.g. as
+    /// `Foo { x: 1u8 }`, rather than by their representation.
+    fn print_const_contents(&self) -> bool {
+        true
+    }
+
     fn pretty_print_dyn_existential(
         mut self,
         predicates: &'tcx ty::List<ty::ExistentialPredicate<'tcx>>,
@@ -930,6 +936,11 @@ pub trait PrettyPrinter<'tcx>:
             (ConstValue::Scalar(Scalar::Raw { data, .. }), ty::Char) => {
                 p!(write("{:?}", ::std::char::from_u32(data as u32).unwrap()))
             }
+            // Integer-valued raw pointers are printed by their address, as that is what
+            // determines whether two of them are equal, e.g. when used as const arguments.
+            (ConstValue::Scalar(Scalar::Raw { data, .. }), ty::RawPtr(_)) => {
+                p!(write("{:#x} as ", data), print(ty))
+            }
             (ConstValue::Scalar(_), ty::RawPtr(_)) => p!(write("{{pointer}}")),
             (ConstValue::Scalar(Scalar::Ptr(ptr)), ty::FnPtr(_)) => {
                 let instance = {
@@ -938,6 +949,48 @@ pub trait PrettyPrinter<'tcx>:
                 };
                 p!(print_value_path(instance.def_id(), instance.substs));
             }
+            // Aggregates are printed like the expressions that build them, from their fields.
+            (_, ty::Array(..)) | (_, ty::Tuple(..)) | (_, ty::Adt(..))
+                if self.print_const_contents()
+                    && !ty.is_union()
+                    && is_const_value_readable(ty) =>
+            {
+                let tcx = self.tcx();
+                let ct = tcx.mk_const(ty::Const { val: ty::ConstKind::Value(ct), ty });
+                let contents = tcx.destructure_const(ty::ParamEnv::reveal_all().and(ct));
+                let fields = contents.fields.iter().copied();
+                match ty.kind {
+                    ty::Array(..) => p!(write("["), comma_sep(fields), write("]")),
+                    ty::Tuple(..) => {
+                        p!(write("("), comma_sep(fields));
+                        if contents.fields.len() == 1 {
+                            p!(write(","));
+                        }
+                        p!(write(")"));
+                    }
+                    ty::Adt(def, substs) => {
+                        let variant_def = &def.variants[contents.variant];
+                        p!(print_value_path(variant_def.def_id, substs));
+                        match variant_def.ctor_kind {
+                            CtorKind::Const => {}
+                            CtorKind::Fn => p!(write("("), comma_sep(fields), write(")")),
+                            CtorKind::Fictive => {
+                                p!(write(" {{ "));
+                                let mut first = true;
+                                for (field_def, field) in variant_def.fields.iter().zip(fields) {
+                                    if !first {
+                                        p!(write(", "));
+                                    }
+                                    p!(write("{}: ", field_def.ident), print(field));
+                                    first = false;
+                                }
+                                p!(write(" }}"));
+                            }
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
             _ => {
                 let printed = if let ty::Ref(_, ref_ty, _) = ty.kind {
                     let byte_str = match (ct, &ref_ty.kind) {
@@ -980,6 +1033,17 @@ pub trait PrettyPrinter<'tcx>:
                         let s = ::std::str::from_utf8(slice).expect("non utf8 str from miri");
                         p!(write("{:?}", s));
                         true
+                    } else if self.print_const_contents() && is_const_value_readable(ty) {
+                        // Print the value behind the reference, unless it is in a static.
+                        let tcx = self.tcx();
+                        let ct = tcx.mk_const(ty::Const { val: ty::ConstKind::Value(ct), ty });
+                        match tcx.deref_const(ty::ParamEnv::reveal_all().and(ct)) {
+                            Some(pointee) => {
+                                p!(write("&"), print(pointee));
+                                true
+                            }
+                            None => false,
+                        }
                     } else {
                         false
                     }
@@ -996,6 +1060,11 @@ pub trait PrettyPrinter<'tcx>:
     }
 }
 
+/// Whether the constant values of type `ty` can be read to print them, which needs their layout.
+fn is_const_value_readable(ty: Ty<'_>) -> bool {
+    !ty.needs_subst() && !ty.needs_infer() && !ty.has_placeholders() && !ty.references_error()
+}
+
 // HACK(eddyb) boxed to avoid moving around a large struct by-value.
 pub struct FmtPrinter<'a, 'tcx, F>(Box<FmtPrinterData<'a, 'tcx, F>>);
 
@@ -1012,6 +1081,9 @@ pub struct FmtPrinterData<'a, 'tcx, F> {
 
     pub region_highlight_mode: RegionHighlightMode,
 
+    /// See `PrettyPrinter::print_const_contents`.
+    pub print_const_contents: bool,
+
     pub name_resolver: Option<Box<&'a dyn Fn(ty::sty::TyVid) -> Option<String>>>,
 }
 
@@ -1039,6 +1111,7 @@ impl<F> FmtPrinter<'a, 'tcx, F> {
             region_index: 0,
             binder_depth: 0,
             region_highlight_mode: RegionHighlightMode::default(),
+            print_const_contents: true,
             name_resolver: None,
         }))
     }
@@ -1288,6 +1361,10 @@ impl<F: fmt::Write> PrettyPrinter<'tcx> for FmtPrinter<'_, 'tcx, F> {
         self.0.name_resolver.as_ref().and_then(|func| func(id))
     }
 
+    fn print_const_contents(&self) -> bool {
+        self.0.print_const_contents
+    }
+
     fn print_value_path(
         mut self,
         def_id: DefId,
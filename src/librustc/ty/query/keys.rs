@@ -227,7 +227,7 @@ impl<'tcx, T> Key for Canonical<'tcx, T> {
     }
 }
 
-impl Key for (Symbol, u32, u32) {
+impl Key for mir::interpret::CallerLocation {
     fn query_crate(&self) -> CrateNum {
         LOCAL_CRATE
     }
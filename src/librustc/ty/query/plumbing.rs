@@ -261,6 +261,10 @@ impl<'tcx> TyCtxt<'tcx> {
     where
         F: FnOnce(TyCtxt<'tcx>) -> R,
     {
+        if let Some(limit) = self.sess.exceeded_resource_limit() {
+            self.report_resource_limit(&job, &limit);
+        }
+
         // The `TyCtxt` stored in TLS has the same global interner lifetime
         // as `self`, so we use `with_related_context` to relate the 'tcx lifetimes
         // when accessing the `ImplicitCtxt`.
@@ -279,6 +283,23 @@ impl<'tcx> TyCtxt<'tcx> {
         })
     }
 
+    #[inline(never)]
+    #[cold]
+    fn report_resource_limit(self, job: &QueryJob<'tcx>, limit: &str) -> ! {
+        let query = &job.info.query;
+        // Describing the query may run other queries, but the limit is only reported once.
+        let description = ty::print::with_forced_impl_filename_line(|| query.describe(self));
+        self.sess
+            .struct_fatal(limit)
+            .note(&format!(
+                "the limit was exceeded while computing `{}`: {}",
+                query.name(),
+                description
+            ))
+            .emit();
+        FatalError.raise()
+    }
+
     #[inline(never)]
     #[cold]
     pub(super) fn report_cycle(
@@ -455,6 +476,10 @@ impl<'tcx> TyCtxt<'tcx> {
             let result = Q::try_load_from_disk(self, prev_dep_node_index);
             prof_timer.finish_with_query_invocation_id(dep_node_index.into());
 
+            if result.is_some() && unlikely!(self.sess.opts.debugging_opts.query_dep_graph) {
+                self.dep_graph.mark_debug_loaded_from_disk(*dep_node);
+            }
+
             // We always expect to find a cached result for things that
             // can be forced from `DepNode`.
             debug_assert!(
@@ -531,6 +531,9 @@ pub fn super_relate_consts<R: TypeRelation<'tcx>>(
             return Ok(a);
         }
         (ty::ConstKind::Value(a_val), ty::ConstKind::Value(b_val)) => {
+            // Mismatches report the values, which are clearer than the expressions computing them.
+            let a = tcx.mk_const(ty::Const { val: ty::ConstKind::Value(a_val), ty: a.ty });
+            let b = tcx.mk_const(ty::Const { val: ty::ConstKind::Value(b_val), ty: b.ty });
             let new_val = match (a_val, b_val) {
                 (ConstValue::Scalar(a_val), ConstValue::Scalar(b_val)) if a.ty == b.ty => {
                     if a_val == b_val {
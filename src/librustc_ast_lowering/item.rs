@@ -67,13 +67,6 @@ impl<'a> Visitor<'a> for ItemLowerer<'a, '_, '_> {
             self.lctx.with_parent_item_lifetime_defs(hir_id, |this| {
                 let this = &mut ItemLowerer { lctx: this };
                 if let ItemKind::Impl { ref of_trait, .. } = item.kind {
-                    if of_trait.as_ref().map(|tr| tr.constness.is_some()).unwrap_or(false) {
-                        this.with_trait_impl_ref(of_trait, |this| visit::walk_item(this, item));
-                        this.lctx
-                            .diagnostic()
-                            .span_err(item.span, "const trait impls are not yet implemented");
-                    }
-
                     this.with_trait_impl_ref(of_trait, |this| visit::walk_item(this, item));
                 } else {
                     visit::walk_item(this, item);
@@ -371,6 +364,10 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 items: ref impl_items,
             } => {
                 let def_id = self.resolver.definitions().local_def_id(id);
+                let constness = match trait_ref {
+                    Some(TraitRef { constness: Some(_), .. }) => Constness::Const,
+                    _ => Constness::NotConst,
+                };
 
                 // Lower the "impl header" first. This ordering is important
                 // for in-band lifetimes! Consider `'a` here:
@@ -421,6 +418,7 @@ impl<'hir> LoweringContext<'_, 'hir> {
                     unsafety,
                     polarity,
                     defaultness: self.lower_defaultness(defaultness, true /* [1] */),
+                    constness,
                     generics,
                     of_trait: trait_ref,
                     self_ty: lowered_ty,
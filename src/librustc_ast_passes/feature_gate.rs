@@ -17,8 +17,12 @@ macro_rules! gate_feature_fn {
         let (cx, has_feature, span, name, explain) = (&*$cx, $has_feature, $span, $name, $explain);
         let has_feature: bool = has_feature(&$cx.features);
         debug!("gate_feature(feature = {:?}, span = {:?}); has? {}", name, span, has_feature);
-        if !has_feature && !span.allows_unstable($name) {
-            feature_err_issue(cx.parse_sess, name, span, GateIssue::Language, explain).emit();
+        if !span.allows_unstable($name) {
+            if has_feature {
+                cx.parse_sess.record_feature_use(name, span);
+            } else {
+                feature_err_issue(cx.parse_sess, name, span, GateIssue::Language, explain).emit();
+            }
         }
     }};
 }
@@ -251,6 +255,19 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                 );
             }
         }
+        // Check the unstable `#[repr(align(cache_line))]`.
+        if attr.check_name(sym::repr) {
+            for nested_meta in attr.meta_item_list().unwrap_or_default() {
+                let cache_line = nested_meta.check_name(sym::align)
+                    && nested_meta.meta_item_list().map_or(false, |list| {
+                        list.iter().any(|inner| inner.check_name(sym::cache_line))
+                    });
+                if cache_line {
+                    let msg = "`#[repr(align(cache_line))]` is experimental";
+                    gate_feature_post!(self, repr_align_cache_line, attr.span, msg);
+                }
+            }
+        }
     }
 
     fn visit_name(&mut self, sp: Span, name: ast::Name) {
@@ -314,6 +331,14 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                                 "SIMD types are experimental and possibly buggy"
                             );
                         }
+                        if item.check_name(sym::scalable) {
+                            gate_feature_post!(
+                                &self,
+                                repr_scalable,
+                                attr.span,
+                                "scalable vector types are experimental"
+                            );
+                        }
                     }
                 }
             }
@@ -566,7 +591,15 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
 
     fn visit_assoc_item(&mut self, ii: &'a ast::AssocItem) {
         if ii.defaultness == ast::Defaultness::Default {
-            gate_feature_post!(&self, specialization, ii.span, "specialization is unstable");
+            // Limit `min_specialization` to only specializing functions.
+            let is_fn = if let ast::AssocItemKind::Fn(..) = ii.kind { true } else { false };
+            gate_feature_fn!(
+                &self,
+                |x: &Features| x.specialization || (is_fn && x.min_specialization),
+                ii.span,
+                sym::specialization,
+                "specialization is unstable"
+            );
         }
 
         match ii.kind {
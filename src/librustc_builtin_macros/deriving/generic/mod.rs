@@ -823,6 +823,8 @@ fn find_repr_type_name(sess: &ParseSess, type_attrs: &[ast::Attribute]) -> &'sta
                 attr::ReprPacked(_)
                 | attr::ReprSimd
                 | attr::ReprAlign(_)
+                | attr::ReprAlignCacheLine
+                | attr::ReprScalable(_)
                 | attr::ReprTransparent => continue,
 
                 attr::ReprC => "i32",
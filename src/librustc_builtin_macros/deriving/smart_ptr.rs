@@ -0,0 +1,163 @@
+use rustc_expand::base::{Annotatable, ExtCtxt};
+use rustc_span::symbol::{sym, Ident};
+use rustc_span::Span;
+use syntax::ast::{self, GenericArg, GenericBound, GenericParamKind, ItemKind, MetaItem};
+use syntax::ast::{TraitBoundModifier, VariantData};
+
+/// Derives the `CoerceUnsized` and `DispatchFromDyn` impls of a `#[repr(transparent)]` smart
+/// pointer, so that `Ptr<T>` coerces to `Ptr<dyn Trait>` and can be the receiver of the methods
+/// of `dyn Trait`. The type parameter being unsized is the one marked `#[pointee]`, or the only
+/// type parameter of the struct.
+pub fn expand_deriving_smart_ptr(
+    cx: &mut ExtCtxt<'_>,
+    span: Span,
+    _mitem: &MetaItem,
+    item: &Annotatable,
+    push: &mut dyn FnMut(Annotatable),
+) {
+    let (item, generics) = match item {
+        Annotatable::Item(item) => match &item.kind {
+            ItemKind::Struct(VariantData::Unit(..), _) => {
+                let msg = "`SmartPointer` can only be derived on `struct`s with fields";
+                cx.span_err(item.span, msg);
+                return;
+            }
+            ItemKind::Struct(_, generics) => (item, generics),
+            _ => {
+                cx.span_err(item.span, "`SmartPointer` can only be derived on `struct`s");
+                return;
+            }
+        },
+        _ => {
+            cx.span_err(span, "`SmartPointer` can only be derived on `struct`s");
+            return;
+        }
+    };
+
+    // `DispatchFromDyn` requires the pointer to be a pointer in the ABI, which only
+    // `#[repr(transparent)]` guarantees for a struct.
+    let is_transparent = item.attrs.iter().any(|attr| {
+        attr.has_name(sym::repr)
+            && attr
+                .meta_item_list()
+                .map_or(false, |list| list.iter().any(|meta| meta.check_name(sym::transparent)))
+    });
+    if !is_transparent {
+        let msg = "`SmartPointer` can only be derived on `#[repr(transparent)]` structs";
+        cx.span_err(item.span, msg);
+        return;
+    }
+
+    let type_params: Vec<_> = generics
+        .params
+        .iter()
+        .enumerate()
+        .filter(|(_, param)| matches!(param.kind, GenericParamKind::Type { .. }))
+        .collect();
+    let marked: Vec<_> = type_params
+        .iter()
+        .filter(|(_, param)| param.attrs.iter().any(|attr| attr.check_name(sym::pointee)))
+        .collect();
+    let (pointee_idx, pointee) = match (&type_params[..], &marked[..]) {
+        (_, [&(idx, param)]) => (idx, param),
+        (&[(idx, param)], []) => (idx, param),
+        ([], []) => {
+            cx.span_err(
+                item.span,
+                "`SmartPointer` can only be derived on `struct`s that are generic over a type",
+            );
+            return;
+        }
+        (_, []) => {
+            cx.span_err(
+                item.span,
+                "exactly one generic type parameter must be marked as `#[pointee]` to derive \
+                 `SmartPointer` traits",
+            );
+            return;
+        }
+        (_, [..]) => {
+            cx.span_err(
+                marked.iter().map(|(_, param)| param.ident.span).collect::<Vec<_>>(),
+                "only one type parameter can be marked as `#[pointee]` when deriving \
+                 `SmartPointer` traits",
+            );
+            return;
+        }
+    };
+    let is_maybe_sized = pointee.bounds.iter().any(|bound| match bound {
+        GenericBound::Trait(_, TraitBoundModifier::Maybe) => true,
+        _ => false,
+    });
+    if !is_maybe_sized {
+        cx.span_err(
+            pointee.ident.span,
+            "`SmartPointer` requires the `#[pointee]` type parameter to be `?Sized`",
+        );
+        return;
+    }
+
+    // The impls are generic over the type `__S` that the pointee is unsized to, which has the
+    // same bounds as the pointee.
+    let target_ident = Ident::from_str_and_span("__S", span);
+    let mut impl_generics = generics.clone();
+    for param in impl_generics.params.iter_mut() {
+        param.attrs = ast::AttrVec::new();
+        if let GenericParamKind::Type { default } = &mut param.kind {
+            *default = None;
+        }
+    }
+    let target_param = cx.typaram(span, target_ident, Vec::new(), pointee.bounds.clone(), None);
+    let unsize = cx.path_all(
+        span,
+        true,
+        cx.std_path(&[sym::marker, sym::Unsize]),
+        vec![GenericArg::Type(cx.ty_ident(span, target_ident))],
+    );
+    impl_generics.params[pointee_idx].bounds.push(cx.trait_bound(unsize));
+    impl_generics.params.insert(pointee_idx + 1, target_param);
+
+    let args = |target: bool| -> Vec<GenericArg> {
+        generics
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, param)| match &param.kind {
+                GenericParamKind::Lifetime => GenericArg::Lifetime(cx.lifetime(span, param.ident)),
+                GenericParamKind::Type { .. } if target && idx == pointee_idx => {
+                    GenericArg::Type(cx.ty_ident(span, target_ident))
+                }
+                GenericParamKind::Type { .. } => GenericArg::Type(cx.ty_ident(span, param.ident)),
+                GenericParamKind::Const { .. } => {
+                    GenericArg::Const(cx.const_ident(span, param.ident))
+                }
+            })
+            .collect()
+    };
+    let self_ty = cx.ty_path(cx.path_all(span, false, vec![item.ident], args(false)));
+    let target_ty = cx.ty_path(cx.path_all(span, false, vec![item.ident], args(true)));
+
+    for &trait_name in &[sym::DispatchFromDyn, sym::CoerceUnsized] {
+        let trait_path = cx.path_all(
+            span,
+            true,
+            cx.std_path(&[sym::ops, trait_name]),
+            vec![GenericArg::Type(target_ty.clone())],
+        );
+        let impl_item = cx.item(
+            span,
+            ast::Ident::invalid(),
+            Vec::new(),
+            ItemKind::Impl {
+                unsafety: ast::Unsafety::Normal,
+                polarity: ast::ImplPolarity::Positive,
+                defaultness: ast::Defaultness::Final,
+                generics: impl_generics.clone(),
+                of_trait: Some(cx.trait_ref(trait_path)),
+                self_ty: self_ty.clone(),
+                items: Vec::new(),
+            },
+        );
+        push(Annotatable::Item(impl_item));
+    }
+}
@@ -54,6 +54,9 @@ struct Context<'a, 'b> {
     arg_unique_types: Vec<Vec<ArgumentType>>,
     /// Map from named arguments to their resolved indices.
     names: FxHashMap<Symbol, usize>,
+    /// The string literal arguments that are only formatted with `{}`, by index. Their text is
+    /// inlined into the string pieces instead of being formatted at runtime.
+    inlined_args: FxHashMap<usize, Symbol>,
 
     /// The latest consecutive literal strings, or empty if there weren't any.
     literal: String,
@@ -516,6 +519,39 @@ impl<'a, 'b> Context<'a, 'b> {
         }
     }
 
+    /// Finds the string literal arguments that are only formatted with `{}`, and inlines them:
+    /// their text becomes part of the string pieces, so that a format string that only
+    /// interpolates literals is entirely known at compile time, as reported by
+    /// `Arguments::as_statically_known_str`.
+    fn inline_literal_args(&mut self, pieces: &[parse::Piece<'_>]) {
+        for (i, arg) in self.args.iter().enumerate() {
+            let lit = match arg.kind {
+                ast::ExprKind::Lit(ast::Lit { kind: ast::LitKind::Str(lit, _), .. }) => lit,
+                _ => continue,
+            };
+            // Literals used as a width or precision are errors reported later.
+            if self.arg_types[i].is_empty() || self.count_positions.contains_key(&i) {
+                continue;
+            }
+            let only_display = pieces.iter().all(|piece| match piece {
+                parse::NextArgument(arg) if arg.position.index() == Some(i) => {
+                    arg.format.fill.is_none()
+                        && arg.format.align == parse::AlignUnknown
+                        && arg.format.flags == 0
+                        && arg.format.precision == parse::CountImplied
+                        && arg.format.width == parse::CountImplied
+                        && arg.format.ty.is_empty()
+                }
+                _ => true,
+            });
+            if only_display {
+                self.inlined_args.insert(i, lit);
+                // Inlined arguments take no slot in the argument array.
+                self.arg_unique_types[i].clear();
+            }
+        }
+    }
+
     /// Builds the mapping between format placeholders and argument objects.
     fn build_index_map(&mut self) {
         // NOTE: Keep the ordering the same as `into_expr`'s expansion would do!
@@ -588,6 +624,14 @@ impl<'a, 'b> Context<'a, 'b> {
                 None
             }
             parse::NextArgument(ref arg) => {
+                if let Some(lit) = arg.position.index().and_then(|i| self.inlined_args.get(&i)) {
+                    // Still count the placeholder, for the `all_pieces_simple` tracking of the
+                    // ones that follow it.
+                    self.curarg += 1;
+                    self.literal.push_str(&lit.as_str());
+                    return None;
+                }
+
                 // Build the position
                 let pos = {
                     let pos = |c, arg| {
@@ -729,6 +773,9 @@ impl<'a, 'b> Context<'a, 'b> {
         // of each variable because we don't want to move out of the arguments
         // passed to this function.
         for (i, e) in self.args.into_iter().enumerate() {
+            if self.inlined_args.contains_key(&i) {
+                continue;
+            }
             let name = names_pos[i];
             let span = self.ecx.with_def_site_ctxt(e.span);
             pats.push(self.ecx.pat_ident(span, name));
@@ -1053,6 +1100,7 @@ pub fn expand_preparsed_format_args(
         arg_types,
         arg_unique_types,
         names,
+        inlined_args: FxHashMap::default(),
         curarg: 0,
         curpiece: 0,
         arg_index_map: Vec::new(),
@@ -1090,6 +1138,7 @@ pub fn expand_preparsed_format_args(
         },
     });
 
+    cx.inline_literal_args(&pieces);
     cx.build_index_map();
 
     let mut arg_index_consumed = vec![0usize; cx.arg_index_map.len()];
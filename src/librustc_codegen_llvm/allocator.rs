@@ -3,6 +3,7 @@ use std::ffi::CString;
 use crate::attributes;
 use libc::c_uint;
 use rustc::bug;
+use rustc::session::config::SymbolVisibility;
 use rustc::ty::TyCtxt;
 use syntax::expand::allocator::{AllocatorKind, AllocatorTy, ALLOCATOR_METHODS};
 
@@ -53,8 +54,14 @@ pub(crate) unsafe fn codegen(tcx: TyCtxt<'_>, mods: &mut ModuleLlvm, kind: Alloc
         let name = CString::new(format!("__rust_{}", method.name)).unwrap();
         let llfn = llvm::LLVMRustGetOrInsertFunction(llmod, name.as_ptr(), ty);
 
-        if tcx.sess.target.target.options.default_hidden_visibility {
-            llvm::LLVMRustSetVisibility(llfn, llvm::Visibility::Hidden);
+        match tcx.sess.default_visibility() {
+            SymbolVisibility::Hidden => {
+                llvm::LLVMRustSetVisibility(llfn, llvm::Visibility::Hidden);
+            }
+            SymbolVisibility::Protected => {
+                llvm::LLVMRustSetVisibility(llfn, llvm::Visibility::Protected);
+            }
+            SymbolVisibility::Interposable => {}
         }
         if tcx.sess.target.target.options.requires_uwtable {
             attributes::emit_uwtable(llfn, true);
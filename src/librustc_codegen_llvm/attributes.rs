@@ -15,6 +15,7 @@ use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_target::abi::call::Conv;
 use rustc_target::spec::PanicStrategy;
+use rustc_target::target_features::all_known_features;
 
 use crate::abi::FnAbi;
 use crate::attributes;
@@ -160,7 +161,18 @@ fn translate_obsolete_target_features(feature: &str) -> &str {
     feature
 }
 
-pub fn llvm_target_features(sess: &Session) -> impl Iterator<Item = &str> {
+/// Translates the Rust name of the feature of the `-C target-feature` entry `flag`, such as
+/// `+rdrand`, to the name LLVM uses. Entries that already use the LLVM name are kept as is.
+fn to_llvm_feature_flag(sess: &Session, flag: &str) -> String {
+    match flag.chars().next() {
+        Some(sign @ '+') | Some(sign @ '-') => {
+            format!("{}{}", sign, llvm_util::to_llvm_feature(sess, &flag[1..]))
+        }
+        _ => flag.to_string(),
+    }
+}
+
+pub fn llvm_target_features(sess: &Session) -> impl Iterator<Item = String> + '_ {
     const RUSTC_SPECIFIC_FEATURES: &[&str] = &["crt-static"];
 
     let cmdline = sess
@@ -176,7 +188,9 @@ pub fn llvm_target_features(sess: &Session) -> impl Iterator<Item = &str> {
         .split(',')
         .chain(cmdline)
         .filter(|l| !l.is_empty())
+        .filter(move |f| llvm_util::llvm_supports_feature(sess, f.get(1..).unwrap_or("")))
         .map(translate_obsolete_target_features)
+        .map(move |f| to_llvm_feature_flag(sess, f))
 }
 
 pub fn apply_target_cpu_attr(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
@@ -335,10 +349,12 @@ pub fn from_fn_attrs(
     apply_target_cpu_attr(cx, llfn);
 
     let features = llvm_target_features(cx.tcx.sess)
-        .map(|s| s.to_string())
-        .chain(codegen_fn_attrs.target_features.iter().map(|f| {
+        .chain(codegen_fn_attrs.target_features.iter().filter_map(|f| {
             let feature = &f.as_str();
-            format!("+{}", llvm_util::to_llvm_feature(cx.tcx.sess, feature))
+            if !llvm_util::llvm_supports_feature(cx.tcx.sess, feature) {
+                return None;
+            }
+            Some(format!("+{}", llvm_util::to_llvm_feature(cx.tcx.sess, feature)))
         }))
         .collect::<Vec<String>>()
         .join(",");
@@ -384,8 +400,7 @@ pub fn provide(providers: &mut Providers<'_>) {
         if tcx.sess.opts.actually_rustdoc {
             // rustdoc needs to be able to document functions that use all the features, so
             // whitelist them all
-            tcx.arena
-                .alloc(llvm_util::all_known_features().map(|(a, b)| (a.to_string(), b)).collect())
+            tcx.arena.alloc(all_known_features().map(|(a, b)| (a.to_string(), b)).collect())
         } else {
             tcx.arena.alloc(
                 llvm_util::target_feature_whitelist(tcx.sess)
@@ -22,6 +22,7 @@ use rustc_span::Span;
 use rustc_target::abi::HasDataLayout;
 
 use std::ffi::CStr;
+use std::ops::Range;
 
 pub fn const_alloc_to_llvm(cx: &CodegenCx<'ll, '_>, alloc: &Allocation) -> &'ll Value {
     let mut llvals = Vec::with_capacity(alloc.relocations().len() + 1);
@@ -40,8 +41,7 @@ pub fn const_alloc_to_llvm(cx: &CodegenCx<'ll, '_>, alloc: &Allocation) -> &'ll
             // some arbitrary byte value.
             //
             // FIXME: relay undef bytes to codegen as undef const bytes
-            let bytes = alloc.inspect_with_undef_and_ptr_outside_interpreter(next_offset..offset);
-            llvals.push(cx.const_bytes(bytes));
+            push_bytes(cx, &mut llvals, alloc, next_offset..offset);
         }
         let ptr_offset = read_target_uint(
             dl.endian,
@@ -67,13 +67,48 @@ pub fn const_alloc_to_llvm(cx: &CodegenCx<'ll, '_>, alloc: &Allocation) -> &'ll
         // arbitrary byte value.
         //
         // FIXME: relay undef bytes to codegen as undef const bytes
-        let bytes = alloc.inspect_with_undef_and_ptr_outside_interpreter(range);
-        llvals.push(cx.const_bytes(bytes));
+        push_bytes(cx, &mut llvals, alloc, range);
     }
 
     cx.const_struct(&llvals, true)
 }
 
+/// Runs of at least this many zero bytes are emitted as a `zeroinitializer` array, which LLVM
+/// represents without materializing the bytes.
+const MIN_ZERO_RUN: usize = 64;
+
+/// Pushes the constants making up the bytes of `alloc` in `range` to `llvals`.
+fn push_bytes(
+    cx: &CodegenCx<'ll, '_>,
+    llvals: &mut Vec<&'ll Value>,
+    alloc: &Allocation,
+    range: Range<usize>,
+) {
+    if alloc.is_zeroed() && range.len() >= MIN_ZERO_RUN {
+        // Do not make the allocation store its bytes just to find out that they are zero.
+        llvals.push(cx.const_null(cx.type_array(cx.type_i8(), range.len() as u64)));
+        return;
+    }
+    let bytes = alloc.inspect_with_undef_and_ptr_outside_interpreter(range);
+    let mut chunk_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let run = bytes[i..].iter().take_while(|&&byte| byte == bytes[i]).count();
+        if bytes[i] == 0 && run >= MIN_ZERO_RUN {
+            if chunk_start < i {
+                llvals.push(cx.const_bytes(&bytes[chunk_start..i]));
+            }
+            llvals.push(cx.const_null(cx.type_array(cx.type_i8(), run as u64)));
+            chunk_start = i + run;
+        }
+        i += run;
+    }
+    // Without any runs of zeros, `bytes` is emitted as one array, even if it is empty.
+    if chunk_start < bytes.len() || chunk_start == 0 {
+        llvals.push(cx.const_bytes(&bytes[chunk_start..]));
+    }
+}
+
 pub fn codegen_static_initializer(
     cx: &CodegenCx<'ll, 'tcx>,
     def_id: DefId,
@@ -439,21 +474,13 @@ impl StaticMethods for CodegenCx<'ll, 'tcx> {
                 if self.tcx.sess.target.target.options.is_like_osx {
                     assert_eq!(alloc.relocations().len(), 0);
 
-                    let is_zeroed = {
-                        // Treats undefined bytes as if they were defined with the byte value that
-                        // happens to be currently assigned in mir. This is valid since reading
-                        // undef bytes may yield arbitrary values.
-                        //
-                        // FIXME: ignore undef bytes even with representation `!= 0`.
-                        //
-                        // The `inspect` method is okay here because we checked relocations, and
-                        // because we are doing this access to inspect the final interpreter state
-                        // (not as part of the interpreter execution).
-                        alloc
-                            .inspect_with_undef_and_ptr_outside_interpreter(0..alloc.len())
-                            .iter()
-                            .all(|b| *b == 0)
-                    };
+                    // Treats undefined bytes as if they were defined with the byte value that
+                    // happens to be currently assigned in mir. This is valid since reading
+                    // undef bytes may yield arbitrary values. Interned allocations whose bytes are
+                    // all zero do not store them.
+                    //
+                    // FIXME: ignore undef bytes even with representation `!= 0`.
+                    let is_zeroed = alloc.is_zeroed();
                     let sect_name = if is_zeroed {
                         CStr::from_bytes_with_nul_unchecked(b"__DATA,__thread_bss\0")
                     } else {
@@ -188,7 +188,7 @@ impl IntrinsicCallMethods<'tcx> for Builder<'a, 'll, 'tcx> {
                 }
             }
             "size_of" | "pref_align_of" | "min_align_of" | "needs_drop" | "type_id"
-            | "type_name" => {
+            | "type_name" | "variant_count" => {
                 let ty_name = self
                     .tcx
                     .const_eval_instance(ty::ParamEnv::reveal_all(), instance, None)
@@ -767,6 +767,20 @@ impl IntrinsicCallMethods<'tcx> for Builder<'a, 'll, 'tcx> {
                 self.exactsdiv(d, pointee_size)
             }
 
+            // At runtime the comparison is always known.
+            "ptr_guaranteed_cmp" => {
+                let a = args[0].immediate();
+                let b = args[1].immediate();
+                let eq = self.icmp(IntPredicate::IntEQ, a, b);
+                self.zext(eq, self.type_i8())
+            }
+
+            // The compile-time heap does not exist at runtime.
+            "const_allocate" => self.const_null(self.type_i8p()),
+            "const_deallocate" => {
+                return;
+            }
+
             _ => bug!("unknown intrinsic '{}'", name),
         };
 
@@ -1119,6 +1133,32 @@ fn get_rust_try_fn<'ll, 'tcx>(
     rust_try
 }
 
+/// The generic SIMD intrinsics that support scalable vectors.
+const SCALABLE_VECTOR_INTRINSICS: &[&str] = &[
+    "simd_splat",
+    "simd_eq",
+    "simd_ne",
+    "simd_lt",
+    "simd_le",
+    "simd_gt",
+    "simd_ge",
+    "simd_add",
+    "simd_sub",
+    "simd_mul",
+    "simd_div",
+    "simd_rem",
+    "simd_shl",
+    "simd_shr",
+    "simd_and",
+    "simd_or",
+    "simd_xor",
+    "simd_fmax",
+    "simd_fmin",
+    "simd_insert",
+    "simd_extract",
+    "simd_cast",
+];
+
 fn generic_simd_intrinsic(
     bx: &mut Builder<'a, 'll, 'tcx>,
     name: &str,
@@ -1169,6 +1209,44 @@ fn generic_simd_intrinsic(
         .normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &callee_ty.fn_sig(tcx));
     let arg_tys = sig.inputs();
 
+    // Scalable vectors only support the intrinsics that work on each element separately, and
+    // they cannot be mixed with vectors of another length.
+    let scalable_len = |ty: Ty<'tcx>| ty.ty_adt_def().and_then(|def| def.repr.scalable);
+    let mut simd_tys = arg_tys.iter().chain(iter::once(&ret_ty)).filter(|ty| ty.is_simd());
+    if let Some(&scalable_ty) = simd_tys.clone().find(|&&ty| scalable_len(ty).is_some()) {
+        require!(
+            SCALABLE_VECTOR_INTRINSICS.contains(&name),
+            "unsupported operation on scalable vector `{}`",
+            scalable_ty
+        );
+        if let Some(&ty) = simd_tys.find(|&&ty| scalable_len(ty) != scalable_len(scalable_ty)) {
+            return_error!(
+                "expected scalable vectors with the same minimum length as `{}`, found `{}`",
+                scalable_ty,
+                ty
+            );
+        }
+    }
+
+    if name == "simd_splat" {
+        require_simd!(ret_ty, "return");
+        let out_elem = ret_ty.simd_type(tcx);
+        require!(
+            arg_tys[0] == out_elem,
+            "expected splatted type `{}` (element of return `{}`), found `{}`",
+            out_elem,
+            ret_ty,
+            arg_tys[0]
+        );
+        let mask_ty = match scalable_len(ret_ty) {
+            Some(elt) => bx.type_scalable_vector(bx.type_i32(), elt as u64),
+            None => bx.type_vector(bx.type_i32(), ret_ty.simd_size(tcx)),
+        };
+        let undef = bx.const_undef(llret_ty);
+        let vector = bx.insert_element(undef, args[0].immediate(), bx.const_i32(0));
+        return Ok(bx.shuffle_vector(vector, undef, bx.const_null(mask_ty)));
+    }
+
     if name == "simd_select_bitmask" {
         let in_ty = arg_tys[0];
         let m_len = match in_ty.kind {
@@ -78,6 +78,7 @@ mod metadata;
 mod mono_item;
 mod type_;
 mod type_of;
+mod unload_info;
 mod va_arg;
 mod value;
 
@@ -105,6 +106,9 @@ impl ExtraBackendMethods for LlvmCodegenBackend {
     ) {
         unsafe { allocator::codegen(tcx, mods, kind) }
     }
+    fn codegen_unload_info<'tcx>(&self, tcx: TyCtxt<'tcx>, mods: &mut ModuleLlvm) {
+        unsafe { unload_info::codegen(tcx, mods) }
+    }
     fn compile_codegen_unit(
         &self,
         tcx: TyCtxt<'_>,
@@ -6,11 +6,15 @@ use rustc::session::config::PrintRequest;
 use rustc::session::Session;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_feature::UnstableFeatures;
-use rustc_span::symbol::sym;
 use rustc_span::symbol::Symbol;
 use rustc_target::spec::{MergeFunctions, PanicStrategy};
-use std::ffi::CString;
+use rustc_target::target_features::{
+    implied_target_features, supported_target_features, target_feature_family,
+};
+use std::ffi::{CStr, CString};
+use syntax::util::lev_distance::find_best_match_for_name;
 
+use std::ptr;
 use std::slice;
 use std::str;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -36,6 +40,7 @@ pub(crate) fn init(sess: &Session) {
             bug!("couldn't enable multi-threaded LLVM");
         }
     }
+    check_target_features(sess);
 }
 
 fn require_inited() {
@@ -119,127 +124,10 @@ unsafe fn configure_llvm(sess: &Session) {
     llvm::LLVMRustSetLLVMOptions(llvm_args.len() as c_int, llvm_args.as_ptr());
 }
 
-// WARNING: the features after applying `to_llvm_feature` must be known
-// to LLVM or the feature detection code will walk past the end of the feature
+// WARNING: the features of `rustc_target::target_features` after applying `to_llvm_feature`
+// must be known to LLVM or the feature detection code will walk past the end of the feature
 // array, leading to crashes.
 
-const ARM_WHITELIST: &[(&str, Option<Symbol>)] = &[
-    ("aclass", Some(sym::arm_target_feature)),
-    ("mclass", Some(sym::arm_target_feature)),
-    ("rclass", Some(sym::arm_target_feature)),
-    ("dsp", Some(sym::arm_target_feature)),
-    ("neon", Some(sym::arm_target_feature)),
-    ("crc", Some(sym::arm_target_feature)),
-    ("crypto", Some(sym::arm_target_feature)),
-    ("v5te", Some(sym::arm_target_feature)),
-    ("v6", Some(sym::arm_target_feature)),
-    ("v6k", Some(sym::arm_target_feature)),
-    ("v6t2", Some(sym::arm_target_feature)),
-    ("v7", Some(sym::arm_target_feature)),
-    ("v8", Some(sym::arm_target_feature)),
-    ("vfp2", Some(sym::arm_target_feature)),
-    ("vfp3", Some(sym::arm_target_feature)),
-    ("vfp4", Some(sym::arm_target_feature)),
-];
-
-const AARCH64_WHITELIST: &[(&str, Option<Symbol>)] = &[
-    ("fp", Some(sym::aarch64_target_feature)),
-    ("neon", Some(sym::aarch64_target_feature)),
-    ("sve", Some(sym::aarch64_target_feature)),
-    ("crc", Some(sym::aarch64_target_feature)),
-    ("crypto", Some(sym::aarch64_target_feature)),
-    ("ras", Some(sym::aarch64_target_feature)),
-    ("lse", Some(sym::aarch64_target_feature)),
-    ("rdm", Some(sym::aarch64_target_feature)),
-    ("fp16", Some(sym::aarch64_target_feature)),
-    ("rcpc", Some(sym::aarch64_target_feature)),
-    ("dotprod", Some(sym::aarch64_target_feature)),
-    ("v8.1a", Some(sym::aarch64_target_feature)),
-    ("v8.2a", Some(sym::aarch64_target_feature)),
-    ("v8.3a", Some(sym::aarch64_target_feature)),
-];
-
-const X86_WHITELIST: &[(&str, Option<Symbol>)] = &[
-    ("adx", Some(sym::adx_target_feature)),
-    ("aes", None),
-    ("avx", None),
-    ("avx2", None),
-    ("avx512bw", Some(sym::avx512_target_feature)),
-    ("avx512cd", Some(sym::avx512_target_feature)),
-    ("avx512dq", Some(sym::avx512_target_feature)),
-    ("avx512er", Some(sym::avx512_target_feature)),
-    ("avx512f", Some(sym::avx512_target_feature)),
-    ("avx512ifma", Some(sym::avx512_target_feature)),
-    ("avx512pf", Some(sym::avx512_target_feature)),
-    ("avx512vbmi", Some(sym::avx512_target_feature)),
-    ("avx512vl", Some(sym::avx512_target_feature)),
-    ("avx512vpopcntdq", Some(sym::avx512_target_feature)),
-    ("bmi1", None),
-    ("bmi2", None),
-    ("cmpxchg16b", Some(sym::cmpxchg16b_target_feature)),
-    ("f16c", Some(sym::f16c_target_feature)),
-    ("fma", None),
-    ("fxsr", None),
-    ("lzcnt", None),
-    ("mmx", Some(sym::mmx_target_feature)),
-    ("movbe", Some(sym::movbe_target_feature)),
-    ("pclmulqdq", None),
-    ("popcnt", None),
-    ("rdrand", None),
-    ("rdseed", None),
-    ("rtm", Some(sym::rtm_target_feature)),
-    ("sha", None),
-    ("sse", None),
-    ("sse2", None),
-    ("sse3", None),
-    ("sse4.1", None),
-    ("sse4.2", None),
-    ("sse4a", Some(sym::sse4a_target_feature)),
-    ("ssse3", None),
-    ("tbm", Some(sym::tbm_target_feature)),
-    ("xsave", None),
-    ("xsavec", None),
-    ("xsaveopt", None),
-    ("xsaves", None),
-];
-
-const HEXAGON_WHITELIST: &[(&str, Option<Symbol>)] = &[
-    ("hvx", Some(sym::hexagon_target_feature)),
-    ("hvx-length128b", Some(sym::hexagon_target_feature)),
-];
-
-const POWERPC_WHITELIST: &[(&str, Option<Symbol>)] = &[
-    ("altivec", Some(sym::powerpc_target_feature)),
-    ("power8-altivec", Some(sym::powerpc_target_feature)),
-    ("power9-altivec", Some(sym::powerpc_target_feature)),
-    ("power8-vector", Some(sym::powerpc_target_feature)),
-    ("power9-vector", Some(sym::powerpc_target_feature)),
-    ("vsx", Some(sym::powerpc_target_feature)),
-];
-
-const MIPS_WHITELIST: &[(&str, Option<Symbol>)] =
-    &[("fp64", Some(sym::mips_target_feature)), ("msa", Some(sym::mips_target_feature))];
-
-const WASM_WHITELIST: &[(&str, Option<Symbol>)] =
-    &[("simd128", Some(sym::wasm_target_feature)), ("atomics", Some(sym::wasm_target_feature))];
-
-/// When rustdoc is running, provide a list of all known features so that all their respective
-/// primitives may be documented.
-///
-/// IMPORTANT: If you're adding another whitelist to the above lists, make sure to add it to this
-/// iterator!
-pub fn all_known_features() -> impl Iterator<Item = (&'static str, Option<Symbol>)> {
-    ARM_WHITELIST
-        .iter()
-        .cloned()
-        .chain(AARCH64_WHITELIST.iter().cloned())
-        .chain(X86_WHITELIST.iter().cloned())
-        .chain(HEXAGON_WHITELIST.iter().cloned())
-        .chain(POWERPC_WHITELIST.iter().cloned())
-        .chain(MIPS_WHITELIST.iter().cloned())
-        .chain(WASM_WHITELIST.iter().cloned())
-}
-
 pub fn to_llvm_feature<'a>(sess: &Session, s: &'a str) -> &'a str {
     let arch = if sess.target.target.arch == "x86_64" { "x86" } else { &*sess.target.target.arch };
     match (arch, s) {
@@ -253,6 +141,21 @@ pub fn to_llvm_feature<'a>(sess: &Session, s: &'a str) -> &'a str {
     }
 }
 
+/// Whether LLVM can generate code for the Rust feature `feature`. The RISC-V vector,
+/// bit-manipulation and scalar cryptography extensions are only known to LLVM 14 and later.
+/// With an older LLVM, they are accepted by `#[target_feature]` and `-C target-feature`, but
+/// they are neither passed to LLVM nor reported by `cfg(target_feature)`, as no code uses them.
+pub fn llvm_supports_feature(sess: &Session, feature: &str) -> bool {
+    match &*sess.target.target.arch {
+        "riscv32" | "riscv64"
+            if feature == "v" || feature.starts_with("zb") || feature.starts_with("zk") =>
+        {
+            get_major_version() >= 14
+        }
+        _ => true,
+    }
+}
+
 pub fn target_features(sess: &Session) -> Vec<Symbol> {
     let target_machine = create_informational_target_machine(sess, true);
     target_feature_whitelist(sess)
@@ -265,6 +168,9 @@ pub fn target_features(sess: &Session) -> Vec<Symbol> {
             }
         })
         .filter(|feature| {
+            if !llvm_supports_feature(sess, feature) {
+                return false;
+            }
             let llvm_feature = to_llvm_feature(sess, feature);
             let cstr = CString::new(llvm_feature).unwrap();
             unsafe { llvm::LLVMRustHasFeature(target_machine, cstr.as_ptr()) }
@@ -273,17 +179,188 @@ pub fn target_features(sess: &Session) -> Vec<Symbol> {
         .collect()
 }
 
-pub fn target_feature_whitelist(sess: &Session) -> &'static [(&'static str, Option<Symbol>)] {
-    match &*sess.target.target.arch {
-        "arm" => ARM_WHITELIST,
-        "aarch64" => AARCH64_WHITELIST,
-        "x86" | "x86_64" => X86_WHITELIST,
-        "hexagon" => HEXAGON_WHITELIST,
-        "mips" | "mips64" => MIPS_WHITELIST,
-        "powerpc" | "powerpc64" => POWERPC_WHITELIST,
-        "wasm32" => WASM_WHITELIST,
-        _ => &[],
+/// Returns the names and descriptions of the features that LLVM knows for the target of `tm`.
+/// This is empty when rustc is not built with its own LLVM.
+fn llvm_feature_table(tm: &llvm::TargetMachine) -> Vec<(&'static str, &'static str)> {
+    let len = unsafe { llvm::LLVMRustGetTargetFeaturesCount(tm) };
+    (0..len)
+        .map(|index| unsafe {
+            let mut feature = ptr::null();
+            let mut desc = ptr::null();
+            llvm::LLVMRustGetTargetFeature(tm, index, &mut feature, &mut desc);
+            (CStr::from_ptr(feature).to_str().unwrap(), CStr::from_ptr(desc).to_str().unwrap())
+        })
+        .collect()
+}
+
+/// Warns about the entries of `-C target-feature` that are unknown, that contradict each other,
+/// or that are implied by other entries. They are all passed to LLVM regardless.
+fn check_target_features(sess: &Session) {
+    const RUSTC_SPECIFIC_FEATURES: &[&str] = &["crt-static"];
+
+    let arch = &*sess.target.target.arch;
+    let rust_features = target_feature_whitelist(sess);
+    let llvm_features = llvm_feature_table(create_informational_target_machine(sess, true));
+    // Without the feature table of LLVM, the features unknown to rustc may still be valid.
+    let is_known = |name: &str| {
+        llvm_features.is_empty()
+            || rust_features.iter().any(|&(feature, _)| feature == name)
+            || llvm_features.iter().any(|&(feature, _)| feature == name)
+    };
+
+    // The state of every feature after the whole flag, as in LLVM, the last entry wins.
+    let mut states: Vec<(&str, bool)> = Vec::new();
+    for entry in sess.opts.cg.target_feature.split(',').filter(|entry| !entry.is_empty()) {
+        let (enable, name) = match entry.chars().next() {
+            Some('+') => (true, &entry[1..]),
+            Some('-') => (false, &entry[1..]),
+            _ => {
+                sess.struct_warn(&format!(
+                    "`-C target-feature` entry `{}` does not start with `+` or `-`",
+                    entry
+                ))
+                .help(&format!("use `+{0}` to enable the feature, or `-{0}` to disable it", entry))
+                .emit();
+                continue;
+            }
+        };
+        if RUSTC_SPECIFIC_FEATURES.contains(&name) {
+            continue;
+        }
+        if !is_known(name) {
+            let mut diag = sess.struct_warn(&format!(
+                "unknown feature specified for `-C target-feature`: `{}`",
+                name
+            ));
+            let candidates = rust_features
+                .iter()
+                .map(|&(feature, _)| feature)
+                .chain(llvm_features.iter().map(|&(feature, _)| feature))
+                .map(Symbol::intern)
+                .collect::<Vec<_>>();
+            if let Some(best) = find_best_match_for_name(candidates.iter(), name, None) {
+                diag.help(&format!("did you mean `{}{}`?", &entry[..1], best));
+            }
+            diag.note("it is still passed to LLVM, which ignores the features it does not know");
+            diag.emit();
+            continue;
+        }
+        match states.iter_mut().find(|(feature, _)| *feature == name) {
+            Some((_, state)) if *state != enable => {
+                sess.struct_warn(&format!(
+                    "target feature `{}` is both enabled and disabled by `-C target-feature`",
+                    name
+                ))
+                .note(&format!("the last entry, `{}`, takes effect", entry))
+                .emit();
+                *state = enable;
+            }
+            Some(_) => {}
+            None => states.push((name, enable)),
+        }
     }
+
+    let mut reported_implied = FxHashSet::default();
+    for &(name, enable) in &states {
+        if !enable {
+            continue;
+        }
+        // All the features that `name` depends on, directly or not.
+        let mut implied = implied_target_features(arch, name).to_vec();
+        let mut i = 0;
+        while i < implied.len() {
+            for &dep in implied_target_features(arch, implied[i]) {
+                if !implied.contains(&dep) {
+                    implied.push(dep);
+                }
+            }
+            i += 1;
+        }
+        for dep in implied {
+            match states.iter().find(|&&(feature, _)| feature == dep) {
+                Some(&(_, false)) => {
+                    sess.struct_warn(&format!(
+                        "`+{}` conflicts with `-{}` in `-C target-feature`",
+                        name, dep
+                    ))
+                    .note(&format!(
+                        "`{}` requires `{}`, so disabling `{}` disables `{}` as well",
+                        name, dep, dep, name
+                    ))
+                    .emit();
+                }
+                Some(&(_, true)) if reported_implied.insert(dep) => {
+                    sess.note_without_error(&format!(
+                        "`+{}` in `-C target-feature` is redundant: it is implied by `+{}`",
+                        dep, name
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Prints the features of the target, those that rustc knows grouped by extension family, then
+/// the others that LLVM knows.
+fn print_target_features(sess: &Session, tm: &llvm::TargetMachine) {
+    let arch = &*sess.target.target.arch;
+    let rust_features = target_feature_whitelist(sess);
+    let llvm_features = llvm_feature_table(tm);
+    let width = rust_features
+        .iter()
+        .map(|&(feature, _)| feature)
+        .chain(llvm_features.iter().map(|&(feature, _)| feature))
+        .map(|feature| feature.len())
+        .max()
+        .unwrap_or(0);
+    let print_feature = |feature: &str, llvm_name: &str| {
+        let desc = llvm_features.iter().find(|&&(name, _)| name == llvm_name);
+        match desc {
+            Some(&(_, desc)) => println!("    {:<width$} - {}.", feature, desc, width = width),
+            None => println!("    {}", feature),
+        }
+    };
+
+    let mut families = rust_features
+        .iter()
+        .map(|&(feature, _)| target_feature_family(arch, feature))
+        .collect::<Vec<_>>();
+    families.sort_by_key(|&family| (family == "other", family));
+    families.dedup();
+    if !families.is_empty() {
+        println!("Features supported by rustc for this target:");
+        for family in families {
+            println!("  {}:", family);
+            for &(feature, _) in rust_features {
+                if target_feature_family(arch, feature) == family {
+                    print_feature(feature, to_llvm_feature(sess, feature));
+                }
+            }
+        }
+        println!();
+    }
+
+    let rust_llvm_names = rust_features
+        .iter()
+        .map(|&(feature, _)| to_llvm_feature(sess, feature))
+        .collect::<FxHashSet<_>>();
+    if !llvm_features.is_empty() {
+        println!("Code-generation features supported by LLVM for this target:");
+        for &(feature, _) in &llvm_features {
+            if !rust_llvm_names.contains(feature) {
+                print_feature(feature, feature);
+            }
+        }
+        println!();
+    }
+
+    println!("Use +feature to enable a feature, or -feature to disable it.");
+    println!("For example, rustc -C target-cpu=mycpu -C target-feature=+feature1,-feature2\n");
+}
+
+pub fn target_feature_whitelist(sess: &Session) -> &'static [(&'static str, Option<Symbol>)] {
+    supported_target_features(&sess.target.target.arch)
 }
 
 pub fn print_version() {
@@ -310,7 +387,7 @@ pub(crate) fn print(req: PrintRequest, sess: &Session) {
     unsafe {
         match req {
             PrintRequest::TargetCPUs => llvm::LLVMRustPrintTargetCPUs(tm),
-            PrintRequest::TargetFeatures => llvm::LLVMRustPrintTargetFeatures(tm),
+            PrintRequest::TargetFeatures => print_target_features(sess, tm),
             _ => bug!("rustc_codegen_llvm can't handle print request: {:?}", req),
         }
     }
@@ -70,6 +70,12 @@ impl CodegenCx<'ll, 'tcx> {
         unsafe { llvm::LLVMVectorType(ty, len as c_uint) }
     }
 
+    /// Creates a vector type with `min_len * vscale` elements, `vscale` being only known at
+    /// runtime.
+    crate fn type_scalable_vector(&self, ty: &'ll Type, min_len: u64) -> &'ll Type {
+        unsafe { llvm::LLVMRustScalableVectorType(ty, min_len as c_uint) }
+    }
+
     crate fn func_params_types(&self, ty: &'ll Type) -> Vec<&'ll Type> {
         unsafe {
             let n_args = llvm::LLVMCountParamTypes(ty) as usize;
@@ -37,6 +37,10 @@ fn uncached_llvm_type<'a, 'tcx>(
                 return cx.type_vector(element, count);
             }
         }
+        layout::Abi::ScalableVector { ref element, elt } => {
+            let element = layout.scalar_llvm_type_at(cx, element, Size::ZERO);
+            return cx.type_scalable_vector(element, elt);
+        }
         layout::Abi::ScalarPair(..) => {
             return cx.type_struct(
                 &[
@@ -205,7 +209,9 @@ pub trait LayoutLlvmExt<'tcx> {
 impl<'tcx> LayoutLlvmExt<'tcx> for TyLayout<'tcx> {
     fn is_llvm_immediate(&self) -> bool {
         match self.abi {
-            layout::Abi::Scalar(_) | layout::Abi::Vector { .. } => true,
+            layout::Abi::Scalar(_)
+            | layout::Abi::Vector { .. }
+            | layout::Abi::ScalableVector { .. } => true,
             layout::Abi::ScalarPair(..) => false,
             layout::Abi::Uninhabited | layout::Abi::Aggregate { .. } => self.is_zst(),
         }
@@ -217,6 +223,7 @@ impl<'tcx> LayoutLlvmExt<'tcx> for TyLayout<'tcx> {
             layout::Abi::Uninhabited
             | layout::Abi::Scalar(_)
             | layout::Abi::Vector { .. }
+            | layout::Abi::ScalableVector { .. }
             | layout::Abi::Aggregate { .. } => false,
         }
     }
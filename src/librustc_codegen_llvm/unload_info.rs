@@ -0,0 +1,42 @@
+use std::ffi::CString;
+
+use libc::c_uint;
+use rustc::ty::TyCtxt;
+use rustc_codegen_ssa::back::unload_info::{
+    self, TLS_DTORS_SYMBOL, UNLOAD_INFO_SYMBOL, UNLOAD_INFO_VERSION,
+};
+
+use crate::llvm::{self, False, True};
+use crate::ModuleLlvm;
+
+pub(crate) unsafe fn codegen(tcx: TyCtxt<'_>, mods: &mut ModuleLlvm) {
+    let llcx = &*mods.llcx;
+    let llmod = mods.llmod();
+    let i8 = llvm::LLVMInt8TypeInContext(llcx);
+    let i32 = llvm::LLVMInt32TypeInContext(llcx);
+    let i8p = llvm::LLVMPointerType(i8, 0);
+
+    let info = unload_info::unload_info(tcx);
+    let tls_dtors = if info.links_std {
+        let name = CString::new(TLS_DTORS_SYMBOL).unwrap();
+        llvm::LLVMRustGetOrInsertGlobal(llmod, name.as_ptr(), name.as_bytes().len(), i8)
+    } else {
+        llvm::LLVMConstNull(i8p)
+    };
+    let fields = [
+        llvm::LLVMConstInt(i32, UNLOAD_INFO_VERSION as u64, False),
+        llvm::LLVMConstInt(i32, info.flags as u64, False),
+        tls_dtors,
+    ];
+    let init = llvm::LLVMConstStructInContext(llcx, fields.as_ptr(), fields.len() as c_uint, False);
+
+    let name = CString::new(UNLOAD_INFO_SYMBOL).unwrap();
+    let llglobal = llvm::LLVMRustGetOrInsertGlobal(
+        llmod,
+        name.as_ptr(),
+        name.as_bytes().len(),
+        llvm::LLVMTypeOf(init),
+    );
+    llvm::LLVMSetInitializer(llglobal, init);
+    llvm::LLVMSetGlobalConstant(llglobal, True);
+}
@@ -10,11 +10,13 @@ use rustc::session::{filesearch, Session};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_fs_util::fix_windows_verbatim_for_gcc;
 use rustc_hir::def_id::CrateNum;
+use rustc_serialize::json::{Json, ToJson};
 use rustc_span::symbol::Symbol;
 use rustc_target::spec::{LinkerFlavor, PanicStrategy, RelroLevel};
 
 use super::archive::ArchiveBuilder;
 use super::command::Command;
+use super::linked_symbols;
 use super::linker::Linker;
 use super::rpath::{self, RPathConfig};
 use crate::{
@@ -26,6 +28,7 @@ use cc::windows_registry;
 use tempfile::{Builder as TempFileBuilder, TempDir};
 
 use std::ascii;
+use std::collections::BTreeMap;
 use std::char;
 use std::env;
 use std::ffi::OsString;
@@ -109,6 +112,13 @@ pub fn link_binary<'a, B: ArchiveBuilder<'a>>(
                         tmpdir.path(),
                         target_cpu,
                     );
+                    if sess.opts.debugging_opts.verify_linked_symbols {
+                        verify_linked_symbols(sess, codegen_results, &out_filename);
+                    }
+                    if sess.opts.prints.contains(&PrintRequest::DeploymentArtifacts) {
+                        let out_filename = &out_filename;
+                        print_deployment_artifacts(sess, crate_type, codegen_results, out_filename);
+                    }
                 }
             }
             if sess.opts.json_artifact_notifications {
@@ -475,6 +485,45 @@ fn link_staticlib<'a, B: ArchiveBuilder<'a>>(
     }
 }
 
+/// Warns about the `#[used]` and `#[no_mangle]` items of the crate that the linker removed from
+/// `out_filename`, usually because they were unreferenced when garbage-collecting sections.
+fn verify_linked_symbols(sess: &Session, codegen_results: &CodegenResults, out_filename: &Path) {
+    let kept_symbols = &codegen_results.crate_info.kept_symbols;
+    if kept_symbols.is_empty() {
+        return;
+    }
+    let data = match fs::read(out_filename) {
+        Ok(data) => data,
+        Err(e) => {
+            sess.warn(&format!(
+                "failed to read `{}` to verify its symbols: {}",
+                out_filename.display(),
+                e
+            ));
+            return;
+        }
+    };
+    let linked = match linked_symbols::defined_symbols(&data) {
+        Some(linked) => linked,
+        None => {
+            sess.warn(&format!(
+                "`-Z verify-linked-symbols` cannot read the symbols of `{}`",
+                out_filename.display()
+            ));
+            return;
+        }
+    };
+    for (name, span, is_static) in kept_symbols {
+        if (*is_static && !linked.includes_statics) || linked.names.contains(name) {
+            continue;
+        }
+        sess.struct_span_warn(*span, &format!("`{}` was removed by the linker", name))
+            .note("the linker removes the sections that nothing references, even `#[used]` ones")
+            .help("reference the item from the program, or export it with a linker argument")
+            .emit();
+    }
+}
+
 // Create a dynamic library or executable
 //
 // This will invoke the system linker/cc to create the resulting file. This
@@ -968,6 +1017,87 @@ pub fn print_native_static_libs(sess: &Session, all_native_libs: &[NativeLibrary
     }
 }
 
+/// Prints the JSON manifest of what `out_filename` needs at runtime for
+/// `--print deployment-artifacts`: the Rust dylibs that it was linked against, the native
+/// libraries that are loaded dynamically, by it or by those dylibs, and the minimum OS version.
+fn print_deployment_artifacts(
+    sess: &Session,
+    crate_type: config::CrateType,
+    codegen_results: &CodegenResults,
+    out_filename: &Path,
+) {
+    let crate_info = &codegen_results.crate_info;
+    let (_, data) = crate_info
+        .dependency_formats
+        .iter()
+        .find(|(ty, _)| *ty == crate_type)
+        .expect("failed to find crate type in dependency format list");
+
+    let mut rust_dylibs = Vec::new();
+    let mut native_libs: Vec<&NativeLibrary> = crate_info.used_libraries.iter().collect();
+    for (i, &linkage) in data.iter().enumerate() {
+        let cnum = CrateNum::new(i + 1);
+        match linkage {
+            Linkage::NotLinked => continue,
+            Linkage::Dynamic => {
+                let path = crate_info.used_crate_source[&cnum]
+                    .dylib
+                    .as_ref()
+                    .map(|(path, _)| path.display().to_string());
+                let hash = crate_info.crate_hash[&cnum].to_string();
+                let mut dylib = BTreeMap::new();
+                dylib.insert("name".to_string(), crate_info.crate_name[&cnum].to_json());
+                dylib.insert("path".to_string(), path.to_json());
+                dylib.insert("hash".to_string(), hash.to_json());
+                rust_dylibs.push(Json::Object(dylib));
+            }
+            Linkage::IncludedFromDylib | Linkage::Static => {}
+        }
+        native_libs.extend(crate_info.native_libraries[&cnum].iter());
+    }
+
+    let mut native_libraries = Vec::new();
+    for lib in native_libs.into_iter().filter(|lib| relevant_lib(sess, lib)) {
+        let name = match lib.name {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let kind = match lib.kind {
+            NativeLibraryKind::NativeUnknown => "dylib",
+            NativeLibraryKind::NativeFramework => "framework",
+            NativeLibraryKind::NativeRawDylib => "raw-dylib",
+            // These are part of the output or of the Rust dylibs
+            NativeLibraryKind::NativeStatic | NativeLibraryKind::NativeStaticNobundle => continue,
+        };
+        let mut native = BTreeMap::new();
+        native.insert("name".to_string(), name.to_json());
+        native.insert("kind".to_string(), kind.to_json());
+        let native = Json::Object(native);
+        if !native_libraries.contains(&native) {
+            native_libraries.push(native);
+        }
+    }
+
+    let target = &sess.target.target;
+    let deployment_target = if target.options.is_like_osx && target.target_os == "ios" {
+        Some(rustc_target::spec::ios_deployment_target())
+    } else if target.options.is_like_osx {
+        Some(rustc_target::spec::macos_deployment_target())
+    } else {
+        None
+    };
+    let min_os_version = deployment_target.map(|(major, minor)| format!("{}.{}", major, minor));
+
+    let mut manifest = BTreeMap::new();
+    manifest.insert("output".to_string(), out_filename.display().to_string().to_json());
+    manifest.insert("crate_type".to_string(), crate_type.to_string().to_json());
+    manifest.insert("target".to_string(), sess.opts.target_triple.to_string().to_json());
+    manifest.insert("rust_dylibs".to_string(), Json::Array(rust_dylibs));
+    manifest.insert("native_libraries".to_string(), Json::Array(native_libraries));
+    manifest.insert("min_os_version".to_string(), min_os_version.to_json());
+    println!("{}", Json::Object(manifest).pretty());
+}
+
 pub fn get_file_path(sess: &Session, name: &str) -> PathBuf {
     let fs = sess.target_filesearch(PathKind::Native);
     let file_path = fs.get_lib_path().join(name);
@@ -0,0 +1,190 @@
+//! A minimal reader of the symbols defined by a linked artifact, used by
+//! `-Z verify-linked-symbols` to find the `#[used]` and `#[no_mangle]` items that the linker
+//! removed.
+//!
+//! Only the formats of the targets where the linker garbage-collects sections by default are
+//! supported: ELF, through its `.symtab` and `.dynsym` tables, and wasm, through its exports
+//! and the function names of its `name` section.
+
+use rustc_data_structures::fx::FxHashSet;
+
+pub struct LinkedSymbols {
+    pub names: FxHashSet<String>,
+    /// Whether `names` includes the statics kept by the linker. wasm only names the functions
+    /// and the exports.
+    pub includes_statics: bool,
+}
+
+/// Returns the symbols defined by the linked artifact `data`, or `None` if its format is not
+/// supported or if it has no symbol table, as happens when it is stripped.
+pub fn defined_symbols(data: &[u8]) -> Option<LinkedSymbols> {
+    if data.starts_with(b"\x7fELF") {
+        elf_symbols(data)
+    } else if data.starts_with(b"\0asm") {
+        wasm_symbols(data)
+    } else {
+        None
+    }
+}
+
+struct ElfReader<'a> {
+    data: &'a [u8],
+    is_64: bool,
+    big_endian: bool,
+}
+
+impl<'a> ElfReader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> Option<&'a [u8]> {
+        self.data.get(offset..offset.checked_add(len)?)
+    }
+
+    fn uint(&self, offset: usize, len: usize) -> Option<u64> {
+        let bytes = self.bytes(offset, len)?;
+        let fold = |acc: u64, &b: &u8| (acc << 8) | u64::from(b);
+        Some(if self.big_endian {
+            bytes.iter().fold(0, fold)
+        } else {
+            bytes.iter().rev().fold(0, fold)
+        })
+    }
+
+    /// Reads a field that is 4 bytes wide in ELF32 and 8 bytes wide in ELF64.
+    fn word(&self, offset: usize) -> Option<usize> {
+        self.uint(offset, if self.is_64 { 8 } else { 4 }).map(|w| w as usize)
+    }
+
+    /// Returns the type, offset, size and link of the section header at `offset`.
+    fn section(&self, offset: usize) -> Option<(u64, usize, usize, usize)> {
+        let ty = self.uint(offset.checked_add(4)?, 4)?;
+        let (data, link, size) = if self.is_64 { (24, 40, 32) } else { (16, 24, 20) };
+        let data_offset = self.word(offset.checked_add(data)?)?;
+        let size = self.word(offset.checked_add(size)?)?;
+        Some((ty, data_offset, size, self.uint(offset.checked_add(link)?, 4)? as usize))
+    }
+
+    /// Returns the offset of the `index`th entry of `entry_size` bytes of a table at `offset`.
+    fn entry(&self, offset: usize, index: usize, entry_size: usize) -> Option<usize> {
+        offset.checked_add(index.checked_mul(entry_size)?)
+    }
+
+    fn c_str(&self, offset: usize) -> Option<&'a [u8]> {
+        let rest = self.data.get(offset..)?;
+        rest.get(..rest.iter().position(|&b| b == 0)?)
+    }
+}
+
+fn elf_symbols(data: &[u8]) -> Option<LinkedSymbols> {
+    const SHT_SYMTAB: u64 = 2;
+    const SHT_DYNSYM: u64 = 11;
+    const SHN_UNDEF: u64 = 0;
+
+    let elf = ElfReader { data, is_64: *data.get(4)? == 2, big_endian: *data.get(5)? == 2 };
+    let (shoff, shentsize, shnum) = if elf.is_64 {
+        (elf.word(0x28)?, elf.uint(0x3a, 2)? as usize, elf.uint(0x3c, 2)? as usize)
+    } else {
+        (elf.word(0x20)?, elf.uint(0x2e, 2)? as usize, elf.uint(0x30, 2)? as usize)
+    };
+    let sym_size = if elf.is_64 { 24 } else { 16 };
+    let shndx_offset = if elf.is_64 { 6 } else { 14 };
+
+    let mut symbols = FxHashSet::default();
+    let mut found_table = false;
+    for i in 0..shnum {
+        let (ty, offset, size, link) = elf.section(elf.entry(shoff, i, shentsize)?)?;
+        if ty != SHT_SYMTAB && ty != SHT_DYNSYM {
+            continue;
+        }
+        found_table = true;
+        let (_, strtab, _, _) = elf.section(elf.entry(shoff, link, shentsize)?)?;
+        for j in 0..size / sym_size {
+            let sym = elf.entry(offset, j, sym_size)?;
+            if elf.uint(sym.checked_add(shndx_offset)?, 2)? == SHN_UNDEF {
+                continue;
+            }
+            let name = elf.c_str(strtab.checked_add(elf.uint(sym, 4)? as usize)?)?;
+            if !name.is_empty() {
+                symbols.insert(String::from_utf8_lossy(name).into_owned());
+            }
+        }
+    }
+    if found_table { Some(LinkedSymbols { names: symbols, includes_statics: true }) } else { None }
+}
+
+struct WasmReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WasmReader<'a> {
+    fn byte(&mut self) -> Option<u8> {
+        let b = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn leb128(&mut self) -> Option<usize> {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let b = self.byte()?;
+            result |= ((b & 0x7f) as usize).checked_shl(shift)?;
+            if b & 0x80 == 0 {
+                return Some(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn name(&mut self) -> Option<String> {
+        let len = self.leb128()?;
+        Some(String::from_utf8_lossy(self.bytes(len)?).into_owned())
+    }
+}
+
+fn wasm_symbols(data: &[u8]) -> Option<LinkedSymbols> {
+    const CUSTOM_SECTION: u8 = 0;
+    const EXPORT_SECTION: u8 = 7;
+    const FUNCTION_NAMES: u8 = 1;
+
+    let mut reader = WasmReader { data, pos: 8 };
+    let mut symbols = FxHashSet::default();
+    let mut found_table = false;
+    while reader.pos < data.len() {
+        let id = reader.byte()?;
+        let len = reader.leb128()?;
+        let mut section = WasmReader { data: reader.bytes(len)?, pos: 0 };
+        match id {
+            EXPORT_SECTION => {
+                found_table = true;
+                for _ in 0..section.leb128()? {
+                    symbols.insert(section.name()?);
+                    section.byte()?;
+                    section.leb128()?;
+                }
+            }
+            CUSTOM_SECTION if section.name()? == "name" => {
+                while section.pos < section.data.len() {
+                    let kind = section.byte()?;
+                    let len = section.leb128()?;
+                    let mut names = WasmReader { data: section.bytes(len)?, pos: 0 };
+                    if kind != FUNCTION_NAMES {
+                        continue;
+                    }
+                    found_table = true;
+                    for _ in 0..names.leb128()? {
+                        names.leb128()?;
+                        symbols.insert(names.name()?);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    if found_table { Some(LinkedSymbols { names: symbols, includes_statics: false }) } else { None }
+}
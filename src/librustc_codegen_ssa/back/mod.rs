@@ -1,8 +1,10 @@
 pub mod archive;
 pub mod command;
 pub mod link;
+pub mod linked_symbols;
 pub mod linker;
 pub mod lto;
 pub mod rpath;
 pub mod symbol_export;
+pub mod unload_info;
 pub mod write;
@@ -17,6 +17,8 @@ use rustc_hir::Node;
 use rustc_index::vec::IndexVec;
 use syntax::expand::allocator::ALLOCATOR_METHODS;
 
+use super::unload_info::{self, UNLOAD_INFO_SYMBOL};
+
 pub type ExportedSymbols = FxHashMap<CrateNum, Arc<Vec<(String, SymbolExportLevel)>>>;
 
 pub fn threshold(tcx: TyCtxt<'_>) -> SymbolExportLevel {
@@ -206,6 +208,11 @@ fn exported_symbols_provider_local(
         }));
     }
 
+    if unload_info::needs_unload_info(tcx) {
+        let exported_symbol = ExportedSymbol::NoDefId(SymbolName::new(UNLOAD_INFO_SYMBOL));
+        symbols.push((exported_symbol, SymbolExportLevel::C));
+    }
+
     if tcx.sess.crate_types.borrow().contains(&config::CrateType::Dylib) {
         let symbol_name = metadata_symbol_name(tcx);
         let exported_symbol = ExportedSymbol::NoDefId(SymbolName::new(&symbol_name));
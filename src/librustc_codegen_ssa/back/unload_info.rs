@@ -0,0 +1,58 @@
+//! The unload information that `-Z unload-info` adds to cdylibs, which `std::os::unload` reads
+//! in the programs loading them.
+//!
+//! It is the symbol `__rust_unload_info`, a `#[repr(C)]` struct of a `u32` version, `u32` flags
+//! describing the crates linked into the library, and a pointer to the number of thread-local
+//! destructors registered by the copy of the standard library in the library, which is null if
+//! it has no such copy.
+
+use rustc::middle::dependency_format::Linkage;
+use rustc::session::config;
+use rustc::ty::TyCtxt;
+use rustc_hir::def_id::{CrateNum, LOCAL_CRATE};
+use rustc_span::symbol::sym;
+
+pub const UNLOAD_INFO_SYMBOL: &str = "__rust_unload_info";
+
+/// The counter of the thread-local destructors in the standard library.
+pub const TLS_DTORS_SYMBOL: &str = "__rust_tls_dtors_registered";
+
+/// The version of the layout of the unload information, which `std` checks before reading it.
+pub const UNLOAD_INFO_VERSION: u32 = 1;
+
+/// Set if a crate linked into the library has statics with destructors, which never run.
+pub const UNLOAD_INFO_STATIC_DTORS: u32 = 1 << 0;
+
+pub struct UnloadInfo {
+    pub flags: u32,
+    /// Whether the library links the standard library, which defines `TLS_DTORS_SYMBOL`.
+    pub links_std: bool,
+}
+
+/// Whether the crate gets unload information, which only cdylibs do.
+pub fn needs_unload_info(tcx: TyCtxt<'_>) -> bool {
+    tcx.sess.opts.debugging_opts.unload_info
+        && tcx.sess.crate_types.borrow().contains(&config::CrateType::Cdylib)
+}
+
+pub fn unload_info(tcx: TyCtxt<'_>) -> UnloadInfo {
+    let formats = tcx.dependency_formats(LOCAL_CRATE);
+    let linked: Vec<CrateNum> = formats
+        .iter()
+        .find(|(ty, _)| *ty == config::CrateType::Cdylib)
+        .map(|(_, list)| {
+            list.iter()
+                .enumerate()
+                .filter(|&(_, &linkage)| linkage != Linkage::NotLinked)
+                .map(|(i, _)| CrateNum::new(i + 1))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut flags = 0;
+    if tcx.has_static_dtors(LOCAL_CRATE) || linked.iter().any(|&cnum| tcx.has_static_dtors(cnum)) {
+        flags |= UNLOAD_INFO_STATIC_DTORS;
+    }
+    let links_std = linked.iter().any(|&cnum| tcx.crate_name(cnum) == sym::std);
+    UnloadInfo { flags, links_std }
+}
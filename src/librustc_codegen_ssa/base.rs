@@ -13,6 +13,7 @@
 //!   but one `llvm::Type` corresponds to many `Ty`s; for instance, `tup(int, int,
 //!   int)` and `rec(x=int, y=int, z=int)` will have the same `llvm::Type`.
 
+use crate::back::unload_info;
 use crate::back::write::{
     start_async_codegen, submit_codegened_module_to_llvm, submit_post_lto_module_to_llvm,
     submit_pre_lto_module_to_llvm, OngoingCodegen,
@@ -25,7 +26,7 @@ use crate::mir::place::PlaceRef;
 use crate::traits::*;
 use crate::{CachedModuleCodegen, CrateInfo, MemFlags, ModuleCodegen, ModuleKind};
 
-use rustc::middle::codegen_fn_attrs::CodegenFnAttrs;
+use rustc::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc::middle::cstore::EncodedMetadata;
 use rustc::middle::cstore::{self, LinkagePreference};
 use rustc::middle::lang_items::StartFnLangItem;
@@ -578,6 +579,19 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
         ongoing_codegen.submit_pre_codegened_module_to_llvm(tcx, allocator_module);
     }
 
+    if unload_info::needs_unload_info(tcx) {
+        let llmod_id = cgu_name_builder
+            .build_cgu_name(LOCAL_CRATE, &["crate"], Some("unload_info"))
+            .to_string();
+        let mut modules = backend.new_metadata(tcx, &llmod_id);
+        tcx.sess
+            .time("write_unload_info_module", || backend.codegen_unload_info(tcx, &mut modules));
+
+        let unload_info_module =
+            ModuleCodegen { name: llmod_id, module_llvm: modules, kind: ModuleKind::Regular };
+        ongoing_codegen.submit_pre_codegened_module_to_llvm(tcx, unload_info_module);
+    }
+
     if need_metadata_module {
         // Codegen the encoded metadata.
         let metadata_cgu_name =
@@ -803,12 +817,14 @@ impl CrateInfo {
             used_libraries: tcx.native_libraries(LOCAL_CRATE),
             link_args: tcx.link_args(LOCAL_CRATE),
             crate_name: Default::default(),
+            crate_hash: Default::default(),
             used_crates_dynamic: cstore::used_crates(tcx, LinkagePreference::RequireDynamic),
             used_crates_static: cstore::used_crates(tcx, LinkagePreference::RequireStatic),
             used_crate_source: Default::default(),
             lang_item_to_crate: Default::default(),
             missing_lang_items: Default::default(),
             dependency_formats: tcx.dependency_formats(LOCAL_CRATE),
+            kept_symbols: Vec::new(),
         };
         let lang_items = tcx.lang_items();
 
@@ -817,12 +833,14 @@ impl CrateInfo {
         let n_crates = crates.len();
         info.native_libraries.reserve(n_crates);
         info.crate_name.reserve(n_crates);
+        info.crate_hash.reserve(n_crates);
         info.used_crate_source.reserve(n_crates);
         info.missing_lang_items.reserve(n_crates);
 
         for &cnum in crates.iter() {
             info.native_libraries.insert(cnum, tcx.native_libraries(cnum));
             info.crate_name.insert(cnum, tcx.crate_name(cnum).to_string());
+            info.crate_hash.insert(cnum, tcx.crate_hash(cnum));
             info.used_crate_source.insert(cnum, tcx.used_crate_source(cnum));
             if tcx.is_panic_runtime(cnum) {
                 info.panic_runtime = Some(cnum);
@@ -853,10 +871,37 @@ impl CrateInfo {
             info.missing_lang_items.insert(cnum, missing);
         }
 
+        if tcx.sess.opts.debugging_opts.verify_linked_symbols {
+            info.kept_symbols = kept_symbols(tcx);
+        }
+
         return info;
     }
 }
 
+/// Returns the symbols of the local `#[used]` and `#[no_mangle]` functions and statics, which
+/// the user expects to find in the linked artifact.
+fn kept_symbols(tcx: TyCtxt<'_>) -> Vec<(String, Span, bool)> {
+    let mut symbols = Vec::new();
+    for item in tcx.hir().krate().items.values() {
+        let is_static = match item.kind {
+            hir::ItemKind::Static(..) => true,
+            hir::ItemKind::Fn(..) => false,
+            _ => continue,
+        };
+        let def_id = tcx.hir().local_def_id(item.hir_id);
+        if tcx.generics_of(def_id).requires_monomorphization(tcx) {
+            continue;
+        }
+        let flags = tcx.codegen_fn_attrs(def_id).flags;
+        if flags.intersects(CodegenFnAttrFlags::USED | CodegenFnAttrFlags::NO_MANGLE) {
+            let name = tcx.symbol_name(Instance::mono(tcx, def_id)).name;
+            symbols.push((name.to_string(), tcx.def_span(def_id), is_static));
+        }
+    }
+    symbols
+}
+
 pub fn provide_both(providers: &mut Providers<'_>) {
     providers.backend_optimization_level = |tcx, cratenum| {
         let for_speed = match tcx.sess.opts.optimize {
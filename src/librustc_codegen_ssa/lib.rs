@@ -33,6 +33,7 @@ use rustc_data_structures::svh::Svh;
 use rustc_data_structures::sync::Lrc;
 use rustc_hir::def_id::CrateNum;
 use rustc_span::symbol::Symbol;
+use rustc_span::Span;
 use std::path::{Path, PathBuf};
 
 pub mod back;
@@ -125,6 +126,7 @@ pub struct CrateInfo {
     pub is_no_builtins: FxHashSet<CrateNum>,
     pub native_libraries: FxHashMap<CrateNum, Lrc<Vec<NativeLibrary>>>,
     pub crate_name: FxHashMap<CrateNum, String>,
+    pub crate_hash: FxHashMap<CrateNum, Svh>,
     pub used_libraries: Lrc<Vec<NativeLibrary>>,
     pub link_args: Lrc<Vec<String>>,
     pub used_crate_source: FxHashMap<CrateNum, Lrc<CrateSource>>,
@@ -133,6 +135,9 @@ pub struct CrateInfo {
     pub lang_item_to_crate: FxHashMap<LangItem, CrateNum>,
     pub missing_lang_items: FxHashMap<CrateNum, Vec<LangItem>>,
     pub dependency_formats: Lrc<Dependencies>,
+    /// The symbol name and span of the `#[used]` and `#[no_mangle]` items of the local crate,
+    /// and whether they are statics, collected for `-Z verify-linked-symbols`.
+    pub kept_symbols: Vec<(String, Span, bool)>,
 }
 
 pub struct CodegenResults {
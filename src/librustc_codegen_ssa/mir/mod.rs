@@ -445,7 +445,7 @@ fn arg_local_refs<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     if fx.instance.def.requires_caller_location(bx.tcx()) {
         assert_eq!(
             fx.fn_abi.args.len(),
-            args.len() + 1,
+            idx + 1,
             "#[track_caller] fn's must have 1 more argument in their ABI than in their MIR",
         );
 
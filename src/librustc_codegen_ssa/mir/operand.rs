@@ -215,8 +215,10 @@ impl<'a, 'tcx, V: CodegenObject> OperandRef<'tcx, V> {
                 }
             }
 
-            // `#[repr(simd)]` types are also immediate.
-            (OperandValue::Immediate(llval), &layout::Abi::Vector { .. }) => {
+            // `#[repr(simd)]` types are also immediate, and the only field of a scalable vector
+            // is its first element.
+            (OperandValue::Immediate(llval), &layout::Abi::Vector { .. })
+            | (OperandValue::Immediate(llval), &layout::Abi::ScalableVector { .. }) => {
                 OperandValue::Immediate(bx.extract_element(llval, bx.cx().const_usize(i as u64)))
             }
 
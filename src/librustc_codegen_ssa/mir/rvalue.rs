@@ -206,11 +206,10 @@ impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
                     mir::CastKind::Pointer(PointerCast::ClosureFnPointer(_)) => {
                         match operand.layout.ty.kind {
                             ty::Closure(def_id, substs) => {
-                                let instance = Instance::resolve_closure(
+                                let instance = Instance::resolve_closure_for_fn_ptr(
                                     bx.cx().tcx(),
                                     def_id,
                                     substs,
-                                    ty::ClosureKind::FnOnce,
                                 );
                                 OperandValue::Immediate(bx.cx().get_fn_addr(instance))
                             }
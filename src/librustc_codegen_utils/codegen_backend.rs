@@ -10,7 +10,7 @@ use std::any::Any;
 
 use rustc::dep_graph::DepGraph;
 use rustc::middle::cstore::{EncodedMetadata, MetadataLoaderDyn};
-use rustc::session::config::{OutputFilenames, PrintRequest};
+use rustc::session::config::{CrateType, OutputFilenames, PrintRequest};
 use rustc::session::Session;
 use rustc::ty::query::Providers;
 use rustc::ty::TyCtxt;
@@ -19,18 +19,89 @@ use rustc_span::symbol::Symbol;
 
 pub use rustc_data_structures::sync::MetadataRef;
 
+/// The version of the interface between rustc and the codegen backends loaded with
+/// `-Z codegen-backend=path/to/backend.so`. It is bumped whenever the `CodegenBackend` trait, or
+/// the way rustc drives it, changes in a way that breaks the existing backends.
+///
+/// A backend dylib tells rustc which version it implements by exporting
+///
+/// ```ignore (illustrative)
+/// #[no_mangle]
+/// pub fn __rustc_codegen_backend_version() -> u32 {
+///     rustc_codegen_utils::codegen_backend::CODEGEN_BACKEND_INTERFACE_VERSION
+/// }
+/// ```
+///
+/// next to its `__rustc_codegen_backend` entry point, and rustc refuses to load the backends that
+/// were built for another version. The backends that don't export it are loaded unchecked.
+pub const CODEGEN_BACKEND_INTERFACE_VERSION: u32 = 1;
+
+/// What a codegen backend supports, checked against the options of the session before the crate
+/// is analyzed, so that the unsupported options are reported as errors instead of failing in the
+/// middle of code generation.
+#[derive(Clone, Debug)]
+pub struct BackendCapabilities {
+    /// The crate types that the backend can produce.
+    pub crate_types: Vec<CrateType>,
+    /// Whether the backend generates the landing pads needed by `-C panic=unwind`.
+    pub unwinding: bool,
+    /// Whether the backend supports the crate graph LTO of `-C lto` and `-C linker-plugin-lto`.
+    pub lto: bool,
+}
+
+impl Default for BackendCapabilities {
+    /// Everything is supported, which is what rustc assumed of the backends before they could
+    /// describe their capabilities.
+    fn default() -> BackendCapabilities {
+        BackendCapabilities {
+            crate_types: vec![
+                CrateType::Executable,
+                CrateType::Dylib,
+                CrateType::Rlib,
+                CrateType::Staticlib,
+                CrateType::Cdylib,
+                CrateType::ProcMacro,
+            ],
+            unwinding: true,
+            lto: true,
+        }
+    }
+}
+
+/// The interface between rustc and the code generators, implemented by `rustc_codegen_llvm` and
+/// by the backends loaded with `-Z codegen-backend`.
+///
+/// rustc calls `init` once the session is created, `provide` and `provide_extern` to set up the
+/// queries, `codegen_crate` once the crate has been analyzed, and `join_codegen_and_link` to wait
+/// for the code generation and link the outputs.
 pub trait CodegenBackend {
+    /// Initializes the backend for the session `_sess`.
     fn init(&self, _sess: &Session) {}
+    /// Prints the information requested with `--print`, for the requests that depend on the
+    /// backend.
     fn print(&self, _req: PrintRequest, _sess: &Session) {}
+    /// The target features enabled for the session, which are available to `#[cfg]`.
     fn target_features(&self, _sess: &Session) -> Vec<Symbol> {
         vec![]
     }
+    /// Prints the passes of the backend, for `-C passes=list`.
     fn print_passes(&self) {}
+    /// Prints the version of the backend, for `rustc -vV`.
     fn print_version(&self) {}
+    /// What the backend supports. Every option is supported by default.
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
 
+    /// The loader of the metadata of the upstream crates, which the backend stores in its
+    /// outputs.
     fn metadata_loader(&self) -> Box<MetadataLoaderDyn>;
+    /// Overrides the local queries that the backend computes, like `exported_symbols`.
     fn provide(&self, _providers: &mut Providers<'_>);
+    /// Overrides the queries on the upstream crates that the backend computes.
     fn provide_extern(&self, _providers: &mut Providers<'_>);
+    /// Starts the code generation of the crate, and returns its state, which is passed to
+    /// `join_codegen_and_link`.
     fn codegen_crate<'tcx>(
         &self,
         tcx: TyCtxt<'tcx>,
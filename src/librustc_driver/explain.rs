@@ -0,0 +1,224 @@
+//! Support for `rustc --explain`: rendering the extended description of an error code for
+//! a terminal, and extracting its code examples.
+
+use rustc_lexer::TokenKind;
+use termcolor::{Buffer, Color, ColorSpec, WriteColor};
+
+use std::io::{self, Write};
+
+/// Indentation used for code examples in the rendered output.
+const CODE_INDENT: &str = "    ";
+
+/// A fenced code block of an extended error description.
+struct CodeBlock<'a> {
+    /// The info string following the opening fence, e.g. `compile_fail,E0382`.
+    info: &'a str,
+    /// The lines of the block, with their indentation and hidden-line markers still present.
+    lines: Vec<&'a str>,
+}
+
+impl CodeBlock<'_> {
+    /// Whether rustdoc would treat this block as Rust code.
+    fn is_rust(&self) -> bool {
+        self.info.split(',').map(str::trim).all(|attr| match attr {
+            "" | "rust" | "compile_fail" | "ignore" | "no_run" | "should_panic" => true,
+            _ if attr.starts_with("edition") => true,
+            _ => attr.starts_with('E') && attr[1..].chars().all(|c| c.is_ascii_digit()),
+        })
+    }
+
+    fn is_compile_fail(&self) -> bool {
+        self.info.split(',').any(|attr| attr.trim() == "compile_fail")
+    }
+
+    /// The source of this block as it is shown in the documentation.
+    fn visible_source(&self) -> String {
+        self.source(false)
+    }
+
+    /// The source of this block including lines that are hidden in the documentation, so it
+    /// can be compiled as is.
+    fn full_source(&self) -> String {
+        self.source(true)
+    }
+
+    fn source(&self, include_hidden: bool) -> String {
+        let mut source = String::new();
+        for line in &self.lines {
+            let dedented_line = line.trim_start();
+            match hidden_line(dedented_line) {
+                Some(hidden) if include_hidden => {
+                    source.push_str(&line[..line.len() - dedented_line.len()]);
+                    source.push_str(hidden);
+                }
+                Some(_) => continue,
+                None => source.push_str(line),
+            }
+            source.push('\n');
+        }
+        source
+    }
+}
+
+/// Returns the contents of `line` without the marker if it is hidden in the documentation.
+fn hidden_line(line: &str) -> Option<&str> {
+    if line == "#" {
+        Some("")
+    } else if line.starts_with("# ") {
+        Some(&line[2..])
+    } else {
+        None
+    }
+}
+
+/// A piece of an extended error description.
+enum Chunk<'a> {
+    Text(&'a str),
+    Code(CodeBlock<'a>),
+}
+
+fn chunks(description: &str) -> Vec<Chunk<'_>> {
+    let mut chunks = vec![];
+    let mut code_block: Option<CodeBlock<'_>> = None;
+    for line in description.lines() {
+        let dedented_line = line.trim_start();
+        match code_block {
+            Some(block) if dedented_line.starts_with("```") => {
+                chunks.push(Chunk::Code(block));
+                code_block = None;
+            }
+            Some(ref mut block) => block.lines.push(line),
+            None if dedented_line.starts_with("```") => {
+                code_block = Some(CodeBlock { info: dedented_line[3..].trim(), lines: vec![] });
+            }
+            None => chunks.push(Chunk::Text(line)),
+        }
+    }
+    // An unterminated code block extends to the end of the description.
+    if let Some(block) = code_block {
+        chunks.push(Chunk::Code(block));
+    }
+    chunks
+}
+
+/// Returns the code example of an extended error description that demonstrates the error,
+/// i.e. the first Rust code block marked `compile_fail`, or the first Rust code block if there
+/// is no such block. Hidden lines are included, so the example is ready to be compiled.
+pub fn extract_example(description: &str) -> Option<String> {
+    let blocks: Vec<_> = chunks(description)
+        .into_iter()
+        .filter_map(|chunk| match chunk {
+            Chunk::Code(block) if block.is_rust() => Some(block),
+            _ => None,
+        })
+        .collect();
+    blocks
+        .iter()
+        .find(|block| block.is_compile_fail())
+        .or_else(|| blocks.first())
+        .map(|block| block.full_source())
+}
+
+/// Renders an extended error description with ANSI colors: headings are emphasized, inline
+/// code is colored, and Rust code examples are syntax highlighted.
+pub fn render_colored(description: &str) -> String {
+    let mut buf = Buffer::ansi();
+    // Writing to a `Buffer` cannot fail.
+    render_chunks(&mut buf, &chunks(description)).unwrap();
+    String::from_utf8(buf.into_inner()).unwrap()
+}
+
+fn render_chunks(buf: &mut Buffer, chunks: &[Chunk<'_>]) -> io::Result<()> {
+    for chunk in chunks {
+        match chunk {
+            Chunk::Text(line) if line.starts_with('#') => {
+                buf.set_color(ColorSpec::new().set_bold(true).set_intense(true))?;
+                write!(buf, "{}", line.trim_start_matches('#').trim_start())?;
+                buf.reset()?;
+                writeln!(buf)?;
+            }
+            Chunk::Text(line) => {
+                render_text_line(buf, line)?;
+                writeln!(buf)?;
+            }
+            Chunk::Code(block) => {
+                let source = block.visible_source();
+                write!(buf, "{}", CODE_INDENT)?;
+                if block.is_rust() {
+                    render_rust(buf, source.trim_end())?;
+                } else {
+                    write_indented(buf, source.trim_end())?;
+                }
+                writeln!(buf)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Renders a line of prose, coloring `inline code`.
+fn render_text_line(buf: &mut Buffer, line: &str) -> io::Result<()> {
+    let mut in_code = false;
+    for (i, part) in line.split('`').enumerate() {
+        if i > 0 {
+            in_code = !in_code;
+            if in_code {
+                buf.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            } else {
+                buf.reset()?;
+            }
+        }
+        write!(buf, "{}", part)?;
+    }
+    if in_code { buf.reset() } else { Ok(()) }
+}
+
+/// Writes `text`, indenting every line after the first one like a code block.
+fn write_indented(buf: &mut Buffer, text: &str) -> io::Result<()> {
+    let mut lines = text.split('\n');
+    if let Some(first) = lines.next() {
+        write!(buf, "{}", first)?;
+    }
+    for line in lines {
+        write!(buf, "\n{}{}", CODE_INDENT, line)?;
+    }
+    Ok(())
+}
+
+fn render_rust(buf: &mut Buffer, source: &str) -> io::Result<()> {
+    let mut pos = 0;
+    for token in rustc_lexer::tokenize(source) {
+        let text = &source[pos..pos + token.len];
+        pos += token.len;
+        let color = match token.kind {
+            TokenKind::LineComment | TokenKind::BlockComment { .. } => Some(Color::Blue),
+            TokenKind::Ident if is_keyword(text) => Some(Color::Magenta),
+            TokenKind::Literal { .. } => Some(Color::Green),
+            TokenKind::Lifetime { .. } => Some(Color::Cyan),
+            _ => None,
+        };
+        match color {
+            Some(color) => {
+                buf.set_color(ColorSpec::new().set_fg(Some(color)))?;
+                write_indented(buf, text)?;
+                buf.reset()?;
+            }
+            None => write_indented(buf, text)?,
+        }
+    }
+    Ok(())
+}
+
+fn is_keyword(ident: &str) -> bool {
+    match ident {
+        "as" | "async" | "await" | "break" | "const" | "continue" | "crate" | "dyn" | "else"
+        | "enum" | "extern" | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop"
+        | "match" | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "self" | "Self"
+        | "static" | "struct" | "super" | "trait" | "true" | "type" | "union" | "unsafe"
+        | "use" | "where" | "while" => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests;
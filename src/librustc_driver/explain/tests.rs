@@ -0,0 +1,98 @@
+use super::{extract_example, render_colored};
+
+/// Removes the ANSI escape sequences from `s`.
+fn strip_ansi(s: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the sequence up to and including its final letter.
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+#[test]
+fn test_extract_compile_fail_example() {
+    let description = "\
+Some text.
+
+```
+fn ok() {}
+```
+
+```compile_fail,E0000
+fn fail() {}
+```
+";
+    assert_eq!(extract_example(description).unwrap(), "fn fail() {}\n");
+}
+
+#[test]
+fn test_extract_first_rust_example() {
+    let description = "\
+```text
+not rust
+```
+
+```
+fn first() {}
+```
+
+```no_run
+fn second() {}
+```
+";
+    assert_eq!(extract_example(description).unwrap(), "fn first() {}\n");
+}
+
+#[test]
+fn test_extract_includes_hidden_lines() {
+    let description = "\
+```compile_fail,E0000,edition2018
+# fn main() {
+    let x = 0;
+#
+# }
+```
+";
+    assert_eq!(extract_example(description).unwrap(), "fn main() {\n    let x = 0;\n\n}\n");
+}
+
+#[test]
+fn test_extract_unterminated_block() {
+    assert_eq!(extract_example("```\nfn f() {}\n").unwrap(), "fn f() {}\n");
+}
+
+#[test]
+fn test_extract_no_example() {
+    assert_eq!(extract_example("No code.\n\n```text\nnot rust\n```\n"), None);
+}
+
+#[test]
+fn test_render_colored_text() {
+    let rendered = render_colored("## Heading\n\nUse `foo` here.\n");
+    assert!(rendered.contains("\x1b[33m"));
+    assert_eq!(strip_ansi(&rendered), "Heading\n\nUse foo here.\n");
+}
+
+#[test]
+fn test_render_colored_code() {
+    let description = "\
+```compile_fail
+# fn main() {
+let x = 1; // one
+# }
+```
+";
+    let rendered = render_colored(description);
+    // Keywords, literals and comments are colored.
+    assert!(rendered.contains("\x1b[35m"));
+    assert!(rendered.contains("\x1b[32m"));
+    assert!(rendered.contains("\x1b[34m"));
+    // Hidden lines are not shown, and the example is indented.
+    assert_eq!(strip_ansi(&rendered), "    let x = 1; // one\n");
+}
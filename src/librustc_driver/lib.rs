@@ -50,6 +50,7 @@ use std::cmp::max;
 use std::default::Default;
 use std::env;
 use std::ffi::OsString;
+use std::fs;
 use std::io::{self, Read, Write};
 use std::mem;
 use std::panic::{self, catch_unwind};
@@ -64,6 +65,7 @@ use rustc_span::FileName;
 use syntax::ast;
 
 mod args;
+mod explain;
 pub mod pretty;
 
 /// Exit status code used for successful compilation and help output.
@@ -196,7 +198,12 @@ pub fn run_compiler(
     };
 
     if let Some(ref code) = matches.opt_str("explain") {
-        handle_explain(diagnostics_registry(), code, sopts.error_format);
+        handle_explain(
+            diagnostics_registry(),
+            code,
+            sopts.error_format,
+            matches.opt_present("example"),
+        );
         return Ok(());
     }
 
@@ -394,6 +401,13 @@ pub fn run_compiler(
 
             queries.global_ctxt()?.peek_mut().enter(|tcx| tcx.analysis(LOCAL_CRATE))?;
 
+            if sess.opts.prints.contains(&PrintRequest::CrateFeatures) {
+                queries
+                    .global_ctxt()?
+                    .peek_mut()
+                    .enter(|tcx| RustcDefaultCalls::print_crate_features(tcx));
+            }
+
             if callbacks.after_analysis(compiler, queries) == Compilation::Stop {
                 return early_exit();
             }
@@ -408,6 +422,10 @@ pub fn run_compiler(
                 sess.code_stats.print_type_sizes();
             }
 
+            if sess.opts.debugging_opts.print_ctfe_stats {
+                sess.code_stats.print_ctfe_stats(&queries.crate_name()?.peek());
+            }
+
             let linker = queries.linker()?;
             Ok(Some(linker))
         })?;
@@ -421,6 +439,10 @@ pub fn run_compiler(
             sess.print_perf_stats();
         }
 
+        if sess.opts.debugging_opts.ctfe_profile {
+            sess.code_stats.print_ctfe_profile();
+        }
+
         if sess.print_fuel_crate.is_some() {
             eprintln!(
                 "Fuel used by {}: {}",
@@ -525,32 +547,57 @@ fn stdout_isatty() -> bool {
     }
 }
 
-fn handle_explain(registry: Registry, code: &str, output: ErrorOutputType) {
+fn handle_explain(
+    registry: Registry,
+    code: &str,
+    output: ErrorOutputType,
+    write_example: bool,
+) {
     let normalised =
         if code.starts_with("E") { code.to_string() } else { format!("E{0:0>4}", code) };
     match registry.find_description(&normalised) {
+        Some(ref description) if write_example => {
+            let example = explain::extract_example(description).unwrap_or_else(|| {
+                early_error(output, &format!("no code example for {}", normalised))
+            });
+            let path = PathBuf::from(format!("{}.rs", normalised));
+            if let Err(err) = fs::write(&path, example) {
+                early_error(output, &format!("failed to write `{}`: {}", path.display(), err));
+            }
+            println!("wrote the example for {} to `{}`", normalised, path.display());
+        }
         Some(ref description) => {
-            let mut is_in_code_block = false;
-            let mut text = String::new();
-
-            // Slice off the leading newline and print.
-            for line in description.lines() {
-                let indent_level =
-                    line.find(|c: char| !c.is_whitespace()).unwrap_or_else(|| line.len());
-                let dedented_line = &line[indent_level..];
-                if dedented_line.starts_with("```") {
-                    is_in_code_block = !is_in_code_block;
-                    text.push_str(&line[..(indent_level + 3)]);
-                } else if is_in_code_block && dedented_line.starts_with("# ") {
-                    continue;
-                } else {
-                    text.push_str(line);
+            // The JSON output types never color their output.
+            let colored = match output {
+                ErrorOutputType::HumanReadable(kind) => kind.unzip().1.colors_stdout(),
+                ErrorOutputType::Json { .. } => false,
+            };
+            let text = if colored {
+                explain::render_colored(description)
+            } else {
+                let mut is_in_code_block = false;
+                let mut text = String::new();
+
+                // Slice off the leading newline and print.
+                for line in description.lines() {
+                    let indent_level =
+                        line.find(|c: char| !c.is_whitespace()).unwrap_or_else(|| line.len());
+                    let dedented_line = &line[indent_level..];
+                    if dedented_line.starts_with("```") {
+                        is_in_code_block = !is_in_code_block;
+                        text.push_str(&line[..(indent_level + 3)]);
+                    } else if is_in_code_block && dedented_line.starts_with("# ") {
+                        continue;
+                    } else {
+                        text.push_str(line);
+                    }
+                    text.push('\n');
                 }
-                text.push('\n');
-            }
+                text
+            };
 
             if stdout_isatty() {
-                show_content_with_pager(&text);
+                show_content_with_pager(&text, colored);
             } else {
                 print!("{}", text);
             }
@@ -561,14 +608,20 @@ fn handle_explain(registry: Registry, code: &str, output: ErrorOutputType) {
     }
 }
 
-fn show_content_with_pager(content: &String) {
+fn show_content_with_pager(content: &String, colored: bool) {
     let pager_name = env::var_os("PAGER").unwrap_or_else(|| {
         if cfg!(windows) { OsString::from("more.com") } else { OsString::from("less") }
     });
 
     let mut fallback_to_println = false;
 
-    match Command::new(pager_name).stdin(Stdio::piped()).spawn() {
+    let mut pager = Command::new(&pager_name);
+    // `less` only passes the color escape sequences through when asked to.
+    if colored && pager_name == "less" {
+        pager.arg("-R");
+    }
+
+    match pager.stdin(Stdio::piped()).spawn() {
         Ok(mut pager) => {
             if let Some(pipe) = pager.stdin.as_mut() {
                 if pipe.write_all(content.as_bytes()).is_err() {
@@ -627,9 +680,9 @@ impl RustcDefaultCalls {
         ofile: &Option<PathBuf>,
     ) -> Compilation {
         use rustc::session::config::PrintRequest::*;
-        // PrintRequest::NativeStaticLibs is special - printed during linking
-        // (empty iterator returns true)
-        if sess.opts.prints.iter().all(|&p| p == PrintRequest::NativeStaticLibs) {
+        // PrintRequest::NativeStaticLibs, DeploymentArtifacts, CrateResolution and
+        // CrateFeatures are special - printed during compilation (empty iterator returns true)
+        if sess.opts.prints.iter().all(|&p| p.is_printed_during_compilation()) {
             return Compilation::Continue;
         }
 
@@ -719,11 +772,39 @@ impl RustcDefaultCalls {
                     codegen_backend.print(*req, sess);
                 }
                 // Any output here interferes with Cargo's parsing of other printed output
-                PrintRequest::NativeStaticLibs => {}
+                PrintRequest::NativeStaticLibs
+                | PrintRequest::DeploymentArtifacts
+                | PrintRequest::CrateResolution
+                | PrintRequest::CrateFeatures => {}
             }
         }
         return Compilation::Stop;
     }
+
+    /// Prints the unstable features the crate enables, each with the place where the crate
+    /// first uses it, or `unused`. The uses of library features are found by the stability
+    /// checks, and those of language features by the feature-gate checks of the AST.
+    fn print_crate_features(tcx: TyCtxt<'_>) {
+        let features = tcx.features();
+        let feature_uses = tcx.sess.parse_sess.feature_uses.borrow();
+        // Language features that are already stable are not listed, the
+        // `stable_features` lint reports them.
+        let lang_features = features
+            .declared_lang_features
+            .iter()
+            .filter(|&&(_, _, since)| since.is_none())
+            .map(|&(name, _, _)| (name, "lang"));
+        let lib_features = features.declared_lib_features.iter().map(|&(name, _)| (name, "lib"));
+        for (name, kind) in lang_features.chain(lib_features) {
+            match feature_uses.get(&name) {
+                Some(span) => {
+                    let loc = tcx.sess.source_map().lookup_char_pos(span.lo());
+                    println!("{} {} {}:{}:{}", name, kind, loc.file.name, loc.line, loc.col.0 + 1);
+                }
+                None => println!("{} {} unused", name, kind),
+            }
+        }
+    }
 }
 
 /// Returns a version string such as "0.12.0-dev".
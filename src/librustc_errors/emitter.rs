@@ -485,6 +485,14 @@ impl ColorConfig {
             ColorConfig::Auto => ColorChoice::Never,
         }
     }
+    /// Whether output written to stdout should be colored, e.g. the output of `--explain`.
+    pub fn colors_stdout(self) -> bool {
+        match self {
+            ColorConfig::Always => true,
+            ColorConfig::Auto => atty::is(atty::Stream::Stdout),
+            ColorConfig::Never => false,
+        }
+    }
     fn suggests_using_colors(self) -> bool {
         match self {
             ColorConfig::Always | ColorConfig::Auto => true,
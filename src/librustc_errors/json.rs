@@ -160,6 +160,12 @@ struct DiagnosticSpan {
     /// 1-based, character offset.
     column_start: usize,
     column_end: usize,
+    /// 1-based, offset in bytes of UTF-8.
+    column_start_utf8: usize,
+    column_end_utf8: usize,
+    /// 1-based, offset in UTF-16 code units.
+    column_start_utf16: usize,
+    column_end_utf16: usize,
     /// Is this a "primary" span -- meaning the point, or one of the points,
     /// where the error occurred?
     is_primary: bool,
@@ -341,6 +347,10 @@ impl DiagnosticSpan {
             line_end: end.line,
             column_start: start.col.0 + 1,
             column_end: end.col.0 + 1,
+            column_start_utf8: start.col_byte + 1,
+            column_end_utf8: end.col_byte + 1,
+            column_start_utf16: start.col_utf16 + 1,
+            column_end_utf16: end.col_utf16 + 1,
             is_primary,
             text: DiagnosticSpanLine::from_span(span, je),
             suggested_replacement: suggestion.map(|x| x.0.clone()),
@@ -23,6 +23,10 @@ struct SpanTestData {
     pub column_start: u32,
     pub line_end: u32,
     pub column_end: u32,
+    pub column_start_utf8: u32,
+    pub column_end_utf8: u32,
+    pub column_start_utf16: u32,
+    pub column_end_utf16: u32,
 }
 
 struct Shared<T> {
@@ -86,6 +90,10 @@ fn empty() {
             column_start: 1,
             line_end: 1,
             column_end: 2,
+            column_start_utf8: 1,
+            column_end_utf8: 2,
+            column_start_utf16: 1,
+            column_end_utf16: 2,
         },
     )
 }
@@ -102,6 +110,10 @@ fn bom() {
             column_start: 1,
             line_end: 1,
             column_end: 2,
+            column_start_utf8: 1,
+            column_end_utf8: 2,
+            column_start_utf16: 1,
+            column_end_utf16: 2,
         },
     )
 }
@@ -118,6 +130,10 @@ fn lf_newlines() {
             column_start: 5,
             line_end: 3,
             column_end: 3,
+            column_start_utf8: 5,
+            column_end_utf8: 3,
+            column_start_utf16: 5,
+            column_end_utf16: 3,
         },
     )
 }
@@ -134,6 +150,10 @@ fn crlf_newlines() {
             column_start: 5,
             line_end: 3,
             column_end: 3,
+            column_start_utf8: 5,
+            column_end_utf8: 3,
+            column_start_utf16: 5,
+            column_end_utf16: 3,
         },
     )
 }
@@ -150,6 +170,10 @@ fn crlf_newlines_with_bom() {
             column_start: 5,
             line_end: 3,
             column_end: 3,
+            column_start_utf8: 5,
+            column_end_utf8: 3,
+            column_start_utf16: 5,
+            column_end_utf16: 3,
         },
     )
 }
@@ -166,6 +190,10 @@ fn span_before_crlf() {
             column_start: 3,
             line_end: 1,
             column_end: 4,
+            column_start_utf8: 3,
+            column_end_utf8: 4,
+            column_start_utf16: 3,
+            column_end_utf16: 4,
         },
     )
 }
@@ -182,6 +210,10 @@ fn span_on_crlf() {
             column_start: 4,
             line_end: 2,
             column_end: 1,
+            column_start_utf8: 4,
+            column_end_utf8: 1,
+            column_start_utf16: 4,
+            column_end_utf16: 1,
         },
     )
 }
@@ -198,6 +230,30 @@ fn span_after_crlf() {
             column_start: 1,
             line_end: 2,
             column_end: 2,
+            column_start_utf8: 1,
+            column_end_utf8: 2,
+            column_start_utf16: 1,
+            column_end_utf16: 2,
+        },
+    )
+}
+
+#[test]
+fn multibyte_chars() {
+    test_positions(
+        "\u{e9}\u{1f600} x",
+        (7, 8),
+        SpanTestData {
+            byte_start: 7,
+            byte_end: 8,
+            line_start: 1,
+            column_start: 4,
+            line_end: 1,
+            column_end: 5,
+            column_start_utf8: 8,
+            column_end_utf8: 9,
+            column_start_utf16: 5,
+            column_end_utf16: 6,
         },
     )
 }
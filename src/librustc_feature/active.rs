@@ -232,6 +232,7 @@ declare_features! (
     (active, movbe_target_feature, "1.34.0", Some(44839), None),
     (active, rtm_target_feature, "1.35.0", Some(44839), None),
     (active, f16c_target_feature, "1.36.0", Some(44839), None),
+    (active, riscv_target_feature, "1.42.0", Some(44839), None),
 
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates (target features)
@@ -547,6 +548,36 @@ declare_features! (
     /// Allows `T: ?const Trait` syntax in bounds.
     (active, const_trait_bound_opt_out, "1.42.0", Some(67794), None),
 
+    /// Allows the use of `#[const_eval_limit]` and `#[const_eval_stack_limit]` to configure the
+    /// number of steps and stack frames the evaluation of a constant may take.
+    (active, const_eval_limit, "1.42.0", Some(67217), None),
+
+    /// Allows the use of `unsafe` blocks inside `unsafe fn`s, so that the bodies of `unsafe fn`s
+    /// can be checked with the `unsafe_op_in_unsafe_fn` lint.
+    (active, unsafe_block_in_unsafe_fn, "1.42.0", Some(71668), None),
+
+    /// A minimal, sound subset of specialization intended to be used by the
+    /// standard library until the soundness issues with specialization
+    /// are fixed.
+    (active, min_specialization, "1.42.0", Some(31844), None),
+
+    /// Allows empty `match`es on types that are known to be uninhabited
+    /// where the match occurs, without the rest of `exhaustive_patterns`.
+    (active, min_exhaustive_patterns, "1.42.0", Some(51085), None),
+
+    /// Allows constructor and literal patterns to match through smart pointers such as
+    /// `Box<T>`, `Rc<T>` and `String`.
+    (active, deref_patterns, "1.42.0", None, None),
+
+    /// Allows `#[repr(scalable(N))]` on SIMD types, for the vectors of AArch64 SVE and RISC-V V.
+    (active, repr_scalable, "1.42.0", None, None),
+
+    /// Allows `#[repr(align(cache_line))]`, which aligns to the cache line size of the target.
+    (active, repr_align_cache_line, "1.42.0", None, None),
+
+    /// Allows `#[derive(SmartPointer)]` and its `#[pointee]` attribute.
+    (active, derive_smart_pointer, "1.42.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -564,4 +595,5 @@ pub const INCOMPLETE_FEATURES: &[Symbol] = &[
     sym::raw_dylib,
     sym::const_trait_impl,
     sym::const_trait_bound_opt_out,
+    sym::repr_scalable,
 ];
@@ -336,6 +336,14 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         register_tool, CrateLevel, template!(List: "tool1, tool2, ..."),
         experimental!(register_tool),
     ),
+    gated!(
+        const_eval_limit, Whitelisted, template!(NameValueStr: "N"), const_eval_limit,
+        experimental!(const_eval_limit),
+    ),
+    gated!(
+        const_eval_stack_limit, Whitelisted, template!(NameValueStr: "N"), const_eval_limit,
+        experimental!(const_eval_stack_limit),
+    ),
 
     // ==========================================================================
     // Internal attributes: Stability, deprecation, and unsafe:
@@ -348,6 +356,9 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         rustc_deprecated, Whitelisted,
         template!(List: r#"since = "version", reason = "...""#)
     ),
+    rustc_attr!(
+        rustc_deprecated_safe, Whitelisted, template!(NameValueStr: "edition"), INTERNAL_UNSTABLE
+    ),
     // FIXME(#14407)
     ungated!(stable, Whitelisted, template!(List: r#"feature = "name", since = "version""#)),
     // FIXME(#14407)
@@ -382,6 +393,7 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         may_dangle, Normal, template!(Word), dropck_eyepatch,
         "`may_dangle` has unstable semantics and may be removed in the future",
     ),
+    gated!(pointee, Whitelisted, template!(Word), derive_smart_pointer, experimental!(pointee)),
 
     // ==========================================================================
     // Internal attributes: Runtime related:
@@ -449,6 +461,11 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         ),
         INTERNAL_UNSTABLE
     ),
+    rustc_attr!(
+        rustc_on_misuse, Whitelisted,
+        template!(List: r#"/*opt*/ note = "...", /*opt*/ on(...)"#),
+        INTERNAL_UNSTABLE
+    ),
     // Whitelists "identity-like" conversion methods to suggest on type mismatch.
     rustc_attr!(rustc_conversion_suggestion, Whitelisted, template!(Word), INTERNAL_UNSTABLE),
 
@@ -527,10 +544,22 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
                 "the `#[rustc_reservation_impl]` attribute is internally used \
                  for reserving for `for<T> From<!> for T` impl"
     ),
+    rustc_attr!(rustc_strict_coherence, Normal, template!(Word),
+                "the `#[rustc_strict_coherence]` attribute is internally used \
+                 for letting coherence rely on the negative impls of `Error`"
+    ),
     rustc_attr!(
         rustc_test_marker, Normal, template!(Word),
         "the `#[rustc_test_marker]` attribute is used internally to track tests",
     ),
+    rustc_attr!(
+        rustc_unsafe_specialization_marker, Normal, template!(Word),
+        "the `#[rustc_unsafe_specialization_marker]` attribute is used to check specializations"
+    ),
+    rustc_attr!(
+        rustc_specialization_trait, Normal, template!(Word),
+        "the `#[rustc_specialization_trait]` attribute is used to check specializations"
+    ),
 
     // ==========================================================================
     // Internal attributes, Testing:
@@ -2440,6 +2440,8 @@ pub enum ItemKind<'hir> {
         unsafety: Unsafety,
         polarity: ImplPolarity,
         defaultness: Defaultness,
+        /// Whether this is an `impl const Trait for Foo`, whose methods are `const fn`s.
+        constness: Constness,
         generics: Generics<'hir>,
 
         /// The trait being implemented, if any.
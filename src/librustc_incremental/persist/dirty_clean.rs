@@ -9,13 +9,18 @@
 //!   current node).
 //! - `#[rustc_clean(cfg="rev2")]` same as above, except that the
 //!   fingerprints must be the SAME (along with all other fingerprints).
+//! - `#[rustc_clean(cfg="rev2", loaded_from_disk="const_eval_raw")]` additionally
+//!   checks that the result of the given queries were loaded from the on-disk
+//!   cache, rather than recomputed, in the current session. This needs
+//!   `-Z query-dep-graph`.
 //!
 //! Errors are reported if we are in the suitable configuration but
 //! the required condition is not met.
 
-use rustc::dep_graph::{label_strs, DepNode};
+use rustc::dep_graph::{label_strs, DepConstructor, DepNode};
 use rustc::hir::map::Map;
-use rustc::ty::TyCtxt;
+use rustc::mir::interpret::GlobalId;
+use rustc::ty::{self, TyCtxt};
 use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
@@ -33,6 +38,7 @@ use syntax::ast::{self, Attribute, NestedMetaItem};
 const EXCEPT: Symbol = sym::except;
 const LABEL: Symbol = sym::label;
 const CFG: Symbol = sym::cfg;
+const LOADED_FROM_DISK: Symbol = sym::loaded_from_disk;
 
 // Base and Extra labels to build up the labels
 
@@ -136,15 +142,16 @@ type Labels = FxHashSet<String>;
 struct Assertion {
     clean: Labels,
     dirty: Labels,
+    loaded_from_disk: Labels,
 }
 
 impl Assertion {
     fn from_clean_labels(labels: Labels) -> Assertion {
-        Assertion { clean: labels, dirty: Labels::default() }
+        Assertion { clean: labels, dirty: Labels::default(), loaded_from_disk: Labels::default() }
     }
 
     fn from_dirty_labels(labels: Labels) -> Assertion {
-        Assertion { clean: Labels::default(), dirty: labels }
+        Assertion { clean: Labels::default(), dirty: labels, loaded_from_disk: Labels::default() }
     }
 }
 
@@ -193,7 +200,7 @@ impl DirtyCleanVisitor<'tcx> {
             // skip: not the correct `cfg=`
             return None;
         }
-        let assertion = if let Some(labels) = self.labels(attr) {
+        let mut assertion = if let Some(labels) = self.labels(attr) {
             if is_clean {
                 Assertion::from_clean_labels(labels)
             } else {
@@ -202,6 +209,7 @@ impl DirtyCleanVisitor<'tcx> {
         } else {
             self.assertion_auto(item_id, attr, is_clean)
         };
+        assertion.loaded_from_disk = self.loaded_from_disk(attr);
         Some(assertion)
     }
 
@@ -224,9 +232,9 @@ impl DirtyCleanVisitor<'tcx> {
             }
         }
         if is_clean {
-            Assertion { clean: auto, dirty: except }
+            Assertion { clean: auto, dirty: except, loaded_from_disk: Labels::default() }
         } else {
-            Assertion { clean: except, dirty: auto }
+            Assertion { clean: except, dirty: auto, loaded_from_disk: Labels::default() }
         }
     }
 
@@ -252,6 +260,23 @@ impl DirtyCleanVisitor<'tcx> {
         Labels::default()
     }
 
+    /// `loaded_from_disk=` attribute value
+    fn loaded_from_disk(&self, attr: &Attribute) -> Labels {
+        for item in attr.meta_item_list().unwrap_or_else(Vec::new) {
+            if item.check_name(LOADED_FROM_DISK) {
+                if !self.tcx.sess.opts.debugging_opts.query_dep_graph {
+                    self.tcx.sess.span_fatal(
+                        item.span(),
+                        "`loaded_from_disk` can only be checked with `-Z query-dep-graph`",
+                    );
+                }
+                let value = expect_associated_value(self.tcx, &item);
+                return self.resolve_labels(&item, &value.as_str());
+            }
+        }
+        Labels::default()
+    }
+
     /// Return all DepNode labels that should be asserted for this item.
     /// index=0 is the "name" used for error messages
     fn auto_labels(&mut self, item_id: hir::HirId, attr: &Attribute) -> (&'static str, Labels) {
@@ -411,6 +436,54 @@ impl DirtyCleanVisitor<'tcx> {
         }
     }
 
+    /// The dep-nodes that a `loaded_from_disk` label names for `def_id`. The const-eval
+    /// queries are keyed by the `GlobalId` of a static or constant rather than by its `DefId`,
+    /// and that key is requested both with the item's own `ParamEnv` (when the item itself is
+    /// evaluated) and with `ParamEnv::reveal_all()` (when the evaluation of another global
+    /// reads it), so these labels name both nodes.
+    fn loaded_from_disk_nodes(&self, label: &str, def_id: DefId) -> Vec<DepNode> {
+        let tcx = self.tcx;
+        if label != label_strs::const_eval_raw && label != label_strs::const_eval_validated {
+            let def_path_hash = tcx.def_path_hash(def_id);
+            return match DepNode::from_label_string(label, def_path_hash) {
+                Ok(dep_node) => vec![dep_node],
+                Err(()) => unreachable!(),
+            };
+        }
+        let cid = GlobalId { instance: ty::Instance::mono(tcx, def_id), promoted: None };
+        let param_envs = [tcx.param_env(def_id).with_reveal_all(), ty::ParamEnv::reveal_all()];
+        param_envs
+            .iter()
+            .map(|param_env| {
+                let key = param_env.and(cid);
+                if label == label_strs::const_eval_raw {
+                    DepNode::new(tcx, DepConstructor::const_eval_raw(key))
+                } else {
+                    DepNode::new(tcx, DepConstructor::const_eval_validated(key))
+                }
+            })
+            .collect()
+    }
+
+    fn assert_loaded_from_disk(&self, item_span: Span, label: &str, def_id: DefId) {
+        debug!("assert_loaded_from_disk({:?}, {:?})", label, def_id);
+
+        let loaded = self
+            .loaded_from_disk_nodes(label, def_id)
+            .into_iter()
+            .any(|dep_node| self.tcx.dep_graph.debug_was_loaded_from_disk(dep_node));
+        if !loaded {
+            self.tcx.sess.span_err(
+                item_span,
+                &format!(
+                    "`{}({})` should have been loaded from disk but it was not",
+                    label,
+                    self.tcx.def_path_str(def_id)
+                ),
+            );
+        }
+    }
+
     fn assert_clean(&self, item_span: Span, dep_node: DepNode) {
         debug!("assert_clean({:?})", dep_node);
 
@@ -445,6 +518,9 @@ impl DirtyCleanVisitor<'tcx> {
             for dep_node in self.dep_nodes(&assertion.dirty, def_id) {
                 self.assert_dirty(item_span, dep_node);
             }
+            for label in assertion.loaded_from_disk.iter() {
+                self.assert_loaded_from_disk(item_span, label, def_id);
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::interface::{Compiler, Result};
 use crate::passes::{self, BoxedResolver, QueryContext};
+use crate::util;
 
 use rustc::arena::Arena;
 use rustc::dep_graph::DepGraph;
@@ -137,7 +138,16 @@ impl<'tcx> Queries<'tcx> {
                 self.compiler.register_lints.as_ref().map(|p| &**p).unwrap_or_else(|| empty),
                 krate,
                 &crate_name,
-            );
+            )
+            .and_then(|result| {
+                // The crate types, which are needed to check what the backend supports, are
+                // known once the crate attributes have been read.
+                util::check_codegen_backend_capabilities(
+                    self.session(),
+                    &***self.codegen_backend(),
+                )?;
+                Ok(result)
+            });
 
             // Compute the dependency graph (in the background). We want to do
             // this as early as possible, to give the DepGraph maximum time to
@@ -1,7 +1,8 @@
 use log::info;
 use rustc::lint;
 use rustc::ty;
-use rustc_codegen_utils::codegen_backend::CodegenBackend;
+use rustc::util::common::ErrorReported;
+use rustc_codegen_utils::codegen_backend::{CodegenBackend, CODEGEN_BACKEND_INTERFACE_VERSION};
 use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 #[cfg(parallel_compiler)]
@@ -19,6 +20,7 @@ use rustc_session::{config, early_error, filesearch, DiagnosticOutput, Session};
 use rustc_span::edition::Edition;
 use rustc_span::source_map::{FileLoader, RealFileLoader, SourceMap};
 use rustc_span::symbol::{sym, Symbol};
+use rustc_target::spec::PanicStrategy;
 use smallvec::SmallVec;
 use std::env;
 use std::io::{self, Write};
@@ -212,6 +214,18 @@ fn load_backend_from_dylib(path: &Path) -> fn() -> Box<dyn CodegenBackend> {
         early_error(ErrorOutputType::default(), &err);
     });
     unsafe {
+        // The backends that predate the versioning of the interface don't export their version.
+        if let Ok(f) = lib.symbol("__rustc_codegen_backend_version") {
+            let version = mem::transmute::<*mut u8, fn() -> u32>(f)();
+            if version != CODEGEN_BACKEND_INTERFACE_VERSION {
+                let err = format!(
+                    "couldn't load codegen backend {:?}: it implements version {} of the codegen \
+                     backend interface, but this compiler uses version {}",
+                    path, version, CODEGEN_BACKEND_INTERFACE_VERSION
+                );
+                early_error(ErrorOutputType::default(), &err);
+            }
+        }
         match lib.symbol("__rustc_codegen_backend") {
             Ok(f) => {
                 mem::forget(lib);
@@ -236,13 +250,8 @@ pub fn get_codegen_backend(sess: &Session) -> Box<dyn CodegenBackend> {
     static mut LOAD: fn() -> Box<dyn CodegenBackend> = || unreachable!();
 
     INIT.call_once(|| {
-        let codegen_name = sess
-            .opts
-            .debugging_opts
-            .codegen_backend
-            .as_ref()
-            .unwrap_or(&sess.target.target.options.codegen_backend);
-        let backend = match &codegen_name[..] {
+        let codegen_name = codegen_backend_name(sess);
+        let backend = match codegen_name {
             filename if filename.contains(".") => load_backend_from_dylib(filename.as_ref()),
             codegen_name => get_builtin_codegen_backend(codegen_name),
         };
@@ -256,6 +265,51 @@ pub fn get_codegen_backend(sess: &Session) -> Box<dyn CodegenBackend> {
     backend
 }
 
+/// The name of the builtin codegen backend, or the path of the backend dylib, selected for the
+/// session.
+fn codegen_backend_name(sess: &Session) -> &str {
+    sess.opts
+        .debugging_opts
+        .codegen_backend
+        .as_ref()
+        .unwrap_or(&sess.target.target.options.codegen_backend)
+}
+
+/// Reports the options of the session that `codegen_backend` does not support, once the crate
+/// types are known.
+pub fn check_codegen_backend_capabilities(
+    sess: &Session,
+    codegen_backend: &dyn CodegenBackend,
+) -> Result<(), ErrorReported> {
+    let capabilities = codegen_backend.capabilities();
+    let name = codegen_backend_name(sess);
+    let mut supported = true;
+    for crate_type in sess.crate_types.borrow().iter() {
+        if !capabilities.crate_types.contains(crate_type) {
+            sess.err(&format!(
+                "the `{}` codegen backend cannot produce `{}` crates",
+                name, crate_type
+            ));
+            supported = false;
+        }
+    }
+    if sess.panic_strategy() == PanicStrategy::Unwind && !capabilities.unwinding {
+        sess.struct_err(&format!("the `{}` codegen backend does not support unwinding", name))
+            .help("compile with `-C panic=abort`")
+            .emit();
+        supported = false;
+    }
+    let lto = match sess.lto() {
+        config::Lto::No | config::Lto::ThinLocal => sess.opts.cg.linker_plugin_lto.enabled(),
+        config::Lto::Thin | config::Lto::Fat => true,
+    };
+    if lto && !capabilities.lto {
+        sess.err(&format!("the `{}` codegen backend does not support LTO", name));
+        supported = false;
+    }
+    if supported { Ok(()) } else { Err(ErrorReported) }
+}
+
 // This is used for rustdoc, but it uses similar machinery to codegen backend
 // loading, so we leave the code here. It is potentially useful for other tools
 // that want to invoke the rustc binary while linking to rustc as well.
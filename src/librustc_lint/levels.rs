@@ -11,9 +11,9 @@ use rustc_hir as hir;
 use rustc_hir::def_id::{CrateNum, LOCAL_CRATE};
 use rustc_hir::hir_id::HirId;
 use rustc_hir::intravisit;
-use rustc_session::lint::{builtin, Level, Lint};
+use rustc_session::lint::{builtin, Level, Lint, LintId};
 use rustc_session::Session;
-use rustc_span::{sym, MultiSpan, Symbol};
+use rustc_span::{sym, MultiSpan, Span, Symbol};
 use syntax::ast;
 use syntax::attr;
 use syntax::print::pprust;
@@ -213,6 +213,7 @@ impl<'a> LintLevelsBuilder<'a> {
                     CheckLintNameResult::Ok(ids) => {
                         let src = LintSource::Node(name, li.span(), reason);
                         for id in ids {
+                            self.check_gated_lint(*id, li.span());
                             specs.insert(*id, (level, src));
                         }
                     }
@@ -380,6 +381,24 @@ impl<'a> LintLevelsBuilder<'a> {
         BuilderPush { prev: prev, changed: prev != self.cur }
     }
 
+    /// Reports the use of a lint that is only available with a feature gate.
+    fn check_gated_lint(&self, id: LintId, span: Span) {
+        if !self.warn_about_weird_lints {
+            return;
+        }
+        if id == LintId::of(builtin::UNSAFE_OP_IN_UNSAFE_FN)
+            && !self.sess.features_untracked().unsafe_block_in_unsafe_fn
+        {
+            feature_err(
+                &self.sess.parse_sess,
+                sym::unsafe_block_in_unsafe_fn,
+                span,
+                "the `unsafe_op_in_unsafe_fn` lint is unstable",
+            )
+            .emit();
+        }
+    }
+
     /// Called after `push` when the scope of a set of attributes are exited.
     pub fn pop(&mut self, push: BuilderPush) {
         self.cur = push.prev;
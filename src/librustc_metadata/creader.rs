@@ -487,6 +487,9 @@ impl<'a> CrateLoader<'a> {
 
     fn load(&self, locator: &mut CrateLocator<'_>) -> Option<LoadResult> {
         let library = locator.maybe_load_library_crate()?;
+        if self.sess.opts.prints.contains(&config::PrintRequest::CrateResolution) {
+            locator.print_resolution(&library);
+        }
 
         // In the case that we're loading a crate, but not matching
         // against a hash, we could load a crate which has the same hash
@@ -380,6 +380,65 @@ impl<'a> CrateLocator<'a> {
         }
     }
 
+    /// Prints the library selected for the crate, and the candidates rejected along the way, for
+    /// `--print crate-resolution`.
+    crate fn print_resolution(&self, library: &Library) {
+        let required_by = match self.root {
+            None => String::new(),
+            Some(r) => format!(" (required by `{}`)", r.name),
+        };
+        println!(
+            "resolved crate `{}`{} with hash {} for {}",
+            self.crate_name,
+            required_by,
+            library.metadata.get_root().hash(),
+            self.triple
+        );
+        let CrateSource { dylib, rlib, rmeta } = &library.source;
+        let selected =
+            [(CrateFlavor::Rlib, rlib), (CrateFlavor::Rmeta, rmeta), (CrateFlavor::Dylib, dylib)];
+        for &(flavor, source) in &selected {
+            if let Some((path, kind)) = source {
+                println!("    selected {} {} (found via {:?})", flavor, path.display(), kind);
+            }
+        }
+
+        let expected_hash = self.hash.map(|hash| format!(", expected {}", hash));
+        for m in &self.rejected_via_hash {
+            println!(
+                "    rejected {}: hash mismatch (found {}{})",
+                m.path.display(),
+                m.got,
+                expected_hash.as_deref().unwrap_or("")
+            );
+        }
+        for m in &self.rejected_via_triple {
+            println!(
+                "    rejected {}: wrong target triple (found `{}`, expected `{}`)",
+                m.path.display(),
+                m.got,
+                self.triple
+            );
+        }
+        for m in &self.rejected_via_kind {
+            println!(
+                "    rejected {}: not a Rust library (found a {} library)",
+                m.path.display(),
+                m.got
+            );
+        }
+        for m in &self.rejected_via_version {
+            println!(
+                "    rejected {}: compiled by another compiler (found `{}`)",
+                m.path.display(),
+                m.got
+            );
+        }
+        for m in &self.rejected_via_filename {
+            println!("    rejected {}: not named like a Rust library", m.path.display());
+        }
+    }
+
     crate fn report_errs(self) -> ! {
         let add = match self.root {
             None => String::new(),
@@ -496,6 +496,20 @@ impl MetadataBlob {
             write!(out, "{} {}{}\n", i + 1, dep.name, dep.extra_filename)?;
         }
         write!(out, "\n")?;
+        if root.repr_c_layouts.meta > 0 {
+            write!(out, "=repr(C) Layouts=\n")?;
+            for layout in root.repr_c_layouts.decode(self) {
+                write!(out, "{} size={} align={}\n", layout.path, layout.size, layout.align)?;
+                for field in layout.fields {
+                    write!(
+                        out,
+                        "    {} offset={} size={}\n",
+                        field.name, field.offset, field.size
+                    )?;
+                }
+            }
+            write!(out, "\n")?;
+        }
         Ok(())
     }
 }
@@ -709,6 +723,7 @@ impl<'a, 'tcx> CrateMetadata {
                     data.paren_sugar,
                     data.has_auto_impl,
                     data.is_marker,
+                    data.specialization_kind,
                     self.def_path_table.def_path_hash(item_id),
                 )
             }
@@ -718,6 +733,7 @@ impl<'a, 'tcx> CrateMetadata {
                 false,
                 false,
                 false,
+                ty::trait_def::TraitSpecializationKind::None,
                 self.def_path_table.def_path_hash(item_id),
             ),
             _ => bug!("def-index does not refer to trait or trait alias"),
@@ -1295,6 +1311,10 @@ impl<'a, 'tcx> CrateMetadata {
         }
     }
 
+    fn get_repr_c_layouts(&self, tcx: TyCtxt<'tcx>) -> &'tcx [ty::layout::ReprCLayout] {
+        tcx.arena.alloc_from_iter(self.root.repr_c_layouts.decode(self))
+    }
+
     fn get_dylib_dependency_formats(
         &self,
         tcx: TyCtxt<'tcx>,
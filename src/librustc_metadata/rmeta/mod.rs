@@ -179,6 +179,7 @@ crate struct CrateRoot<'tcx> {
     edition: Edition,
     has_global_allocator: bool,
     has_panic_handler: bool,
+    has_static_dtors: bool,
     has_default_lib_allocator: bool,
     plugin_registrar_fn: Option<DefIndex>,
     proc_macro_decls_static: Option<DefIndex>,
@@ -192,6 +193,8 @@ crate struct CrateRoot<'tcx> {
     diagnostic_items: Lazy<[(Symbol, DefIndex)]>,
     native_libraries: Lazy<[NativeLibrary]>,
     foreign_modules: Lazy<[ForeignModule]>,
+    /// Only recorded with `-Z encode-repr-c-layouts`.
+    repr_c_layouts: Lazy<[ty::layout::ReprCLayout]>,
     source_map: Lazy<[rustc_span::SourceFile]>,
     def_path_table: Lazy<map::definitions::DefPathTable>,
     impls: Lazy<[TraitImpls]>,
@@ -348,6 +351,7 @@ struct TraitData {
     paren_sugar: bool,
     has_auto_impl: bool,
     is_marker: bool,
+    specialization_kind: ty::trait_def::TraitSpecializationKind,
 }
 
 #[derive(RustcEncodable, RustcDecodable)]
@@ -293,6 +293,7 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 "",
                 Some(borrow_span),
             );
+        self.note_overloaded_deref_borrow(borrow, &mut err);
         err.buffer(&mut self.errors_buffer);
     }
 
@@ -333,6 +334,7 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 "",
                 None,
             );
+        self.note_overloaded_deref_borrow(borrow, &mut err);
         err
     }
 
@@ -544,6 +546,23 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
             None,
         );
 
+        let issued_deref = self.overloaded_deref_borrow(issued_borrow.reserve_location);
+        let borrow_deref = self.overloaded_deref_borrow(location);
+        match (issued_deref, borrow_deref) {
+            (Some(issued_deref), Some(borrow_deref)) if issued_deref == borrow_deref => {
+                issued_deref.add_note(&mut err, "both borrows create");
+            }
+            (issued_deref, borrow_deref) => {
+                if let Some(issued_deref) = issued_deref {
+                    let desc = format!("the {}borrow creates", first_borrow_desc);
+                    issued_deref.add_note(&mut err, &desc);
+                }
+                if let Some(borrow_deref) = borrow_deref {
+                    borrow_deref.add_note(&mut err, "the second borrow creates");
+                }
+            }
+        }
+
         err
     }
 
@@ -1418,6 +1437,7 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
             "",
             None,
         );
+        self.note_overloaded_deref_borrow(loan, &mut err);
 
         err.buffer(&mut self.errors_buffer);
     }
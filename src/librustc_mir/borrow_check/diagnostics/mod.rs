@@ -816,4 +816,81 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
         let span = self.body.source_info(borrow.reserve_location).span;
         self.borrow_spans(span, borrow.reserve_location)
     }
+
+    /// If the borrow created at `location` is the implicit autoref of an overloaded
+    /// dereference (e.g., `&mut p` in `p.field` where `p: MyBox<T>`), returns the
+    /// `Deref` or `DerefMut` impl that the borrow is passed to.
+    pub(super) fn overloaded_deref_borrow(&self, location: Location) -> Option<OverloadedDeref> {
+        let tcx = self.infcx.tcx;
+        let block = &self.body[location.block];
+        let temp = match block.statements.get(location.statement_index)?.kind {
+            StatementKind::Assign(box (ref place, Rvalue::Ref(..))) => place.as_local()?,
+            _ => return None,
+        };
+        let (func, args) = match block.terminator().kind {
+            TerminatorKind::Call { ref func, ref args, from_hir_call: false, .. } => (func, args),
+            _ => return None,
+        };
+        let passes_temp = args.iter().any(|arg| match arg {
+            Operand::Move(place) => place.as_local() == Some(temp),
+            _ => false,
+        });
+        if !passes_temp {
+            return None;
+        }
+        let (def_id, substs) = match func.ty(*self.body, tcx).kind {
+            ty::FnDef(def_id, substs) => (def_id, substs),
+            _ => return None,
+        };
+        let trait_id = tcx.trait_of_item(def_id)?;
+        let lang_items = tcx.lang_items();
+        let is_deref_trait = Some(trait_id) == lang_items.deref_trait()
+            || Some(trait_id) == lang_items.deref_mut_trait();
+        if !is_deref_trait {
+            return None;
+        }
+
+        let substs = tcx.erase_regions(&substs);
+        let param_env = tcx.param_env(self.mir_def_id);
+        let impl_span = ty::Instance::resolve(tcx, param_env, def_id, substs)
+            .and_then(|instance| tcx.impl_of_method(instance.def_id()))
+            .map(|impl_def_id| tcx.def_span(impl_def_id));
+        Some(OverloadedDeref {
+            trait_ref: format!("<{} as {}>", substs.type_at(0), tcx.def_path_str(trait_id)),
+            impl_span,
+        })
+    }
+
+    /// Notes that `borrow` goes through an overloaded dereference, if it does.
+    pub(super) fn note_overloaded_deref_borrow(
+        &self,
+        borrow: &BorrowData<'_>,
+        err: &mut DiagnosticBuilder<'_>,
+    ) {
+        if let Some(deref) = self.overloaded_deref_borrow(borrow.reserve_location) {
+            deref.add_note(err, "the borrow creates");
+        }
+    }
+}
+
+/// An overloaded dereference that a borrow is passed to, see
+/// `MirBorrowckCtxt::overloaded_deref_borrow`.
+#[derive(PartialEq)]
+pub(super) struct OverloadedDeref {
+    /// The trait being called, e.g. `<MyBox<T> as std::ops::DerefMut>`.
+    trait_ref: String,
+    /// The span of the impl header, if the impl could be resolved.
+    impl_span: Option<Span>,
+}
+
+impl OverloadedDeref {
+    /// Notes that the borrow described by `borrow_desc` (e.g. "the first borrow creates")
+    /// goes through this dereference, pointing at the impl if possible.
+    pub(super) fn add_note(&self, err: &mut DiagnosticBuilder<'_>, borrow_desc: &str) {
+        let msg = format!("{} a temporary borrow through `{}`", borrow_desc, self.trait_ref);
+        match self.impl_span {
+            Some(span) => err.span_note(span, &msg),
+            None => err.note(&msg),
+        };
+    }
 }
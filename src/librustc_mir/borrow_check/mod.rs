@@ -43,7 +43,8 @@ use crate::transform::MirSource;
 
 use self::diagnostics::{AccessKind, RegionName};
 use self::flows::Flows;
-use self::location::LocationTable;
+use self::location::{LocationTable, RichLocation};
+use self::nll::PoloniusOutput;
 use self::prefixes::PrefixSet;
 use self::MutateMode::{JustWrite, WriteAndRead};
 
@@ -280,6 +281,7 @@ fn do_mir_borrowck<'a, 'tcx>(
         movable_generator,
         locals_are_invalidated_at_exit,
         access_place_error_reported: Default::default(),
+        conflict_error_locations: Default::default(),
         reservation_error_reported: Default::default(),
         reservation_warnings: Default::default(),
         move_error_reported: BTreeMap::new(),
@@ -299,6 +301,14 @@ fn do_mir_borrowck<'a, 'tcx>(
     // Compute and report region errors, if any.
     mbcx.report_region_errors(nll_errors);
 
+    // With `-Z polonius=compare`, NLL computes the results and the Polonius output is only used
+    // to find discrepancies.
+    let (polonius_output, polonius_comparison) =
+        if tcx.sess.opts.debugging_opts.polonius.is_enabled() {
+            (polonius_output, None)
+        } else {
+            (None, polonius_output)
+        };
     let mut state = Flows::new(flow_borrows, flow_uninits, flow_ever_inits, polonius_output);
 
     if let Some(errors) = move_errors {
@@ -306,6 +316,10 @@ fn do_mir_borrowck<'a, 'tcx>(
     }
     mbcx.analyze_results(&mut state); // entry point for DataflowResultsConsumer
 
+    if let Some(polonius_comparison) = polonius_comparison {
+        mbcx.report_polonius_discrepancies(&polonius_comparison);
+    }
+
     // Convert any reservation warnings into lints.
     let reservation_warnings = mem::take(&mut mbcx.reservation_warnings);
     for (_, (place, span, location, bk, borrow)) in reservation_warnings {
@@ -439,6 +453,9 @@ crate struct MirBorrowckCtxt<'cx, 'tcx> {
     /// of the `Span` type (while required to mute some errors) stops the muting of the reservation
     /// errors.
     access_place_error_reported: FxHashSet<(Place<'tcx>, Span)>,
+    /// The locations of the accesses for which a conflicting borrow error was reported, used to
+    /// compare the results with Polonius under `-Z polonius=compare`.
+    conflict_error_locations: FxHashSet<Location>,
     /// This field keeps track of when borrow conflict errors are reported
     /// for reservations, so that we don't report seemingly duplicate
     /// errors for corresponding activations.
@@ -902,6 +919,10 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
         let conflict_error =
             self.check_access_for_conflict(location, place_span, sd, rw, flow_state);
 
+        if conflict_error {
+            self.conflict_error_locations.insert(location);
+        }
+
         if let (Activation(_, borrow_idx), true) = (kind.1, conflict_error) {
             // Suppress this warning when there's an error being emitted for the
             // same borrow: fixing the error is likely to fix the warning.
@@ -915,6 +936,36 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
         }
     }
 
+    /// Warns about the locations where exactly one of NLL and Polonius found a conflicting borrow,
+    /// for `-Z polonius=compare`.
+    fn report_polonius_discrepancies(&self, polonius_output: &PoloniusOutput) {
+        let polonius_error_locations: FxHashSet<Location> = polonius_output
+            .errors
+            .keys()
+            .map(|&point| match self.location_table.to_location(point) {
+                RichLocation::Start(location) | RichLocation::Mid(location) => location,
+            })
+            .collect();
+        let mut discrepancies: Vec<_> =
+            self.conflict_error_locations.symmetric_difference(&polonius_error_locations).collect();
+        discrepancies.sort();
+
+        for &location in discrepancies {
+            let (found_by, missed_by) = if self.conflict_error_locations.contains(&location) {
+                ("NLL", "Polonius")
+            } else {
+                ("Polonius", "NLL")
+            };
+            self.infcx.tcx.sess.span_warn(
+                self.body.source_info(location).span,
+                &format!(
+                    "borrow checker discrepancy: {} reports a borrow conflict here, {} does not",
+                    found_by, missed_by
+                ),
+            );
+        }
+    }
+
     fn check_access_for_conflict(
         &mut self,
         location: Location,
@@ -5,6 +5,7 @@ use rustc::mir::{
     BasicBlock, Body, BodyAndCache, ClosureOutlivesSubject, ClosureRegionRequirements, LocalKind,
     Location, Promoted, ReadOnlyBodyAndCache,
 };
+use rustc::session::config::Polonius;
 use rustc::ty::{self, RegionKind, RegionVid};
 use rustc_errors::Diagnostic;
 use rustc_hir::def_id::DefId;
@@ -263,10 +264,18 @@ pub(in crate::borrow_check) fn compute_regions<'cx, 'tcx>(
             all_facts.write_to_dir(dir_path, location_table).unwrap();
         }
 
-        if infcx.tcx.sess.opts.debugging_opts.polonius {
-            let algorithm =
-                env::var("POLONIUS_ALGORITHM").unwrap_or_else(|_| String::from("Naive"));
-            let algorithm = Algorithm::from_str(&algorithm).unwrap();
+        let polonius = infcx.tcx.sess.opts.debugging_opts.polonius;
+        if polonius.is_computed() {
+            let algorithm = match polonius {
+                Polonius::Legacy => {
+                    let algorithm =
+                        env::var("POLONIUS_ALGORITHM").unwrap_or_else(|_| String::from("Naive"));
+                    Algorithm::from_str(&algorithm).unwrap()
+                }
+                // The location-insensitive pre-pass is enough to show that most functions have
+                // no errors, the expensive analysis only runs on the others.
+                _ => Algorithm::Hybrid,
+            };
             debug!("compute_regions: using polonius algorithm {:?}", algorithm);
             let _prof_timer = infcx.tcx.prof.generic_activity("polonius_analysis");
             Some(Rc::new(Output::compute(&all_facts, algorithm, false)))
@@ -1,17 +1,23 @@
 // Not in interpret to make sure we do not use private implementation details
 
 use rustc::mir;
-use rustc::ty::layout::VariantIdx;
+use rustc::mir::interpret::{CallerLocation, GlobalAlloc, Scalar};
+use rustc::ty::layout::{Size, VariantIdx};
 use rustc::ty::{self, TyCtxt};
-use rustc_span::{source_map::DUMMY_SP, symbol::Symbol};
+use rustc_span::source_map::DUMMY_SP;
 
-use crate::interpret::{intern_const_alloc_recursive, ConstValue, InternKind, InterpCx};
+use crate::interpret::{
+    intern_const_alloc_recursive, ConstValue, InternKind, InterpCx, MemPlaceMeta,
+};
 
+mod emulated_fns;
 mod error;
 mod eval_queries;
 mod fn_queries;
 mod machine;
+mod target_dependence;
 
+pub use emulated_fns::{EmulatedFn, EmulatedFns};
 pub use error::*;
 pub use eval_queries::*;
 pub use fn_queries::*;
@@ -45,13 +51,13 @@ pub(crate) fn const_field<'tcx>(
 
 pub(crate) fn const_caller_location<'tcx>(
     tcx: TyCtxt<'tcx>,
-    (file, line, col): (Symbol, u32, u32),
+    location: CallerLocation,
 ) -> &'tcx ty::Const<'tcx> {
-    trace!("const_caller_location: {}:{}:{}", file, line, col);
+    trace!("const_caller_location: {:?}", location);
     let mut ecx = mk_eval_cx(tcx, DUMMY_SP, ty::ParamEnv::reveal_all(), false);
 
     let loc_ty = tcx.caller_location_ty();
-    let loc_place = ecx.alloc_caller_location(file, line, col);
+    let loc_place = ecx.alloc_caller_location(location).unwrap();
     intern_const_alloc_recursive(&mut ecx, InternKind::Constant, loc_place, false).unwrap();
     let loc_const = ty::Const {
         ty: loc_ty,
@@ -90,3 +96,38 @@ pub(crate) fn destructure_const<'tcx>(
 
     mir::DestructuredConst { variant, fields }
 }
+
+/// Reads the value that the constant reference `val` points to. References to slices result in
+/// arrays.
+///
+/// Returns `None` for references to statics and to trait objects, as reading them would need
+/// more than the memory of `val`.
+pub(crate) fn deref_const<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    val: &'tcx ty::Const<'tcx>,
+) -> Option<&'tcx ty::Const<'tcx>> {
+    trace!("deref_const: {:?}", val);
+    let ecx = mk_eval_cx(tcx, DUMMY_SP, param_env, false);
+    let op = ecx.eval_const_to_op(val, None).unwrap();
+    let mplace = ecx.deref_operand(op).unwrap();
+    if let Scalar::Ptr(ptr) = mplace.ptr {
+        match tcx.alloc_map.lock().get(ptr.alloc_id) {
+            Some(GlobalAlloc::Memory(_)) => {}
+            _ => return None,
+        }
+    }
+    let mplace = match mplace.meta {
+        MemPlaceMeta::None => mplace,
+        MemPlaceMeta::Meta(len) => match mplace.layout.ty.kind {
+            ty::Slice(elem) => {
+                let len = len.to_machine_usize(&tcx).unwrap();
+                let layout = tcx.layout_of(param_env.and(tcx.mk_array(elem, len))).unwrap();
+                mplace.offset(Size::ZERO, MemPlaceMeta::None, layout, &tcx).unwrap()
+            }
+            _ => return None,
+        },
+        MemPlaceMeta::Poison => bug!("deref_const: poisoned metadata in {:?}", val),
+    };
+    Some(op_to_const(&ecx, mplace.into()))
+}
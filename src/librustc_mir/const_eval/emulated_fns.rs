@@ -0,0 +1,160 @@
+//! Functions without a body, such as the `memcmp` and `memcpy` of the C library, that const
+//! evaluation emulates instead of calling them.
+//!
+//! Only functions declared in `extern` blocks are emulated, and they are looked up by the name
+//! they are linked with. Each `CompileTimeInterpreter` has its own registry of them, which custom
+//! drivers can extend with `EmulatedFns::register` on the machines they evaluate with.
+
+use std::cmp::Ordering;
+use std::iter;
+
+use rustc::mir::interpret::{InterpResult, Scalar};
+use rustc::ty::layout::{Align, Size};
+use rustc::ty::TyCtxt;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefId;
+
+use super::{CompileTimeEvalContext, CompileTimeInterpreter};
+use crate::interpret::{InterpCx, OpTy, PlaceTy};
+
+/// The emulation of a function, which writes the return value of the call to the place.
+pub type EmulatedFn = for<'mir, 'tcx> fn(
+    &mut InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>>,
+    &[OpTy<'tcx>],
+    PlaceTy<'tcx>,
+) -> InterpResult<'tcx>;
+
+/// The foreign functions that an evaluation emulates, by the name they are linked with.
+#[derive(Clone)]
+pub struct EmulatedFns {
+    fns: FxHashMap<&'static str, EmulatedFn>,
+}
+
+impl Default for EmulatedFns {
+    /// Returns the registry of the functions of the C library that are always emulated.
+    fn default() -> Self {
+        let mut fns = FxHashMap::default();
+        fns.insert("memcmp", memcmp as EmulatedFn);
+        fns.insert("memcpy", memcpy as EmulatedFn);
+        fns.insert("memmove", memmove as EmulatedFn);
+        fns.insert("memset", memset as EmulatedFn);
+        fns.insert("strlen", strlen as EmulatedFn);
+        EmulatedFns { fns }
+    }
+}
+
+impl EmulatedFns {
+    /// Emulates the foreign function linked with the name `name` with `emulate`, replacing the
+    /// previous emulation of that function, if any.
+    pub fn register(&mut self, name: &'static str, emulate: EmulatedFn) {
+        self.fns.insert(name, emulate);
+    }
+
+    /// Returns the emulation of the function `def_id`, if it is an emulated foreign function.
+    crate fn get(&self, tcx: TyCtxt<'_>, def_id: DefId) -> Option<EmulatedFn> {
+        if !tcx.is_foreign_item(def_id) {
+            return None;
+        }
+        let attrs = tcx.codegen_fn_attrs(def_id);
+        let name = attrs.link_name.unwrap_or_else(|| tcx.item_name(def_id));
+        self.fns.get(&*name.as_str()).copied()
+    }
+}
+
+fn check_arg_count(args: &[OpTy<'_>], expected: usize, name: &str) -> InterpResult<'static> {
+    if args.len() != expected {
+        throw_unsup_format!(
+            "`{}` is declared with {} arguments, but it takes {}",
+            name,
+            args.len(),
+            expected
+        );
+    }
+    Ok(())
+}
+
+fn read_size<'mir, 'tcx>(
+    ecx: &CompileTimeEvalContext<'mir, 'tcx>,
+    op: OpTy<'tcx>,
+) -> InterpResult<'tcx, Size> {
+    Ok(Size::from_bytes(ecx.read_scalar(op)?.to_machine_usize(ecx)?))
+}
+
+fn memcmp<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+) -> InterpResult<'tcx> {
+    check_arg_count(args, 3, "memcmp")?;
+    let left = ecx.read_scalar(args[0])?.not_undef()?;
+    let right = ecx.read_scalar(args[1])?.not_undef()?;
+    let size = read_size(ecx, args[2])?;
+    let result = match ecx.memory.read_bytes(left, size)?.cmp(ecx.memory.read_bytes(right, size)?)
+    {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    };
+    ecx.write_scalar(Scalar::from_int(result, dest.layout.size), dest)
+}
+
+fn copy<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+    nonoverlapping: bool,
+) -> InterpResult<'tcx> {
+    check_arg_count(args, 3, if nonoverlapping { "memcpy" } else { "memmove" })?;
+    let to = ecx.read_scalar(args[0])?.not_undef()?;
+    let from = ecx.read_scalar(args[1])?.not_undef()?;
+    let size = read_size(ecx, args[2])?;
+    let align = Align::from_bytes(1).unwrap();
+    let to_ptr = ecx.memory.check_ptr_access(to, size, align)?;
+    let from_ptr = ecx.memory.check_ptr_access(from, size, align)?;
+    // Both are `None` for a zero-sized copy.
+    if let (Some(from), Some(to)) = (from_ptr, to_ptr) {
+        ecx.memory.copy(from, to, size, nonoverlapping)?;
+    }
+    ecx.write_scalar(to, dest)
+}
+
+fn memcpy<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+) -> InterpResult<'tcx> {
+    copy(ecx, args, dest, true)
+}
+
+fn memmove<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+) -> InterpResult<'tcx> {
+    copy(ecx, args, dest, false)
+}
+
+fn memset<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+) -> InterpResult<'tcx> {
+    check_arg_count(args, 3, "memset")?;
+    let to = ecx.read_scalar(args[0])?.not_undef()?;
+    // Like C, only use the low byte of the `int` argument.
+    let byte = ecx.read_scalar(args[1])?.to_i32()? as u8;
+    let size = read_size(ecx, args[2])?;
+    ecx.memory.write_bytes(to, iter::repeat(byte).take(size.bytes() as usize))?;
+    ecx.write_scalar(to, dest)
+}
+
+fn strlen<'mir, 'tcx>(
+    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+    args: &[OpTy<'tcx>],
+    dest: PlaceTy<'tcx>,
+) -> InterpResult<'tcx> {
+    check_arg_count(args, 1, "strlen")?;
+    let s = ecx.read_scalar(args[0])?.not_undef()?;
+    let len = ecx.memory.read_c_str(s)?.len();
+    ecx.write_scalar(Scalar::from_uint(len as u64, dest.layout.size), dest)
+}
@@ -1,17 +1,30 @@
-use super::{error_to_const_error, CompileTimeEvalContext, CompileTimeInterpreter, MemoryExtra};
+use super::{
+    error_to_const_error, target_dependence, CompileTimeEvalContext, CompileTimeInterpreter,
+    MemoryExtra, MemoryLimit,
+};
 use crate::interpret::eval_nullary_intrinsic;
 use crate::interpret::{
-    intern_const_alloc_recursive, Allocation, ConstValue, GlobalId, ImmTy, Immediate, InternKind,
-    InterpCx, InterpResult, MPlaceTy, MemoryKind, OpTy, RawConst, RefTracking, Scalar,
-    ScalarMaybeUndef, StackPopCleanup,
+    intern_const_alloc_recursive, AllocMap, Allocation, CompileTimeMachine, ConstValue,
+    CtfeValidationMode, GlobalId, ImmTy, Immediate, InternKind, InterpCx, InterpResult, MPlaceTy,
+    Machine, MemoryKind, OpTy, RawConst, RefTracking, Scalar, ScalarMaybeUndef, StackPopCleanup,
 };
+use rustc::lint::builtin::LONG_RUNNING_CONST_EVAL;
+use rustc::lint::Level;
+use rustc::middle::recursion_limit;
 use rustc::mir;
-use rustc::mir::interpret::{ConstEvalErr, ErrorHandled};
+use rustc::mir::interpret::{ConstEvalErr, ConstEvalRawResult, ErrorHandled, GlobalAlloc};
 use rustc::traits::Reveal;
-use rustc::ty::{self, layout, layout::LayoutOf, subst::Subst, TyCtxt};
+use rustc::ty::layout::{self, LayoutOf, Size};
+use rustc::ty::{self, query::TyCtxtAt, subst::Subst, TyCtxt};
+use rustc_hir as hir;
 use rustc_hir::def::DefKind;
-use rustc_span::source_map::Span;
+use rustc_hir::def_id::DefId;
+use rustc_span::source_map::{Span, DUMMY_SP};
+use rustc_span::symbol::sym;
 use std::convert::TryInto;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 pub fn note_on_undefined_behavior_error() -> &'static str {
     "The rules on what exactly is undefined behavior aren't clear, \
@@ -19,21 +32,90 @@ pub fn note_on_undefined_behavior_error() -> &'static str {
      repository if you believe it should not be considered undefined behavior."
 }
 
-// Returns a pointer to where the result lives
-fn eval_body_using_ecx<'mir, 'tcx>(
-    ecx: &mut CompileTimeEvalContext<'mir, 'tcx>,
+/// The maximum number of interpreter steps the evaluation of `def_id` may take, with `0` meaning
+/// that there is no limit. `-Z const-eval-limit` takes precedence over a `#[const_eval_limit]`
+/// attribute on the item, which takes precedence over the one on the crate.
+fn const_eval_limit(tcx: TyCtxt<'_>, def_id: DefId) -> usize {
+    if let Some(limit) = tcx.sess.opts.debugging_opts.const_eval_limit {
+        return limit;
+    }
+    let item_limit = tcx
+        .get_attrs(def_id)
+        .iter()
+        .find(|attr| attr.check_name(sym::const_eval_limit))
+        .and_then(|attr| recursion_limit::const_eval_limit_value(tcx.sess, attr));
+    item_limit.unwrap_or_else(|| *tcx.sess.const_eval_limit.get())
+}
+
+/// The maximum number of stack frames of the evaluation of `def_id`, or of a constant without an
+/// item if it is `None`. `-Z const-eval-stack-limit` takes precedence over a
+/// `#[const_eval_stack_limit]` attribute on the item, which takes precedence over the
+/// `#![recursion_limit]` of the crate.
+fn const_eval_stack_limit(tcx: TyCtxt<'_>, def_id: Option<DefId>) -> usize {
+    if let Some(limit) = tcx.sess.opts.debugging_opts.const_eval_stack_limit {
+        return limit;
+    }
+    let item_limit = def_id.and_then(|def_id| {
+        tcx.get_attrs(def_id)
+            .iter()
+            .filter(|attr| attr.check_name(sym::const_eval_stack_limit))
+            .filter_map(|attr| attr.value_str()?.as_str().parse().ok())
+            .next()
+    });
+    item_limit.unwrap_or_else(|| *tcx.sess.recursion_limit.get())
+}
+
+/// The number of interpreter steps after which the evaluation of a constant is considered to be
+/// long-running, and `long_running_const_eval` is emitted.
+const LONG_RUNNING_STEPS: usize = 100_000;
+
+/// Reports that the evaluation of `def_id` has taken `steps` interpreter steps and is currently
+/// at `span`. The first report is the `long_running_const_eval` lint; every later one is a note
+/// showing the progress of the evaluation, unless the lint is allowed.
+fn report_long_running_eval(tcx: TyCtxt<'_>, def_id: DefId, span: Span, name: &str, steps: usize) {
+    let hir_id = tcx.hir().as_local_hir_id(def_id).unwrap_or(hir::CRATE_HIR_ID);
+    if steps == LONG_RUNNING_STEPS {
+        let msg = format!("evaluation of `{}` is taking a long time", name);
+        tcx.struct_span_lint_hir(LONG_RUNNING_CONST_EVAL, hir_id, span, &msg)
+            .note(&format!("the evaluation has taken {} interpreter steps so far", steps))
+            .emit();
+    } else if tcx.lint_level_at_node(LONG_RUNNING_CONST_EVAL, hir_id).0 != Level::Allow {
+        let msg = format!("evaluation of `{}` is still running after {} steps", name, steps);
+        tcx.sess.span_note_without_error(span, &msg);
+    }
+}
+
+/// The number and total size of the allocations of `ecx` that have not been interned yet.
+fn uninterned_memory<'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx>>(
+    ecx: &InterpCx<'mir, 'tcx, M>,
+) -> (usize, u64) {
+    let sizes = ecx.memory.alloc_map().filter_map_collect(|_, (_, alloc)| Some(alloc.size.bytes()));
+    (sizes.len(), sizes.iter().sum())
+}
+
+/// Requests the unvalidated result of `key`, counting the request for `-Z print-ctfe-stats`.
+crate fn request_const_eval_raw<'tcx>(
+    tcx: TyCtxtAt<'tcx>,
+    key: ty::ParamEnvAnd<'tcx, GlobalId<'tcx>>,
+) -> ConstEvalRawResult<'tcx> {
+    if tcx.sess.opts.debugging_opts.print_ctfe_stats {
+        tcx.sess.code_stats.record_const_eval_raw_request();
+    }
+    tcx.const_eval_raw(key)
+}
+
+/// Runs `body`, the MIR of the global `cid` named `name`, with `ecx`. Returns the place of the
+/// result, which is not interned, and the number of interpreter steps the evaluation took.
+fn run_body_using_ecx<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>>(
+    ecx: &mut InterpCx<'mir, 'tcx, M>,
     cid: GlobalId<'tcx>,
     body: &'mir mir::Body<'tcx>,
-) -> InterpResult<'tcx, MPlaceTy<'tcx>> {
-    debug!("eval_body_using_ecx: {:?}, {:?}", cid, ecx.param_env);
+    name: &str,
+) -> InterpResult<'tcx, (MPlaceTy<'tcx, M::PointerTag>, usize)> {
     let tcx = ecx.tcx.tcx;
     let layout = ecx.layout_of(body.return_ty().subst(tcx, cid.instance.substs))?;
     assert!(!layout.is_unsized());
-    let ret = ecx.allocate(layout, MemoryKind::Stack);
-
-    let name = ty::tls::with(|tcx| tcx.def_path_str(cid.instance.def_id()));
-    let prom = cid.promoted.map_or(String::new(), |p| format!("::promoted[{:?}]", p));
-    trace!("eval_body_using_ecx: pushing stack frame for global: {}{}", name, prom);
+    let ret = ecx.allocate(layout, MemoryKind::Stack)?;
 
     // Assert all args (if any) are zero-sized types; `eval_body_using_ecx` doesn't
     // make sense if the body is expecting nontrivial arguments.
@@ -53,7 +135,43 @@ fn eval_body_using_ecx<'mir, 'tcx>(
     )?;
 
     // The main interpreter loop.
-    ecx.run()?;
+    let limit = const_eval_limit(tcx, cid.instance.def_id());
+    let mut steps = 0;
+    let mut next_progress_report = LONG_RUNNING_STEPS;
+    while ecx.step()? {
+        steps += 1;
+        if limit != 0 && steps > limit {
+            throw_exhaust!(StepLimitReached { name: name.to_owned(), limit })
+        }
+        if steps == next_progress_report {
+            report_long_running_eval(tcx, cid.instance.def_id(), ecx.tcx.span, name, steps);
+            next_progress_report = next_progress_report.saturating_mul(2);
+        }
+    }
+
+    Ok((ret, steps))
+}
+
+/// Evaluates `body`, the MIR of the global `cid`, with `ecx` and interns the result. Returns a
+/// pointer to where the result lives.
+///
+/// This works with any machine that uses the memory representation of CTFE, so that tools
+/// providing their own `Machine` can evaluate globals exactly like the compiler does.
+pub fn eval_body_using_ecx<'mir, 'tcx: 'mir, M: CompileTimeMachine<'mir, 'tcx>>(
+    ecx: &mut InterpCx<'mir, 'tcx, M>,
+    cid: GlobalId<'tcx>,
+    body: &'mir mir::Body<'tcx>,
+) -> InterpResult<'tcx, MPlaceTy<'tcx>> {
+    debug!("eval_body_using_ecx: {:?}, {:?}", cid, ecx.param_env);
+    let tcx = ecx.tcx.tcx;
+    let profile_start =
+        if tcx.sess.opts.debugging_opts.ctfe_profile { Some(Instant::now()) } else { None };
+    let print_stats = tcx.sess.opts.debugging_opts.print_ctfe_stats;
+
+    let name = ty::tls::with(|tcx| tcx.def_path_str(cid.instance.def_id()));
+    let prom = cid.promoted.map_or(String::new(), |p| format!("::promoted[{:?}]", p));
+    trace!("eval_body_using_ecx: pushing stack frame for global: {}{}", name, prom);
+    let (ret, steps) = run_body_using_ecx(ecx, cid, body, &name)?;
 
     // Intern the result
     let intern_kind = match tcx.static_mutability(cid.instance.def_id()) {
@@ -61,6 +179,8 @@ fn eval_body_using_ecx<'mir, 'tcx>(
         None if cid.promoted.is_some() => InternKind::Promoted,
         _ => InternKind::Constant,
     };
+    let before_interning =
+        if profile_start.is_some() || print_stats { Some(uninterned_memory(ecx)) } else { None };
     intern_const_alloc_recursive(
         ecx,
         intern_kind,
@@ -68,6 +188,26 @@ fn eval_body_using_ecx<'mir, 'tcx>(
         body.ignore_interior_mut_in_const_validation,
     )?;
 
+    if let Some((allocs_before, bytes_before)) = before_interning {
+        let (allocs_after, bytes_after) = uninterned_memory(ecx);
+        let interned_bytes = bytes_before - bytes_after;
+        if let Some(start) = profile_start {
+            tcx.sess.code_stats.record_ctfe_eval(
+                format!("{}{}", name, prom),
+                start.elapsed(),
+                steps,
+                interned_bytes,
+            );
+        }
+        if print_stats {
+            tcx.sess.code_stats.record_ctfe_interning(
+                format!("{}{}", name, prom),
+                interned_bytes,
+                allocs_before - allocs_after,
+            );
+        }
+    }
+
     debug!("eval_body_using_ecx done: {:?}", *ret);
     Ok(ret)
 }
@@ -79,7 +219,9 @@ fn eval_body_using_ecx<'mir, 'tcx>(
 /// that inform us about the generic bounds of the constant. E.g., using an associated constant
 /// of a function's generic parameter will require knowledge about the bounds on the generic
 /// parameter. These bounds are passed to `mk_eval_cx` via the `ParamEnv` argument.
-pub(super) fn mk_eval_cx<'mir, 'tcx>(
+///
+/// Tools can use it to read the fields of constants computed by the compiler.
+pub fn mk_eval_cx<'mir, 'tcx>(
     tcx: TyCtxt<'tcx>,
     span: Span,
     param_env: ty::ParamEnv<'tcx>,
@@ -89,13 +231,14 @@ pub(super) fn mk_eval_cx<'mir, 'tcx>(
     InterpCx::new(
         tcx.at(span),
         param_env,
-        CompileTimeInterpreter::new(),
-        MemoryExtra { can_access_statics },
+        CompileTimeInterpreter::new(const_eval_stack_limit(tcx, None)),
+        MemoryExtra::new(tcx, can_access_statics, None),
     )
 }
 
-pub(super) fn op_to_const<'tcx>(
-    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+/// Turns `op`, a validated value of `ecx`, into a `ty::Const`.
+pub fn op_to_const<'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx>>(
+    ecx: &InterpCx<'mir, 'tcx, M>,
     op: OpTy<'tcx>,
 ) -> &'tcx ty::Const<'tcx> {
     // We do not have value optimizations for everything.
@@ -185,9 +328,20 @@ fn validate_and_turn_into_const<'tcx>(
         // FIXME do not validate promoteds until a decision on
         // https://github.com/rust-lang/rust/issues/67465 is made
         if cid.promoted.is_none() {
-            let mut ref_tracking = RefTracking::new(mplace);
-            while let Some((mplace, path)) = ref_tracking.todo.pop() {
-                ecx.validate_operand(mplace.into(), path, Some(&mut ref_tracking))?;
+            if tcx.sess.opts.debugging_opts.skip_const_validation {
+                // The value may be invalid, so it is only meant for debugging.
+                tcx.sess.span_warn(
+                    tcx.def_span(def_id),
+                    "the value of this item was not validated because of \
+                     `-Z skip-const-validation`",
+                );
+            } else {
+                let ctfe_mode =
+                    if is_static { CtfeValidationMode::Static } else { CtfeValidationMode::Const };
+                let mut ref_tracking = RefTracking::new(mplace);
+                while let Some((mplace, path)) = ref_tracking.todo.pop() {
+                    ecx.const_validate_operand(mplace.into(), path, &mut ref_tracking, ctfe_mode)?;
+                }
             }
         }
         // Now that we validated, turn this into a proper constant.
@@ -251,7 +405,90 @@ pub fn const_eval_validated_provider<'tcx>(
         });
     }
 
-    tcx.const_eval_raw(key).and_then(|val| validate_and_turn_into_const(tcx, val, key))
+    request_const_eval_raw(tcx.at(DUMMY_SP), key)
+        .and_then(|val| validate_and_turn_into_const(tcx, val, key))
+}
+
+/// Creates the interpreter evaluating the global `key` with `machine`.
+fn mk_global_eval_cx<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>>(
+    tcx: TyCtxt<'tcx>,
+    key: ty::ParamEnvAnd<'tcx, GlobalId<'tcx>>,
+    machine: M,
+    memory_extra: M::MemoryExtra,
+) -> InterpCx<'mir, 'tcx, M> {
+    let span = tcx.def_span(key.value.instance.def_id());
+    InterpCx::new(tcx.at(span), key.param_env, machine, memory_extra)
+}
+
+/// Evaluates the global `key` with an interpreter driven by `machine`, like the compiler does
+/// with its own machine to compute `const_eval_raw`. This lets tools that implement their own
+/// `Machine`, e.g. with different intrinsics, memory hooks or pointer tags, evaluate globals
+/// without duplicating the setup of the interpreter.
+///
+/// Returns the interpreter together with the place of the result, so that the caller can inspect
+/// its memory or turn an error into a `ConstEvalErr` with `error_to_const_error`. The result is
+/// not interned: only the machines using the memory representation of CTFE can intern it, with
+/// `intern_const_alloc_recursive`, and `eval_body_using_ecx` does both for them.
+pub fn eval_global_using_machine<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>>(
+    tcx: TyCtxt<'tcx>,
+    key: ty::ParamEnvAnd<'tcx, GlobalId<'tcx>>,
+    machine: M,
+    memory_extra: M::MemoryExtra,
+) -> (InterpCx<'mir, 'tcx, M>, InterpResult<'tcx, MPlaceTy<'tcx, M::PointerTag>>) {
+    let cid = key.value;
+    let mut ecx = mk_global_eval_cx(tcx, key, machine, memory_extra);
+    let name = ty::tls::with(|tcx| tcx.def_path_str(cid.instance.def_id()));
+    let res = ecx.load_mir(cid.instance.def, cid.promoted);
+    let res = res.and_then(|body| run_body_using_ecx(&mut ecx, cid, *body, &name));
+    (ecx, res.map(|(place, _)| place))
+}
+
+/// Writes the final allocation of the global `name` for `-Z dump-const-alloc`: its bytes to
+/// `path`, and a description of its relocations and undefined bytes to `path.relocations`.
+fn dump_const_alloc(tcx: TyCtxt<'_>, name: &str, alloc: &Allocation, path: &Path) {
+    let bytes = alloc.inspect_with_undef_and_ptr_outside_interpreter(0..alloc.len());
+    let mut desc = format!(
+        "allocation of `{}`: {} bytes, aligned to {} bytes\n",
+        name,
+        alloc.len(),
+        alloc.align.bytes()
+    );
+    desc.push_str("the bytes of a pointer hold its offset in the allocation it points to\n");
+    for &(offset, ((), alloc_id)) in alloc.relocations().iter() {
+        let target = match tcx.alloc_map.lock().get(alloc_id) {
+            Some(GlobalAlloc::Function(instance)) => format!("function `{}`", instance),
+            Some(GlobalAlloc::Static(def_id)) => format!("static `{}`", tcx.def_path_str(def_id)),
+            Some(GlobalAlloc::Memory(alloc)) => format!("an allocation of {} bytes", alloc.len()),
+            None => "a deallocated allocation".to_string(),
+        };
+        desc.push_str(&format!(
+            "relocation at offset {}: pointer to {} ({:?})\n",
+            offset.bytes(),
+            target,
+            alloc_id
+        ));
+    }
+    let is_defined = |offset: usize| alloc.undef_mask().get(Size::from_bytes(offset as u64));
+    let mut offset = 0;
+    while offset < alloc.len() {
+        let start = offset;
+        let defined = is_defined(start);
+        while offset < alloc.len() && is_defined(offset) == defined {
+            offset += 1;
+        }
+        if !defined {
+            desc.push_str(&format!("undefined bytes at offsets {}..{}\n", start, offset));
+        }
+    }
+
+    let mut relocations_path = path.as_os_str().to_owned();
+    relocations_path.push(".relocations");
+    let relocations_path = PathBuf::from(relocations_path);
+    for (path, contents) in &[(path, bytes), (&*relocations_path, desc.as_bytes())] {
+        if let Err(err) = fs::write(path, contents) {
+            tcx.sess.err(&format!("could not write `{}`: {}", path.display(), err));
+        }
+    }
 }
 
 pub fn const_eval_raw_provider<'tcx>(
@@ -269,13 +506,16 @@ pub fn const_eval_raw_provider<'tcx>(
     if key.param_env.reveal == Reveal::All {
         let mut key = key.clone();
         key.param_env.reveal = Reveal::UserFacing;
-        match tcx.const_eval_raw(key) {
+        match request_const_eval_raw(tcx.at(DUMMY_SP), key) {
             // try again with reveal all as requested
             Err(ErrorHandled::TooGeneric) => {}
             // dedupliate calls
             other => return other,
         }
     }
+    if tcx.sess.opts.debugging_opts.print_ctfe_stats {
+        tcx.sess.code_stats.record_const_eval_raw_computation();
+    }
     if cfg!(debug_assertions) {
         // Make sure we format the instance even if we do not print it.
         // This serves as a regression test against an ICE on printing.
@@ -295,88 +535,105 @@ pub fn const_eval_raw_provider<'tcx>(
 
     let is_static = tcx.is_static(def_id);
 
-    let span = tcx.def_span(cid.instance.def_id());
-    let mut ecx = InterpCx::new(
-        tcx.at(span),
-        key.param_env,
-        CompileTimeInterpreter::new(),
-        MemoryExtra { can_access_statics: is_static },
+    let mut machine = CompileTimeInterpreter::new(const_eval_stack_limit(tcx, Some(def_id)));
+    if tcx.sess.opts.debugging_opts.audit_const_target_dependence {
+        machine.target_dependence = Some(Vec::new());
+    }
+    let mut ecx = mk_global_eval_cx(
+        tcx,
+        key,
+        machine,
+        MemoryExtra::new(tcx, is_static, MemoryLimit::new(tcx, def_id)),
     );
-
     let res = ecx.load_mir(cid.instance.def, cid.promoted);
-    res.and_then(|body| eval_body_using_ecx(&mut ecx, cid, *body))
-        .and_then(|place| {
-            Ok(RawConst { alloc_id: place.ptr.assert_ptr().alloc_id, ty: place.layout.ty })
-        })
-        .map_err(|error| {
-            let err = error_to_const_error(&ecx, error);
-            // errors in statics are always emitted as fatal errors
-            if is_static {
-                // Ensure that if the above error was either `TooGeneric` or `Reported`
-                // an error must be reported.
-                let v = err.report_as_error(ecx.tcx, "could not evaluate static initializer");
-
-                // If this is `Reveal:All`, then we need to make sure an error is reported but if
-                // this is `Reveal::UserFacing`, then it's expected that we could get a
-                // `TooGeneric` error. When we fall back to `Reveal::All`, then it will either
-                // succeed or we'll report this error then.
-                if key.param_env.reveal == Reveal::All {
-                    tcx.sess.delay_span_bug(
-                        err.span,
-                        &format!("static eval failure did not emit an error: {:#?}", v),
-                    );
-                }
+    let res = res.and_then(|body| eval_body_using_ecx(&mut ecx, cid, *body));
+    res.and_then(|place| {
+        match &ecx.machine.target_dependence {
+            Some(inputs) if cid.promoted.is_none() && !inputs.is_empty() => {
+                target_dependence::report(tcx, def_id, inputs);
+            }
+            _ => {}
+        }
+        let alloc_id = place.ptr.assert_ptr().alloc_id;
+        let dumps = &tcx.sess.opts.debugging_opts.dump_const_alloc;
+        if cid.promoted.is_none() && !dumps.is_empty() {
+            let name = tcx.def_path_str(def_id);
+            let alloc = tcx.alloc_map.lock().unwrap_memory(alloc_id);
+            for (_, path) in dumps.iter().filter(|(path, _)| *path == name) {
+                dump_const_alloc(tcx, &name, alloc, path);
+            }
+        }
+        Ok(RawConst { alloc_id, ty: place.layout.ty })
+    })
+    .map_err(|error| {
+        let err = error_to_const_error(&ecx, error);
+        // errors in statics are always emitted as fatal errors
+        if is_static {
+            // Ensure that if the above error was either `TooGeneric` or `Reported`
+            // an error must be reported.
+            let v = err.report_as_error(ecx.tcx, "could not evaluate static initializer");
 
-                v
-            } else if def_id.is_local() {
-                // constant defined in this crate, we can figure out a lint level!
-                match tcx.def_kind(def_id) {
-                    // constants never produce a hard error at the definition site. Anything else is
-                    // a backwards compatibility hazard (and will break old versions of winapi for
-                    // sure)
-                    //
-                    // note that validation may still cause a hard error on this very same constant,
-                    // because any code that existed before validation could not have failed
-                    // validation thus preventing such a hard error from being a backwards
-                    // compatibility hazard
-                    Some(DefKind::Const) | Some(DefKind::AssocConst) => {
-                        let hir_id = tcx.hir().as_local_hir_id(def_id).unwrap();
-                        err.report_as_lint(
-                            tcx.at(tcx.def_span(def_id)),
-                            "any use of this value will cause an error",
-                            hir_id,
-                            Some(err.span),
-                        )
-                    }
-                    // promoting runtime code is only allowed to error if it references broken
-                    // constants any other kind of error will be reported to the user as a
-                    // deny-by-default lint
-                    _ => {
-                        if let Some(p) = cid.promoted {
-                            let span = tcx.promoted_mir(def_id)[p].span;
-                            if let err_inval!(ReferencedConstant) = err.error {
-                                err.report_as_error(
-                                    tcx.at(span),
-                                    "evaluation of constant expression failed",
-                                )
-                            } else {
-                                err.report_as_lint(
-                                    tcx.at(span),
-                                    "reaching this expression at runtime will panic or abort",
-                                    tcx.hir().as_local_hir_id(def_id).unwrap(),
-                                    Some(err.span),
-                                )
-                            }
-                        // anything else (array lengths, enum initializers, constant patterns) are
-                        // reported as hard errors
+            // If this is `Reveal:All`, then we need to make sure an error is reported but if
+            // this is `Reveal::UserFacing`, then it's expected that we could get a
+            // `TooGeneric` error. When we fall back to `Reveal::All`, then it will either
+            // succeed or we'll report this error then.
+            if key.param_env.reveal == Reveal::All {
+                tcx.sess.delay_span_bug(
+                    err.span,
+                    &format!("static eval failure did not emit an error: {:#?}", v),
+                );
+            }
+
+            v
+        } else if def_id.is_local() {
+            // constant defined in this crate, we can figure out a lint level!
+            match tcx.def_kind(def_id) {
+                // constants never produce a hard error at the definition site. Anything else is
+                // a backwards compatibility hazard (and will break old versions of winapi for
+                // sure)
+                //
+                // note that validation may still cause a hard error on this very same constant,
+                // because any code that existed before validation could not have failed
+                // validation thus preventing such a hard error from being a backwards
+                // compatibility hazard
+                Some(DefKind::Const) | Some(DefKind::AssocConst) => {
+                    let hir_id = tcx.hir().as_local_hir_id(def_id).unwrap();
+                    err.report_as_lint(
+                        tcx.at(tcx.def_span(def_id)),
+                        "any use of this value will cause an error",
+                        hir_id,
+                        Some(err.span),
+                    )
+                }
+                // promoting runtime code is only allowed to error if it references broken
+                // constants any other kind of error will be reported to the user as a
+                // deny-by-default lint
+                _ => {
+                    if let Some(p) = cid.promoted {
+                        let span = tcx.promoted_mir(def_id)[p].span;
+                        if let err_inval!(ReferencedConstant) = err.error {
+                            err.report_as_error(
+                                tcx.at(span),
+                                "evaluation of constant expression failed",
+                            )
                         } else {
-                            err.report_as_error(ecx.tcx, "evaluation of constant value failed")
+                            err.report_as_lint(
+                                tcx.at(span),
+                                "reaching this expression at runtime will panic or abort",
+                                tcx.hir().as_local_hir_id(def_id).unwrap(),
+                                Some(err.span),
+                            )
                         }
+                    // anything else (array lengths, enum initializers, constant patterns) are
+                    // reported as hard errors
+                    } else {
+                        err.report_as_error(ecx.tcx, "evaluation of constant value failed")
                     }
                 }
-            } else {
-                // use of broken constant from other crate
-                err.report_as_error(ecx.tcx, "could not evaluate constant")
             }
-        })
+        } else {
+            // use of broken constant from other crate
+            err.report_as_error(ecx.tcx, "could not evaluate constant")
+        }
+    })
 }
@@ -94,8 +94,21 @@ pub fn provide(providers: &mut Providers<'_>) {
         }
     }
 
-    /// Checks whether the function has a `const` modifier or, in case it is an intrinsic, whether
-    /// said intrinsic is on the whitelist for being const callable.
+    /// Checks whether the function is a method of an `impl const Trait for Foo` block.
+    fn is_const_impl_method(tcx: TyCtxt<'_>, hir_id: hir::HirId) -> bool {
+        let parent_id = tcx.hir().get_parent_item(hir_id);
+        match tcx.hir().get(parent_id) {
+            hir::Node::Item(hir::Item {
+                kind: hir::ItemKind::Impl { constness: hir::Constness::Const, .. },
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks whether the function has a `const` modifier (or is a method of a `const` trait
+    /// impl) or, in case it is an intrinsic, whether said intrinsic is on the whitelist for
+    /// being const callable.
     fn is_const_fn_raw(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
         let hir_id = tcx
             .hir()
@@ -107,7 +120,7 @@ pub fn provide(providers: &mut Providers<'_>) {
         if let Some(whitelisted) = is_const_intrinsic(tcx, def_id) {
             whitelisted
         } else if let Some(fn_like) = FnLikeNode::from_node(node) {
-            fn_like.constness() == hir::Constness::Const
+            fn_like.constness() == hir::Constness::Const || is_const_impl_method(tcx, hir_id)
         } else if let hir::Node::Ctor(_) = node {
             true
         } else {
@@ -1,5 +1,5 @@
 use rustc::mir;
-use rustc::ty::layout::HasTyCtxt;
+use rustc::ty::layout::{Align, HasTyCtxt, LayoutOf, Size};
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc_hir::def_id::DefId;
 use std::borrow::{Borrow, Cow};
@@ -9,13 +9,16 @@ use std::hash::Hash;
 use rustc_data_structures::fx::FxHashMap;
 
 use rustc_span::source_map::Span;
+use rustc_span::symbol::sym;
 
 use crate::interpret::{
-    self, snapshot, AllocId, Allocation, AssertMessage, GlobalId, ImmTy, InterpCx, InterpResult,
-    Memory, MemoryKind, OpTy, PlaceTy, Pointer, Scalar,
+    self, snapshot, AllocCheck, AllocId, AllocOrigin, Allocation, AssertMessage, GlobalId, ImmTy,
+    InterpCx, InterpResult, Memory, OpTy, PlaceTy, Pointer, Scalar,
 };
 
+use super::emulated_fns::EmulatedFns;
 use super::error::*;
+use super::target_dependence;
 
 impl<'mir, 'tcx> InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>> {
     /// Evaluate a const function where all arguments (if any) are zero-sized types.
@@ -34,6 +37,11 @@ impl<'mir, 'tcx> InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>> {
         if instance.def.requires_caller_location(self.tcx()) {
             return Ok(false);
         }
+        // The inputs that a memoized call observes would be recorded by the machine of its own
+        // evaluation, so audited evaluations interpret the call instead.
+        if self.machine.target_dependence.is_some() {
+            return Ok(false);
+        }
         // For the moment we only do this for functions which take no arguments
         // (or all arguments are ZSTs) so that we don't memoize too much.
         if args.iter().any(|a| !a.layout.is_zst()) {
@@ -56,6 +64,186 @@ impl<'mir, 'tcx> InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>> {
         self.dump_place(*dest);
         return Ok(true);
     }
+
+    /// Evaluates the intrinsics that only make sense during CTFE, i.e. the ones managing the
+    /// compile-time heap and comparing pointers. Returns `true` if the intrinsic was handled.
+    fn emulate_ctfe_intrinsic(
+        &mut self,
+        instance: ty::Instance<'tcx>,
+        args: &[OpTy<'tcx>],
+        ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
+    ) -> InterpResult<'tcx, bool> {
+        let intrinsic_name = self.tcx.item_name(instance.def_id());
+        let (dest, ret) = match ret {
+            Some(p) => p,
+            None => return Ok(false),
+        };
+
+        match intrinsic_name {
+            sym::const_allocate => {
+                let size = self.read_scalar(args[0])?.to_machine_usize(self)?;
+                let align = self.read_scalar(args[1])?.to_machine_usize(self)?;
+                let align = match Align::from_bytes(align) {
+                    Ok(align) => align,
+                    Err(_) => throw_unsup!(HeapAllocNonPowerOfTwoAlignment(align)),
+                };
+
+                let ptr = self.allocate_heap(Size::from_bytes(size), align)?;
+                self.write_scalar(Scalar::Ptr(ptr), dest)?;
+            }
+            sym::const_deallocate => {
+                let ptr = self.read_scalar(args[0])?.not_undef()?;
+                let size = self.read_scalar(args[1])?.to_machine_usize(self)?;
+                let align = self.read_scalar(args[2])?.to_machine_usize(self)?;
+                let align = match Align::from_bytes(align) {
+                    Ok(align) => align,
+                    Err(_) => throw_unsup!(HeapAllocNonPowerOfTwoAlignment(align)),
+                };
+
+                let ptr = self.force_ptr(ptr)?;
+                // Memory of other constants has already been interned. Their heap allocations
+                // are shared by all users of that constant, so we must not touch them.
+                if self.tcx.alloc_map.lock().get(ptr.alloc_id).is_none() {
+                    self.memory.deallocate(
+                        ptr,
+                        Some((Size::from_bytes(size), align)),
+                        interpret::MemoryKind::Machine(MemoryKind::Heap),
+                    )?;
+                }
+            }
+            sym::ptr_guaranteed_cmp => {
+                let a = self.read_scalar(args[0])?.not_undef()?;
+                let b = self.read_scalar(args[1])?.not_undef()?;
+                let cmp = self.guaranteed_cmp(a, b);
+                self.write_scalar(Scalar::from_u8(cmp), dest)?;
+            }
+            _ => return Ok(false),
+        }
+
+        self.dump_place(*dest);
+        self.go_to_block(ret);
+        Ok(true)
+    }
+
+    /// Allocates memory on the compile-time heap, for `const_allocate` and `box` expressions.
+    fn allocate_heap(&mut self, size: Size, align: Align) -> InterpResult<'tcx, Pointer> {
+        let kind = interpret::MemoryKind::Machine(MemoryKind::Heap);
+        let ptr = self.memory.allocate(size, align, kind)?;
+        let frame = self.frame();
+        let span = frame.current_source_info().map_or(frame.span, |info| info.span);
+        let instance = if self.stack().len() > 1 { Some(frame.instance) } else { None };
+        let origin = AllocOrigin { span, instance, is_heap: true };
+        self.machine.alloc_origins.insert(ptr.alloc_id, origin);
+        Ok(ptr)
+    }
+
+    /// Compares two pointers the way `ptr_guaranteed_cmp` does: returns `1` if they are equal
+    /// at runtime, `0` if they are unequal at runtime, and `2` if the result is not known
+    /// during CTFE.
+    fn guaranteed_cmp(&self, a: Scalar, b: Scalar) -> u8 {
+        match (a, b) {
+            // Comparisons between integers are always known.
+            (Scalar::Raw { .. }, Scalar::Raw { .. }) => (a == b) as u8,
+            // Comparisons of abstract pointers with null pointers are known if the pointer
+            // is in bounds, because an in-bounds pointer can't be null.
+            (Scalar::Raw { data: 0, .. }, Scalar::Ptr(ptr))
+            | (Scalar::Ptr(ptr), Scalar::Raw { data: 0, .. })
+                if !self.memory.ptr_may_be_null(ptr) =>
+            {
+                0
+            }
+            // Equality with other integers can never be known for sure.
+            (Scalar::Raw { .. }, Scalar::Ptr(_)) | (Scalar::Ptr(_), Scalar::Raw { .. }) => 2,
+            // FIXME: return `1` for identical pointers, except that functions and vtables do
+            // not have stable addresses, and `0` for pointers into different allocations
+            // whose addresses can be told apart, such as separate statics.
+            (Scalar::Ptr(_), Scalar::Ptr(_)) => 2,
+        }
+    }
+
+    /// Evaluates calls to the `align_offset` lang item without looking at its body, which needs
+    /// the address of the pointer. Returns `true` if the call has been evaluated.
+    fn hook_align_offset(
+        &mut self,
+        instance: ty::Instance<'tcx>,
+        args: &[OpTy<'tcx>],
+        ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
+    ) -> InterpResult<'tcx, bool> {
+        if Some(instance.def_id()) != self.tcx.lang_items().align_offset_fn() {
+            return Ok(false);
+        }
+        let (dest, ret) = match ret {
+            Some(p) => p,
+            None => return Ok(false),
+        };
+
+        let ptr = self.read_scalar(args[0])?.not_undef()?;
+        let align = self.read_scalar(args[1])?.to_machine_usize(self)?;
+        if !align.is_power_of_two() {
+            throw_ub_format!("`align_offset` called with non-power-of-two align {}", align);
+        }
+        let stride = self.layout_of(instance.substs.type_at(0))?.size.bytes();
+
+        let offset = match self.align_offset(ptr, stride, align)? {
+            Some(offset) => u128::from(offset),
+            // `usize::max_value()` signals that the pointer cannot be aligned.
+            None => self.truncate(u128::max_value(), dest.layout),
+        };
+        self.write_scalar(Scalar::from_uint(offset, dest.layout.size), dest)?;
+
+        self.dump_place(*dest);
+        self.go_to_block(ret);
+        Ok(true)
+    }
+
+    /// Computes the number of elements of size `stride` that `ptr` needs to be offset by to be
+    /// aligned to `align`, or `None` if that is not possible or not known during CTFE. The
+    /// address of an allocation is not known, only its alignment, so pointers into allocations
+    /// can only be aligned to at most that alignment.
+    fn align_offset(
+        &self,
+        ptr: Scalar,
+        stride: u64,
+        align: u64,
+    ) -> InterpResult<'tcx, Option<u64>> {
+        let addr = match ptr {
+            Scalar::Raw { .. } => ptr.to_machine_usize(self)?,
+            Scalar::Ptr(ptr) => {
+                let (_size, alloc_align) =
+                    self.memory.get_size_and_align(ptr.alloc_id, AllocCheck::MaybeDead)?;
+                if align > alloc_align.bytes() {
+                    return Ok(None);
+                }
+                // The base address is a multiple of `align`, so only the offset matters.
+                ptr.offset.bytes()
+            }
+        };
+
+        let addr_mod_align = addr & (align - 1);
+        if addr_mod_align == 0 {
+            return Ok(Some(0));
+        }
+        if stride == 0 {
+            return Ok(None);
+        }
+
+        // Solve `addr + stride * offset ≡ 0 (mod align)`, which only has solutions if the
+        // greatest common divisor of `stride` and `align` divides `addr`.
+        let gcd_pow = stride.trailing_zeros().min(align.trailing_zeros());
+        if addr & ((1 << gcd_pow) - 1) != 0 {
+            return Ok(None);
+        }
+        let modulus = align >> gcd_pow;
+        // `stride >> gcd_pow` is odd, so it has an inverse modulo `modulus`.
+        // Each step of Newton's iteration doubles the number of correct low bits.
+        let x = stride >> gcd_pow;
+        let mut inverse = 1u64;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(inverse)));
+        }
+        let distance = (align - addr_mod_align) >> gcd_pow;
+        Ok(Some(distance.wrapping_mul(inverse) & (modulus - 1)))
+    }
 }
 
 /// Number of steps until the detector even starts doing anything.
@@ -75,21 +263,117 @@ pub struct CompileTimeInterpreter<'mir, 'tcx> {
 
     /// Extra state to detect loops.
     pub(super) loop_detector: snapshot::InfiniteLoopDetector<'mir, 'tcx>,
+
+    /// Where the local and heap allocations of the evaluation were created, to point at them
+    /// when a pointer to them remains in the final value after they were deallocated.
+    pub(super) alloc_origins: FxHashMap<AllocId, AllocOrigin<'tcx>>,
+
+    /// The maximum number of stack frames of the evaluation.
+    pub(super) stack_limit: usize,
+
+    /// The target-specific inputs that the evaluation observed, and where, if it is audited with
+    /// `-Z audit-const-target-dependence`.
+    pub(super) target_dependence: Option<Vec<(Span, String)>>,
+
+    /// The foreign functions that the evaluation emulates instead of calling them.
+    pub(super) emulated_fns: EmulatedFns,
+}
+
+/// Extra memory kinds of the CTFE machine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MemoryKind {
+    /// Memory allocated by the `const_allocate` intrinsic. Error if deallocated with any other
+    /// kind, but may remain live at the end of evaluation, in which case it gets interned
+    /// together with the constant's value.
+    Heap,
+}
+
+impl interpret::MayLeak for MemoryKind {
+    #[inline(always)]
+    fn may_leak(self) -> bool {
+        match self {
+            // Heap allocations that are not deallocated become part of the final value.
+            MemoryKind::Heap => false,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
 pub struct MemoryExtra {
     /// Whether this machine may read from statics
     pub(super) can_access_statics: bool,
+    /// The limit on the memory the evaluation may use, if any.
+    pub(super) memory_limit: Option<MemoryLimit>,
+    /// The number of bytes in all live allocations created by the evaluation.
+    pub(super) allocated_bytes: u64,
+    /// Whether to check the alignment of every memory access and the validity of every typed
+    /// copy, as requested with `-Z extra-const-ub-checks`.
+    pub(super) extra_ub_checks: bool,
+    /// Whether to reject casts between pointers and integers, as requested with
+    /// `-Z const-strict-provenance`.
+    pub(super) strict_provenance: bool,
+}
+
+impl MemoryExtra {
+    pub(super) fn new(
+        tcx: TyCtxt<'_>,
+        can_access_statics: bool,
+        memory_limit: Option<MemoryLimit>,
+    ) -> Self {
+        let extra_ub_checks = tcx.sess.opts.debugging_opts.extra_const_ub_checks;
+        let strict_provenance = tcx.sess.opts.debugging_opts.const_strict_provenance;
+        MemoryExtra {
+            can_access_statics,
+            memory_limit,
+            allocated_bytes: 0,
+            extra_ub_checks,
+            strict_provenance,
+        }
+    }
+}
+
+/// The maximum number of bytes the evaluation of a constant may have allocated at the same
+/// time, unless configured otherwise with `-Z const-eval-memory-limit`.
+const DEFAULT_MEMORY_LIMIT: u64 = 4 << 30;
+
+/// A limit on the number of bytes the evaluation of a constant may have allocated at the same
+/// time, so that runaway evaluations fail instead of exhausting the memory of the compiler.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct MemoryLimit {
+    /// The item being evaluated, for diagnostics.
+    def_id: DefId,
+    bytes: u64,
+}
+
+impl MemoryLimit {
+    /// The limit for evaluating `def_id`, or `None` if `-Z const-eval-memory-limit=0` disables
+    /// the limit.
+    pub(super) fn new(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Self> {
+        let bytes = match tcx.sess.opts.debugging_opts.const_eval_memory_limit {
+            Some(bytes) => bytes as u64,
+            None => DEFAULT_MEMORY_LIMIT,
+        };
+        if bytes == 0 { None } else { Some(MemoryLimit { def_id, bytes }) }
+    }
 }
 
 impl<'mir, 'tcx> CompileTimeInterpreter<'mir, 'tcx> {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(stack_limit: usize) -> Self {
         CompileTimeInterpreter {
             loop_detector: Default::default(),
             steps_since_detector_enabled: -STEPS_UNTIL_DETECTOR_ENABLED,
+            alloc_origins: Default::default(),
+            stack_limit,
+            target_dependence: None,
+            emulated_fns: EmulatedFns::default(),
         }
     }
+
+    /// Returns the registry of the foreign functions that the evaluation emulates, so that custom
+    /// drivers can emulate more of them.
+    pub fn emulated_fns_mut(&mut self) -> &mut EmulatedFns {
+        &mut self.emulated_fns
+    }
 }
 
 impl<K: Hash + Eq, V> interpret::AllocMap<K, V> for FxHashMap<K, V> {
@@ -154,7 +438,7 @@ impl interpret::MayLeak for ! {
 }
 
 impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir, 'tcx> {
-    type MemoryKinds = !;
+    type MemoryKinds = MemoryKind;
     type PointerTag = ();
     type ExtraFnVal = !;
 
@@ -162,17 +446,28 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
     type MemoryExtra = MemoryExtra;
     type AllocExtra = ();
 
-    type MemoryMap = FxHashMap<AllocId, (MemoryKind<!>, Allocation)>;
+    type MemoryMap = FxHashMap<AllocId, (interpret::MemoryKind<MemoryKind>, Allocation)>;
 
-    const STATIC_KIND: Option<!> = None; // no copying of statics allowed
+    const STATIC_KIND: Option<MemoryKind> = None; // no copying of statics allowed
 
     // We do not check for alignment to avoid having to carry an `Align`
-    // in `ConstValue::ByRef`.
+    // in `ConstValue::ByRef`, unless `-Z extra-const-ub-checks` asks for it.
     const CHECK_ALIGN: bool = false;
 
     #[inline(always)]
-    fn enforce_validity(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
-        false // for now, we don't enforce validity
+    fn enforce_alignment(memory_extra: &MemoryExtra) -> bool {
+        memory_extra.extra_ub_checks
+    }
+
+    #[inline(always)]
+    fn enforce_strict_provenance(memory_extra: &MemoryExtra) -> bool {
+        memory_extra.strict_provenance
+    }
+
+    #[inline(always)]
+    fn enforce_validity(ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
+        // For now, we only enforce validity with `-Z extra-const-ub-checks`.
+        ecx.memory.extra.extra_ub_checks
     }
 
     fn find_mir_or_eval_fn(
@@ -187,6 +482,20 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
 
         // Only check non-glue functions
         if let ty::InstanceDef::Item(def_id) = instance.def {
+            // `align_offset` is a `const fn`, but its body cannot be evaluated.
+            if ecx.hook_align_offset(instance, args, ret)? {
+                return Ok(None);
+            }
+            // Some foreign functions have no body, but are emulated.
+            if let Some(emulate) = ecx.machine.emulated_fns.get(ecx.tcx.tcx, def_id) {
+                let (dest, ret) = match ret {
+                    Some(p) => p,
+                    None => throw_unsup_format!("calling diverging function `{}`", instance),
+                };
+                emulate(ecx, args, dest)?;
+                ecx.return_to_block(Some(ret))?;
+                return Ok(None);
+            }
             // Execution might have wandered off into other crates, so we cannot do a stability-
             // sensitive check here.  But we can at least rule out functions that are not const
             // at all.
@@ -241,9 +550,15 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
         ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
         _unwind: Option<mir::BasicBlock>,
     ) -> InterpResult<'tcx> {
+        if ecx.machine.target_dependence.is_some() {
+            target_dependence::record_intrinsic(ecx, instance);
+        }
         if ecx.emulate_intrinsic(span, instance, args, ret)? {
             return Ok(());
         }
+        if ecx.emulate_ctfe_intrinsic(instance, args, ret)? {
+            return Ok(());
+        }
         // An intrinsic that we do not support
         let intrinsic_name = ecx.tcx.item_name(instance.def_id());
         Err(ConstEvalError::NeedsRfc(format!("calling intrinsic `{}`", intrinsic_name)).into())
@@ -282,7 +597,10 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
     }
 
     fn ptr_to_int(_mem: &Memory<'mir, 'tcx, Self>, _ptr: Pointer) -> InterpResult<'tcx, u64> {
-        Err(ConstEvalError::NeedsRfc("pointer-to-integer cast".to_string()).into())
+        // The address of an allocation is only picked by codegen, so the bytes of a pointer are
+        // not known yet. This is also hit when a pointer was transmuted to an integer type and
+        // that integer is then used like one.
+        throw_unsup!(ReadPointerAsBytes)
     }
 
     fn binary_ptr_op(
@@ -301,12 +619,47 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
         throw_unsup!(ReadForeignStatic)
     }
 
+    fn after_local_allocated(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        frame: usize,
+        local: mir::Local,
+        id: AllocId,
+    ) {
+        let frame_data = &ecx.stack()[frame];
+        let span = frame_data.body.local_decls[local].source_info.span;
+        let instance = if frame > 0 { Some(frame_data.instance) } else { None };
+        ecx.machine.alloc_origins.insert(id, AllocOrigin { span, instance, is_heap: false });
+    }
+
+    fn alloc_origin(ecx: &InterpCx<'mir, 'tcx, Self>, id: AllocId) -> Option<AllocOrigin<'tcx>> {
+        ecx.machine.alloc_origins.get(&id).cloned()
+    }
+
+    fn before_memory_allocation(memory_extra: &mut MemoryExtra, size: Size) -> InterpResult<'tcx> {
+        let allocated_bytes = memory_extra.allocated_bytes.saturating_add(size.bytes());
+        if let Some(limit) = memory_extra.memory_limit {
+            if allocated_bytes > limit.bytes {
+                let name = ty::tls::with(|tcx| tcx.def_path_str(limit.def_id));
+                throw_exhaust!(MemoryLimitReached { name, limit: limit.bytes })
+            }
+        }
+        memory_extra.allocated_bytes = allocated_bytes;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn after_memory_deallocation(memory_extra: &mut MemoryExtra, size: Size) {
+        // Allocations that were not created by `before_memory_allocation`, like copies of
+        // statics, are not counted.
+        memory_extra.allocated_bytes = memory_extra.allocated_bytes.saturating_sub(size.bytes());
+    }
+
     #[inline(always)]
     fn init_allocation_extra<'b>(
         _memory_extra: &MemoryExtra,
         _id: AllocId,
         alloc: Cow<'b, Allocation>,
-        _kind: Option<MemoryKind<!>>,
+        _kind: Option<interpret::MemoryKind<MemoryKind>>,
     ) -> (Cow<'b, Allocation<Self::PointerTag>>, Self::PointerTag) {
         // We do not use a tag so we can just cheaply forward the allocation
         (alloc, ())
@@ -317,11 +670,13 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
         ()
     }
 
-    fn box_alloc(
-        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
-        _dest: PlaceTy<'tcx>,
-    ) -> InterpResult<'tcx> {
-        Err(ConstEvalError::NeedsRfc("heap allocations via `box` keyword".to_string()).into())
+    fn box_alloc(ecx: &mut InterpCx<'mir, 'tcx, Self>, dest: PlaceTy<'tcx>) -> InterpResult<'tcx> {
+        // `box` expressions are only accepted in constants with `#![feature(const_heap)]`.
+        // Their memory is on the compile-time heap, so that `Box::leak` can turn it into
+        // `'static` memory of the final value, like the memory of `const_allocate`.
+        let layout = ecx.layout_of(dest.layout.ty.boxed_ty())?;
+        let ptr = ecx.allocate_heap(layout.size, layout.align.abi)?;
+        ecx.write_scalar(Scalar::Ptr(ptr), dest)
     }
 
     fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
@@ -343,6 +698,11 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
         ecx.machine.loop_detector.observe_and_analyze(*ecx.tcx, span, &ecx.memory, &ecx.stack[..])
     }
 
+    #[inline(always)]
+    fn stack_limit(ecx: &InterpCx<'mir, 'tcx, Self>) -> usize {
+        ecx.machine.stack_limit
+    }
+
     #[inline(always)]
     fn stack_push(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
         Ok(())
@@ -0,0 +1,155 @@
+//! The target-specific inputs that the evaluation of a constant observed, recorded for
+//! `-Z audit-const-target-dependence` to find the constants whose value may differ between the
+//! host and the target of a cross-compilation.
+//!
+//! Only what the interpreter sees is recorded: the size and alignment of the types that depend
+//! on the pointer width or on the alignment of the wide scalars, and the transmutes whose result
+//! depends on the byte order. Items selected with `#[cfg(target_...)]` are resolved before
+//! evaluation, so they are not visible here.
+
+use rustc::lint::builtin::TARGET_DEPENDENT_CONST;
+use rustc::ty::layout::LayoutOf;
+use rustc::ty::{self, Instance, Ty, TyCtxt};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_span::symbol::sym;
+use rustc_span::Span;
+use syntax::ast;
+
+use super::CompileTimeEvalContext;
+
+/// Records that the evaluation called the intrinsic `instance`, if its result depends on the
+/// target. The input is attributed to the statement of the constant's own body being evaluated,
+/// since that is the part of the program that the user can act on.
+crate fn record_intrinsic<'tcx>(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    instance: Instance<'tcx>,
+) {
+    let tcx = ecx.tcx.tcx;
+    let substs = instance.substs;
+    let input = match tcx.item_name(instance.def_id()) {
+        sym::size_of | sym::size_of_val
+            if layout_depends_on_target(tcx, substs.type_at(0), true) =>
+        {
+            format!("the size of `{}`", substs.type_at(0))
+        }
+        sym::min_align_of | sym::min_align_of_val
+            if layout_depends_on_target(tcx, substs.type_at(0), false) =>
+        {
+            format!("the alignment of `{}`", substs.type_at(0))
+        }
+        sym::pref_align_of => format!("the preferred alignment of `{}`", substs.type_at(0)),
+        sym::transmute if depends_on_byte_order(ecx, substs.type_at(0), substs.type_at(1)) => {
+            format!(
+                "the byte order, by transmuting `{}` to `{}`",
+                substs.type_at(0),
+                substs.type_at(1)
+            )
+        }
+        _ => return,
+    };
+    let span = ecx.stack().first().map_or(ecx.tcx.span, |frame| {
+        frame.current_source_info().map_or(frame.span, |info| info.span)
+    });
+    if let Some(inputs) = &mut ecx.machine.target_dependence {
+        if !inputs.iter().any(|(s, i)| *s == span && *i == input) {
+            inputs.push((span, input));
+        }
+    }
+}
+
+/// Whether the layout of `ty` differs between targets: for its size if `is_size`, and for its
+/// alignment otherwise.
+fn layout_depends_on_target<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, is_size: bool) -> bool {
+    fn visit<'tcx>(
+        tcx: TyCtxt<'tcx>,
+        ty: Ty<'tcx>,
+        is_size: bool,
+        visited: &mut FxHashSet<Ty<'tcx>>,
+    ) -> bool {
+        if !visited.insert(ty) {
+            return false;
+        }
+        match ty.kind {
+            ty::Int(ast::IntTy::Isize)
+            | ty::Uint(ast::UintTy::Usize)
+            | ty::RawPtr(_)
+            | ty::Ref(..)
+            | ty::FnPtr(_) => true,
+            // The size of the wide scalars is the same everywhere, but their alignment is not,
+            // which changes the padding of the aggregates that contain them.
+            ty::Int(ast::IntTy::I64)
+            | ty::Int(ast::IntTy::I128)
+            | ty::Uint(ast::UintTy::U64)
+            | ty::Uint(ast::UintTy::U128)
+            | ty::Float(ast::FloatTy::F64) => !is_size,
+            ty::Array(elem, _) | ty::Slice(elem) => visit(tcx, elem, is_size, visited),
+            ty::Tuple(_) => ty.tuple_fields().any(|field| visit(tcx, field, false, visited)),
+            ty::Adt(def, _) if def.is_box() => true,
+            ty::Adt(def, substs) => {
+                def.all_fields().any(|field| visit(tcx, field.ty(tcx, substs), false, visited))
+            }
+            _ => false,
+        }
+    }
+    visit(tcx, ty, is_size, &mut FxHashSet::default())
+}
+
+/// Whether transmuting `from` to `to` reinterprets the bytes of a scalar as scalars of another
+/// size, whose values then depend on the byte order.
+fn depends_on_byte_order<'tcx>(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    from: Ty<'tcx>,
+    to: Ty<'tcx>,
+) -> bool {
+    let from_scalar = widest_scalar(ecx, from, &mut FxHashSet::default());
+    let to_scalar = widest_scalar(ecx, to, &mut FxHashSet::default());
+    from_scalar != to_scalar && from_scalar.max(to_scalar).map_or(false, |size| size > 1)
+}
+
+/// The size of the widest scalar in `ty`, if it has any.
+fn widest_scalar<'tcx>(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    ty: Ty<'tcx>,
+    visited: &mut FxHashSet<Ty<'tcx>>,
+) -> Option<u64> {
+    if !visited.insert(ty) {
+        return None;
+    }
+    let tcx = ecx.tcx.tcx;
+    match ty.kind {
+        _ if ty.is_scalar() || ty.is_unsafe_ptr() || ty.is_region_ptr() => {
+            ecx.layout_of(ty).ok().map(|layout| layout.size.bytes())
+        }
+        ty::Array(elem, _) | ty::Slice(elem) => widest_scalar(ecx, elem, visited),
+        ty::Tuple(_) => {
+            ty.tuple_fields().filter_map(|field| widest_scalar(ecx, field, visited)).max()
+        }
+        ty::Adt(def, substs) => def
+            .all_fields()
+            .filter_map(|field| widest_scalar(ecx, field.ty(tcx, substs), visited))
+            .max(),
+        _ => None,
+    }
+}
+
+/// Lints the constant `def_id` of the local crate, whose evaluation observed the
+/// target-specific `inputs`.
+crate fn report(tcx: TyCtxt<'_>, def_id: DefId, inputs: &[(Span, String)]) {
+    let hir_id = match tcx.hir().as_local_hir_id(def_id) {
+        Some(hir_id) => hir_id,
+        None => return,
+    };
+    match tcx.def_kind(def_id) {
+        Some(DefKind::Const) | Some(DefKind::AssocConst) | Some(DefKind::Static) => {}
+        _ => return,
+    }
+    let msg = format!("the value of `{}` may depend on the target", tcx.def_path_str(def_id));
+    let span = tcx.def_span(def_id);
+    let mut lint = tcx.struct_span_lint_hir(TARGET_DEPENDENT_CONST, hir_id, span, &msg);
+    for (span, input) in inputs {
+        lint.span_note(*span, &format!("the evaluation uses {}", input));
+    }
+    lint.emit();
+}
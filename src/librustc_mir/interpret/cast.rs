@@ -80,12 +80,8 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                             throw_inval!(TooGeneric);
                         }
 
-                        let instance = ty::Instance::resolve_closure(
-                            *self.tcx,
-                            def_id,
-                            substs,
-                            ty::ClosureKind::FnOnce,
-                        );
+                        let instance =
+                            ty::Instance::resolve_closure_for_fn_ptr(*self.tcx, def_id, substs);
                         let fn_ptr = self.memory.create_fn_alloc(FnVal::Instance(instance));
                         self.write_scalar(fn_ptr, dest)?;
                     }
@@ -166,11 +162,28 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
             return Ok(*src);
         }
 
+        if src.layout.ty.is_any_ptr() && M::enforce_strict_provenance(&self.memory.extra) {
+            throw_unsup_format!(
+                "casting a pointer to an integer exposes its provenance, which is not allowed \
+                 with `-Z const-strict-provenance`"
+            );
+        }
+
         // For all remaining casts, we either
         // (a) cast a raw ptr to usize, or
         // (b) cast from an integer-like (including bool, char, enums).
         // In both cases we want the bits.
         let bits = self.force_bits(src.to_scalar()?, src.layout.size)?;
+        if dest_layout.ty.is_unsafe_ptr()
+            && bits != 0
+            && M::enforce_strict_provenance(&self.memory.extra)
+        {
+            throw_unsup_format!(
+                "casting the integer `{}` to a pointer creates a pointer without provenance, \
+                 which is not allowed with `-Z const-strict-provenance`",
+                bits
+            );
+        }
         Ok(self.cast_from_int(bits, src.layout, dest_layout)?.into())
     }
 
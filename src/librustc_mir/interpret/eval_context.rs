@@ -418,23 +418,12 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 );
 
                 // Recurse to get the size of the dynamically sized field (must be
-                // the last field).  Can't have foreign types here, how would we
-                // adjust alignment and size for them?
+                // the last field).
                 let field = layout.field(self, layout.fields.count() - 1)?;
                 let (unsized_size, unsized_align) = match self.size_and_align_of(metadata, field)? {
                     Some(size_and_align) => size_and_align,
-                    None => {
-                        // A field with extern type.  If this field is at offset 0, we behave
-                        // like the underlying extern type.
-                        // FIXME: Once we have made decisions for how to handle size and alignment
-                        // of `extern type`, this should be adapted.  It is just a temporary hack
-                        // to get some code to work that probably ought to work.
-                        if sized_size == Size::ZERO {
-                            return Ok(None);
-                        } else {
-                            bug!("Fields cannot be extern types, unless they are at offset 0")
-                        }
-                    }
+                    // A type that ends in an extern type has no known size or alignment either.
+                    None => return Ok(None),
                 };
 
                 // FIXME (#26403, #27023): We should be adding padding
@@ -559,8 +548,9 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
 
         info!("ENTERING({}) {}", self.cur_frame(), self.frame().instance);
 
-        if self.stack.len() > *self.tcx.sess.recursion_limit.get() {
-            throw_exhaust!(StackFrameLimitReached)
+        let limit = M::stack_limit(self);
+        if self.stack.len() > limit {
+            throw_exhaust!(StackFrameLimitReached { limit })
         } else {
             Ok(())
         }
@@ -798,7 +788,7 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         // Our result will later be validated anyway, and there seems no good reason
         // to have to fail early here.  This is also more consistent with
         // `Memory::get_static_alloc` which has to use `const_eval_raw` to avoid cycles.
-        let val = self.tcx.const_eval_raw(param_env.and(gid))?;
+        let val = crate::const_eval::request_const_eval_raw(self.tcx, param_env.and(gid))?;
         self.raw_const_to_mplace(val)
     }
 
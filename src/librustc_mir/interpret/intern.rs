@@ -5,23 +5,26 @@
 
 use super::validity::RefTracking;
 use rustc::mir::interpret::{ErrorHandled, InterpResult};
-use rustc::ty::{self, Ty};
+use rustc::ty::{self, Ty, TyCtxt};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir as hir;
 
 use syntax::ast::Mutability;
 
-use super::{AllocId, Allocation, InterpCx, MPlaceTy, Machine, MemoryKind, Scalar, ValueVisitor};
+use super::{
+    AllocId, Allocation, GlobalAlloc, InterpCx, MPlaceTy, Machine, MemoryKind, Scalar, ValueVisitor,
+};
+use crate::const_eval;
 
 pub trait CompileTimeMachine<'mir, 'tcx> = Machine<
     'mir,
     'tcx,
-    MemoryKinds = !,
+    MemoryKinds = const_eval::MemoryKind,
     PointerTag = (),
     ExtraFnVal = !,
     FrameExtra = (),
     AllocExtra = (),
-    MemoryMap = FxHashMap<AllocId, (MemoryKind<!>, Allocation)>,
+    MemoryMap = FxHashMap<AllocId, (MemoryKind<const_eval::MemoryKind>, Allocation)>,
 >;
 
 struct InternVisitor<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx>> {
@@ -101,7 +104,10 @@ fn intern_shallow<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx>>(
     // This match is just a canary for future changes to `MemoryKind`, which most likely need
     // changes in this function.
     match kind {
-        MemoryKind::Stack | MemoryKind::Vtable | MemoryKind::CallerLocation => {}
+        MemoryKind::Stack
+        | MemoryKind::Machine(const_eval::MemoryKind::Heap)
+        | MemoryKind::Vtable
+        | MemoryKind::CallerLocation => {}
     }
     // Set allocation mutability as appropriate. This is used by LLVM to put things into
     // read-only memory, and also by Miri when evluating other constants/statics that
@@ -219,25 +225,14 @@ impl<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx>> ValueVisitor<'mir, 'tcx
                 // and we check that in `visit_aggregate`.
                 // This is not an inherent limitation, but one that we know to be true, because
                 // const qualification enforces it. We can lift it in the future.
-                match (self.mode, mutability) {
-                    // immutable references are fine everywhere
-                    (_, hir::Mutability::Not) => {}
-                    // all is "good and well" in the unsoundness of `static mut`
-
-                    // mutable references are ok in `static`. Either they are treated as immutable
-                    // because they are behind an immutable one, or they are behind an `UnsafeCell`
-                    // and thus ok.
-                    (InternMode::Static, hir::Mutability::Mut) => {}
-                    // we statically prevent `&mut T` via `const_qualif` and double check this here
-                    (InternMode::ConstBase, hir::Mutability::Mut)
-                    | (InternMode::Const, hir::Mutability::Mut) => match referenced_ty.kind {
-                        ty::Array(_, n)
-                            if n.eval_usize(self.ecx.tcx.tcx, self.ecx.param_env) == 0 => {}
-                        ty::Slice(_)
-                            if mplace.meta.unwrap_meta().to_machine_usize(self.ecx)? == 0 => {}
-                        _ => bug!("const qualif failed to prevent mutable references"),
-                    },
-                }
+                // Mutable references are ok in `static`. Either they are treated as immutable
+                // because they are behind an immutable one, or they are behind an `UnsafeCell`
+                // and thus ok, and all is "good and well" in the unsoundness of `static mut`.
+                // With `const_mut_refs`, mutable references to allocations created by the
+                // evaluation can also end up in the final value of a constant. Their pointee is
+                // interned as immutable like everything else in a constant, which is fine when
+                // it is zero-sized (e.g. `&mut []`), as nothing can be written through the
+                // reference. Validation rejects all other mutable references in constants.
                 // Compute the mutability with which we'll start visiting the allocation. This is
                 // what gets changed when we encounter an `UnsafeCell`.
                 //
@@ -298,6 +293,8 @@ pub fn intern_const_alloc_recursive<M: CompileTimeMachine<'mir, 'tcx>>(
     // be available in a typed way. They get interned at the end.
     let mut ref_tracking = RefTracking::new((ret, base_mutability, base_intern_mode));
     let leftover_allocations = &mut FxHashSet::default();
+    // The allocations that are created by this evaluation, rather than by another constant.
+    let local_allocations: FxHashSet<AllocId> = ecx.memory.alloc_map.keys().copied().collect();
 
     // start with the outermost allocation
     intern_shallow(
@@ -342,7 +339,7 @@ pub fn intern_const_alloc_recursive<M: CompileTimeMachine<'mir, 'tcx>>(
 
     let mut todo: Vec<_> = leftover_allocations.iter().cloned().collect();
     while let Some(alloc_id) = todo.pop() {
-        if let Some((_, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
+        if let Some((kind, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
             // We can't call the `intern_shallow` method here, as its logic is tailored to safe
             // references and a `leftover_allocations` set (where we only have a todo-list here).
             // So we hand-roll the interning logic here again.
@@ -365,13 +362,16 @@ pub fn intern_const_alloc_recursive<M: CompileTimeMachine<'mir, 'tcx>>(
                 InternKind::Constant | InternKind::ConstProp => {
                     // If it's a constant, it *must* be immutable.
                     // We cannot have mutable memory inside a constant.
-                    // We use `delay_span_bug` here, because this can be reached in the presence
-                    // of fancy transmutes.
                     if alloc.mutability == Mutability::Mut {
-                        // For better errors later, mark the allocation as immutable
-                        // (on top of the delayed ICE).
                         alloc.mutability = Mutability::Not;
-                        ecx.tcx.sess.delay_span_bug(ecx.tcx.span, "mutable allocation in constant");
+                        // Heap allocations are only reachable via raw pointers, so ending up
+                        // here is expected for them. Anything else can only be reached in the
+                        // presence of fancy transmutes, so we use `delay_span_bug` here.
+                        if kind != MemoryKind::Machine(const_eval::MemoryKind::Heap) {
+                            ecx.tcx
+                                .sess
+                                .delay_span_bug(ecx.tcx.span, "mutable allocation in constant");
+                        }
                     }
                 }
             }
@@ -384,12 +384,68 @@ pub fn intern_const_alloc_recursive<M: CompileTimeMachine<'mir, 'tcx>>(
             }
         } else if ecx.memory.dead_alloc_map.contains_key(&alloc_id) {
             // dangling pointer
-            throw_unsup!(ValidationFailure("encountered dangling pointer in final constant".into()))
+            throw_unsup!(DanglingPointerInFinalValue(M::alloc_origin(ecx, alloc_id)))
         } else if ecx.tcx.alloc_map.lock().get(alloc_id).is_none() {
             // We have hit an `AllocId` that is neither in local or global memory and isn't marked
             // as dangling by local memory.
             span_bug!(ecx.tcx.span, "encountered unknown alloc id {:?}", alloc_id);
         }
     }
+
+    dedup_immutable_allocations(*tcx, ret.ptr.assert_ptr().alloc_id, &local_allocations);
     Ok(())
 }
+
+/// Points the relocations of the allocations interned for a constant at one `AllocId` for all
+/// immutable allocations with the same contents, across all constants, so that they are only
+/// encoded in the metadata and emitted by codegen once. The allocations are visited after the
+/// ones they point to, so that the relocations that make them identical are already rewritten.
+///
+/// The `AllocId`s of the duplicates stay valid, as does `root`, which is never replaced as it is
+/// the result of the evaluation.
+fn dedup_immutable_allocations<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    root: AllocId,
+    local_allocations: &FxHashSet<AllocId>,
+) {
+    let mut replacements = FxHashMap::default();
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![(root, false)];
+    while let Some((alloc_id, relocations_done)) = stack.pop() {
+        let alloc = match tcx.alloc_map.lock().get(alloc_id) {
+            Some(GlobalAlloc::Memory(alloc)) => alloc,
+            _ => continue,
+        };
+        if !relocations_done {
+            if visited.insert(alloc_id) {
+                stack.push((alloc_id, true));
+                for &(_, ((), reloc)) in alloc.relocations().iter() {
+                    if local_allocations.contains(&reloc) && !visited.contains(&reloc) {
+                        stack.push((reloc, false));
+                    }
+                }
+            }
+            continue;
+        }
+
+        // Allocations in a cycle are visited before some of the allocations they point to, whose
+        // duplicates are then kept.
+        let is_replaced = |reloc| replacements.get(&reloc).map_or(false, |&new| new != reloc);
+        let alloc = if alloc.relocations().iter().any(|&(_, ((), reloc))| is_replaced(reloc)) {
+            let new_alloc = alloc
+                .clone()
+                .map_relocations(|reloc| replacements.get(&reloc).copied().unwrap_or(reloc));
+            let new_alloc = tcx.intern_const_alloc(new_alloc);
+            tcx.alloc_map.lock().reset_alloc_id_memory(alloc_id, new_alloc);
+            new_alloc
+        } else {
+            alloc
+        };
+        let replacement = if alloc_id != root && alloc.mutability == Mutability::Not {
+            tcx.alloc_map.lock().dedup_immutable_memory(alloc_id, alloc)
+        } else {
+            alloc_id
+        };
+        replacements.insert(alloc_id, replacement);
+    }
+}
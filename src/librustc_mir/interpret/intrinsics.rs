@@ -4,13 +4,13 @@
 
 use rustc::mir::{
     self,
-    interpret::{ConstValue, GlobalId, InterpResult, Scalar},
+    interpret::{CallerLocation, CheckInAllocMsg, ConstValue, GlobalId, InterpResult, Scalar},
     BinOp,
 };
-use rustc::ty;
 use rustc::ty::layout::{LayoutOf, Primitive, Size};
 use rustc::ty::subst::SubstsRef;
 use rustc::ty::TyCtxt;
+use rustc::ty::{self, Ty};
 use rustc_hir::def_id::DefId;
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::Span;
@@ -18,6 +18,8 @@ use rustc_span::Span;
 use super::{ImmTy, InterpCx, Machine, OpTy, PlaceTy};
 
 mod caller_location;
+mod float;
+mod simd;
 mod type_name;
 
 fn numeric_intrinsic<'tcx, Tag>(
@@ -77,6 +79,20 @@ crate fn eval_nullary_intrinsic<'tcx>(
         sym::type_id => {
             ty::Const::from_bits(tcx, tcx.type_id_hash(tp_ty).into(), param_env.and(tcx.types.u64))
         }
+        sym::variant_count => {
+            let n = match tp_ty.kind {
+                ty::Adt(adt, _) if adt.is_enum() => adt.variants.len() as u64,
+                ty::Projection(_)
+                | ty::Opaque(..)
+                | ty::Param(_)
+                | ty::Bound(..)
+                | ty::Placeholder(_)
+                | ty::Infer(_) => throw_inval!(TooGeneric),
+                ty::Error => throw_inval!(TypeckError),
+                _ => 0,
+            };
+            ty::Const::from_usize(tcx, n)
+        }
         other => bug!("`{}` is not a zero arg intrinsic", other),
     })
 }
@@ -107,9 +123,9 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         // `src/librustc/ty/constness.rs`
         match intrinsic_name {
             sym::caller_location => {
-                let span = self.find_closest_untracked_caller_location().unwrap_or(span);
-                let location = self.alloc_caller_location_for_span(span);
-                self.write_scalar(location.ptr, dest)?;
+                let location = self.find_closest_untracked_caller_location(span);
+                let location = self.caller_location_ptr(location);
+                self.write_scalar(location, dest)?;
             }
 
             sym::min_align_of
@@ -117,12 +133,20 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
             | sym::needs_drop
             | sym::size_of
             | sym::type_id
-            | sym::type_name => {
+            | sym::type_name
+            | sym::variant_count => {
                 let gid = GlobalId { instance, promoted: None };
                 let val = self.const_eval(gid)?;
                 self.copy_op(val, dest)?;
             }
 
+            sym::panic_if_uninhabited => {
+                let ty = substs.type_at(0);
+                if self.layout_of(ty)?.abi.is_uninhabited() {
+                    throw_ub_format!("attempted to instantiate uninhabited type `{}`", ty);
+                }
+            }
+
             sym::ctpop
             | sym::cttz
             | sym::cttz_nonzero
@@ -176,46 +200,7 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 let l = self.read_immediate(args[0])?;
                 let r = self.read_immediate(args[1])?;
                 let is_add = intrinsic_name == sym::saturating_add;
-                let (val, overflowed, _ty) =
-                    self.overflowing_binary_op(if is_add { BinOp::Add } else { BinOp::Sub }, l, r)?;
-                let val = if overflowed {
-                    let num_bits = l.layout.size.bits();
-                    if l.layout.abi.is_signed() {
-                        // For signed ints the saturated value depends on the sign of the first
-                        // term since the sign of the second term can be inferred from this and
-                        // the fact that the operation has overflowed (if either is 0 no
-                        // overflow can occur)
-                        let first_term: u128 = self.force_bits(l.to_scalar()?, l.layout.size)?;
-                        let first_term_positive = first_term & (1 << (num_bits - 1)) == 0;
-                        if first_term_positive {
-                            // Negative overflow not possible since the positive first term
-                            // can only increase an (in range) negative term for addition
-                            // or corresponding negated positive term for subtraction
-                            Scalar::from_uint(
-                                (1u128 << (num_bits - 1)) - 1, // max positive
-                                Size::from_bits(num_bits),
-                            )
-                        } else {
-                            // Positive overflow not possible for similar reason
-                            // max negative
-                            Scalar::from_uint(1u128 << (num_bits - 1), Size::from_bits(num_bits))
-                        }
-                    } else {
-                        // unsigned
-                        if is_add {
-                            // max unsigned
-                            Scalar::from_uint(
-                                u128::max_value() >> (128 - num_bits),
-                                Size::from_bits(num_bits),
-                            )
-                        } else {
-                            // underflow to 0
-                            Scalar::from_uint(0u128, Size::from_bits(num_bits))
-                        }
-                    }
-                } else {
-                    val
-                };
+                let val = self.saturating_arith(is_add, l, r)?;
                 self.write_scalar(val, dest)?;
             }
             sym::unchecked_shl | sym::unchecked_shr => {
@@ -234,6 +219,28 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 }
                 self.write_scalar(val, dest)?;
             }
+            sym::unchecked_add
+            | sym::unchecked_sub
+            | sym::unchecked_mul
+            | sym::unchecked_div
+            | sym::unchecked_rem => {
+                let l = self.read_immediate(args[0])?;
+                let r = self.read_immediate(args[1])?;
+                let bin_op = match intrinsic_name {
+                    sym::unchecked_add => BinOp::Add,
+                    sym::unchecked_sub => BinOp::Sub,
+                    sym::unchecked_mul => BinOp::Mul,
+                    sym::unchecked_div => BinOp::Div,
+                    sym::unchecked_rem => BinOp::Rem,
+                    _ => bug!("Already checked for int ops"),
+                };
+                // Division by zero is reported by the binary operation itself.
+                let (val, overflowed, _ty) = self.overflowing_binary_op(bin_op, l, r)?;
+                if overflowed {
+                    throw_ub_format!("Overflow executing `{}`", intrinsic_name);
+                }
+                self.write_scalar(val, dest)?;
+            }
             sym::rotate_left | sym::rotate_right => {
                 // rotate_left: (X << (S % BW)) | (X >> ((BW - S) % BW))
                 // rotate_right: (X << ((BW - S) % BW)) | (X >> (S % BW))
@@ -255,6 +262,25 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 self.write_scalar(result, dest)?;
             }
 
+            sym::offset => {
+                let ptr = self.read_scalar(args[0])?.not_undef()?;
+                let offset_count = self.read_scalar(args[1])?.to_machine_isize(self)?;
+                let pointee_ty = substs.type_at(0);
+
+                let offset_ptr = self.ptr_offset_inbounds(ptr, pointee_ty, offset_count)?;
+                self.write_scalar(offset_ptr, dest)?;
+            }
+            sym::arith_offset => {
+                let ptr = self.read_scalar(args[0])?.not_undef()?;
+                let offset_count = self.read_scalar(args[1])?.to_machine_isize(self)?;
+                let pointee_ty = substs.type_at(0);
+
+                // A type's size is at most `isize::MAX`, so this cannot overflow an `i64`.
+                let pointee_size = self.layout_of(pointee_ty)?.size.bytes() as i64;
+                let offset_bytes = offset_count.wrapping_mul(pointee_size);
+                let offset_ptr = ptr.ptr_wrapping_signed_offset(offset_bytes, self);
+                self.write_scalar(offset_ptr, dest)?;
+            }
             sym::ptr_offset_from => {
                 let isize_layout = self.layout_of(self.tcx.types.isize)?;
                 let a = self.read_immediate(args[0])?.to_scalar()?;
@@ -353,7 +379,13 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 );
                 self.copy_op(self.operand_field(args[0], index)?, dest)?;
             }
-            _ => return Ok(false),
+            _ => {
+                if !self.emulate_float_intrinsic(intrinsic_name, args, dest)?
+                    && !self.emulate_simd_intrinsic(intrinsic_name, args, dest)?
+                {
+                    return Ok(false);
+                }
+            }
         }
 
         self.dump_place(*dest);
@@ -361,6 +393,56 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         Ok(true)
     }
 
+    /// Returns the result of the saturating addition or subtraction of `l` and `r`, which is
+    /// clamped to the bounds of their type instead of overflowing.
+    crate fn saturating_arith(
+        &self,
+        is_add: bool,
+        l: ImmTy<'tcx, M::PointerTag>,
+        r: ImmTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx, Scalar<M::PointerTag>> {
+        let (val, overflowed, _ty) =
+            self.overflowing_binary_op(if is_add { BinOp::Add } else { BinOp::Sub }, l, r)?;
+        Ok(if overflowed {
+            let num_bits = l.layout.size.bits();
+            if l.layout.abi.is_signed() {
+                // For signed ints the saturated value depends on the sign of the first
+                // term since the sign of the second term can be inferred from this and
+                // the fact that the operation has overflowed (if either is 0 no
+                // overflow can occur)
+                let first_term: u128 = self.force_bits(l.to_scalar()?, l.layout.size)?;
+                let first_term_positive = first_term & (1 << (num_bits - 1)) == 0;
+                if first_term_positive {
+                    // Negative overflow not possible since the positive first term
+                    // can only increase an (in range) negative term for addition
+                    // or corresponding negated positive term for subtraction
+                    Scalar::from_uint(
+                        (1u128 << (num_bits - 1)) - 1, // max positive
+                        Size::from_bits(num_bits),
+                    )
+                } else {
+                    // Positive overflow not possible for similar reason
+                    // max negative
+                    Scalar::from_uint(1u128 << (num_bits - 1), Size::from_bits(num_bits))
+                }
+            } else {
+                // unsigned
+                if is_add {
+                    // max unsigned
+                    Scalar::from_uint(
+                        u128::max_value() >> (128 - num_bits),
+                        Size::from_bits(num_bits),
+                    )
+                } else {
+                    // underflow to 0
+                    Scalar::from_uint(0u128, Size::from_bits(num_bits))
+                }
+            }
+        } else {
+            val
+        })
+    }
+
     /// "Intercept" a function call to a panic-related function
     /// because we have something special to do for it.
     /// Returns `true` if an intercept happened.
@@ -379,14 +461,46 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
 
             let msg_place = self.deref_operand(args[0])?;
             let msg = Symbol::intern(self.read_str(msg_place)?);
-            let span = self.find_closest_untracked_caller_location().unwrap_or(span);
-            let (file, line, col) = self.location_triple_for_span(span);
+            let CallerLocation { file, line, col, .. } =
+                self.find_closest_untracked_caller_location(span);
             throw_panic!(Panic { msg, file, line, col })
         } else {
             return Ok(false);
         }
     }
 
+    /// Offsets a pointer by some multiple of its type, returning an error if the pointer leaves
+    /// its allocation. For integer pointers, we consider each of them their own tiny
+    /// allocation of size 0, so offsetting those by 0 is okay and everything else is an error.
+    pub fn ptr_offset_inbounds(
+        &self,
+        ptr: Scalar<M::PointerTag>,
+        pointee_ty: Ty<'tcx>,
+        offset_count: i64,
+    ) -> InterpResult<'tcx, Scalar<M::PointerTag>> {
+        // A type's size is at most `isize::MAX`, so this cannot overflow an `i64`.
+        let pointee_size = self.layout_of(pointee_ty)?.size.bytes() as i64;
+        // The computed offset, in bytes, cannot overflow an isize.
+        let offset_bytes =
+            offset_count.checked_mul(pointee_size).ok_or(err_ub!(PointerArithOverflow))?;
+        // The offset being in bounds cannot rely on "wrapping around" the address space.
+        // So, first rule out overflows in the pointer arithmetic.
+        let offset_ptr = ptr.ptr_signed_offset(offset_bytes, self)?;
+        // `ptr` and `offset_ptr` must be in bounds of the same allocation. This means all of the
+        // memory between these pointers must be accessible. Note that we do not require the
+        // pointers to be properly aligned (unlike a read/write operation).
+        let min_ptr = if offset_bytes >= 0 { ptr } else { offset_ptr };
+        let size = i128::from(offset_bytes).abs() as u64;
+        // This call handles checking for integer/NULL pointers.
+        self.memory.check_ptr_access_align(
+            min_ptr,
+            Size::from_bytes(size),
+            None,
+            CheckInAllocMsg::InboundsTest,
+        )?;
+        Ok(offset_ptr)
+    }
+
     pub fn exact_div(
         &mut self,
         a: ImmTy<'tcx, M::PointerTag>,
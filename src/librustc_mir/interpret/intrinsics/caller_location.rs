@@ -1,71 +1,80 @@
 use rustc::middle::lang_items::PanicLocationLangItem;
-use rustc::ty::subst::Subst;
-use rustc_span::{Span, Symbol};
+use rustc::mir::interpret::CallerLocation;
+use rustc::ty::{self, subst::Subst};
+use rustc_span::Span;
 use rustc_target::abi::LayoutOf;
 
 use crate::interpret::{
     intrinsics::{InterpCx, Machine},
-    MPlaceTy, MemoryKind, Scalar,
+    ConstValue, InterpResult, MPlaceTy, MemoryKind, Scalar,
 };
 
 impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
-    /// Walks up the callstack from the intrinsic's callsite, searching for the first callsite in a
-    /// frame which is not `#[track_caller]`. If the first frame found lacks `#[track_caller]`, then
-    /// `None` is returned and the callsite of the function invocation itself should be used.
-    crate fn find_closest_untracked_caller_location(&self) -> Option<Span> {
-        let mut caller_span = None;
-        for next_caller in self.stack.iter().rev() {
-            if !next_caller.instance.def.requires_caller_location(*self.tcx) {
-                return caller_span;
+    /// Walks up the callstack from the intrinsic's callsite at `span`, searching for the first
+    /// callsite in a frame which is not `#[track_caller]`, and returns its location. If the current
+    /// frame lacks `#[track_caller]`, then this is the location of `span` itself.
+    crate fn find_closest_untracked_caller_location(&self, span: Span) -> CallerLocation {
+        let mut caller_span = span;
+        let mut frames = self.stack.iter().rev();
+        let mut caller = frames.next();
+        while let Some(frame) = caller {
+            if !frame.instance.def.requires_caller_location(*self.tcx) {
+                break;
             }
-            caller_span = Some(next_caller.span);
+            caller_span = frame.span;
+            caller = frames.next();
         }
 
-        caller_span
+        // `caller` is the frame making the call, unless even the bottom frame is `#[track_caller]`.
+        let can_unwind = caller.map_or(true, |frame| {
+            let in_cleanup =
+                frame.block.map_or(false, |block| frame.body.basic_blocks()[block].is_cleanup);
+            CallerLocation::can_unwind_from(*self.tcx, frame.instance.def_id(), in_cleanup)
+        });
+        CallerLocation::for_span(*self.tcx, caller_span, can_unwind)
     }
 
-    /// Allocate a `const core::panic::Location` with the provided filename and line/column numbers.
+    /// Allocate a `const core::panic::Location` for the provided call location.
     crate fn alloc_caller_location(
         &mut self,
-        filename: Symbol,
-        line: u32,
-        col: u32,
-    ) -> MPlaceTy<'tcx, M::PointerTag> {
-        let file = self.allocate_str(&filename.as_str(), MemoryKind::CallerLocation);
-        let line = Scalar::from_u32(line);
-        let col = Scalar::from_u32(col);
+        location: CallerLocation,
+    ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::PointerTag>> {
+        let file = self.allocate_str(&location.file.as_str(), MemoryKind::CallerLocation);
 
-        // Allocate memory for `CallerLocation` struct.
+        // Allocate memory for `Location` struct.
         let loc_ty = self
             .tcx
             .type_of(self.tcx.require_lang_item(PanicLocationLangItem, None))
             .subst(*self.tcx, self.tcx.mk_substs([self.tcx.lifetimes.re_static.into()].iter()));
         let loc_layout = self.layout_of(loc_ty).unwrap();
-        let location = self.allocate(loc_layout, MemoryKind::CallerLocation);
+        let place = self.allocate(loc_layout, MemoryKind::CallerLocation)?;
 
         // Initialize fields.
-        self.write_immediate(file.to_ref(), self.mplace_field(location, 0).unwrap().into())
+        self.write_immediate(file.to_ref(), self.mplace_field(place, 0).unwrap().into())
             .expect("writing to memory we just allocated cannot fail");
-        self.write_scalar(line, self.mplace_field(location, 1).unwrap().into())
-            .expect("writing to memory we just allocated cannot fail");
-        self.write_scalar(col, self.mplace_field(location, 2).unwrap().into())
+        let fields = [location.line, location.col, location.col_end, location.offset, location.len];
+        for (i, &field) in fields.iter().enumerate() {
+            let field_place = self.mplace_field(place, i as u64 + 1).unwrap();
+            self.write_scalar(Scalar::from_u32(field), field_place.into())
+                .expect("writing to memory we just allocated cannot fail");
+        }
+        let can_unwind_place = self.mplace_field(place, fields.len() as u64 + 1).unwrap();
+        self.write_scalar(Scalar::from_bool(location.can_unwind), can_unwind_place.into())
             .expect("writing to memory we just allocated cannot fail");
 
-        location
-    }
-
-    pub fn alloc_caller_location_for_span(&mut self, span: Span) -> MPlaceTy<'tcx, M::PointerTag> {
-        let (file, line, column) = self.location_triple_for_span(span);
-        self.alloc_caller_location(file, line, column)
+        Ok(place)
     }
 
-    pub(super) fn location_triple_for_span(&self, span: Span) -> (Symbol, u32, u32) {
-        let topmost = span.ctxt().outer_expn().expansion_cause().unwrap_or(span);
-        let caller = self.tcx.sess.source_map().lookup_char_pos(topmost.lo());
-        (
-            Symbol::intern(&caller.file.name.to_string()),
-            caller.line as u32,
-            caller.col_display as u32 + 1,
-        )
+    /// Returns a pointer to the `core::panic::Location` of `location`. The locations are interned
+    /// by the `const_caller_location` query, so all the evaluations, and codegen, share a single
+    /// allocation for each call location.
+    pub fn caller_location_ptr(&self, location: CallerLocation) -> Scalar<M::PointerTag> {
+        let location = self.tcx.const_caller_location(location);
+        match location.val {
+            ty::ConstKind::Value(ConstValue::Scalar(Scalar::Ptr(ptr))) => {
+                Scalar::Ptr(self.memory.tag_static_base_pointer(ptr))
+            }
+            _ => bug!("`const_caller_location` returned {:?}", location),
+        }
     }
 }
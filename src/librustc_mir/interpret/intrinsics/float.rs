@@ -0,0 +1,181 @@
+//! Evaluation of the floating-point intrinsics.
+//!
+//! The operations whose result IEEE 754 specifies exactly (rounding to an integer, `fma`,
+//! `minnum`, `maxnum` and the sign manipulations) are evaluated with the soft-float
+//! implementation of `rustc_apfloat`, so they give the same result as the operation performed
+//! at runtime, on every target. `sqrt` is evaluated by the host, which gives the same result
+//! because IEEE 754 requires it to be correctly rounded. The only exception to this is the sign
+//! and payload of a NaN result, which IEEE 754 leaves unspecified.
+//!
+//! The transcendental functions and `powi` are not required to be correctly rounded, so their
+//! result at runtime depends on the platform's math library, which the interpreter cannot
+//! reproduce. They are not evaluated, and are not `const`.
+//!
+//! With `-Z const-float-strict`, a NaN result is an error.
+
+use rustc::mir::interpret::{InterpResult, Scalar};
+use rustc_apfloat::ieee::{Double, Single};
+use rustc_apfloat::{Float, Round};
+use rustc_span::symbol::{sym, Symbol};
+
+use crate::interpret::{
+    intrinsics::{InterpCx, Machine},
+    OpTy, PlaceTy,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum FloatOp {
+    /// `floor`, `ceil`, `trunc`, `round`, `rint` and `nearbyint`, which only differ in the
+    /// rounding mode.
+    RoundToIntegral(Round),
+    Fma,
+    Abs,
+    CopySign,
+    MinNum,
+    MaxNum,
+    Sqrt,
+}
+
+impl FloatOp {
+    /// Returns the operation performed by the float intrinsic `name`, and whether it operates
+    /// on `f64` rather than `f32`.
+    fn from_intrinsic(name: Symbol) -> Option<(FloatOp, bool)> {
+        use FloatOp::*;
+
+        Some(match name {
+            sym::floorf32 => (RoundToIntegral(Round::TowardNegative), false),
+            sym::floorf64 => (RoundToIntegral(Round::TowardNegative), true),
+            sym::ceilf32 => (RoundToIntegral(Round::TowardPositive), false),
+            sym::ceilf64 => (RoundToIntegral(Round::TowardPositive), true),
+            sym::truncf32 => (RoundToIntegral(Round::TowardZero), false),
+            sym::truncf64 => (RoundToIntegral(Round::TowardZero), true),
+            sym::roundf32 => (RoundToIntegral(Round::NearestTiesToAway), false),
+            sym::roundf64 => (RoundToIntegral(Round::NearestTiesToAway), true),
+            // Floating-point exceptions are not observable, so `rint` is `nearbyint`.
+            sym::rintf32 | sym::nearbyintf32 => (RoundToIntegral(Round::NearestTiesToEven), false),
+            sym::rintf64 | sym::nearbyintf64 => (RoundToIntegral(Round::NearestTiesToEven), true),
+            sym::fmaf32 => (Fma, false),
+            sym::fmaf64 => (Fma, true),
+            sym::fabsf32 => (Abs, false),
+            sym::fabsf64 => (Abs, true),
+            sym::copysignf32 => (CopySign, false),
+            sym::copysignf64 => (CopySign, true),
+            sym::minnumf32 => (MinNum, false),
+            sym::minnumf64 => (MinNum, true),
+            sym::maxnumf32 => (MaxNum, false),
+            sym::maxnumf64 => (MaxNum, true),
+            sym::sqrtf32 => (Sqrt, false),
+            sym::sqrtf64 => (Sqrt, true),
+            _ => return None,
+        })
+    }
+
+    /// The number of float arguments of this operation.
+    fn float_args(self) -> usize {
+        match self {
+            FloatOp::Fma => 3,
+            FloatOp::CopySign | FloatOp::MinNum | FloatOp::MaxNum => 2,
+            _ => 1,
+        }
+    }
+
+    /// Whether this operation only manipulates the sign bit, so that even a NaN result has
+    /// the same bit pattern on every platform.
+    fn is_sign_op(self) -> bool {
+        match self {
+            FloatOp::Abs | FloatOp::CopySign => true,
+            _ => false,
+        }
+    }
+
+    fn eval<F: HostFloat>(self, args: &[F]) -> F {
+        match self {
+            FloatOp::RoundToIntegral(round) => args[0].round_to_integral(round).value,
+            FloatOp::Fma => args[0].mul_add(args[1], args[2]).value,
+            FloatOp::Abs => args[0].abs(),
+            FloatOp::CopySign => args[0].copy_sign(args[1]),
+            FloatOp::MinNum => args[0].min(args[1]),
+            FloatOp::MaxNum => args[0].max(args[1]),
+            FloatOp::Sqrt => args[0].sqrt_on_host(),
+        }
+    }
+}
+
+/// `sqrt`, which `rustc_apfloat` does not implement, evaluated with the host's floats.
+trait HostFloat: Float {
+    fn sqrt_on_host(self) -> Self;
+}
+
+macro_rules! impl_host_float {
+    ($soft:ty, $host:ident) => {
+        impl HostFloat for $soft {
+            fn sqrt_on_host(self) -> Self {
+                let res = $host::from_bits(self.to_bits() as _).sqrt();
+                <$soft>::from_bits(res.to_bits().into())
+            }
+        }
+    };
+}
+
+impl_host_float!(Single, f32);
+impl_host_float!(Double, f64);
+
+impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
+    /// Evaluates the floating-point intrinsic `name`. Returns `false` if `name` is not one.
+    crate fn emulate_float_intrinsic(
+        &mut self,
+        name: Symbol,
+        args: &[OpTy<'tcx, M::PointerTag>],
+        dest: PlaceTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx, bool> {
+        let (op, is_f64) = match FloatOp::from_intrinsic(name) {
+            Some(op) => op,
+            None => return Ok(false),
+        };
+        let args = &args[..op.float_args()];
+        let (res, is_nan) = if is_f64 {
+            let args = args
+                .iter()
+                .map(|&arg| self.read_scalar(arg)?.to_f64())
+                .collect::<InterpResult<'_, Vec<_>>>()?;
+            let res = op.eval(&args);
+            (Scalar::from_f64(res), res.is_nan())
+        } else {
+            let args = args
+                .iter()
+                .map(|&arg| self.read_scalar(arg)?.to_f32())
+                .collect::<InterpResult<'_, Vec<_>>>()?;
+            let res = op.eval(&args);
+            (Scalar::from_f32(res), res.is_nan())
+        };
+        let strict = self.tcx.sess.opts.debugging_opts.const_float_strict;
+        if strict && is_nan && !op.is_sign_op() {
+            throw_unsup_format!(
+                "`{}` evaluated to NaN, whose sign and payload may differ at runtime",
+                name
+            );
+        }
+        self.write_scalar(res, dest)?;
+        Ok(true)
+    }
+
+    /// With `-Z const-float-strict`, checks that the result of a float arithmetic operation is
+    /// not NaN, as the sign and payload of the NaN may differ at runtime.
+    crate fn check_strict_float_result(
+        &self,
+        val: Scalar<M::PointerTag>,
+        is_f64: bool,
+    ) -> InterpResult<'tcx> {
+        if !self.tcx.sess.opts.debugging_opts.const_float_strict {
+            return Ok(());
+        }
+        let is_nan = if is_f64 { val.to_f64()?.is_nan() } else { val.to_f32()?.is_nan() };
+        if is_nan {
+            throw_unsup_format!(
+                "floating-point operation evaluated to NaN, whose sign and payload may differ \
+                 at runtime"
+            );
+        }
+        Ok(())
+    }
+}
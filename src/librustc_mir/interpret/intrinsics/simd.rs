@@ -0,0 +1,308 @@
+//! Evaluation of the SIMD platform intrinsics.
+//!
+//! The vectors are `#[repr(simd)]` structs, so the intrinsics are evaluated lane by lane, on the
+//! fields of their arguments, with the operation the interpreter uses for the corresponding
+//! scalar values. The float math intrinsics are evaluated by the scalar float intrinsics, so they
+//! give the same results as these, and the transcendental ones are not evaluated either.
+
+use rustc::mir::{
+    interpret::{InterpResult, Scalar},
+    BinOp, CastKind,
+};
+use rustc::ty;
+use rustc_apfloat::Float;
+use rustc_span::symbol::{sym, Symbol};
+use syntax::ast::FloatTy;
+
+use crate::interpret::{
+    intrinsics::{InterpCx, Machine},
+    ImmTy, OpTy, PlaceTy,
+};
+
+/// Returns the binary operation that the lane-wise SIMD intrinsic `name` applies, and whether it
+/// is a comparison.
+fn simd_binary_op(name: Symbol) -> Option<(BinOp, bool)> {
+    Some(match name {
+        sym::simd_add => (BinOp::Add, false),
+        sym::simd_sub => (BinOp::Sub, false),
+        sym::simd_mul => (BinOp::Mul, false),
+        sym::simd_div => (BinOp::Div, false),
+        sym::simd_rem => (BinOp::Rem, false),
+        sym::simd_shl => (BinOp::Shl, false),
+        sym::simd_shr => (BinOp::Shr, false),
+        sym::simd_and => (BinOp::BitAnd, false),
+        sym::simd_or => (BinOp::BitOr, false),
+        sym::simd_xor => (BinOp::BitXor, false),
+        sym::simd_eq => (BinOp::Eq, true),
+        sym::simd_ne => (BinOp::Ne, true),
+        sym::simd_lt => (BinOp::Lt, true),
+        sym::simd_le => (BinOp::Le, true),
+        sym::simd_gt => (BinOp::Gt, true),
+        sym::simd_ge => (BinOp::Ge, true),
+        _ => return None,
+    })
+}
+
+/// Returns the scalar float intrinsic that the SIMD float math intrinsic `name` applies to each
+/// lane.
+fn simd_float_intrinsic(name: Symbol, is_f64: bool) -> Option<Symbol> {
+    let (f32_name, f64_name) = match name {
+        sym::simd_fsqrt => (sym::sqrtf32, sym::sqrtf64),
+        sym::simd_fabs => (sym::fabsf32, sym::fabsf64),
+        sym::simd_floor => (sym::floorf32, sym::floorf64),
+        sym::simd_ceil => (sym::ceilf32, sym::ceilf64),
+        sym::simd_fma => (sym::fmaf32, sym::fmaf64),
+        _ => return None,
+    };
+    Some(if is_f64 { f64_name } else { f32_name })
+}
+
+impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
+    /// Evaluates the SIMD intrinsic `name`. Returns `false` if `name` is not one.
+    ///
+    /// `simd_insert` and `simd_extract` are evaluated by `emulate_intrinsic` itself.
+    crate fn emulate_simd_intrinsic(
+        &mut self,
+        name: Symbol,
+        args: &[OpTy<'tcx, M::PointerTag>],
+        dest: PlaceTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx, bool> {
+        if !name.as_str().starts_with("simd_") {
+            return Ok(false);
+        }
+
+        if let Some((bin_op, is_comparison)) = simd_binary_op(name) {
+            let len = self.simd_len(args[0]);
+            assert_eq!(len, self.simd_len(args[1]), "mismatched lengths in `{}`", name);
+            assert_eq!(len, dest.layout.ty.simd_size(self.tcx.tcx), "mismatched return length");
+            for i in 0..len {
+                let l = self.read_immediate(self.operand_field(args[0], i)?)?;
+                let r = self.read_immediate(self.operand_field(args[1], i)?)?;
+                let (val, overflowed, _ty) = self.overflowing_binary_op(bin_op, l, r)?;
+                let place = self.place_field(dest, i)?;
+                let val = if is_comparison {
+                    // The lanes of the result are all ones for `true`, as the comparison is
+                    // sign-extended to the lane type.
+                    Scalar::from_int(if val.to_bool()? { -1 } else { 0 }, place.layout.size)
+                } else {
+                    // Addition, subtraction and multiplication wrap around, but overflowing
+                    // shifts and divisions produce poison at runtime.
+                    match bin_op {
+                        BinOp::Shl | BinOp::Shr | BinOp::Div | BinOp::Rem if overflowed => {
+                            throw_ub_format!("overflow executing `{}` in lane {}", name, i)
+                        }
+                        _ => val,
+                    }
+                };
+                self.write_scalar(val, place)?;
+            }
+            return Ok(true);
+        }
+
+        if let Some(float_name) =
+            self.simd_float_elem(args[0]).and_then(|is_f64| simd_float_intrinsic(name, is_f64))
+        {
+            let len = self.simd_len(args[0]);
+            for i in 0..len {
+                let lane_args = args
+                    .iter()
+                    .map(|&arg| self.operand_field(arg, i))
+                    .collect::<InterpResult<'_, Vec<_>>>()?;
+                let place = self.place_field(dest, i)?;
+                let emulated = self.emulate_float_intrinsic(float_name, &lane_args, place)?;
+                assert!(emulated, "`{}` is not a float intrinsic", float_name);
+            }
+            return Ok(true);
+        }
+
+        if name.as_str().starts_with("simd_shuffle") {
+            let len = self.simd_len(args[0]);
+            let out_len = dest.layout.ty.simd_size(self.tcx.tcx);
+            for i in 0..out_len {
+                let index = u64::from(self.read_scalar(self.operand_field(args[2], i)?)?.to_u32()?);
+                let lane = if index < len {
+                    self.operand_field(args[0], index)?
+                } else if index < len * 2 {
+                    self.operand_field(args[1], index - len)?
+                } else {
+                    throw_ub_format!("shuffle index #{} is out of bounds (limit {})", i, len * 2)
+                };
+                let place = self.place_field(dest, i)?;
+                self.copy_op(lane, place)?;
+            }
+            return Ok(true);
+        }
+
+        match name {
+            sym::simd_fmax
+            | sym::simd_fmin
+            | sym::simd_saturating_add
+            | sym::simd_saturating_sub => {
+                let len = self.simd_len(args[0]);
+                for i in 0..len {
+                    let l = self.read_immediate(self.operand_field(args[0], i)?)?;
+                    let r = self.read_immediate(self.operand_field(args[1], i)?)?;
+                    let val = match name {
+                        sym::simd_fmax => self.simd_min_max(false, l, r)?,
+                        sym::simd_fmin => self.simd_min_max(true, l, r)?,
+                        _ => self.saturating_arith(name == sym::simd_saturating_add, l, r)?,
+                    };
+                    let place = self.place_field(dest, i)?;
+                    self.write_scalar(val, place)?;
+                }
+            }
+            sym::simd_cast => {
+                let len = self.simd_len(args[0]);
+                assert_eq!(len, dest.layout.ty.simd_size(self.tcx.tcx), "mismatched return length");
+                for i in 0..len {
+                    let lane = self.operand_field(args[0], i)?;
+                    let place = self.place_field(dest, i)?;
+                    self.cast(lane, CastKind::Misc, place)?;
+                }
+            }
+            sym::simd_select => {
+                let len = self.simd_len(args[0]);
+                assert_eq!(len, self.simd_len(args[1]), "mismatched lengths in `{}`", name);
+                for i in 0..len {
+                    // Only the lowest bit of each lane of the mask is used.
+                    let mask = self.read_scalar(self.operand_field(args[0], i)?)?.not_undef()?;
+                    let mask = self.force_bits(mask, args[0].layout.field(self, 0)?.size)?;
+                    let lane = self.operand_field(args[if mask & 1 != 0 { 1 } else { 2 }], i)?;
+                    let place = self.place_field(dest, i)?;
+                    self.copy_op(lane, place)?;
+                }
+            }
+            sym::simd_select_bitmask => {
+                let len = self.simd_len(args[1]);
+                let mask = self.read_scalar(args[0])?.not_undef()?;
+                let mask = self.force_bits(mask, args[0].layout.size)?;
+                assert_eq!(len, args[0].layout.size.bits(), "mismatched lengths in `{}`", name);
+                for i in 0..len {
+                    let lane =
+                        self.operand_field(args[if mask >> i & 1 != 0 { 1 } else { 2 }], i)?;
+                    let place = self.place_field(dest, i)?;
+                    self.copy_op(lane, place)?;
+                }
+            }
+            sym::simd_bitmask => {
+                let len = self.simd_len(args[0]);
+                let lane_size = args[0].layout.field(self, 0)?.size;
+                let mut bitmask = 0u128;
+                for i in 0..len {
+                    let lane = self.read_scalar(self.operand_field(args[0], i)?)?.not_undef()?;
+                    let lane = self.force_bits(lane, lane_size)?;
+                    bitmask |= (lane >> (lane_size.bits() - 1)) << i;
+                }
+                self.write_scalar(Scalar::from_uint(bitmask, dest.layout.size), dest)?;
+            }
+            sym::simd_reduce_add_ordered
+            | sym::simd_reduce_add_unordered
+            | sym::simd_reduce_mul_ordered
+            | sym::simd_reduce_mul_unordered
+            | sym::simd_reduce_and
+            | sym::simd_reduce_or
+            | sym::simd_reduce_xor
+            | sym::simd_reduce_min
+            | sym::simd_reduce_max
+            | sym::simd_reduce_min_nanless
+            | sym::simd_reduce_max_nanless => {
+                let len = self.simd_len(args[0]);
+                // The ordered arithmetic reductions start from an accumulator, the other
+                // reductions from the first lane.
+                let (mut acc, first) = match name {
+                    sym::simd_reduce_add_ordered | sym::simd_reduce_mul_ordered => {
+                        (self.read_immediate(args[1])?, 0)
+                    }
+                    _ => (self.read_immediate(self.operand_field(args[0], 0)?)?, 1),
+                };
+                for i in first..len {
+                    let lane = self.read_immediate(self.operand_field(args[0], i)?)?;
+                    let val = match name {
+                        sym::simd_reduce_min | sym::simd_reduce_min_nanless => {
+                            self.simd_min_max(true, acc, lane)?
+                        }
+                        sym::simd_reduce_max | sym::simd_reduce_max_nanless => {
+                            self.simd_min_max(false, acc, lane)?
+                        }
+                        _ => {
+                            let bin_op =
+                                match name {
+                                    sym::simd_reduce_add_ordered
+                                    | sym::simd_reduce_add_unordered => BinOp::Add,
+                                    sym::simd_reduce_mul_ordered
+                                    | sym::simd_reduce_mul_unordered => BinOp::Mul,
+                                    sym::simd_reduce_and => BinOp::BitAnd,
+                                    sym::simd_reduce_or => BinOp::BitOr,
+                                    _ => BinOp::BitXor,
+                                };
+                            // Integer overflow wraps around, as in `simd_add` and `simd_mul`.
+                            self.overflowing_binary_op(bin_op, acc, lane)?.0
+                        }
+                    };
+                    acc = ImmTy::from_scalar(val, acc.layout);
+                }
+                self.write_immediate(*acc, dest)?;
+            }
+            sym::simd_reduce_all | sym::simd_reduce_any => {
+                let len = self.simd_len(args[0]);
+                let lane_size = args[0].layout.field(self, 0)?.size;
+                let is_all = name == sym::simd_reduce_all;
+                let mut res = is_all;
+                for i in 0..len {
+                    // As in `simd_select`, only the lowest bit of each lane is used.
+                    let lane = self.read_scalar(self.operand_field(args[0], i)?)?.not_undef()?;
+                    let set = self.force_bits(lane, lane_size)? & 1 != 0;
+                    res = if is_all { res && set } else { res || set };
+                }
+                self.write_scalar(Scalar::from_bool(res), dest)?;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Returns the number of lanes of the vector `op`.
+    fn simd_len(&self, op: OpTy<'tcx, M::PointerTag>) -> u64 {
+        op.layout.ty.simd_size(self.tcx.tcx)
+    }
+
+    /// Returns whether the lanes of `op` are `f64` rather than `f32`, or `None` if `op` is not a
+    /// vector of floats.
+    fn simd_float_elem(&self, op: OpTy<'tcx, M::PointerTag>) -> Option<bool> {
+        if !op.layout.ty.is_simd() {
+            return None;
+        }
+        match op.layout.ty.simd_type(self.tcx.tcx).kind {
+            ty::Float(fty) => Some(fty == FloatTy::F64),
+            _ => None,
+        }
+    }
+
+    /// Returns the smaller (if `is_min`) or the larger of `l` and `r`. For floats, this is
+    /// `minnum` or `maxnum`, which return the other operand if one of them is NaN.
+    fn simd_min_max(
+        &self,
+        is_min: bool,
+        l: ImmTy<'tcx, M::PointerTag>,
+        r: ImmTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx, Scalar<M::PointerTag>> {
+        Ok(match l.layout.ty.kind {
+            ty::Float(FloatTy::F32) => {
+                let (l, r) = (l.to_scalar()?.to_f32()?, r.to_scalar()?.to_f32()?);
+                Scalar::from_f32(if is_min { l.min(r) } else { l.max(r) })
+            }
+            ty::Float(FloatTy::F64) => {
+                let (l, r) = (l.to_scalar()?.to_f64()?, r.to_scalar()?.to_f64()?);
+                Scalar::from_f64(if is_min { l.min(r) } else { l.max(r) })
+            }
+            _ => {
+                let l_is_less = self.overflowing_binary_op(BinOp::Lt, l, r)?.0.to_bool()?;
+                if l_is_less == is_min {
+                    l.to_scalar()?
+                } else {
+                    r.to_scalar()?
+                }
+            }
+        })
+    }
+}
@@ -6,13 +6,14 @@ use std::borrow::{Borrow, Cow};
 use std::hash::Hash;
 
 use rustc::mir;
+use rustc::ty::layout::Size;
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc_hir::def_id::DefId;
 use rustc_span::Span;
 
 use super::{
-    AllocId, Allocation, AllocationExtra, AssertMessage, Frame, ImmTy, InterpCx, InterpResult,
-    Memory, MemoryKind, OpTy, Operand, PlaceTy, Pointer, Scalar,
+    AllocId, AllocOrigin, Allocation, AllocationExtra, AssertMessage, Frame, ImmTy, InterpCx,
+    InterpResult, Memory, MemoryKind, OpTy, Operand, PlaceTy, Pointer, Scalar,
 };
 
 /// Data returned by Machine::stack_pop,
@@ -120,6 +121,21 @@ pub trait Machine<'mir, 'tcx>: Sized {
     /// Whether memory accesses should be alignment-checked.
     const CHECK_ALIGN: bool;
 
+    /// Whether memory accesses should be alignment-checked, which machines can decide at runtime
+    /// by overriding this. Defaults to `CHECK_ALIGN`.
+    #[inline(always)]
+    fn enforce_alignment(_memory_extra: &Self::MemoryExtra) -> bool {
+        Self::CHECK_ALIGN
+    }
+
+    /// Whether to reject casts from integers to pointers, which create pointers without
+    /// provenance, and from pointers to integers, which expose the provenance of a pointer.
+    /// Casting `0` to a pointer is always allowed, as that is how null pointers are made.
+    #[inline(always)]
+    fn enforce_strict_provenance(_memory_extra: &Self::MemoryExtra) -> bool {
+        false
+    }
+
     /// Whether to enforce the validity invariant
     fn enforce_validity(ecx: &InterpCx<'mir, 'tcx, Self>) -> bool;
 
@@ -220,6 +236,38 @@ pub trait Machine<'mir, 'tcx>: Sized {
         Ok(())
     }
 
+    /// Called before an allocation of `size` bytes is created, so that the machine can limit
+    /// the amount of memory that is used.
+    #[inline(always)]
+    fn before_memory_allocation(
+        _memory_extra: &mut Self::MemoryExtra,
+        _size: Size,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
+    /// Called after an allocation of `size` bytes has been deallocated.
+    #[inline(always)]
+    fn after_memory_deallocation(_memory_extra: &mut Self::MemoryExtra, _size: Size) {}
+
+    /// Called after the local `local` of the frame at index `frame` was moved to the allocation
+    /// `id`, because a pointer to it was needed.
+    #[inline(always)]
+    fn after_local_allocated(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _frame: usize,
+        _local: mir::Local,
+        _id: AllocId,
+    ) {
+    }
+
+    /// Returns where the allocation `id` was created, if the machine records it. This is used
+    /// to point at the allocation in errors about the pointers to it.
+    #[inline(always)]
+    fn alloc_origin(_ecx: &InterpCx<'mir, 'tcx, Self>, _id: AllocId) -> Option<AllocOrigin<'tcx>> {
+        None
+    }
+
     /// Called to initialize the "extra" state of an allocation and make the pointers
     /// it contains (in relocations) tagged.  The way we construct allocations is
     /// to always first construct it without extra and then add the extra.
@@ -259,6 +307,12 @@ pub trait Machine<'mir, 'tcx>: Sized {
         Ok(())
     }
 
+    /// The maximum number of stack frames, after which pushing a new frame is an error.
+    #[inline(always)]
+    fn stack_limit(ecx: &InterpCx<'mir, 'tcx, Self>) -> usize {
+        *ecx.tcx.sess.recursion_limit.get()
+    }
+
     /// Called immediately before a new stack frame got pushed
     fn stack_push(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx, Self::FrameExtra>;
 
@@ -30,8 +30,8 @@ pub use self::operand::{ImmTy, Immediate, OpTy, Operand, ScalarMaybeUndef};
 
 pub use self::visitor::{MutValueVisitor, ValueVisitor};
 
-pub use self::validity::RefTracking;
+pub use self::validity::{CtfeValidationMode, RefTracking};
 
-pub use self::intern::{intern_const_alloc_recursive, InternKind};
+pub use self::intern::{intern_const_alloc_recursive, CompileTimeMachine, InternKind};
 
 crate use self::intrinsics::eval_nullary_intrinsic;
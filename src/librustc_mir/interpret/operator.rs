@@ -302,14 +302,19 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 let ty = left.layout.ty;
                 let left = left.to_scalar()?;
                 let right = right.to_scalar()?;
-                Ok(match fty {
+                let (val, overflow, res_ty) = match fty {
                     FloatTy::F32 => {
                         self.binary_float_op(bin_op, ty, left.to_f32()?, right.to_f32()?)
                     }
                     FloatTy::F64 => {
                         self.binary_float_op(bin_op, ty, left.to_f64()?, right.to_f64()?)
                     }
-                })
+                };
+                // Comparisons cannot produce a NaN.
+                if res_ty.is_floating_point() {
+                    self.check_strict_float_result(val, fty == FloatTy::F64)?;
+                }
+                Ok((val, overflow, res_ty))
             }
             _ if left.layout.ty.is_integral() => {
                 // the RHS type can be different, e.g. for shifts -- but it has to be integral, too
@@ -432,14 +432,9 @@ where
             // happens at run-time so that's okay.
             let align = match self.size_and_align_of(base.meta, field_layout)? {
                 Some((_, align)) => align,
-                None if offset == Size::ZERO => {
-                    // An extern type at offset 0, we fall back to its static alignment.
-                    // FIXME: Once we have made decisions for how to handle size and alignment
-                    // of `extern type`, this should be adapted.  It is just a temporary hack
-                    // to get some code to work that probably ought to work.
-                    field_layout.align.abi
-                }
-                None => bug!("Cannot compute offset for extern type field at non-0 offset"),
+                // A field of extern type, or of a type that ends in one, is placed with its
+                // static alignment, like codegen does.
+                None => field_layout.align.abi,
             };
             (base.meta, offset.align_to(align))
         } else {
@@ -860,15 +855,22 @@ where
         );
 
         // Let us see if the layout is simple so we take a shortcut, avoid force_allocation.
-        let src = match self.try_read_immediate(src)? {
-            Ok(src_val) => {
+        let src = match self.try_read_immediate(src) {
+            Ok(Ok(src_val)) => {
                 assert!(!src.layout.is_unsized(), "cannot have unsized immediates");
                 // Yay, we got a value that we can write directly.
                 // FIXME: Add a check to make sure that if `src` is indirect,
                 // it does not overlap with `dest`.
                 return self.write_immediate_no_validate(*src_val, dest);
             }
-            Err(mplace) => mplace,
+            Ok(Err(mplace)) => mplace,
+            // The fields of the immediate would each cover only part of a pointer, e.g. when a
+            // reference was transmuted to `(u32, u32)`. A plain copy of the memory keeps the
+            // pointer intact.
+            Err(err) => match err.kind {
+                err_unsup!(ReadPointerAsBytes) => src.assert_mem_place(self),
+                _ => return Err(err),
+            },
         };
         // Slow path, this does not fit into an immediate. Just memcpy.
         trace!("copy_op: {:?} <- {:?}: {}", *dest, src, dest.layout.ty);
@@ -976,7 +978,7 @@ where
                         let (size, align) = self
                             .size_and_align_of(meta, local_layout)?
                             .expect("Cannot allocate for non-dyn-sized type");
-                        let ptr = self.memory.allocate(size, align, MemoryKind::Stack);
+                        let ptr = self.memory.allocate(size, align, MemoryKind::Stack)?;
                         let mplace = MemPlace { ptr: ptr.into(), align, meta };
                         if let LocalValue::Live(Operand::Immediate(value)) = local_val {
                             // Preserve old value.
@@ -989,6 +991,7 @@ where
                         // and actually overwrite things.
                         *self.stack[frame].locals[local].access_mut().unwrap().unwrap() =
                             LocalValue::Live(Operand::Indirect(mplace));
+                        M::after_local_allocated(self, frame, local, ptr.alloc_id);
                         (mplace, Some(size))
                     }
                     Err(mplace) => (mplace, None), // this already was an indirect local
@@ -1012,9 +1015,9 @@ where
         &mut self,
         layout: TyLayout<'tcx>,
         kind: MemoryKind<M::MemoryKinds>,
-    ) -> MPlaceTy<'tcx, M::PointerTag> {
-        let ptr = self.memory.allocate(layout.size, layout.align.abi, kind);
-        MPlaceTy::from_aligned_ptr(ptr, layout)
+    ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::PointerTag>> {
+        let ptr = self.memory.allocate(layout.size, layout.align.abi, kind)?;
+        Ok(MPlaceTy::from_aligned_ptr(ptr, layout))
     }
 
     /// Returns a wide MPlace.
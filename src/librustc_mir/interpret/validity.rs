@@ -75,6 +75,18 @@ pub enum PathElem {
     DynDowncast,
 }
 
+/// The kind of value that is validated during const evaluation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CtfeValidationMode {
+    /// The value of a `static`. Mutable references in it are fine: they are either reachable
+    /// only through shared references and thus immutable, or part of a `static mut`.
+    Static,
+    /// The final value of a `const`, or a value that const propagation wants to turn into a
+    /// constant. Every use of a constant gets the same pointers, so mutable references in it
+    /// are only allowed if there is nothing to mutate behind them.
+    Const,
+}
+
 /// State for tracking recursive validation of references
 pub struct RefTracking<T, PATH = ()> {
     pub seen: FxHashSet<T>,
@@ -165,6 +177,8 @@ struct ValidityVisitor<'rt, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
     path: Vec<PathElem>,
     ref_tracking_for_consts:
         Option<&'rt mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>>,
+    /// Only relevant when `ref_tracking_for_consts` is `Some`.
+    ctfe_mode: CtfeValidationMode,
     ecx: &'rt InterpCx<'mir, 'tcx, M>,
 }
 
@@ -441,6 +455,28 @@ impl<'rt, 'mir, 'tcx, M: Machine<'mir, 'tcx>> ValueVisitor<'mir, 'tcx, M>
                 };
                 // Recursive checking
                 if let Some(ref mut ref_tracking) = self.ref_tracking_for_consts {
+                    if let ty::Ref(_, _, hir::Mutability::Mut) = ty.kind {
+                        // A mutable reference can only end up in the final value of a `const`
+                        // if its pointee was allocated by the evaluation, but all uses of the
+                        // constant would share that allocation. This is only sound if the
+                        // pointee is zero-sized, e.g. for `&mut []`.
+                        if self.ctfe_mode == CtfeValidationMode::Const && size.bytes() > 0 {
+                            throw_validation_failure!("mutable reference in a `const`", self.path)
+                        }
+                    }
+                    if ty.is_box()
+                        && self.ctfe_mode == CtfeValidationMode::Const
+                        && size.bytes() > 0
+                    {
+                        // The pointee of a `Box` in the final value was allocated on the
+                        // compile-time heap and is interned as `'static` memory, so every use of
+                        // the constant would free that memory when dropping its copy of the box.
+                        throw_validation_failure!(
+                            "a `Box` in a `const`",
+                            self.path,
+                            "its memory to be leaked with `Box::leak`"
+                        )
+                    }
                     if let Some(ptr) = ptr {
                         // not a ZST
                         // Skip validation entirely for some external statics
@@ -664,7 +700,8 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
     /// `ref_tracking_for_consts` can be `None` to avoid recursive checking below references.
     /// This also toggles between "run-time" (no recursion) and "compile-time" (with recursion)
     /// validation (e.g., pointer values are fine in integers at runtime) and various other const
-    /// specific validation checks.
+    /// specific validation checks. Compile-time validation uses the rules for the final value
+    /// of a `const`, use `const_validate_operand` for other kinds of values.
     pub fn validate_operand(
         &self,
         op: OpTy<'tcx, M::PointerTag>,
@@ -672,11 +709,35 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         ref_tracking_for_consts: Option<
             &mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>,
         >,
+    ) -> InterpResult<'tcx> {
+        self.validate_operand_internal(op, path, ref_tracking_for_consts, CtfeValidationMode::Const)
+    }
+
+    /// Like `validate_operand`, but always performs compile-time validation, following the rules
+    /// for `ctfe_mode`.
+    pub fn const_validate_operand(
+        &self,
+        op: OpTy<'tcx, M::PointerTag>,
+        path: Vec<PathElem>,
+        ref_tracking: &mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>,
+        ctfe_mode: CtfeValidationMode,
+    ) -> InterpResult<'tcx> {
+        self.validate_operand_internal(op, path, Some(ref_tracking), ctfe_mode)
+    }
+
+    fn validate_operand_internal(
+        &self,
+        op: OpTy<'tcx, M::PointerTag>,
+        path: Vec<PathElem>,
+        ref_tracking_for_consts: Option<
+            &mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>,
+        >,
+        ctfe_mode: CtfeValidationMode,
     ) -> InterpResult<'tcx> {
         trace!("validate_operand: {:?}, {:?}", *op, op.layout.ty);
 
         // Construct a visitor
-        let mut visitor = ValidityVisitor { path, ref_tracking_for_consts, ecx: self };
+        let mut visitor = ValidityVisitor { path, ref_tracking_for_consts, ctfe_mode, ecx: self };
 
         // Try to cast to ptr *once* instead of all the time.
         let op = self.force_op_ptr(op).unwrap_or(op);
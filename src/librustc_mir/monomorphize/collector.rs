@@ -194,6 +194,9 @@ use rustc_hir as hir;
 use rustc_hir::def_id::{DefId, DefIdMap, LOCAL_CRATE};
 use rustc_hir::itemlikevisit::ItemLikeVisitor;
 use rustc_index::bit_set::GrowableBitSet;
+use rustc_span::symbol::sym;
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
 use smallvec::SmallVec;
 use std::iter;
 
@@ -493,6 +496,7 @@ fn check_type_length_limit<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) {
 
 struct MirNeighborCollector<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
+    caller: DefId,
     body: &'a mir::Body<'tcx>,
     output: &'a mut Vec<MonoItem<'tcx>>,
     param_substs: SubstsRef<'tcx>,
@@ -562,12 +566,8 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
                 );
                 match source_ty.kind {
                     ty::Closure(def_id, substs) => {
-                        let instance = Instance::resolve_closure(
-                            self.tcx,
-                            def_id,
-                            substs,
-                            ty::ClosureKind::FnOnce,
-                        );
+                        let instance =
+                            Instance::resolve_closure_for_fn_ptr(self.tcx, def_id, substs);
                         if should_monomorphize_locally(self.tcx, &instance) {
                             self.output.push(create_fn_mono_item(instance));
                         }
@@ -612,6 +612,8 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
                     ty::ParamEnv::reveal_all(),
                     &callee_ty,
                 );
+                let span = self.body.source_info(location).span;
+                check_size_and_align_of_extern_type(tcx, self.caller, callee_ty, span);
                 visit_fn_use(self.tcx, callee_ty, true, &mut self.output);
             }
             mir::TerminatorKind::Drop { ref location, .. }
@@ -1187,10 +1189,67 @@ fn collect_neighbours<'tcx>(
     debug!("collect_neighbours: {:?}", instance.def_id());
     let body = tcx.instance_mir(instance.def);
 
-    MirNeighborCollector { tcx, body: &body, output, param_substs: instance.substs }
+    MirNeighborCollector {
+        tcx,
+        caller: instance.def_id(),
+        body: &body,
+        output,
+        param_substs: instance.substs,
+    }
         .visit_body(body);
 }
 
+/// Reports the calls of `size_of_val` and `align_of_val` with a value whose type is or ends in
+/// an extern type, which has no size or alignment that Rust knows. Code that is generic over the
+/// type of the value can only be rejected here, once it is known.
+///
+/// The calls of the intrinsics inside of the `mem` functions are not reported, only the calls of
+/// these functions are.
+fn check_size_and_align_of_extern_type<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    caller: DefId,
+    callee_ty: Ty<'tcx>,
+    span: Span,
+) {
+    let (def_id, substs) = match callee_ty.kind {
+        ty::FnDef(def_id, substs) => (def_id, substs),
+        _ => return,
+    };
+    let what = if tcx.is_diagnostic_item(sym::mem_size_of_val, def_id) {
+        "size"
+    } else if tcx.is_diagnostic_item(sym::mem_align_of_val, def_id)
+        || tcx.is_diagnostic_item(sym::mem_min_align_of_val, def_id)
+    {
+        "alignment"
+    } else if tcx.fn_sig(def_id).abi() == Abi::RustIntrinsic {
+        match tcx.item_name(def_id) {
+            sym::size_of_val => "size",
+            sym::min_align_of_val => "alignment",
+            _ => return,
+        }
+    } else {
+        return;
+    };
+    if tcx.is_diagnostic_item(sym::mem_size_of_val, caller)
+        || tcx.is_diagnostic_item(sym::mem_align_of_val, caller)
+        || tcx.is_diagnostic_item(sym::mem_min_align_of_val, caller)
+    {
+        return;
+    }
+
+    let ty = substs.type_at(0);
+    let tail = tcx.struct_tail_erasing_lifetimes(ty, ty::ParamEnv::reveal_all());
+    if let ty::Foreign(_) = tail.kind {
+        let msg = format!("cannot compute the {} of a value of type `{}`", what, ty);
+        let mut err = tcx.sess.struct_span_err(span, &msg);
+        if tail != ty {
+            err.note(&format!("`{}` ends in the extern type `{}`", ty, tail));
+        }
+        err.note("the size and alignment of extern types are not known to Rust");
+        err.emit();
+    }
+}
+
 fn def_id_to_string(tcx: TyCtxt<'_>, def_id: DefId) -> String {
     let mut output = String::new();
     let printer = DefPathBasedNames::new(tcx, false, false);
@@ -100,6 +100,7 @@ use rustc::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc::middle::exported_symbols::SymbolExportLevel;
 use rustc::mir::mono::{CodegenUnit, CodegenUnitNameBuilder, Linkage, Visibility};
 use rustc::mir::mono::{InstantiationMode, MonoItem};
+use rustc::session::config::SymbolVisibility;
 use rustc::ty::print::characteristic_def_id_of_type;
 use rustc::ty::query::Providers;
 use rustc::ty::{self, DefIdTree, InstanceDef, TyCtxt};
@@ -438,26 +439,28 @@ fn mono_item_visibility(
 }
 
 fn default_visibility(tcx: TyCtxt<'_>, id: DefId, is_generic: bool) -> Visibility {
-    if !tcx.sess.target.target.options.default_hidden_visibility {
-        return Visibility::Default;
-    }
+    let internal = match tcx.sess.default_visibility() {
+        SymbolVisibility::Interposable => return Visibility::Default,
+        SymbolVisibility::Protected => Visibility::Protected,
+        SymbolVisibility::Hidden => Visibility::Hidden,
+    };
 
     // Generic functions never have export-level C.
     if is_generic {
-        return Visibility::Hidden;
+        return internal;
     }
 
     // Things with export level C don't get instantiated in
     // downstream crates.
     if !id.is_local() {
-        return Visibility::Hidden;
+        return internal;
     }
 
     // C-export level items remain at `Default`, all other internal
-    // items become `Hidden`.
+    // items get the visibility selected for the session.
     match tcx.reachable_non_generics(id.krate).get(&id) {
         Some(SymbolExportLevel::C) => Visibility::Default,
-        _ => Visibility::Hidden,
+        _ => internal,
     }
 }
 
@@ -125,6 +125,12 @@ pub struct HeapAllocation;
 impl NonConstOp for HeapAllocation {
     const IS_SUPPORTED_IN_MIRI: bool = false;
 
+    /// `box` expressions allocate on the compile-time heap, which is a library feature as the
+    /// interface to it is the `const_allocate` intrinsic.
+    fn is_allowed_in_item(&self, item: &Item<'_, '_>) -> bool {
+        item.tcx.features().declared_lib_features.iter().any(|&(sym, _)| sym == sym::const_heap)
+    }
+
     fn emit_error(&self, item: &Item<'_, '_>, span: Span) {
         let mut err = struct_span_err!(
             item.tcx.sess,
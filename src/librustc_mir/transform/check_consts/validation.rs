@@ -5,7 +5,7 @@ use rustc::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext,
 use rustc::mir::*;
 use rustc::traits::{self, TraitEngine};
 use rustc::ty::cast::CastTy;
-use rustc::ty::{self, TyCtxt};
+use rustc::ty::{self, Instance, TyCtxt};
 use rustc_errors::struct_span_err;
 use rustc_hir::{def_id::DefId, HirId};
 use rustc_index::bit_set::BitSet;
@@ -525,8 +525,8 @@ impl Visitor<'tcx> for Validator<'_, 'mir, 'tcx> {
             TerminatorKind::Call { func, .. } => {
                 let fn_ty = func.ty(*self.body, self.tcx);
 
-                let def_id = match fn_ty.kind {
-                    ty::FnDef(def_id, _) => def_id,
+                let (def_id, substs) = match fn_ty.kind {
+                    ty::FnDef(def_id, substs) => (def_id, substs),
 
                     ty::FnPtr(_) => {
                         self.check_op(ops::FnCallIndirect);
@@ -543,6 +543,30 @@ impl Visitor<'tcx> for Validator<'_, 'mir, 'tcx> {
                     return;
                 }
 
+                // ...or a trait method, which we accept if it resolves to a method of a const
+                // trait impl.
+                let mut def_id = def_id;
+                if self.tcx.features().const_trait_impl && self.tcx.trait_of_item(def_id).is_some()
+                {
+                    match Instance::resolve(self.tcx, self.param_env, def_id, substs) {
+                        Some(Instance { def: ty::InstanceDef::Item(impl_def_id), .. })
+                            if self.tcx.is_const_fn_raw(impl_def_id) =>
+                        {
+                            if is_const_fn(self.tcx, impl_def_id) {
+                                return;
+                            }
+                            // The method is const but unstable, which is reported below like
+                            // for the unstable const fns.
+                            def_id = impl_def_id;
+                        }
+                        // Calls through a trait object are dispatched on the vtable of the
+                        // receiver. The method found there is only known once the object has
+                        // been created, so it is checked by the const evaluator instead.
+                        Some(Instance { def: ty::InstanceDef::Virtual(..), .. }) => return,
+                        _ => {}
+                    }
+                }
+
                 if is_lang_panic_fn(self.tcx, def_id) {
                     self.check_op(ops::Panic);
                 } else if let Some(feature) = is_unstable_const_fn(self.tcx, def_id) {
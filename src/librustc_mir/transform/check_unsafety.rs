@@ -1,17 +1,23 @@
 use rustc::hir::map::Map;
-use rustc::lint::builtin::{SAFE_PACKED_BORROWS, UNUSED_UNSAFE};
+use rustc::lint::builtin::{
+    DEPRECATED_SAFE, SAFE_PACKED_BORROWS, UNSAFE_OP_IN_UNSAFE_FN, UNUSED_UNSAFE,
+};
+use rustc::lint::Level;
 use rustc::mir::visit::{MutatingUseContext, PlaceContext, Visitor};
 use rustc::mir::*;
 use rustc::ty::cast::CastTy;
 use rustc::ty::query::Providers;
 use rustc::ty::{self, TyCtxt};
-use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::struct_span_err;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::{struct_span_err, Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit;
 use rustc_hir::Node;
+use rustc_span::edition::Edition;
+use rustc_span::source_map::original_sp;
 use rustc_span::symbol::{sym, Symbol};
+use rustc_span::{BytePos, Span};
 
 use std::ops::Bound;
 
@@ -80,7 +86,37 @@ impl<'a, 'tcx> Visitor<'tcx> for UnsafetyChecker<'a, 'tcx> {
             TerminatorKind::Call { ref func, .. } => {
                 let func_ty = func.ty(self.body, self.tcx);
                 let sig = func_ty.fn_sig(self.tcx);
-                if let hir::Unsafety::Unsafe = sig.unsafety() {
+                let deprecated_safe = match func_ty.kind {
+                    ty::FnDef(def_id, _) => deprecated_safe_edition(self.tcx, def_id)
+                        .filter(|&edition| self.source_info.span.edition() < edition)
+                        .map(|edition| (def_id, edition)),
+                    _ => None,
+                };
+                if let Some((def_id, edition)) = deprecated_safe {
+                    let source_info = self.source_info;
+                    let lint_root = self.body.source_scopes[source_info.scope]
+                        .local_data
+                        .as_ref()
+                        .assert_crate_local()
+                        .lint_root;
+                    let description =
+                        format!("call to unsafe function `{}`", self.tcx.def_path_str(def_id));
+                    let details = format!(
+                        "calling this function requires an `unsafe` block in Rust {} and later \
+                         editions: consult its documentation for information on how to avoid \
+                         undefined behavior",
+                        edition
+                    );
+                    self.register_violations(
+                        &[UnsafetyViolation {
+                            source_info,
+                            description: Symbol::intern(&description),
+                            details: Symbol::intern(&details),
+                            kind: UnsafetyViolationKind::DeprecatedSafe(lint_root),
+                        }],
+                        &[],
+                    );
+                } else if let hir::Unsafety::Unsafe = sig.unsafety() {
                     self.require_unsafe(
                         "call to unsafe function",
                         "consult the function's documentation for information on how to avoid \
@@ -349,7 +385,8 @@ impl<'a, 'tcx> UnsafetyChecker<'a, 'tcx> {
                     let mut violation = violation.clone();
                     match violation.kind {
                         UnsafetyViolationKind::GeneralAndConstFn
-                        | UnsafetyViolationKind::General => {}
+                        | UnsafetyViolationKind::General
+                        | UnsafetyViolationKind::DeprecatedSafe(_) => {}
                         UnsafetyViolationKind::BorrowPacked(_) => {
                             if self.min_const_fn {
                                 // const fns don't need to be backwards compatible and can
@@ -358,6 +395,9 @@ impl<'a, 'tcx> UnsafetyChecker<'a, 'tcx> {
                                 violation.kind = UnsafetyViolationKind::General;
                             }
                         }
+                        UnsafetyViolationKind::UnsafeFn(_) => {
+                            bug!("`UnsafetyViolationKind::UnsafeFn` in a safe context")
+                        }
                     }
                     if !self.violations.contains(&violation) {
                         self.violations.push(violation)
@@ -365,8 +405,45 @@ impl<'a, 'tcx> UnsafetyChecker<'a, 'tcx> {
                 }
                 false
             }
-            // `unsafe` function bodies allow unsafe without additional unsafe blocks
-            Safety::BuiltinUnsafe | Safety::FnUnsafe => true,
+            // `unsafe` function bodies allow unsafe without additional unsafe blocks, unless
+            // `unsafe_op_in_unsafe_fn` asks for them
+            Safety::FnUnsafe => {
+                let lint_root = self.body.source_scopes[self.source_info.scope]
+                    .local_data
+                    .as_ref()
+                    .assert_crate_local()
+                    .lint_root;
+                if unsafe_op_in_unsafe_fn_allowed(self.tcx, lint_root) {
+                    true
+                } else {
+                    for violation in violations {
+                        let lint_root = match violation.kind {
+                            // only some unsafety is allowed in const fn, the rest is accepted
+                            // in the bodies of `unsafe const fn`s but not in `unsafe` blocks
+                            UnsafetyViolationKind::General
+                            | UnsafetyViolationKind::BorrowPacked(_)
+                                if self.min_const_fn =>
+                            {
+                                continue;
+                            }
+                            UnsafetyViolationKind::BorrowPacked(lint_root) => lint_root,
+                            UnsafetyViolationKind::GeneralAndConstFn
+                            | UnsafetyViolationKind::General
+                            | UnsafetyViolationKind::DeprecatedSafe(_) => lint_root,
+                            UnsafetyViolationKind::UnsafeFn(_) => {
+                                bug!("`UnsafetyViolationKind::UnsafeFn` in an unsafe context")
+                            }
+                        };
+                        let mut violation = violation.clone();
+                        violation.kind = UnsafetyViolationKind::UnsafeFn(lint_root);
+                        if !self.violations.contains(&violation) {
+                            self.violations.push(violation)
+                        }
+                    }
+                    false
+                }
+            }
+            Safety::BuiltinUnsafe => true,
             Safety::ExplicitUnsafe(hir_id) => {
                 // mark unsafe block as used if there are any unsafe operations inside
                 if !violations.is_empty() {
@@ -377,7 +454,8 @@ impl<'a, 'tcx> UnsafetyChecker<'a, 'tcx> {
                     for violation in violations {
                         match violation.kind {
                             // these unsafe things are stable in const fn
-                            UnsafetyViolationKind::GeneralAndConstFn => {}
+                            UnsafetyViolationKind::GeneralAndConstFn
+                            | UnsafetyViolationKind::DeprecatedSafe(_) => {}
                             // these things are forbidden in const fns
                             UnsafetyViolationKind::General
                             | UnsafetyViolationKind::BorrowPacked(_) => {
@@ -390,6 +468,9 @@ impl<'a, 'tcx> UnsafetyChecker<'a, 'tcx> {
                                     self.violations.push(violation)
                                 }
                             }
+                            UnsafetyViolationKind::UnsafeFn(_) => {
+                                bug!("`UnsafetyViolationKind::UnsafeFn` in an unsafe context")
+                            }
                         }
                     }
                 }
@@ -550,6 +631,21 @@ fn unsafe_derive_on_repr_packed(tcx: TyCtxt<'_>, def_id: DefId) {
     tcx.lint_hir(SAFE_PACKED_BORROWS, lint_hir_id, tcx.def_span(def_id), &message);
 }
 
+/// Returns the edition from which calling the `unsafe fn` `def_id` requires an `unsafe` block, if
+/// it was safe to call in older editions, as declared by `#[rustc_deprecated_safe = "edition"]`.
+fn deprecated_safe_edition(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Edition> {
+    let attrs = tcx.get_attrs(def_id);
+    let attr = attrs.iter().find(|attr| attr.check_name(sym::rustc_deprecated_safe))?;
+    attr.value_str()?.as_str().parse().ok()
+}
+
+/// Whether unsafe operations are allowed in the body of an `unsafe fn` without an `unsafe` block
+/// at `id`, i.e. whether `unsafe_op_in_unsafe_fn` is not enabled there.
+fn unsafe_op_in_unsafe_fn_allowed(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    !tcx.features().unsafe_block_in_unsafe_fn
+        || tcx.lint_level_at_node(UNSAFE_OP_IN_UNSAFE_FN, id).0 == Level::Allow
+}
+
 /// Returns the `HirId` for an enclosing scope that is also `unsafe`.
 fn is_enclosed(
     tcx: TyCtxt<'_>,
@@ -565,8 +661,10 @@ fn is_enclosed(
         })) = tcx.hir().find(parent_id)
         {
             match sig.header.unsafety {
-                hir::Unsafety::Unsafe => Some(("fn".to_string(), parent_id)),
-                hir::Unsafety::Normal => None,
+                hir::Unsafety::Unsafe if unsafe_op_in_unsafe_fn_allowed(tcx, parent_id) => {
+                    Some(("fn".to_string(), parent_id))
+                }
+                hir::Unsafety::Unsafe | hir::Unsafety::Normal => None,
             }
         } else {
             is_enclosed(tcx, used_unsafe, parent_id)
@@ -590,6 +688,173 @@ fn report_unused_unsafe(tcx: TyCtxt<'_>, used_unsafe: &FxHashSet<hir::HirId>, id
     db.emit();
 }
 
+/// An `unsafe` block suggested by `unsafe_op_in_unsafe_fn`, covering one or more unsafe
+/// operations in the body of an `unsafe fn`.
+struct UnsafeBlockSuggestion {
+    /// The code to wrap in the `unsafe` block.
+    span: Span,
+    /// Whether `span` is the whole body, in which case only its contents are wrapped.
+    is_body: bool,
+    /// The number of unsafe operations the block covers.
+    operations: usize,
+}
+
+impl UnsafeBlockSuggestion {
+    fn add_to(&self, db: &mut DiagnosticBuilder<'_>) {
+        // Only insert code around `span`, so that comments and formatting inside of it are
+        // preserved.
+        let parts = if self.is_body {
+            vec![
+                (self.span.with_lo(self.span.lo() + BytePos(1)).shrink_to_lo(), " unsafe {"),
+                (self.span.with_hi(self.span.hi() - BytePos(1)).shrink_to_hi(), "} "),
+            ]
+        } else {
+            vec![(self.span.shrink_to_lo(), "unsafe { "), (self.span.shrink_to_hi(), " }")]
+        };
+        let msg = if self.operations == 1 {
+            "consider wrapping the unsafe operation in an `unsafe` block"
+        } else {
+            "consider wrapping the unsafe operations in an `unsafe` block"
+        };
+        db.multipart_suggestion(
+            msg,
+            parts.into_iter().map(|(span, code)| (span, code.to_string())).collect(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Collects the code in the body of an `unsafe fn` that can be wrapped in an `unsafe` block
+/// without changing its meaning: statements, initializers of `let`s and the tail expressions
+/// of blocks.
+struct UnsafeBlockCandidates<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    /// The span of the body. All candidates are in its syntax context, so that code produced
+    /// by a macro is only ever wrapped at the macro invocation.
+    body_span: Span,
+    candidates: Vec<Span>,
+}
+
+impl UnsafeBlockCandidates<'_> {
+    fn add(&mut self, span: Span) {
+        let span = original_sp(span, self.body_span);
+        if !span.from_expansion() && self.body_span.contains(span) {
+            self.candidates.push(span);
+        }
+    }
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for UnsafeBlockCandidates<'tcx> {
+    type Map = Map<'tcx>;
+
+    fn nested_visit_map<'this>(&'this mut self) -> intravisit::NestedVisitorMap<'this, Self::Map> {
+        intravisit::NestedVisitorMap::OnlyBodies(&self.tcx.hir())
+    }
+
+    fn visit_block(&mut self, block: &'tcx hir::Block<'tcx>) {
+        // All statements that are produced by the same macro invocation are wrapped together,
+        // which would change the scope of the `let`s among them.
+        let body_span = self.body_span;
+        let macro_lets: FxHashSet<Span> = block
+            .stmts
+            .iter()
+            .filter(|stmt| stmt.span.from_expansion())
+            .filter(|stmt| if let hir::StmtKind::Local(_) = stmt.kind { true } else { false })
+            .map(|stmt| original_sp(stmt.span, body_span))
+            .collect();
+        let is_macro_let = |span| macro_lets.contains(&original_sp(span, body_span));
+
+        for stmt in block.stmts {
+            match stmt.kind {
+                hir::StmtKind::Local(local) => {
+                    // Wrapping the initializer turns it from a place into a value, which matters
+                    // if parts of it are borrowed by the pattern.
+                    if !stmt.span.from_expansion()
+                        && local.pat.contains_explicit_ref_binding().is_none()
+                    {
+                        if let Some(init) = local.init {
+                            self.add(init.span);
+                        }
+                    }
+                }
+                hir::StmtKind::Expr(_) | hir::StmtKind::Semi(_) => {
+                    if !is_macro_let(stmt.span) {
+                        self.add(stmt.span);
+                    }
+                }
+                hir::StmtKind::Item(_) => {}
+            }
+        }
+        if let Some(expr) = block.expr {
+            if !is_macro_let(expr.span) {
+                self.add(expr.span);
+            }
+        }
+        intravisit::walk_block(self, block);
+    }
+}
+
+/// Computes the `unsafe` blocks to suggest for the unsafe operations at `operations` in the
+/// body of the `unsafe fn` `def_id`. Every operation is covered by the innermost candidate
+/// around it, unless that is nested in the block for another operation. The suggestions are
+/// keyed by the first operation, in source order, covered by them.
+fn unsafe_block_suggestions(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    mut operations: Vec<Span>,
+) -> FxHashMap<Span, UnsafeBlockSuggestion> {
+    let mut suggestions = FxHashMap::default();
+    let body_id =
+        tcx.hir().as_local_hir_id(def_id).and_then(|hir_id| tcx.hir().maybe_body_owned_by(hir_id));
+    let body = match body_id {
+        Some(body_id) => tcx.hir().body(body_id),
+        None => return suggestions,
+    };
+    let body_span = body.value.span;
+    if body_span.from_expansion() {
+        // The body is produced by a macro, which we cannot rewrite.
+        return suggestions;
+    }
+
+    let mut candidates = UnsafeBlockCandidates { tcx, body_span, candidates: vec![] };
+    intravisit::Visitor::visit_body(&mut candidates, body);
+    let candidates = candidates.candidates;
+    let len = |span: Span| span.hi() - span.lo();
+
+    operations.sort_by_key(|span| span.lo());
+    operations.dedup();
+    let innermost: Vec<(Span, Span)> = operations
+        .into_iter()
+        .filter_map(|operation| {
+            let source = original_sp(operation, body_span);
+            if !body_span.contains(source) {
+                return None;
+            }
+            let innermost = candidates
+                .iter()
+                .filter(|candidate| candidate.contains(source))
+                .min_by_key(|candidate| len(**candidate));
+            Some((operation, innermost.copied().unwrap_or(body_span)))
+        })
+        .collect();
+
+    let mut first_operation = FxHashMap::default();
+    for &(operation, candidate) in &innermost {
+        let span = innermost
+            .iter()
+            .map(|&(_, other)| other)
+            .filter(|other| other.contains(candidate))
+            .max_by_key(|other| len(*other))
+            .unwrap_or(candidate);
+        let first = *first_operation.entry(span).or_insert(operation);
+        suggestions
+            .entry(first)
+            .or_insert(UnsafeBlockSuggestion { span, is_body: span == body_span, operations: 0 })
+            .operations += 1;
+    }
+    suggestions
+}
+
 fn builtin_derive_def_id(tcx: TyCtxt<'_>, def_id: DefId) -> Option<DefId> {
     debug!("builtin_derive_def_id({:?})", def_id);
     if let Some(impl_def_id) = tcx.impl_of_method(def_id) {
@@ -616,6 +881,20 @@ pub fn check_unsafety(tcx: TyCtxt<'_>, def_id: DefId) {
 
     let UnsafetyCheckResult { violations, unsafe_blocks } = tcx.unsafety_check_result(def_id);
 
+    let unsafe_fn_operations = violations
+        .iter()
+        .filter(|violation| match violation.kind {
+            UnsafetyViolationKind::UnsafeFn(_) => true,
+            _ => false,
+        })
+        .map(|violation| violation.source_info.span)
+        .collect::<Vec<_>>();
+    let mut suggestions = if unsafe_fn_operations.is_empty() {
+        FxHashMap::default()
+    } else {
+        unsafe_block_suggestions(tcx, def_id, unsafe_fn_operations)
+    };
+
     for &UnsafetyViolation { source_info, description, details, kind } in violations.iter() {
         // Report an error.
         match kind {
@@ -648,6 +927,37 @@ pub fn check_unsafety(tcx: TyCtxt<'_>, def_id: DefId) {
                     .emit();
                 }
             }
+            UnsafetyViolationKind::UnsafeFn(lint_hir_id) => {
+                let mut db = tcx.struct_span_lint_hir(
+                    UNSAFE_OP_IN_UNSAFE_FN,
+                    lint_hir_id,
+                    source_info.span,
+                    &format!("{} is unsafe and requires unsafe block (error E0133)", description),
+                );
+                db.span_label(source_info.span, &*description.as_str()).note(&details.as_str());
+                if let Some(suggestion) = suggestions.remove(&source_info.span) {
+                    suggestion.add_to(&mut db);
+                }
+                db.emit();
+            }
+            UnsafetyViolationKind::DeprecatedSafe(lint_hir_id) => {
+                let mut db = tcx.struct_span_lint_hir(
+                    DEPRECATED_SAFE,
+                    lint_hir_id,
+                    source_info.span,
+                    &format!("{} without an unsafe block is deprecated", description),
+                );
+                db.span_label(source_info.span, "call to unsafe function").note(&details.as_str());
+                if !source_info.span.from_expansion() {
+                    let suggestion = UnsafeBlockSuggestion {
+                        span: source_info.span,
+                        is_body: false,
+                        operations: 1,
+                    };
+                    suggestion.add_to(&mut db);
+                }
+                db.emit();
+            }
         }
     }
 
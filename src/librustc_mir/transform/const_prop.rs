@@ -28,7 +28,7 @@ use rustc_index::vec::IndexVec;
 use rustc_span::{Span, DUMMY_SP};
 use syntax::ast::Mutability;
 
-use crate::const_eval::error_to_const_error;
+use crate::const_eval::{self, error_to_const_error};
 use crate::interpret::{
     self, intern_const_alloc_recursive, AllocId, Allocation, Frame, ImmTy, Immediate, InternKind,
     InterpCx, LocalState, LocalValue, Memory, MemoryKind, OpTy, Operand as InterpOperand, PlaceTy,
@@ -144,7 +144,8 @@ impl<'tcx> MirPass<'tcx> for ConstProp {
 struct ConstPropMachine;
 
 impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for ConstPropMachine {
-    type MemoryKinds = !;
+    // Shared with CTFE so that we can reuse its interning. We never create heap allocations.
+    type MemoryKinds = const_eval::MemoryKind;
     type PointerTag = ();
     type ExtraFnVal = !;
 
@@ -152,9 +153,9 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for ConstPropMachine {
     type MemoryExtra = ();
     type AllocExtra = ();
 
-    type MemoryMap = FxHashMap<AllocId, (MemoryKind<!>, Allocation)>;
+    type MemoryMap = FxHashMap<AllocId, (MemoryKind<const_eval::MemoryKind>, Allocation)>;
 
-    const STATIC_KIND: Option<!> = None;
+    const STATIC_KIND: Option<const_eval::MemoryKind> = None;
 
     const CHECK_ALIGN: bool = false;
 
@@ -233,7 +234,7 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for ConstPropMachine {
         _memory_extra: &(),
         _id: AllocId,
         alloc: Cow<'b, Allocation>,
-        _kind: Option<MemoryKind<!>>,
+        _kind: Option<MemoryKind<const_eval::MemoryKind>>,
     ) -> (Cow<'b, Allocation<Self::PointerTag>>, Self::PointerTag) {
         // We do not use a tag so we can just cheaply forward the allocation
         (alloc, ())
@@ -358,7 +359,7 @@ impl<'mir, 'tcx> ConstPropagator<'mir, 'tcx> {
             .filter(|ret_layout| {
                 !ret_layout.is_zst() && ret_layout.size < Size::from_bytes(MAX_ALLOC_LIMIT)
             })
-            .map(|ret_layout| ecx.allocate(ret_layout, MemoryKind::Stack));
+            .and_then(|ret_layout| ecx.allocate(ret_layout, MemoryKind::Stack).ok());
 
         ecx.push_stack_frame(
             Instance::new(def_id, substs),
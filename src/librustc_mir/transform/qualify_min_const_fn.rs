@@ -342,7 +342,11 @@ fn check_terminator(
         TerminatorKind::Call { func, args, from_hir_call: _, destination: _, cleanup: _ } => {
             let fn_ty = func.ty(body, tcx);
             if let ty::FnDef(def_id, _) = fn_ty.kind {
-                if !crate::const_eval::is_min_const_fn(tcx, def_id) {
+                // Calls of trait methods are checked by the const checker, which knows which
+                // method of a const impl they resolve to.
+                let is_const_trait_method =
+                    tcx.features().const_trait_impl && tcx.trait_of_item(def_id).is_some();
+                if !is_const_trait_method && !crate::const_eval::is_min_const_fn(tcx, def_id) {
                     return Err((
                         span,
                         format!(
@@ -2,6 +2,8 @@ use crate::build::matches::ArmHasGuard;
 use crate::build::ForGuard::OutsideGuard;
 use crate::build::{BlockAnd, BlockAndExtension, BlockFrame, Builder};
 use crate::hair::*;
+use rustc::lint::builtin::UNSAFE_OP_IN_UNSAFE_FN;
+use rustc::lint::Level;
 use rustc::mir::*;
 use rustc_hir as hir;
 use rustc_span::Span;
@@ -202,6 +204,14 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         block.unit()
     }
 
+    /// Whether the `unsafe` block `hir_id` in the body of an `unsafe fn` is checked by
+    /// `unsafe_op_in_unsafe_fn`, so that it is needed for the unsafe operations inside of it.
+    fn unsafe_op_in_unsafe_fn_enabled(&self, hir_id: hir::HirId) -> bool {
+        let tcx = self.hir.tcx();
+        tcx.features().unsafe_block_in_unsafe_fn
+            && tcx.lint_level_at_node(UNSAFE_OP_IN_UNSAFE_FN, hir_id).0 != Level::Allow
+    }
+
     /// If we are changing the safety mode, create a new source scope
     fn update_source_scope_for_safety_mode(&mut self, span: Span, safety_mode: BlockSafety) {
         debug!("update_source_scope_for({:?}, {:?})", span, safety_mode);
@@ -211,6 +221,9 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 assert_eq!(self.push_unsafe_count, 0);
                 match self.unpushed_unsafe {
                     Safety::Safe => {}
+                    // with `unsafe_op_in_unsafe_fn`, the body of an `unsafe fn` is not an
+                    // unsafe context
+                    Safety::FnUnsafe if self.unsafe_op_in_unsafe_fn_enabled(hir_id) => {}
                     _ => return,
                 }
                 self.unpushed_unsafe = Safety::ExplicitUnsafe(hir_id);
@@ -591,6 +591,12 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 self.visit_bindings(subpattern, pattern_user_ty.deref(), f);
             }
 
+            PatKind::DerefPattern { ref subpattern } => {
+                // The subpattern is matched against the result of a call, which user type
+                // projections cannot describe.
+                self.visit_bindings(subpattern, UserTypeProjections::none(), f);
+            }
+
             PatKind::AscribeUserType {
                 ref subpattern,
                 ascription: hair::pattern::Ascription { ref user_ty, user_ty_span, variance: _ },
@@ -736,6 +742,13 @@ enum TestKind<'tcx> {
 
     /// Test length of the slice is equal to len
     Len { len: u64, op: BinOp },
+
+    /// Call `Deref::deref` on a smart pointer for a deref pattern. This is not really a test,
+    /// as it always succeeds: the subpatterns are then matched against `*temp`.
+    Deref {
+        /// The temporary that holds the reference returned by `Deref::deref`.
+        temp: Place<'tcx>,
+    },
 }
 
 #[derive(Debug)]
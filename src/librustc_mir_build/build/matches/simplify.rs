@@ -198,7 +198,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 Ok(())
             }
 
-            PatKind::Or { .. } => Err(match_pair),
+            PatKind::DerefPattern { .. } | PatKind::Or { .. } => Err(match_pair),
         }
     }
 }
@@ -9,6 +9,7 @@ use crate::build::matches::{Candidate, MatchPair, Test, TestKind};
 use crate::build::Builder;
 use crate::hair::pattern::compare_const_vals;
 use crate::hair::*;
+use rustc::middle::lang_items::DerefTraitLangItem;
 use rustc::mir::*;
 use rustc::ty::layout::VariantIdx;
 use rustc::ty::util::IntTypeExt;
@@ -70,6 +71,11 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 }
             }
 
+            PatKind::DerefPattern { ref subpattern } => Test {
+                span: match_pair.pattern.span,
+                kind: TestKind::Deref { temp: self.temp(subpattern.ty, match_pair.pattern.span) },
+            },
+
             PatKind::Or { .. } => self
                 .hir
                 .tcx()
@@ -122,7 +128,8 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             | PatKind::Binding { .. }
             | PatKind::AscribeUserType { .. }
             | PatKind::Leaf { .. }
-            | PatKind::Deref { .. } => {
+            | PatKind::Deref { .. }
+            | PatKind::DerefPattern { .. } => {
                 // don't know how to add these patterns to a switch
                 false
             }
@@ -341,6 +348,46 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                     bug!("`TestKind::Len` should have two target blocks");
                 }
             }
+
+            TestKind::Deref { temp } => {
+                let target = if let [target] = *make_target_blocks(self) {
+                    target
+                } else {
+                    bug!("`TestKind::Deref` should have one target block");
+                };
+                let tcx = self.hir.tcx();
+                let place_ty = place.ty(&self.local_decls, tcx).ty;
+
+                // ref = &place
+                let ref_ty = tcx.mk_imm_ref(tcx.lifetimes.re_erased, place_ty);
+                let ref_place = self.temp(ref_ty, test.span);
+                self.cfg.push_assign(
+                    block,
+                    source_info,
+                    &ref_place,
+                    Rvalue::Ref(tcx.lifetimes.re_erased, BorrowKind::Shared, place.clone()),
+                );
+
+                // temp = <T as Deref>::deref(ref)
+                let deref_def_id = tcx.require_lang_item(DerefTraitLangItem, None);
+                let method = self.hir.trait_method(deref_def_id, sym::deref, place_ty, &[]);
+                let cleanup = self.diverge_cleanup();
+                self.cfg.terminate(
+                    block,
+                    source_info,
+                    TerminatorKind::Call {
+                        func: Operand::Constant(box Constant {
+                            span: test.span,
+                            user_ty: None,
+                            literal: method,
+                        }),
+                        args: vec![Operand::Move(ref_place)],
+                        destination: Some((temp, target)),
+                        cleanup: Some(cleanup),
+                        from_hir_call: false,
+                    },
+                );
+            }
         }
     }
 
@@ -698,6 +745,15 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 
             (&TestKind::Range { .. }, _) => None,
 
+            (&TestKind::Deref { temp }, &PatKind::DerefPattern { ref subpattern }) => {
+                // `temp` now holds the reference returned by `Deref::deref`, which the
+                // subpattern is matched against.
+                candidate.match_pairs[match_pair_index] = MatchPair::new(temp, subpattern);
+                Some(0)
+            }
+
+            (&TestKind::Deref { .. }, _) => None,
+
             (&TestKind::Eq { .. }, _) | (&TestKind::Len { .. }, _) => {
                 // These are all binary tests.
                 //
@@ -809,6 +865,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 impl Test<'_> {
     pub(super) fn targets(&self) -> usize {
         match self.kind {
+            TestKind::Deref { .. } => 1,
             TestKind::Eq { .. } | TestKind::Range(_) | TestKind::Len { .. } => 2,
             TestKind::Switch { adt_def, .. } => {
                 // While the switch that we generate doesn't test for all
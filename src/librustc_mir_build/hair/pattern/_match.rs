@@ -230,6 +230,9 @@ use self::Usefulness::*;
 use self::WitnessPreference::*;
 
 use rustc_data_structures::captures::Captures;
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_index::vec::Idx;
 
 use super::{compare_const_vals, PatternFoldable, PatternFolder};
@@ -243,9 +246,14 @@ use rustc_hir::{HirId, RangeEnd};
 use rustc::lint;
 use rustc::mir::interpret::{truncate, AllocId, ConstValue, Pointer, Scalar};
 use rustc::mir::Field;
+use rustc::traits;
 use rustc::util::common::ErrorReported;
 
+use rustc_span::symbol::Ident;
+use rustc_apfloat::ieee::{Double, Single};
+use rustc_apfloat::Float;
 use rustc_span::{Span, DUMMY_SP};
+use syntax::ast::FloatTy;
 use syntax::attr::{SignedInt, UnsignedInt};
 
 use arena::TypedArena;
@@ -255,8 +263,10 @@ use std::borrow::Cow;
 use std::cmp::{self, max, min, Ordering};
 use std::convert::TryInto;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::RangeInclusive;
+use std::ptr;
 use std::u128;
 
 crate fn expand_pattern<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, pat: Pat<'tcx>) -> Pat<'tcx> {
@@ -379,6 +389,10 @@ impl<'tcx> Pat<'tcx> {
 
 /// A row of a matrix. Rows of len 1 are very common, which is why `SmallVec[_; 2]`
 /// works well.
+///
+/// Rows are compared and hashed by the identity of their patterns rather than by their
+/// structure. Since the wildcards introduced by specialization are hash-consed (see
+/// `MatchCheckCtxt::ctor_wild_subpatterns`), this is enough to recognize duplicate rows cheaply.
 #[derive(Debug, Clone)]
 crate struct PatStack<'p, 'tcx>(SmallVec<[&'p Pat<'tcx>; 2]>);
 
@@ -452,6 +466,31 @@ impl<'p, 'tcx> PatStack<'p, 'tcx> {
             new_head
         })
     }
+
+    /// Identifies the patterns of this row, for the usefulness cache.
+    fn fingerprint(&self) -> Fingerprint {
+        let mut hasher = StableHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl<'p, 'tcx> PartialEq for PatStack<'p, 'tcx> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(other.0.iter()).all(|(a, b)| ptr::eq(*a, *b))
+    }
+}
+
+impl<'p, 'tcx> Eq for PatStack<'p, 'tcx> {}
+
+impl<'p, 'tcx> Hash for PatStack<'p, 'tcx> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state);
+        for pat in self.0.iter() {
+            ptr::hash(*pat, state);
+        }
+    }
 }
 
 impl<'p, 'tcx> Default for PatStack<'p, 'tcx> {
@@ -469,32 +508,48 @@ impl<'p, 'tcx> FromIterator<&'p Pat<'tcx>> for PatStack<'p, 'tcx> {
     }
 }
 
-/// A 2D matrix.
+/// A 2D matrix. A matrix never contains the same row twice: duplicates cannot change the
+/// usefulness of anything, but or-patterns in machine-generated matches can produce
+/// huge numbers of them.
 #[derive(Clone)]
-crate struct Matrix<'p, 'tcx>(Vec<PatStack<'p, 'tcx>>);
+crate struct Matrix<'p, 'tcx> {
+    rows: Vec<PatStack<'p, 'tcx>>,
+    /// The set of `rows`, to recognize duplicates.
+    seen: FxHashSet<PatStack<'p, 'tcx>>,
+}
 
 impl<'p, 'tcx> Matrix<'p, 'tcx> {
     crate fn empty() -> Self {
-        Matrix(vec![])
+        Matrix { rows: vec![], seen: Default::default() }
     }
 
-    /// Pushes a new row to the matrix. If the row starts with an or-pattern, this expands it.
+    /// Pushes a new row to the matrix, unless it is already there. If the row starts with an
+    /// or-pattern, this expands it, recursively.
     crate fn push(&mut self, row: PatStack<'p, 'tcx>) {
         if let Some(rows) = row.expand_or_pat() {
-            self.0.extend(rows);
-        } else {
-            self.0.push(row);
+            for row in rows {
+                self.push(row);
+            }
+        } else if self.seen.insert(row.clone()) {
+            self.rows.push(row);
         }
     }
 
+    /// Identifies the rows of this matrix, for the usefulness cache.
+    fn fingerprint(&self) -> Fingerprint {
+        let mut hasher = StableHasher::new();
+        self.rows.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Iterate over the first component of each row
     fn heads<'a>(&'a self) -> impl Iterator<Item = &'a Pat<'tcx>> + Captures<'p> {
-        self.0.iter().map(|r| r.head())
+        self.rows.iter().map(|r| r.head())
     }
 
     /// This computes `D(self)`. See top of the file for explanations.
     fn specialize_wildcard(&self) -> Self {
-        self.0.iter().filter_map(|r| r.specialize_wildcard()).collect()
+        self.rows.iter().filter_map(|r| r.specialize_wildcard()).collect()
     }
 
     /// This computes `S(constructor, self)`. See top of the file for explanations.
@@ -504,7 +559,7 @@ impl<'p, 'tcx> Matrix<'p, 'tcx> {
         constructor: &Constructor<'tcx>,
         ctor_wild_subpatterns: &'p [Pat<'tcx>],
     ) -> Matrix<'p, 'tcx> {
-        self.0
+        self.rows
             .iter()
             .filter_map(|r| r.specialize_constructor(cx, constructor, ctor_wild_subpatterns))
             .collect()
@@ -527,7 +582,7 @@ impl<'p, 'tcx> fmt::Debug for Matrix<'p, 'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "\n")?;
 
-        let &Matrix(ref m) = self;
+        let m = &self.rows;
         let pretty_printed_matrix: Vec<Vec<String>> =
             m.iter().map(|row| row.iter().map(|pat| format!("{:?}", pat)).collect()).collect();
 
@@ -579,8 +634,19 @@ crate struct MatchCheckCtxt<'a, 'tcx> {
     crate module: DefId,
     param_env: ty::ParamEnv<'tcx>,
     crate pattern_arena: &'a TypedArena<Pat<'tcx>>,
+    /// The wildcard subpatterns of constructors, hash-consed by their types.
+    wildcard_subpatterns: FxHashMap<Vec<Ty<'tcx>>, &'a [Pat<'tcx>]>,
+    /// The results of `is_useful` for the subproblems that have been solved so far, keyed by the
+    /// fingerprints of the matrix and of the row. This only lives while one match is checked,
+    /// and is emptied whenever it reaches `USEFULNESS_CACHE_LIMIT` entries.
+    usefulness_cache: FxHashMap<UsefulnessCacheKey, Usefulness<'tcx, 'a>>,
 }
 
+type UsefulnessCacheKey = (Fingerprint, Fingerprint, WitnessPreference, HirId);
+
+/// The maximum number of results in the usefulness cache, which bounds the memory it uses.
+const USEFULNESS_CACHE_LIMIT: usize = 1 << 14;
+
 impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
     crate fn create_and_enter<F, R>(
         tcx: TyCtxt<'tcx>,
@@ -593,7 +659,28 @@ impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
     {
         let pattern_arena = TypedArena::default();
 
-        f(MatchCheckCtxt { tcx, param_env, module, pattern_arena: &pattern_arena })
+        f(MatchCheckCtxt {
+            tcx,
+            param_env,
+            module,
+            pattern_arena: &pattern_arena,
+            wildcard_subpatterns: Default::default(),
+            usefulness_cache: Default::default(),
+        })
+    }
+
+    /// Returns the wildcard subpatterns of `ctor`, e.g. `[_, _]` for a pair. Wildcards only
+    /// depend on their type, so these are hash-consed: all rows specialized from wildcards
+    /// share the same patterns, which lets `Matrix` recognize duplicate rows.
+    fn ctor_wild_subpatterns(
+        &mut self,
+        ctor: &Constructor<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> &'a [Pat<'tcx>] {
+        let pats = ctor.wildcard_subpatterns(self, ty);
+        let tys = pats.iter().map(|pat| pat.ty).collect();
+        let pattern_arena = self.pattern_arena;
+        *self.wildcard_subpatterns.entry(tys).or_insert_with(|| pattern_arena.alloc_from_iter(pats))
     }
 
     fn is_uninhabited(&self, ty: Ty<'tcx>) -> bool {
@@ -604,6 +691,44 @@ impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
         }
     }
 
+    /// With `#![feature(deref_patterns)]`, returns `&<ty as Deref>::Target` if `ty` is a smart
+    /// pointer other than `Box` that patterns can match through. The only constructor of
+    /// such a type is `Single`, whose one field is the reference `Deref::deref` returns.
+    fn deref_pattern_ref_ty(&self, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        let tcx = self.tcx;
+        if !tcx.features().deref_patterns {
+            return None;
+        }
+        match ty.kind {
+            ty::Adt(adt, _) if !adt.is_box() => {}
+            _ => return None,
+        }
+        let deref_pure = tcx.lang_items().deref_pure_trait()?;
+        let is_deref_pure = tcx.infer_ctxt().enter(|infcx| {
+            traits::type_known_to_meet_bound_modulo_regions(
+                &infcx,
+                self.param_env,
+                ty,
+                deref_pure,
+                DUMMY_SP,
+            )
+        });
+        if !is_deref_pure {
+            return None;
+        }
+        let trait_ref = ty::TraitRef {
+            def_id: tcx.lang_items().deref_trait()?,
+            substs: tcx.mk_substs_trait(ty, &[]),
+        };
+        let projection =
+            ty::ProjectionTy::from_ref_and_name(tcx, trait_ref, Ident::from_str("Target"));
+        let target = tcx.normalize_erasing_regions(
+            self.param_env,
+            tcx.mk_projection(projection.item_def_id, projection.substs),
+        );
+        Some(tcx.mk_imm_ref(tcx.lifetimes.re_erased, target))
+    }
+
     // Returns whether the given type is an enum from another crate declared `#[non_exhaustive]`.
     crate fn is_foreign_non_exhaustive_enum(&self, ty: Ty<'tcx>) -> bool {
         match ty.kind {
@@ -622,6 +747,25 @@ impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
             _ => false,
         }
     }
+
+    // Returns whether an empty match on the given type is exhaustive, i.e. whether the type is
+    // known to be uninhabited from the current module. Types declared `#[non_exhaustive]` in
+    // another crate are never known to be uninhabited.
+    crate fn is_empty_match_exhaustive(&self, ty: Ty<'tcx>) -> bool {
+        if self.tcx.features().exhaustive_patterns || self.tcx.features().min_exhaustive_patterns {
+            self.tcx.is_ty_uninhabited_from(self.module, ty)
+        } else {
+            match ty.kind {
+                ty::Never => true,
+                ty::Adt(def, _) => {
+                    def.is_enum()
+                        && def.variants.is_empty()
+                        && !self.is_foreign_non_exhaustive_enum(ty)
+                }
+                _ => false,
+            }
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -878,7 +1022,9 @@ impl<'tcx> Constructor<'tcx> {
                 }
                 ty::Ref(_, rty, _) => vec![Pat::wildcard_from_ty(rty)],
                 ty::Adt(adt, substs) => {
-                    if adt.is_box() {
+                    if let Some(ref_ty) = cx.deref_pattern_ref_ty(ty) {
+                        vec![Pat::wildcard_from_ty(ref_ty)]
+                    } else if adt.is_box() {
                         // Use T as the sub pattern type of Box<T>.
                         vec![Pat::wildcard_from_ty(substs.type_at(0))]
                     } else {
@@ -948,6 +1094,7 @@ impl<'tcx> Constructor<'tcx> {
                 ty::Tuple(ref fs) => fs.len() as u64,
                 ty::Slice(..) | ty::Array(..) => bug!("bad slice pattern {:?} {:?}", self, ty),
                 ty::Ref(..) => 1,
+                ty::Adt(..) if cx.deref_pattern_ref_ty(ty).is_some() => 1,
                 ty::Adt(adt, _) => {
                     adt.variants[self.variant_index_for_adt(cx, adt)].fields.len() as u64
                 }
@@ -983,6 +1130,9 @@ impl<'tcx> Constructor<'tcx> {
 
         let pat = match self {
             Single | Variant(_) => match ty.kind {
+                ty::Adt(..) if cx.deref_pattern_ref_ty(ty).is_some() => {
+                    PatKind::DerefPattern { subpattern: subpatterns.nth(0).unwrap() }
+                }
                 ty::Adt(..) | ty::Tuple(..) => {
                     let subpatterns = subpatterns
                         .enumerate()
@@ -1049,6 +1199,12 @@ impl<'tcx> Constructor<'tcx> {
     }
 }
 
+/// The maximum number of witnesses of non-exhaustiveness that are reported. There can be
+/// exponentially many of them, e.g. when matching on a tuple of enums, but only a few are shown
+/// to the user. At most `WITNESS_LIMIT + 1` witnesses are computed, so having more than
+/// `WITNESS_LIMIT` of them means that the limit was exceeded.
+crate const WITNESS_LIMIT: usize = 100;
+
 #[derive(Clone, Debug)]
 crate enum Usefulness<'tcx, 'p> {
     /// Carries a list of unreachable subpatterns. Used only in the presence of or-patterns.
@@ -1129,6 +1285,7 @@ impl<'tcx, 'p> Usefulness<'tcx, 'p> {
                                 witness
                             })
                         })
+                        .take(WITNESS_LIMIT + 1)
                         .collect(),
                 )
             }
@@ -1137,7 +1294,7 @@ impl<'tcx, 'p> Usefulness<'tcx, 'p> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 crate enum WitnessPreference {
     ConstructWitness,
     LeaveOutWitness,
@@ -1328,6 +1485,11 @@ fn all_constructors<'a, 'tcx>(
             let max = truncate(u128::max_value(), size);
             vec![make_range(0, max)]
         }
+        ty::Float(_) => {
+            // NaN is not equal to any float, so only a wildcard can match every float. We treat
+            // floats like `#[non_exhaustive]` enums, their patterns are still `IntRange`s.
+            vec![NonExhaustive]
+        }
         _ => {
             if cx.is_uninhabited(pcx.ty) {
                 vec![]
@@ -1346,6 +1508,9 @@ fn all_constructors<'a, 'tcx>(
 /// This makes comparisons and arithmetic on interval endpoints much more
 /// straightforward. See `signed_bias` for details.
 ///
+/// Floats are encoded such that the order of the encodings is the order of the
+/// floats, see `float_bounds`.
+///
 /// `IntRange` is never used to encode an empty range or a "range" that wraps
 /// around the (offset) space: i.e., `range.lo <= range.hi`.
 #[derive(Clone, Debug)]
@@ -1391,6 +1556,39 @@ impl<'tcx> IntRange<'tcx> {
         }
     }
 
+    /// The sign bit of the floats of type `ty`.
+    fn float_sign_bit(ty: Ty<'_>) -> Option<u128> {
+        match ty.kind {
+            ty::Float(fty) => Some(1u128 << (fty.bit_width() - 1)),
+            _ => None,
+        }
+    }
+
+    /// Returns the lowest and the highest encoding of the floats equal to the float with the
+    /// given bits, or `None` if it is NaN, which is not equal to anything.
+    ///
+    /// Negative floats have all their bits flipped, so that larger magnitudes come first, and
+    /// positive floats have their sign bit set, so that they come after all the negative ones.
+    /// `-0.0` and `0.0` are equal and their encodings are adjacent.
+    fn float_bounds(ty: Ty<'_>, bits: u128) -> Option<(u128, u128)> {
+        let is_nan = match ty.kind {
+            ty::Float(FloatTy::F32) => Single::from_bits(bits).is_nan(),
+            ty::Float(FloatTy::F64) => Double::from_bits(bits).is_nan(),
+            _ => bug!("`float_bounds` called with non-float type {:?}", ty),
+        };
+        if is_nan {
+            return None;
+        }
+        let sign_bit = Self::float_sign_bit(ty).unwrap();
+        let val = if bits & sign_bit == 0 { bits | sign_bit } else { !bits & (sign_bit * 2 - 1) };
+        // The encodings of `-0.0` and `0.0` are `sign_bit - 1` and `sign_bit`.
+        if val == sign_bit - 1 || val == sign_bit {
+            Some((sign_bit - 1, sign_bit))
+        } else {
+            Some((val, val))
+        }
+    }
+
     #[inline]
     fn from_const(
         tcx: TyCtxt<'tcx>,
@@ -1398,6 +1596,11 @@ impl<'tcx> IntRange<'tcx> {
         value: &Const<'tcx>,
         span: Span,
     ) -> Option<IntRange<'tcx>> {
+        if let ty::Float(_) = value.ty.kind {
+            let bits = value.try_eval_bits(tcx, param_env, value.ty)?;
+            let (lo, hi) = Self::float_bounds(value.ty, bits)?;
+            return Some(IntRange { range: lo..=hi, ty: value.ty, span });
+        }
         if let Some((target_size, bias)) = Self::integral_size_and_signed_bias(tcx, value.ty) {
             let ty = value.ty;
             let val = if let ty::ConstKind::Value(ConstValue::Scalar(Scalar::Raw { data, size })) =
@@ -1442,6 +1645,15 @@ impl<'tcx> IntRange<'tcx> {
                 bug!("malformed range pattern: {}..={}", lo, (hi - offset));
             }
             Some(IntRange { range: lo..=(hi - offset), ty, span })
+        } else if let ty::Float(_) = ty.kind {
+            let (lo, _) = Self::float_bounds(ty, lo)?;
+            let (hi_lo, hi_hi) = Self::float_bounds(ty, hi)?;
+            // An excluded end excludes all the floats equal to it.
+            let hi = if *end == RangeEnd::Excluded { hi_lo.checked_sub(1)? } else { hi_hi };
+            if lo > hi {
+                return None;
+            }
+            Some(IntRange { range: lo..=hi, ty, span })
         } else {
             None
         }
@@ -1539,8 +1751,17 @@ impl<'tcx> IntRange<'tcx> {
     fn to_pat(&self, tcx: TyCtxt<'tcx>) -> Pat<'tcx> {
         let (lo, hi) = self.boundaries();
 
-        let bias = IntRange::signed_bias(tcx, self.ty);
-        let (lo, hi) = (lo ^ bias, hi ^ bias);
+        let (lo, hi) = if let Some(sign_bit) = IntRange::float_sign_bit(self.ty) {
+            // Print `0.0` rather than `-0.0` when the range contains both.
+            let lo = if lo == sign_bit - 1 && hi >= sign_bit { sign_bit } else { lo };
+            let decode = |val: u128| {
+                if val & sign_bit != 0 { val ^ sign_bit } else { !val & (sign_bit * 2 - 1) }
+            };
+            (decode(lo), decode(hi))
+        } else {
+            let bias = IntRange::signed_bias(tcx, self.ty);
+            (lo ^ bias, hi ^ bias)
+        };
 
         let ty = ty::ParamEnv::empty().and(self.ty);
         let lo_const = ty::Const::from_bits(tcx, lo, ty);
@@ -1623,6 +1844,10 @@ impl<'tcx> fmt::Debug for MissingConstructors<'tcx> {
 /// relation to preceding patterns, it is not reachable) and exhaustiveness
 /// checking (if a wildcard pattern is useful in relation to a matrix, the
 /// matrix isn't exhaustive).
+///
+/// The results are cached: since rows are compared by the identity of their patterns, the
+/// subproblems that or-patterns give rise to are cheap to recognize, and are often the same for
+/// all the alternatives of an or-pattern.
 crate fn is_useful<'p, 'tcx>(
     cx: &mut MatchCheckCtxt<'p, 'tcx>,
     matrix: &Matrix<'p, 'tcx>,
@@ -1631,7 +1856,30 @@ crate fn is_useful<'p, 'tcx>(
     hir_id: HirId,
     is_top_level: bool,
 ) -> Usefulness<'tcx, 'p> {
-    let &Matrix(ref rows) = matrix;
+    if is_top_level {
+        return is_useful_uncached(cx, matrix, v, witness_preference, hir_id, true);
+    }
+    let key = (matrix.fingerprint(), v.fingerprint(), witness_preference, hir_id);
+    if let Some(usefulness) = cx.usefulness_cache.get(&key) {
+        return usefulness.clone();
+    }
+    let usefulness = is_useful_uncached(cx, matrix, v, witness_preference, hir_id, false);
+    if cx.usefulness_cache.len() >= USEFULNESS_CACHE_LIMIT {
+        cx.usefulness_cache.clear();
+    }
+    cx.usefulness_cache.insert(key, usefulness.clone());
+    usefulness
+}
+
+fn is_useful_uncached<'p, 'tcx>(
+    cx: &mut MatchCheckCtxt<'p, 'tcx>,
+    matrix: &Matrix<'p, 'tcx>,
+    v: &PatStack<'p, 'tcx>,
+    witness_preference: WitnessPreference,
+    hir_id: HirId,
+    is_top_level: bool,
+) -> Usefulness<'tcx, 'p> {
+    let rows = &matrix.rows;
     debug!("is_useful({:#?}, {:#?})", matrix, v);
 
     // The base case. We are pattern-matching on () and the return value is
@@ -1817,8 +2065,7 @@ fn is_useful_specialized<'p, 'tcx>(
 ) -> Usefulness<'tcx, 'p> {
     debug!("is_useful_specialized({:#?}, {:#?}, {:?})", v, ctor, lty);
 
-    let ctor_wild_subpatterns =
-        cx.pattern_arena.alloc_from_iter(ctor.wildcard_subpatterns(cx, lty));
+    let ctor_wild_subpatterns = cx.ctor_wild_subpatterns(&ctor, lty);
     let matrix = matrix.specialize_constructor(cx, &ctor, ctor_wild_subpatterns);
     v.specialize_constructor(cx, &ctor, ctor_wild_subpatterns)
         .map(|v| is_useful(cx, &matrix, &v, witness_preference, hir_id, false))
@@ -1836,7 +2083,9 @@ fn pat_constructor<'tcx>(
     match *pat.kind {
         PatKind::AscribeUserType { .. } => bug!(), // Handled by `expand_pattern`
         PatKind::Binding { .. } | PatKind::Wild => None,
-        PatKind::Leaf { .. } | PatKind::Deref { .. } => Some(Single),
+        PatKind::Leaf { .. } | PatKind::Deref { .. } | PatKind::DerefPattern { .. } => {
+            Some(Single)
+        }
         PatKind::Variant { adt_def, variant_index, .. } => {
             Some(Variant(adt_def.variants[variant_index].def_id))
         }
@@ -2049,14 +2298,16 @@ fn split_grouped_constructors<'p, 'tcx>(
                 // `borders` is the set of borders between equivalence classes: each equivalence
                 // class lies between 2 borders.
                 let row_borders = matrix
-                    .0
+                    .rows
                     .iter()
                     .flat_map(|row| {
                         IntRange::from_pat(tcx, param_env, row.head()).map(|r| (r, row.len()))
                     })
                     .flat_map(|(range, row_len)| {
                         let intersection = ctor_range.intersection(tcx, &range);
-                        let should_lint = ctor_range.suspicious_intersection(&range);
+                        // FIXME: also lint on float ranges.
+                        let should_lint = IntRange::is_integral(ty)
+                            && ctor_range.suspicious_intersection(&range);
                         if let (Some(range), 1, true) = (&intersection, row_len, should_lint) {
                             // FIXME: for now, only check for overlapping ranges on simple range
                             // patterns. Otherwise with the current logic the following is detected
@@ -2348,7 +2599,9 @@ fn specialize_one_pattern<'p, 'tcx>(
             Some(patterns_for_variant(cx, subpatterns, ctor_wild_subpatterns, false))
         }
 
-        PatKind::Deref { ref subpattern } => Some(PatStack::from_pattern(subpattern)),
+        PatKind::Deref { ref subpattern } | PatKind::DerefPattern { ref subpattern } => {
+            Some(PatStack::from_pattern(subpattern))
+        }
 
         PatKind::Constant { value } if constructor.is_slice() => {
             // We extract an `Option` for the pointer because slices of zero
@@ -1,6 +1,6 @@
 use super::_match::Usefulness::*;
 use super::_match::WitnessPreference::*;
-use super::_match::{expand_pattern, is_useful, MatchCheckCtxt, Matrix, PatStack};
+use super::_match::{expand_pattern, is_useful, MatchCheckCtxt, Matrix, PatStack, WITNESS_LIMIT};
 
 use super::{PatCtxt, PatKind, PatternError};
 
@@ -442,16 +442,7 @@ fn check_exhaustive<'p, 'tcx>(
     // In the absence of the `exhaustive_patterns` feature, empty matches are not detected by
     // `is_useful` to exhaustively match uninhabited types, so we manually check here.
     if is_empty_match && !cx.tcx.features().exhaustive_patterns {
-        let scrutinee_is_visibly_uninhabited = match scrut_ty.kind {
-            ty::Never => true,
-            ty::Adt(def, _) => {
-                def.is_enum()
-                    && def.variants.is_empty()
-                    && !cx.is_foreign_non_exhaustive_enum(scrut_ty)
-            }
-            _ => false,
-        };
-        if scrutinee_is_visibly_uninhabited {
+        if cx.is_empty_match_exhaustive(scrut_ty) {
             // If the type *is* uninhabited, an empty match is vacuously exhaustive.
             return;
         }
@@ -490,9 +481,46 @@ fn check_exhaustive<'p, 'tcx>(
         "ensure that all possible cases are being handled, \
          possibly by adding wildcards or more match arms",
     );
+    if is_empty_match {
+        explain_foreign_non_exhaustive(cx, &mut err, scrut_ty);
+    }
     err.emit();
 }
 
+/// Explains why an empty match on an uninhabited type declared `#[non_exhaustive]` in another
+/// crate is not exhaustive, if uninhabited types may be matched by empty matches.
+fn explain_foreign_non_exhaustive(
+    cx: &MatchCheckCtxt<'_, '_>,
+    err: &mut DiagnosticBuilder<'_>,
+    ty: Ty<'_>,
+) {
+    if !cx.tcx.features().exhaustive_patterns && !cx.tcx.features().min_exhaustive_patterns {
+        return;
+    }
+    let def = match ty.kind {
+        ty::Adt(def, _) if !def.did.is_local() => def,
+        _ => return,
+    };
+    let is_declared_non_exhaustive = if def.is_enum() {
+        def.is_variant_list_non_exhaustive()
+    } else {
+        def.non_enum_variant().is_field_list_non_exhaustive()
+    };
+    if is_declared_non_exhaustive {
+        err.note(&format!(
+            "`{}` is marked `#[non_exhaustive]` in another crate, so it is never considered \
+             uninhabited outside of that crate",
+            ty,
+        ));
+    } else if def.variants.iter().any(|variant| variant.is_field_list_non_exhaustive()) {
+        err.note(&format!(
+            "`{}` has variants marked `#[non_exhaustive]` in another crate, so they are never \
+             considered uninhabited outside of that crate",
+            ty,
+        ));
+    }
+}
+
 fn joined_uncovered_patterns(witnesses: &[super::Pat<'_>]) -> String {
     const LIMIT: usize = 3;
     match witnesses {
@@ -502,6 +530,10 @@ fn joined_uncovered_patterns(witnesses: &[super::Pat<'_>]) -> String {
             let head: Vec<_> = head.iter().map(<_>::to_string).collect();
             format!("`{}` and `{}`", head.join("`, `"), tail)
         }
+        _ if witnesses.len() > WITNESS_LIMIT => {
+            let head: Vec<_> = witnesses[..LIMIT].iter().map(<_>::to_string).collect();
+            format!("`{}` and over {} more", head.join("`, `"), WITNESS_LIMIT - LIMIT)
+        }
         _ => {
             let (head, tail) = witnesses.split_at(LIMIT);
             let head: Vec<_> = head.iter().map(<_>::to_string).collect();
@@ -541,9 +573,11 @@ fn maybe_point_at_variant(ty: Ty<'_>, patterns: &[super::Pat<'_>]) -> Vec<Span>
         // Don't point at variants that have already been covered due to other patterns to avoid
         // visual clutter.
         for pattern in patterns {
-            use PatKind::{AscribeUserType, Deref, Leaf, Or, Variant};
+            use PatKind::{AscribeUserType, Deref, DerefPattern, Leaf, Or, Variant};
             match &*pattern.kind {
-                AscribeUserType { subpattern, .. } | Deref { subpattern } => {
+                AscribeUserType { subpattern, .. }
+                | Deref { subpattern }
+                | DerefPattern { subpattern } => {
                     covered.extend(maybe_point_at_variant(ty, slice::from_ref(&subpattern)));
                 }
                 Variant { adt_def, variant_index, subpatterns, .. } if adt_def.did == def.did => {
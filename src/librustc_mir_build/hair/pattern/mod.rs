@@ -152,6 +152,12 @@ crate enum PatKind<'tcx> {
         subpattern: Pat<'tcx>,
     },
 
+    /// A deref pattern (`#![feature(deref_patterns)]`) on a smart pointer other than `Box`:
+    /// `subpattern` is matched against the reference that `Deref::deref` returns.
+    DerefPattern {
+        subpattern: Pat<'tcx>,
+    },
+
     Constant {
         value: &'tcx ty::Const<'tcx>,
     },
@@ -305,6 +311,11 @@ impl<'tcx> fmt::Display for Pat<'tcx> {
                 }
                 write!(f, "{}", subpattern)
             }
+            PatKind::DerefPattern { ref subpattern } => match *subpattern.kind {
+                // The reference returned by `Deref::deref` does not appear in the source.
+                PatKind::Deref { subpattern: ref pointee } => write!(f, "{}", pointee),
+                _ => write!(f, "{}", subpattern),
+            },
             PatKind::Constant { value } => write!(f, "{}", value),
             PatKind::Range(PatRange { lo, hi, end }) => {
                 write!(f, "{}", lo)?;
@@ -400,16 +411,36 @@ impl<'a, 'tcx> PatCtxt<'a, 'tcx> {
         // we wrap the unadjusted pattern in `PatKind::Deref` repeatedly, consuming the
         // adjustments in *reverse order* (last-in-first-out, so that the last `Deref` inserted
         // gets the least-dereferenced type).
+        //
+        // With `#![feature(deref_patterns)]`, the adjustments can also contain smart pointers.
+        // Apart from `Box`, which is dereferenced like a reference, these become a
+        // `PatKind::DerefPattern` matching the pattern against the result of `Deref::deref`.
+        let tcx = self.tcx;
         let unadjusted_pat = self.lower_pattern_unadjusted(pat);
         self.tables.pat_adjustments().get(pat.hir_id).unwrap_or(&vec![]).iter().rev().fold(
             unadjusted_pat,
             |pat, ref_ty| {
                 debug!("{:?}: wrapping pattern with type {:?}", pat, ref_ty);
-                Pat {
-                    span: pat.span,
-                    ty: ref_ty,
-                    kind: Box::new(PatKind::Deref { subpattern: pat }),
-                }
+                let span = pat.span;
+                let kind = match ref_ty.kind {
+                    ty::Adt(adt_def, _) if !adt_def.is_box() => {
+                        let subpattern = match *pat.kind {
+                            // A string literal matched against a `str` is compared with the
+                            // `&str` directly.
+                            PatKind::Constant { value } if pat.ty.is_str() => {
+                                Pat { ty: value.ty, ..pat }
+                            }
+                            _ => Pat {
+                                span,
+                                ty: tcx.mk_imm_ref(tcx.lifetimes.re_erased, pat.ty),
+                                kind: Box::new(PatKind::Deref { subpattern: pat }),
+                            },
+                        };
+                        PatKind::DerefPattern { subpattern }
+                    }
+                    _ => PatKind::Deref { subpattern: pat },
+                };
+                Pat { span, ty: ref_ty, kind: Box::new(kind) }
             },
         )
     }
@@ -977,6 +1008,9 @@ impl<'tcx> PatternFoldable<'tcx> for PatKind<'tcx> {
             PatKind::Deref { ref subpattern } => {
                 PatKind::Deref { subpattern: subpattern.fold_with(folder) }
             }
+            PatKind::DerefPattern { ref subpattern } => {
+                PatKind::DerefPattern { subpattern: subpattern.fold_with(folder) }
+            }
             PatKind::Constant { value } => PatKind::Constant { value },
             PatKind::Range(range) => PatKind::Range(range),
             PatKind::Slice { ref prefix, ref slice, ref suffix } => PatKind::Slice {
@@ -1,10 +1,16 @@
 use rustc::hir::map::blocks::FnLikeNode;
+use rustc::hir::map::Map;
 use rustc::lint::builtin::UNCONDITIONAL_RECURSION;
 use rustc::mir::{self, Body, TerminatorKind};
-use rustc::ty::subst::InternalSubsts;
-use rustc::ty::{self, AssocItem, AssocItemContainer, Instance, TyCtxt};
+use rustc::ty::adjustment::Adjust;
+use rustc::ty::subst::{GenericArg, InternalSubsts, Subst, SubstsRef};
+use rustc::ty::{self, AssocItem, AssocItemContainer, Instance, TyCtxt, TypeckTables};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
 use rustc_hir::def_id::DefId;
-use rustc_hir::intravisit::FnKind;
+use rustc_hir::intravisit::{self, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::HirId;
 use rustc_index::bit_set::BitSet;
 
 crate fn check<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, def_id: DefId) {
@@ -68,6 +74,20 @@ fn check_fn_for_unconditional_recursion<'tcx>(
         _ => 0,
     };
     let caller_substs = &InternalSubsts::identity_for_item(tcx, def_id)[..trait_substs_count];
+    let is_self_call = |fn_def_id: DefId, substs: SubstsRef<'tcx>| {
+        is_recursive_call(tcx, param_env, def_id, caller_substs, fn_def_id, substs)
+    };
+
+    // Besides calling itself directly, a function recurses if it calls another local function
+    // that always calls it back, i.e., a default method of a trait it implements, a closure, or
+    // a helper. Looking at the bodies of other functions requires their type-checking results,
+    // which may again require evaluating a `const fn`, so this is skipped for those.
+    let check_callees = !tcx.is_const_fn_raw(def_id);
+    let fn_ptr_aliases = if check_callees {
+        fn_ptr_aliases(tcx, def_id, &is_self_call)
+    } else {
+        FxHashSet::default()
+    };
 
     while let Some(bb) = reachable_without_self_call_queue.pop() {
         if !visited.insert(bb) {
@@ -83,23 +103,40 @@ fn check_fn_for_unconditional_recursion<'tcx>(
                     let func_ty = func.ty(body, tcx);
 
                     if let ty::FnDef(fn_def_id, substs) = func_ty.kind {
-                        let (call_fn_id, call_substs) = if let Some(instance) =
-                            Instance::resolve(tcx, param_env, fn_def_id, substs)
-                        {
+                        let instance = Instance::resolve(tcx, param_env, fn_def_id, substs);
+                        let (call_fn_id, call_substs) = if let Some(instance) = instance {
                             (instance.def_id(), instance.substs)
                         } else {
                             (fn_def_id, substs)
                         };
 
-                        let is_self_call = call_fn_id == def_id
+                        // Only look at the body of the callee if it is known, and not a method
+                        // which may still be overridden.
+                        let is_indirect_self_call = || {
+                            check_callees
+                                && instance.is_some()
+                                && always_calls(
+                                    tcx,
+                                    call_fn_id,
+                                    &fn_ptr_aliases,
+                                    |callee, substs| {
+                                        is_self_call(callee, substs.subst(tcx, call_substs))
+                                    },
+                                )
+                        };
+
+                        let is_direct_self_call = call_fn_id == def_id
                             && &call_substs[..caller_substs.len()] == caller_substs;
 
-                        if is_self_call {
-                            self_call_locations.push(terminator.source_info);
+                        if is_direct_self_call {
+                            self_call_locations.push((terminator.source_info, None));
 
                             //this is a self call so we shouldn't explore
                             //further down this path
                             continue;
+                        } else if call_fn_id != def_id && is_indirect_self_call() {
+                            self_call_locations.push((terminator.source_info, Some(call_fn_id)));
+                            continue;
                         }
                     }
                 }
@@ -132,10 +169,273 @@ fn check_fn_for_unconditional_recursion<'tcx>(
         );
         db.span_label(sp, "cannot return without recursing");
         // offer some help to the programmer.
-        for location in &self_call_locations {
-            db.span_label(location.span, "recursive call site");
+        for (location, through) in &self_call_locations {
+            match through {
+                None => db.span_label(location.span, "recursive call site"),
+                Some(callee) if tcx.is_closure(*callee) => {
+                    db.span_label(location.span, "recursive call site through a closure")
+                }
+                Some(callee) => db.span_label(
+                    location.span,
+                    format!("recursive call site through `{}`", tcx.def_path_str(*callee)),
+                ),
+            };
         }
         db.help("a `loop` may express intention better if this is on purpose");
         db.emit();
     }
 }
+
+/// Whether the function `fn_def_id`, called with `substs`, is the function `def_id` whose body
+/// is being checked, with `caller_substs` being the trait parameters of `def_id` (if any).
+fn is_recursive_call<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    def_id: DefId,
+    caller_substs: &[GenericArg<'tcx>],
+    fn_def_id: DefId,
+    substs: SubstsRef<'tcx>,
+) -> bool {
+    let substs = tcx.erase_regions(&substs);
+    let (call_fn_id, call_substs) =
+        if let Some(instance) = Instance::resolve(tcx, param_env, fn_def_id, substs) {
+            (instance.def_id(), instance.substs)
+        } else {
+            (fn_def_id, substs)
+        };
+
+    call_fn_id == def_id
+        && call_substs.len() >= caller_substs.len()
+        && &call_substs[..caller_substs.len()] == caller_substs
+}
+
+/// Collects the immutable bindings in the body of `def_id` that hold a function pointer to a
+/// function for which `is_target` holds, e.g., `let f: fn() = foo;`. These may be captured by
+/// closures.
+fn fn_ptr_aliases<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    is_target: &dyn Fn(DefId, SubstsRef<'tcx>) -> bool,
+) -> FxHashSet<HirId> {
+    struct AliasCollector<'a, 'tcx> {
+        tables: &'a TypeckTables<'tcx>,
+        is_target: &'a dyn Fn(DefId, SubstsRef<'tcx>) -> bool,
+        aliases: FxHashSet<HirId>,
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for AliasCollector<'a, 'tcx> {
+        type Map = Map<'tcx>;
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<'_, Self::Map> {
+            NestedVisitorMap::None
+        }
+
+        fn visit_local(&mut self, local: &'tcx hir::Local<'tcx>) {
+            if let (
+                hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, hir_id, _, None),
+                Some(init),
+            ) = (&local.pat.kind, local.init)
+            {
+                if let ty::FnDef(fn_def_id, substs) = self.tables.expr_ty(init).kind {
+                    if (self.is_target)(fn_def_id, substs) {
+                        self.aliases.insert(*hir_id);
+                    }
+                }
+            }
+            intravisit::walk_local(self, local);
+        }
+    }
+
+    let hir_id = tcx.hir().as_local_hir_id(def_id).unwrap();
+    let body_id = match tcx.hir().maybe_body_owned_by(hir_id) {
+        Some(body_id) => body_id,
+        None => return FxHashSet::default(),
+    };
+    let mut collector = AliasCollector {
+        tables: tcx.typeck_tables_of(def_id),
+        is_target,
+        aliases: FxHashSet::default(),
+    };
+    collector.visit_body(tcx.hir().body(body_id));
+    collector.aliases
+}
+
+/// Whether every execution of the local function `def_id` calls a function for which
+/// `is_target` holds before returning, with the substitutions of that call being in terms of
+/// the generics of `def_id`.
+///
+/// This is a syntactic approximation, so that it causes no false positives: only the code that
+/// is always executed before any branch, loop, or early exit of the body is considered.
+fn always_calls<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    fn_ptr_aliases: &FxHashSet<HirId>,
+    is_target: impl Fn(DefId, SubstsRef<'tcx>) -> bool,
+) -> bool {
+    let hir_id = match tcx.hir().as_local_hir_id(def_id) {
+        Some(hir_id) => hir_id,
+        None => return false,
+    };
+    let body_id = match tcx.hir().maybe_body_owned_by(hir_id) {
+        Some(body_id) => body_id,
+        None => return false,
+    };
+    let tables = tcx.typeck_tables_of(def_id);
+    if tables.tainted_by_errors {
+        return false;
+    }
+    let finder = CallFinder { tcx, tables, fn_ptr_aliases, is_target: &is_target };
+    finder.expr_calls(&tcx.hir().body(body_id).value)
+}
+
+struct CallFinder<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    tables: &'a TypeckTables<'tcx>,
+    fn_ptr_aliases: &'a FxHashSet<HirId>,
+    is_target: &'a dyn Fn(DefId, SubstsRef<'tcx>) -> bool,
+}
+
+impl<'tcx> CallFinder<'_, 'tcx> {
+    /// Whether evaluating `expr` always calls a target function.
+    fn expr_calls(&self, expr: &'tcx hir::Expr<'tcx>) -> bool {
+        use hir::ExprKind;
+
+        let calls = match expr.kind {
+            ExprKind::Call(callee, args) => {
+                self.sequence_calls(Some(callee).into_iter().chain(args))
+                    || self.calls_callee(callee) && !args.iter().any(|arg| self.may_exit(arg))
+            }
+            ExprKind::MethodCall(_, _, args) => {
+                self.sequence_calls(args)
+                    || self.calls_overloaded(expr) && !args.iter().any(|arg| self.may_exit(arg))
+            }
+            ExprKind::Binary(op, lhs, _) if op.node.is_lazy() => self.expr_calls(lhs),
+            ExprKind::Binary(_, lhs, rhs)
+            | ExprKind::AssignOp(_, lhs, rhs)
+            | ExprKind::Index(lhs, rhs) => {
+                self.sequence_calls([lhs, rhs].iter().copied())
+                    || self.calls_overloaded(expr) && !self.may_exit(lhs) && !self.may_exit(rhs)
+            }
+            ExprKind::Unary(_, operand) => {
+                self.expr_calls(operand) || self.calls_overloaded(expr) && !self.may_exit(operand)
+            }
+            ExprKind::Assign(lhs, rhs, _) => self.sequence_calls([rhs, lhs].iter().copied()),
+            ExprKind::Array(exprs) | ExprKind::Tup(exprs) => self.sequence_calls(exprs),
+            ExprKind::Struct(_, fields, base) => {
+                self.sequence_calls(fields.iter().map(|field| field.expr).chain(base))
+            }
+            ExprKind::Box(operand)
+            | ExprKind::Cast(operand, _)
+            | ExprKind::Type(operand, _)
+            | ExprKind::DropTemps(operand)
+            | ExprKind::Field(operand, _)
+            | ExprKind::AddrOf(_, _, operand)
+            | ExprKind::Ret(Some(operand)) => self.expr_calls(operand),
+            // The scrutinee is evaluated whatever arm is taken.
+            ExprKind::Match(scrutinee, ..) => self.expr_calls(scrutinee),
+            ExprKind::Block(block, _) => self.block_calls(block),
+            _ => false,
+        };
+        calls || self.calls_overloaded_deref(expr)
+    }
+
+    /// Whether evaluating `exprs` in order always calls a target function, i.e., one of them
+    /// does and none of the ones before may exit early.
+    fn sequence_calls(&self, exprs: impl IntoIterator<Item = &'tcx hir::Expr<'tcx>>) -> bool {
+        for expr in exprs {
+            if self.expr_calls(expr) {
+                return true;
+            }
+            if self.may_exit(expr) {
+                return false;
+            }
+        }
+        false
+    }
+
+    fn block_calls(&self, block: &'tcx hir::Block<'tcx>) -> bool {
+        let stmt_exprs = block.stmts.iter().filter_map(|stmt| match stmt.kind {
+            hir::StmtKind::Local(local) => local.init,
+            hir::StmtKind::Expr(expr) | hir::StmtKind::Semi(expr) => Some(expr),
+            hir::StmtKind::Item(_) => None,
+        });
+        self.sequence_calls(stmt_exprs.chain(block.expr))
+    }
+
+    /// Whether `callee`, the function of a call expression, is a target function.
+    fn calls_callee(&self, callee: &hir::Expr<'_>) -> bool {
+        match self.tables.expr_ty(callee).kind {
+            ty::FnDef(def_id, substs) => (self.is_target)(def_id, substs),
+            ty::FnPtr(_) => match callee.kind {
+                hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+                    Res::Local(hir_id) => self.fn_ptr_aliases.contains(&hir_id),
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` is a method call or an overloaded operator calling a target function.
+    fn calls_overloaded(&self, expr: &hir::Expr<'_>) -> bool {
+        match self.tables.type_dependent_def_id(expr.hir_id) {
+            Some(def_id) => (self.is_target)(def_id, self.tables.node_substs(expr.hir_id)),
+            None => false,
+        }
+    }
+
+    /// Whether the adjustments of `expr` call a target `Deref` or `DerefMut` impl.
+    fn calls_overloaded_deref(&self, expr: &hir::Expr<'_>) -> bool {
+        let mut source = match self.tables.expr_ty_opt(expr) {
+            Some(ty) => ty,
+            None => return false,
+        };
+        for adjustment in self.tables.expr_adjustments(expr) {
+            if let Adjust::Deref(Some(ref deref)) = adjustment.kind {
+                let (def_id, substs) = deref.method_call(self.tcx, source);
+                if (self.is_target)(def_id, substs) {
+                    return true;
+                }
+            }
+            source = adjustment.target;
+        }
+        false
+    }
+
+    /// Whether evaluating `expr` may exit the body or skip some code after it in the body, by
+    /// returning, breaking, diverging or using the `?` operator.
+    fn may_exit(&self, expr: &'tcx hir::Expr<'tcx>) -> bool {
+        struct ExitFinder<'a, 'tcx> {
+            tables: &'a TypeckTables<'tcx>,
+            found: bool,
+        }
+
+        impl<'a, 'tcx> Visitor<'tcx> for ExitFinder<'a, 'tcx> {
+            type Map = Map<'tcx>;
+
+            fn nested_visit_map(&mut self) -> NestedVisitorMap<'_, Self::Map> {
+                // Closures are not executed where they are defined.
+                NestedVisitorMap::None
+            }
+
+            fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+                match expr.kind {
+                    hir::ExprKind::Ret(_)
+                    | hir::ExprKind::Break(..)
+                    | hir::ExprKind::Continue(_)
+                    | hir::ExprKind::Yield(..)
+                    | hir::ExprKind::Match(_, _, hir::MatchSource::TryDesugar) => self.found = true,
+                    _ if self.tables.expr_ty_opt(expr).map_or(false, |ty| ty.is_never()) => {
+                        self.found = true
+                    }
+                    _ => intravisit::walk_expr(self, expr),
+                }
+            }
+        }
+
+        let mut finder = ExitFinder { tables: self.tables, found: false };
+        finder.visit_expr(expr);
+        finder.found
+    }
+}
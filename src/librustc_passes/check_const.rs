@@ -50,6 +50,10 @@ impl NonConstExpr {
 
             Self::Loop(Loop) => &[sym::const_loop],
 
+            // The `?` operator calls the methods of `Try` and `From`, which are only const in the
+            // const impls of these traits.
+            Self::Match(TryDesugar) => &[sym::const_if_match, sym::const_trait_impl],
+
             Self::Loop(While)
             | Self::Loop(WhileLet)
             | Self::Match(WhileDesugar)
@@ -126,6 +126,14 @@ impl<'a, 'tcx> Visitor<'tcx> for ReachableContext<'a, 'tcx> {
                                 self.worklist.push(hir_id);
                             }
 
+                            // Statics are linked to, and their initializers
+                            // may also be evaluated by other crates, so we
+                            // recurse into them as well.
+                            Res::Def(DefKind::Static, _) => {
+                                self.reachable_symbols.insert(hir_id);
+                                self.worklist.push(hir_id);
+                            }
+
                             // If this wasn't a static, then the destination is
                             // surely reachable.
                             _ => {
@@ -251,8 +259,11 @@ impl<'a, 'tcx> ReachableContext<'a, 'tcx> {
 
                     // Reachable constants will be inlined into other crates
                     // unconditionally, so we need to make sure that their
-                    // contents are also reachable.
-                    hir::ItemKind::Const(_, init) => {
+                    // contents are also reachable. The same goes for the
+                    // initializers of reachable statics, which the static
+                    // initializers of other crates may read: the statics and
+                    // functions they point to must be linkable from there.
+                    hir::ItemKind::Const(_, init) | hir::ItemKind::Static(_, _, init) => {
                         self.visit_nested_body(init);
                     }
 
@@ -263,7 +274,6 @@ impl<'a, 'tcx> ReachableContext<'a, 'tcx> {
                     | hir::ItemKind::Use(..)
                     | hir::ItemKind::OpaqueTy(..)
                     | hir::ItemKind::TyAlias(..)
-                    | hir::ItemKind::Static(..)
                     | hir::ItemKind::Mod(..)
                     | hir::ItemKind::ForeignMod(..)
                     | hir::ItemKind::Impl { .. }
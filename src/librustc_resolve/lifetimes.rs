@@ -20,6 +20,7 @@ use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_hir::{GenericArg, GenericParam, LifetimeName, Node, ParamName, QPath};
 use rustc_hir::{GenericParamKind, HirIdMap, HirIdSet, LifetimeParamKind};
 use rustc_span::symbol::{kw, sym};
+use rustc_span::source_map::SourceMap;
 use rustc_span::Span;
 use std::borrow::Cow;
 use std::cell::Cell;
@@ -254,7 +255,18 @@ enum Elide {
     /// Always use this one lifetime.
     Exact(Region),
     /// Less or more than one lifetime were found, error on unspecified.
-    Error(Vec<ElisionFailureInfo>),
+    Error(ElisionFailure),
+}
+
+#[derive(Clone, Debug)]
+struct ElisionFailure {
+    /// The arguments other than `self`.
+    params: Vec<ElisionFailureInfo>,
+    /// The reference in the type of `self` if it was not used for elision,
+    /// e.g. in `self: &Alias` where `Alias` is an alias of `Self`.
+    self_ref: Option<Span>,
+    /// Where a new lifetime parameter can be added to the generics, and the code to add.
+    new_lifetime_param: Option<(Span, String)>,
 }
 
 #[derive(Clone, Debug)]
@@ -263,6 +275,8 @@ struct ElisionFailureInfo {
     parent: Option<hir::BodyId>,
     /// The index of the argument in the original definition.
     index: usize,
+    /// The type of the argument.
+    span: Span,
     lifetime_count: usize,
     have_bound_regions: bool,
 }
@@ -2094,11 +2108,14 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
         // what region to use for elision in the output type in two ways.
         // First (determined here), if `self` is by-reference, then the
         // implied output region is the region of the self parameter.
+        let mut self_ref = None;
         if has_self {
             struct SelfVisitor<'a> {
                 map: &'a NamedRegionMap,
                 impl_self: Option<&'a hir::TyKind<'a>>,
                 lifetime: Set1<Region>,
+                /// The first reference in the type, for suggestions.
+                first_ref: Option<Span>,
             }
 
             impl SelfVisitor<'_> {
@@ -2139,6 +2156,7 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
 
                 fn visit_ty(&mut self, ty: &'a hir::Ty<'a>) {
                     if let hir::TyKind::Rptr(lifetime_ref, ref mt) = ty.kind {
+                        self.first_ref.get_or_insert(ty.span);
                         if let hir::TyKind::Path(hir::QPath::Resolved(None, ref path)) = mt.ty.kind
                         {
                             if self.is_self_ty(path.res) {
@@ -2156,6 +2174,7 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
                 map: self.map,
                 impl_self: impl_self.map(|ty| &ty.kind),
                 lifetime: Set1::Empty,
+                first_ref: None,
             };
             visitor.visit_ty(&inputs[0]);
             if let Set1::One(lifetime) = visitor.lifetime {
@@ -2163,6 +2182,7 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
                 self.with(scope, |_, this| this.visit_ty(output));
                 return;
             }
+            self_ref = visitor.first_ref;
         }
 
         // Second, if there was exactly one lifetime (either a substitution or a
@@ -2195,6 +2215,7 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
                 ElisionFailureInfo {
                     parent: body,
                     index: i,
+                    span: input.span,
                     lifetime_count: gather.lifetimes.len(),
                     have_bound_regions: gather.have_bound_regions,
                 }
@@ -2204,7 +2225,13 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
         let elide = if lifetime_count == 1 {
             Elide::Exact(possible_implied_output_region.unwrap())
         } else {
-            Elide::Error(arg_lifetimes)
+            let new_lifetime_param = self
+                .tcx
+                .hir()
+                .opt_local_def_id(parent)
+                .and_then(|def_id| self.tcx.hir().get_generics(def_id))
+                .map(new_lifetime_param_spot);
+            Elide::Error(ElisionFailure { params: arg_lifetimes, self_ref, new_lifetime_param })
         };
 
         debug!("visit_fn_like_elision: elide={:?}", elide);
@@ -2355,18 +2382,19 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
         let mut err = report_missing_lifetime_specifiers(self.tcx.sess, span, lifetime_refs.len());
         let mut add_label = true;
 
-        if let Some(params) = error {
+        if let Some(failure) = error {
             if lifetime_refs.len() == 1 {
-                add_label = add_label && self.report_elision_failure(&mut err, params, span);
+                add_label = add_label && self.report_elision_failure(&mut err, failure, span);
             }
         }
         if add_label {
             add_missing_lifetime_specifiers_label(
                 &mut err,
+                self.tcx.sess.source_map(),
                 span,
                 lifetime_refs.len(),
                 &lifetime_names,
-                self.tcx.sess.source_map().span_to_snippet(span).ok().as_ref().map(|s| s.as_str()),
+                error,
             );
         }
 
@@ -2396,9 +2424,10 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
     fn report_elision_failure(
         &mut self,
         db: &mut DiagnosticBuilder<'_>,
-        params: &[ElisionFailureInfo],
+        failure: &ElisionFailure,
         span: Span,
     ) -> bool {
+        let params = &failure.params;
         let mut m = String::new();
         let len = params.len();
 
@@ -2440,7 +2469,13 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
             }
         }
 
-        if len == 0 {
+        if elided_len == 0 && failure.self_ref.is_some() {
+            db.help(
+                "this function's return type contains a borrowed value, but the lifetime of \
+                `self` is only used for elision if its type contains `&Self`",
+            );
+            true
+        } else if len == 0 {
             db.help(
                 "this function's return type contains a borrowed value, \
                 but there is no value for it to be borrowed from",
@@ -2873,15 +2908,17 @@ fn report_missing_lifetime_specifiers(
 
 fn add_missing_lifetime_specifiers_label(
     err: &mut DiagnosticBuilder<'_>,
+    source_map: &SourceMap,
     span: Span,
     count: usize,
     lifetime_names: &FxHashSet<ast::Ident>,
-    snippet: Option<&str>,
+    failure: Option<&ElisionFailure>,
 ) {
+    let snippet = source_map.span_to_snippet(span).ok();
     if count > 1 {
         err.span_label(span, format!("expected {} lifetime parameters", count));
     } else if let (1, Some(name), Some("&")) =
-        (lifetime_names.len(), lifetime_names.iter().next(), snippet)
+        (lifetime_names.len(), lifetime_names.iter().next(), snippet.as_deref())
     {
         err.span_suggestion(
             span,
@@ -2891,5 +2928,81 @@ fn add_missing_lifetime_specifiers_label(
         );
     } else {
         err.span_label(span, "expected lifetime parameter");
+        if let (0, Some(failure), Some("&")) = (lifetime_names.len(), failure, snippet.as_deref()) {
+            suggest_new_named_lifetime(err, source_map, span, failure);
+        }
+    }
+}
+
+/// Suggests introducing a lifetime `'a` for the elided reference at `span` in the return type,
+/// tying it to the reference in `self` if there is one, or else to all references among the
+/// arguments.
+fn suggest_new_named_lifetime(
+    err: &mut DiagnosticBuilder<'_>,
+    source_map: &SourceMap,
+    span: Span,
+    failure: &ElisionFailure,
+) {
+    let (param_span, param) = match failure.new_lifetime_param {
+        Some((param_span, ref param)) => (param_span, param.clone()),
+        None => return,
+    };
+    let mut suggestion = vec![(param_span, param)];
+    let borrowed_args: Vec<_> = failure
+        .params
+        .iter()
+        .filter(|info| info.lifetime_count > 0)
+        .filter_map(|info| Some((info.span, named_reference(source_map, info.span)?)))
+        .collect();
+    let (msg, applicability) = if let Some(self_ref) = failure.self_ref {
+        match named_reference(source_map, self_ref) {
+            Some(sugg) => suggestion.push((self_ref, sugg)),
+            None => return,
+        }
+        // Methods usually borrow from `self`, but this is only a guess if other arguments
+        // could be borrowed from as well.
+        let applicability = if failure.params.iter().all(|info| info.lifetime_count == 0) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        ("consider introducing a named lifetime parameter to borrow from `self`", applicability)
+    } else if !borrowed_args.is_empty() {
+        suggestion.extend(borrowed_args);
+        ("consider introducing a named lifetime parameter", Applicability::MaybeIncorrect)
+    } else {
+        return;
+    };
+    suggestion.push((span, "&'a ".to_string()));
+    err.multipart_suggestion(msg, suggestion, applicability);
+}
+
+/// Returns the code of the reference type at `span` with its elided lifetime named `'a`.
+fn named_reference(source_map: &SourceMap, span: Span) -> Option<String> {
+    let snippet = source_map.span_to_snippet(span).ok()?;
+    if snippet.starts_with("&'_ ") {
+        Some(format!("&'a {}", &snippet[4..]))
+    } else if snippet.starts_with('&') && !snippet.starts_with("&'") {
+        Some(format!("&'a {}", &snippet[1..]))
+    } else {
+        None
+    }
+}
+
+/// Returns where a new lifetime parameter `'a` can be added to `generics`, and the code to add.
+fn new_lifetime_param_spot(generics: &hir::Generics<'_>) -> (Span, String) {
+    // Lifetimes have to come first, but parameters that were not written by the user, like
+    // those of `impl Trait` arguments and the elided lifetimes of `async fn`s, have no place.
+    let first_param = generics.params.iter().find(|param| match param.kind {
+        GenericParamKind::Lifetime { kind: LifetimeParamKind::Elided }
+        | GenericParamKind::Lifetime { kind: LifetimeParamKind::InBand } => false,
+        GenericParamKind::Type {
+            synthetic: Some(hir::SyntheticTyParamKind::ImplTrait), ..
+        } => false,
+        _ => true,
+    });
+    match first_param {
+        Some(param) => (param.span.shrink_to_lo(), "'a, ".to_string()),
+        None => (generics.span, "<'a>".to_string()),
     }
 }
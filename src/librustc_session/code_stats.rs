@@ -2,6 +2,7 @@ use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lock;
 use rustc_target::abi::{Align, Size};
 use std::cmp::{self, Ordering};
+use std::time::Duration;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -45,9 +46,38 @@ pub struct TypeSizeInfo {
     pub variants: Vec<VariantInfo>,
 }
 
+/// Measurements of a successful constant evaluation, recorded with `-Z ctfe-profile`.
+#[derive(Debug)]
+pub struct CtfeEvalInfo {
+    /// The path of the evaluated constant, including the promoted index, if any.
+    pub name: String,
+    /// The time the evaluation took, including the time spent evaluating other constants that
+    /// it depends on.
+    pub duration: Duration,
+    pub steps: usize,
+    pub interned_bytes: u64,
+}
+
+/// Totals over all the constant evaluations of the crate, recorded with `-Z print-ctfe-stats`.
+#[derive(Default, Debug)]
+pub struct CtfeStats {
+    /// The number of successful evaluations.
+    pub evaluations: usize,
+    pub interned_bytes: u64,
+    pub interned_allocations: usize,
+    /// The path of the constant that interned the most bytes, and how many it interned.
+    pub largest: Option<(String, u64)>,
+    /// The number of times the result of the `const_eval_raw` query was requested by const
+    /// evaluation, and the number of times it had to be computed.
+    pub raw_requests: usize,
+    pub raw_computations: usize,
+}
+
 #[derive(Default)]
 pub struct CodeStats {
     type_sizes: Lock<FxHashSet<TypeSizeInfo>>,
+    ctfe_evals: Lock<Vec<CtfeEvalInfo>>,
+    ctfe_stats: Lock<CtfeStats>,
 }
 
 impl CodeStats {
@@ -77,6 +107,83 @@ impl CodeStats {
         self.type_sizes.borrow_mut().insert(info);
     }
 
+    pub fn record_ctfe_eval(
+        &self,
+        name: String,
+        duration: Duration,
+        steps: usize,
+        interned_bytes: u64,
+    ) {
+        self.ctfe_evals.borrow_mut().push(CtfeEvalInfo { name, duration, steps, interned_bytes });
+    }
+
+    pub fn record_ctfe_interning(&self, name: String, interned_bytes: u64, allocations: usize) {
+        let mut stats = self.ctfe_stats.borrow_mut();
+        stats.evaluations += 1;
+        stats.interned_bytes += interned_bytes;
+        stats.interned_allocations += allocations;
+        if stats.largest.as_ref().map_or(true, |&(_, bytes)| interned_bytes > bytes) {
+            stats.largest = Some((name, interned_bytes));
+        }
+    }
+
+    pub fn record_const_eval_raw_request(&self) {
+        self.ctfe_stats.borrow_mut().raw_requests += 1;
+    }
+
+    pub fn record_const_eval_raw_computation(&self) {
+        self.ctfe_stats.borrow_mut().raw_computations += 1;
+    }
+
+    pub fn print_ctfe_stats(&self, crate_name: &str) {
+        let stats = self.ctfe_stats.borrow();
+        println!("ctfe-stats: crate `{}`", crate_name);
+        println!("ctfe-stats: {} constants evaluated", stats.evaluations);
+        println!(
+            "ctfe-stats: {} bytes interned in {} allocations",
+            stats.interned_bytes, stats.interned_allocations
+        );
+        if let Some((name, bytes)) = &stats.largest {
+            println!("ctfe-stats: largest: `{}`, {} bytes interned", name, bytes);
+        }
+        // Incremental compilation can force the query without a request.
+        let hits = stats.raw_requests.saturating_sub(stats.raw_computations);
+        let hit_rate =
+            if stats.raw_requests == 0 { 0.0 } else { hits as f64 / stats.raw_requests as f64 };
+        println!(
+            "ctfe-stats: `const_eval_raw`: {} requests, {} computed, {:.1}% cache hits",
+            stats.raw_requests,
+            stats.raw_computations,
+            hit_rate * 100.0
+        );
+    }
+
+    pub fn print_ctfe_profile(&self) {
+        let mut ctfe_evals = self.ctfe_evals.borrow_mut();
+
+        // Primary sort: slow-to-fast.
+        // Secondary sort: name (dictionary order)
+        ctfe_evals.sort_by(|info1, info2| {
+            info2.duration.cmp(&info1.duration).then_with(|| info1.name.cmp(&info2.name))
+        });
+
+        let total = ctfe_evals.iter().map(|info| info.duration).sum::<Duration>();
+        println!(
+            "ctfe-profile: {} evaluations in {:.3}ms",
+            ctfe_evals.len(),
+            total.as_secs_f64() * 1000.0
+        );
+        for info in ctfe_evals.iter() {
+            println!(
+                "ctfe-profile: `{}`: {:.3}ms, {} steps, {} bytes interned",
+                info.name,
+                info.duration.as_secs_f64() * 1000.0,
+                info.steps,
+                info.interned_bytes
+            );
+        }
+    }
+
     pub fn print_type_sizes(&self) {
         let type_sizes = self.type_sizes.borrow();
         let mut sorted: Vec<_> = type_sizes.iter().collect();
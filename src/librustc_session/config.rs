@@ -156,6 +156,52 @@ pub enum SymbolManglingVersion {
 
 impl_stable_hash_via_hash!(SymbolManglingVersion);
 
+/// The ELF visibility of the symbols that a crate does not export, selected with
+/// `-Z default-visibility` or by the `default_hidden_visibility` option of the target.
+///
+/// The `#[no_mangle]` and `extern` items exported with the C ABI are the interface of cdylibs
+/// and executables, so they always keep the default visibility.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolVisibility {
+    /// The symbols are not visible outside of the linked binary.
+    Hidden,
+    /// The symbols are visible outside of the linked binary, but cannot be interposed.
+    Protected,
+    /// The symbols have the default visibility and can be interposed by other libraries.
+    Interposable,
+}
+
+/// The different settings that the `-Z polonius` flag can have.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Polonius {
+    /// Polonius is disabled, NLL computes the borrow checking results.
+    Off,
+    /// `-Z polonius` or `-Z polonius=legacy`: the algorithm to use is taken from the
+    /// `POLONIUS_ALGORITHM` environment variable, and is `Naive` by default.
+    Legacy,
+    /// `-Z polonius=next`: a cheap location-insensitive pre-pass, refined with the
+    /// location-sensitive analysis only when the pre-pass finds potential errors.
+    Next,
+    /// `-Z polonius=compare`: NLL computes the borrow checking results, and the analysis of
+    /// `-Z polonius=next` only runs to report where the loan errors of the two differ.
+    Compare,
+}
+
+impl Polonius {
+    /// Whether Polonius computes the borrow checking results.
+    pub fn is_enabled(&self) -> bool {
+        match *self {
+            Polonius::Legacy | Polonius::Next => true,
+            Polonius::Off | Polonius::Compare => false,
+        }
+    }
+
+    /// Whether the Polonius analysis runs at all, possibly only to be compared with NLL.
+    pub fn is_computed(&self) -> bool {
+        *self != Polonius::Off
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Hash)]
 pub enum DebugInfo {
     None,
@@ -390,6 +436,23 @@ pub enum PrintRequest {
     TlsModels,
     TargetSpec,
     NativeStaticLibs,
+    CrateFeatures,
+    DeploymentArtifacts,
+    CrateResolution,
+}
+
+impl PrintRequest {
+    /// Whether this is printed while compiling the crate, like the libraries found when loading
+    /// its dependencies or linking its outputs, instead of before compiling the crate.
+    pub fn is_printed_during_compilation(self) -> bool {
+        match self {
+            PrintRequest::NativeStaticLibs
+            | PrintRequest::DeploymentArtifacts
+            | PrintRequest::CrateResolution
+            | PrintRequest::CrateFeatures => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -894,7 +957,8 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "Compiler information to print on stdout",
             "[crate-name|file-names|sysroot|cfg|target-list|\
              target-cpus|target-features|relocation-models|\
-             code-models|tls-models|target-spec-json|native-static-libs]",
+             code-models|tls-models|target-spec-json|native-static-libs|\
+             crate-features]",
         ),
         opt::flagmulti_s("g", "", "Equivalent to -C debuginfo=2"),
         opt::flagmulti_s("O", "", "Equivalent to -C opt-level=2"),
@@ -972,6 +1036,12 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
                   `expanded,identified` (fully parenthesized, AST nodes with IDs).",
             "TYPE",
         ),
+        opt::flag(
+            "",
+            "example",
+            "With `--explain`, write the erroneous code example to `EXXXX.rs` \
+             in the current directory instead of printing the explanation",
+        ),
         opt::multi_s(
             "",
             "remap-path-prefix",
@@ -1336,6 +1406,39 @@ fn collect_print_requests(
                 );
             }
         }
+        "crate-features" => {
+            if dopts.unstable_options {
+                PrintRequest::CrateFeatures
+            } else {
+                early_error(
+                    error_format,
+                    "the `-Z unstable-options` flag must also be passed to \
+                     enable the crate-features print option",
+                );
+            }
+        }
+        "deployment-artifacts" => {
+            if dopts.unstable_options {
+                PrintRequest::DeploymentArtifacts
+            } else {
+                early_error(
+                    error_format,
+                    "the `-Z unstable-options` flag must also be passed to \
+                     enable the deployment-artifacts print option",
+                );
+            }
+        }
+        "crate-resolution" => {
+            if dopts.unstable_options {
+                PrintRequest::CrateResolution
+            } else {
+                early_error(
+                    error_format,
+                    "the `-Z unstable-options` flag must also be passed to \
+                     enable the crate-resolution print option",
+                );
+            }
+        }
         req => early_error(error_format, &format!("unknown print request `{}`", req)),
     }));
 
@@ -1972,7 +2075,7 @@ impl PpMode {
 crate mod dep_tracking {
     use super::{
         CrateType, DebugInfo, ErrorOutputType, LinkerPluginLto, LtoCli, OptLevel, OutputTypes,
-        Passes, Sanitizer, SwitchWithOptPath, SymbolManglingVersion,
+        Passes, Sanitizer, SwitchWithOptPath, SymbolManglingVersion, SymbolVisibility,
     };
     use crate::lint;
     use crate::utils::NativeLibraryKind;
@@ -2049,6 +2152,7 @@ crate mod dep_tracking {
     impl_dep_tracking_hash_via_hash!(LinkerPluginLto);
     impl_dep_tracking_hash_via_hash!(SwitchWithOptPath);
     impl_dep_tracking_hash_via_hash!(SymbolManglingVersion);
+    impl_dep_tracking_hash_via_hash!(Option<SymbolVisibility>);
 
     impl_dep_tracking_hash_for_sortable_vec_of!(String);
     impl_dep_tracking_hash_for_sortable_vec_of!(PathBuf);
@@ -43,6 +43,18 @@ declare_lint! {
     report_in_external_macro
 }
 
+declare_lint! {
+    pub LONG_RUNNING_CONST_EVAL,
+    Warn,
+    "constant evaluation that takes a long time"
+}
+
+declare_lint! {
+    pub TARGET_DEPENDENT_CONST,
+    Warn,
+    "constants whose value may depend on the target, found by `-Z audit-const-target-dependence`"
+}
+
 declare_lint! {
     pub UNUSED_IMPORTS,
     Warn,
@@ -263,6 +275,18 @@ declare_lint! {
     "unnecessary use of an `unsafe` block"
 }
 
+declare_lint! {
+    pub UNSAFE_OP_IN_UNSAFE_FN,
+    Allow,
+    "unsafe operations in unsafe functions without an explicit unsafe block"
+}
+
+declare_lint! {
+    pub DEPRECATED_SAFE,
+    Warn,
+    "calls without an unsafe block to unsafe functions that were safe in older editions"
+}
+
 declare_lint! {
     pub UNUSED_MUT,
     Warn,
@@ -493,6 +517,8 @@ declare_lint_pass! {
         PUB_USE_OF_PRIVATE_EXTERN_CRATE,
         INVALID_TYPE_PARAM_DEFAULT,
         CONST_ERR,
+        LONG_RUNNING_CONST_EVAL,
+        TARGET_DEPENDENT_CONST,
         RENAMED_AND_REMOVED_LINTS,
         SAFE_PACKED_BORROWS,
         PATTERNS_IN_FNS_WITHOUT_BODY,
@@ -501,6 +527,8 @@ declare_lint_pass! {
         ORDER_DEPENDENT_TRAIT_OBJECTS,
         DEPRECATED,
         UNUSED_UNSAFE,
+        UNSAFE_OP_IN_UNSAFE_FN,
+        DEPRECATED_SAFE,
         UNUSED_MUT,
         UNCONDITIONAL_RECURSION,
         SINGLE_USE_LIFETIMES,
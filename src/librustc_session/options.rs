@@ -267,6 +267,8 @@ macro_rules! options {
             Some(::rustc_target::spec::LinkerFlavor::one_of());
         pub const parse_optimization_fuel: Option<&str> =
             Some("crate=integer");
+        pub const parse_const_alloc_dump: Option<&str> =
+            Some("`path=file`");
         pub const parse_unpretty: Option<&str> =
             Some("`string` or `string=string`");
         pub const parse_treat_err_as_bug: Option<&str> =
@@ -283,12 +285,16 @@ macro_rules! options {
             Some("one of: `disabled`, `trampolines`, or `aliases`");
         pub const parse_symbol_mangling_version: Option<&str> =
             Some("either `legacy` or `v0` (RFC 2603)");
+        pub const parse_symbol_visibility: Option<&str> =
+            Some("one of: `hidden`, `protected`, or `interposable`");
+        pub const parse_polonius: Option<&str> =
+            Some("either no value, `legacy`, `next`, or `compare`");
     }
 
     #[allow(dead_code)]
     mod $mod_set {
         use super::{$struct_name, Passes, Sanitizer, LtoCli, LinkerPluginLto, SwitchWithOptPath,
-            SymbolManglingVersion};
+            SymbolManglingVersion, SymbolVisibility, Polonius};
         use rustc_target::spec::{LinkerFlavor, MergeFunctions, PanicStrategy, RelroLevel};
         use std::path::PathBuf;
         use std::str::FromStr;
@@ -507,6 +513,16 @@ macro_rules! options {
             true
         }
 
+        fn parse_const_alloc_dump(slot: &mut Vec<(String, PathBuf)>, v: Option<&str>) -> bool {
+            match v.and_then(|s| s.find('=').map(|i| (&s[..i], &s[i + 1..]))) {
+                Some((path, file)) if !path.is_empty() && !file.is_empty() => {
+                    slot.push((path.to_string(), PathBuf::from(file)));
+                    true
+                }
+                _ => false,
+            }
+        }
+
         fn parse_optimization_fuel(slot: &mut Option<(String, u64)>, v: Option<&str>) -> bool {
             match v {
                 None => false,
@@ -609,6 +625,26 @@ macro_rules! options {
             };
             true
         }
+
+        fn parse_symbol_visibility(slot: &mut Option<SymbolVisibility>, v: Option<&str>) -> bool {
+            *slot = match v {
+                Some("hidden") => Some(SymbolVisibility::Hidden),
+                Some("protected") => Some(SymbolVisibility::Protected),
+                Some("interposable") => Some(SymbolVisibility::Interposable),
+                _ => return false,
+            };
+            true
+        }
+
+        fn parse_polonius(slot: &mut Polonius, v: Option<&str>) -> bool {
+            *slot = match v {
+                None | Some("legacy") => Polonius::Legacy,
+                Some("next") => Polonius::Next,
+                Some("compare") => Polonius::Compare,
+                _ => return false,
+            };
+            true
+        }
     }
 ) }
 
@@ -846,6 +882,41 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "describes how to render the `rendered` field of json diagnostics"),
     unleash_the_miri_inside_of_you: bool = (false, parse_bool, [TRACKED],
         "take the breaks off const evaluation. NOTE: this is unsound"),
+    const_eval_limit: Option<usize> = (None, parse_opt_uint, [TRACKED],
+        "the maximum number of steps the evaluation of a constant may take, 0 meaning no limit \
+        (overrides `#[const_eval_limit]`)"),
+    const_eval_stack_limit: Option<usize> = (None, parse_opt_uint, [TRACKED],
+        "the maximum number of stack frames the evaluation of a constant may have \
+        (default: the recursion limit, overrides `#[const_eval_stack_limit]`)"),
+    const_eval_memory_limit: Option<usize> = (None, parse_opt_uint, [TRACKED],
+        "the maximum number of bytes the evaluation of a constant may have allocated at once, \
+        0 meaning no limit (default: 4 GiB)"),
+    const_float_strict: bool = (false, parse_bool, [TRACKED],
+        "make const evaluation error on floating-point operations producing NaN, whose sign and \
+        payload may differ from the ones at runtime"),
+    audit_const_target_dependence: bool = (false, parse_bool, [TRACKED],
+        "record which target-specific inputs (pointer width, alignment, byte order) the \
+        evaluation of each constant observed, and warn about the constants that used any"),
+    skip_const_validation: bool = (false, parse_bool, [TRACKED],
+        "skip the validation of the final value of constants and statics, warning about it \
+        instead, to get at the value of a constant whose validation fails or ICEs"),
+    extra_const_ub_checks: bool = (false, parse_bool, [TRACKED],
+        "make const evaluation check the alignment of every memory access and the validity of \
+        every typed copy, like the checks of a debug build at runtime, to detect more UB"),
+    const_strict_provenance: bool = (false, parse_bool, [TRACKED],
+        "make const evaluation reject casts from integers to pointers and from pointers to \
+        integers, to check that const code follows strict provenance"),
+    verify_linked_symbols: bool = (false, parse_bool, [UNTRACKED],
+        "after linking, check that the `#[used]` and `#[no_mangle]` items of the crate \
+        were not removed by the linker (ELF and wasm outputs only)"),
+    ctfe_profile: bool = (false, parse_bool, [UNTRACKED],
+        "print the time, steps and interned bytes of every constant evaluation, slowest first"),
+    dump_const_alloc: Vec<(String, PathBuf)> = (Vec::new(), parse_const_alloc_dump, [UNTRACKED],
+        "after evaluating the constant or static `path`, write the bytes of its allocation to \
+        `file`, and its relocations and undefined bytes to `file.relocations`"),
+    print_ctfe_stats: bool = (false, parse_bool, [UNTRACKED],
+        "print the number of constants evaluated, the memory they interned, and the cache hit \
+        rate of `const_eval_raw`"),
     osx_rpath_install_name: bool = (false, parse_bool, [TRACKED],
         "pass `-install_name @rpath/...` to the macOS linker"),
     sanitizer: Option<Sanitizer> = (None, parse_sanitizer, [TRACKED],
@@ -872,8 +943,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
                        "dump facts from NLL analysis into side files"),
     dont_buffer_diagnostics: bool = (false, parse_bool, [UNTRACKED],
         "emit diagnostics rather than buffering (breaks NLL error downgrading, sorting)."),
-    polonius: bool = (false, parse_bool, [UNTRACKED],
-        "enable polonius-based borrow-checker"),
+    polonius: Polonius = (Polonius::Off, parse_polonius, [UNTRACKED],
+        "enable polonius-based borrow-checker (`legacy`, `next`, or `compare` with NLL)"),
     codegen_time_graph: bool = (false, parse_bool, [UNTRACKED],
         "generate a graphical HTML report of time spent in codegen and LLVM"),
     thinlto: Option<bool> = (None, parse_opt_bool, [TRACKED],
@@ -912,6 +983,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "make the current crate share its generic instantiations"),
     chalk: bool = (false, parse_bool, [TRACKED],
         "enable the experimental Chalk-based trait solving engine"),
+    chalk_cross_check: bool = (false, parse_bool, [UNTRACKED],
+        "with `-Z chalk`, also solve each obligation with the old trait solver, and warn where \
+        the two disagree"),
     no_parallel_llvm: bool = (false, parse_bool, [UNTRACKED],
         "don't run LLVM in parallel (while keeping codegen-units and ThinLTO)"),
     no_leak_check: bool = (false, parse_bool, [UNTRACKED],
@@ -943,6 +1017,16 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     symbol_mangling_version: SymbolManglingVersion = (SymbolManglingVersion::Legacy,
         parse_symbol_mangling_version, [TRACKED],
         "which mangling version to use for symbol names"),
+    default_visibility: Option<SymbolVisibility> = (None, parse_symbol_visibility, [TRACKED],
+        "the visibility of the symbols that are not exported with the C ABI in cdylibs, \
+        executables, static libraries and the rlibs linked into them: `hidden`, `protected` or \
+        `interposable` (default: from the target)"),
+    encode_repr_c_layouts: bool = (false, parse_bool, [TRACKED],
+        "record the layouts of the non-generic `#[repr(C)]` structs and unions in the crate \
+        metadata, for tools checking them against C declarations"),
+    unload_info: bool = (false, parse_bool, [TRACKED],
+        "export `__rust_unload_info` from cdylibs, which tells the programs loading them whether \
+        they can be unloaded"),
     binary_dep_depinfo: bool = (false, parse_bool, [TRACKED],
         "include artifacts (sysroot, crate dependencies) used during compilation in dep-info"),
     insert_sideeffect: bool = (false, parse_bool, [TRACKED],
@@ -950,4 +1034,10 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
          (such as entering an empty infinite loop) by inserting llvm.sideeffect"),
     deduplicate_diagnostics: Option<bool> = (None, parse_opt_bool, [UNTRACKED],
         "deduplicate identical diagnostics"),
+    time_limit: Option<usize> = (None, parse_opt_uint, [UNTRACKED],
+        "abort the compilation with an error naming the active query once it has run for this \
+        many seconds"),
+    memory_limit: Option<usize> = (None, parse_opt_uint, [UNTRACKED],
+        "abort the compilation with an error naming the active query once its resident set size \
+        exceeds this many megabytes"),
 }
@@ -119,6 +119,9 @@ pub struct ParseSess {
     pub ambiguous_block_expr_parse: Lock<FxHashMap<Span, Span>>,
     pub injected_crate_name: Once<Symbol>,
     pub gated_spans: GatedSpans,
+    /// The first place where each enabled unstable feature is used, for
+    /// `--print crate-features`.
+    pub feature_uses: Lock<FxHashMap<Symbol, Span>>,
     /// The parser has reached `Eof` due to an unclosed brace. Used to silence unnecessary errors.
     pub reached_eof: Lock<bool>,
 }
@@ -144,6 +147,7 @@ impl ParseSess {
             ambiguous_block_expr_parse: Lock::new(FxHashMap::default()),
             injected_crate_name: Once::new(),
             gated_spans: GatedSpans::default(),
+            feature_uses: Lock::new(FxHashMap::default()),
             reached_eof: Lock::new(false),
         }
     }
@@ -159,6 +163,19 @@ impl ParseSess {
         &self.source_map
     }
 
+    /// Records that the enabled unstable `feature` is used at `span`, keeping the use that
+    /// comes first in the source.
+    pub fn record_feature_use(&self, feature: Symbol, span: Span) {
+        if span.is_dummy() {
+            return;
+        }
+        let mut feature_uses = self.feature_uses.borrow_mut();
+        let first_use = feature_uses.entry(feature).or_insert(span);
+        if span.lo() < first_use.lo() {
+            *first_use = span;
+        }
+    }
+
     pub fn buffer_lint(
         &self,
         lint: &'static Lint,
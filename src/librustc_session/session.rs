@@ -6,16 +6,18 @@ use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 
 use crate::config::{self, OutputType, PrintRequest, Sanitizer, SwitchWithOptPath};
+use crate::config::{CrateType, SymbolVisibility};
 use crate::filesearch;
 use crate::lint;
 use crate::search_paths::{PathKind, SearchPath};
-use rustc_data_structures::profiling::duration_to_secs_str;
+use rustc_data_structures::profiling::{duration_to_secs_str, get_resident};
 use rustc_errors::ErrorReported;
 
 use rustc_data_structures::base_n;
 use rustc_data_structures::impl_stable_hash_via_hash;
 use rustc_data_structures::sync::{
-    self, AtomicU64, AtomicUsize, Lock, Lrc, Once, OneThread, Ordering, Ordering::SeqCst,
+    self, AtomicBool, AtomicU64, AtomicUsize, Lock, Lrc, Once, OneThread, Ordering,
+    Ordering::SeqCst,
 };
 
 use crate::parse::ParseSess;
@@ -41,7 +43,7 @@ use std::io::Write;
 use std::num::NonZeroU32;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 pub struct OptimizationFuel {
     /// If `-zfuel=crate=n` is specified, initially set to `n`, otherwise `0`.
@@ -89,6 +91,10 @@ pub struct Session {
     /// The maximum length of types during monomorphization.
     pub type_length_limit: Once<usize>,
 
+    /// The maximum number of interpreter steps the evaluation of a constant may take, 0 meaning
+    /// that there is no limit.
+    pub const_eval_limit: Once<usize>,
+
     /// Map from imported macro spans (which consist of
     /// the localized span for the macro body) to the
     /// macro name and definition span in the source crate.
@@ -105,6 +111,9 @@ pub struct Session {
     /// Some measurements that are being gathered during compilation.
     pub perf_stats: PerfStats,
 
+    /// Tracks the limits set with `-Z time-limit` and `-Z memory-limit`.
+    resource_limits: ResourceLimits,
+
     /// Data about code being compiled, gathered during compilation.
     pub code_stats: CodeStats,
 
@@ -148,6 +157,20 @@ pub struct PerfStats {
     pub normalize_projection_ty: AtomicUsize,
 }
 
+struct ResourceLimits {
+    /// When the session was created, which `-Z time-limit` is measured from.
+    start: Instant,
+    /// The number of times the limits were asked about, used to only check them every
+    /// `RESOURCE_LIMIT_CHECK_INTERVAL` times.
+    checks: AtomicUsize,
+    /// Whether a limit was already reported as exceeded.
+    exceeded: AtomicBool,
+}
+
+/// Reading the resident set size is comparatively expensive, so the resource limits are only
+/// checked once every this many queries.
+const RESOURCE_LIMIT_CHECK_INTERVAL: usize = 256;
+
 /// Enum to support dispatch of one-time diagnostics (in `Session.diag_once`).
 enum DiagnosticBuilderMethod {
     Note,
@@ -508,6 +531,25 @@ impl Session {
     pub fn panic_strategy(&self) -> PanicStrategy {
         self.opts.cg.panic.unwrap_or(self.target.target.options.panic_strategy)
     }
+    /// Returns the visibility of the symbols that are not exported with the C ABI. If the user
+    /// explicitly selected one using `-Z default-visibility`, use that, otherwise use the
+    /// visibility defined by the target.
+    ///
+    /// Rust dylibs and proc macros are linked against through their Rust symbols, so they ignore
+    /// `-Z default-visibility=hidden` and `-Z default-visibility=protected`.
+    pub fn default_visibility(&self) -> SymbolVisibility {
+        let exports_rust_symbols = self
+            .crate_types
+            .borrow()
+            .iter()
+            .any(|ty| *ty == CrateType::Dylib || *ty == CrateType::ProcMacro);
+        match self.opts.debugging_opts.default_visibility {
+            Some(SymbolVisibility::Interposable) => SymbolVisibility::Interposable,
+            Some(visibility) if !exports_rust_symbols => visibility,
+            _ if self.target.target.options.default_hidden_visibility => SymbolVisibility::Hidden,
+            _ => SymbolVisibility::Interposable,
+        }
+    }
     pub fn fewer_names(&self) -> bool {
         let more_names = self.opts.output_types.contains_key(&OutputType::LlvmAssembly)
             || self.opts.output_types.contains_key(&OutputType::Bitcode);
@@ -676,6 +718,46 @@ impl Session {
         self.opts.incremental.as_ref().map(|_| self.incr_comp_session_dir())
     }
 
+    /// Checks the limits set with `-Z time-limit` and `-Z memory-limit`, and returns a message
+    /// describing the limit that was exceeded, if any. This is called each time a query starts
+    /// executing, and only reports an exceeded limit once.
+    pub fn exceeded_resource_limit(&self) -> Option<String> {
+        let time_limit = self.opts.debugging_opts.time_limit;
+        let memory_limit = self.opts.debugging_opts.memory_limit;
+        if time_limit.is_none() && memory_limit.is_none() {
+            return None;
+        }
+        let limits = &self.resource_limits;
+        if limits.checks.fetch_add(1, Ordering::Relaxed) % RESOURCE_LIMIT_CHECK_INTERVAL != 0 {
+            return None;
+        }
+
+        let mut exceeded = None;
+        if let Some(limit) = time_limit {
+            let elapsed = limits.start.elapsed();
+            if elapsed >= Duration::from_secs(limit as u64) {
+                exceeded = Some(format!(
+                    "the compilation exceeded the time limit of {}s set with `-Z time-limit`",
+                    limit
+                ));
+            }
+        }
+        if let (None, Some(limit)) = (&exceeded, memory_limit) {
+            let resident = get_resident().map_or(0, |n| n / 1_000_000);
+            if resident > limit {
+                exceeded = Some(format!(
+                    "the compilation exceeded the memory limit of {}MB set with \
+                     `-Z memory-limit` (rss: {}MB)",
+                    limit, resident
+                ));
+            }
+        }
+        if exceeded.is_some() && limits.exceeded.swap(true, Ordering::Relaxed) {
+            return None;
+        }
+        exceeded
+    }
+
     pub fn print_perf_stats(&self) {
         println!(
             "Total time spent computing symbol hashes:      {}",
@@ -1049,6 +1131,7 @@ fn build_session_(
         features: Once::new(),
         recursion_limit: Once::new(),
         type_length_limit: Once::new(),
+        const_eval_limit: Once::new(),
         imported_macro_spans: OneThread::new(RefCell::new(FxHashMap::default())),
         incr_comp_session: OneThread::new(RefCell::new(IncrCompSession::NotInitialized)),
         cgu_reuse_tracker,
@@ -1060,6 +1143,11 @@ fn build_session_(
             normalize_ty_after_erasing_regions: AtomicUsize::new(0),
             normalize_projection_ty: AtomicUsize::new(0),
         },
+        resource_limits: ResourceLimits {
+            start: Instant::now(),
+            checks: AtomicUsize::new(0),
+            exceeded: AtomicBool::new(false),
+        },
         code_stats: Default::default(),
         optimization_fuel_crate,
         optimization_fuel,
@@ -1116,7 +1204,7 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
     if sess.opts.cg.profile_generate.enabled()
         && sess.target.target.options.is_like_msvc
         && sess.panic_strategy() == PanicStrategy::Unwind
-        && sess.opts.prints.iter().all(|&p| p == PrintRequest::NativeStaticLibs)
+        && sess.opts.prints.iter().all(|&p| p.is_printed_during_compilation())
     {
         sess.err(
             "Profile-guided optimization does not yet work in conjunction \
@@ -1132,6 +1132,20 @@ impl SourceFile {
         self.lines[line_index]
     }
 
+    /// Returns the number of characters in `start..end` that are outside the Basic
+    /// Multilingual Plane, and so take two UTF-16 code units.
+    pub fn astral_chars_between(&self, start: BytePos, end: BytePos) -> usize {
+        let start_idx = self
+            .multibyte_chars
+            .binary_search_by_key(&start, |mbc| mbc.pos)
+            .unwrap_or_else(|idx| idx);
+        let end_idx = self
+            .multibyte_chars
+            .binary_search_by_key(&end, |mbc| mbc.pos)
+            .unwrap_or_else(|idx| idx);
+        self.multibyte_chars[start_idx..end_idx].iter().filter(|mbc| mbc.bytes == 4).count()
+    }
+
     /// Add externally loaded source.
     /// If the hash of the input doesn't match or no input is supplied via None,
     /// it is interpreted as an error and the corresponding enum variant is set.
@@ -1477,6 +1491,10 @@ pub struct Loc {
     pub col: CharPos,
     /// The (0-based) column offset when displayed.
     pub col_display: usize,
+    /// The (0-based) column offset in bytes of UTF-8.
+    pub col_byte: usize,
+    /// The (0-based) column offset in UTF-16 code units.
+    pub col_utf16: usize,
 }
 
 // Used to be structural records.
@@ -370,11 +370,13 @@ impl SourceMap {
                         .sum();
                     col.0 - special_chars + non_narrow
                 };
+                let col_byte = (pos - linebpos).to_usize();
+                let col_utf16 = col.0 + f.astral_chars_between(linebpos, pos);
                 debug!("byte pos {:?} is on the line at byte pos {:?}", pos, linebpos);
                 debug!("char pos {:?} is on the line at char pos {:?}", chpos, linechpos);
                 debug!("byte is on line: {}", line);
                 assert!(chpos >= linechpos);
-                Loc { file: f, line, col, col_display }
+                Loc { file: f, line, col, col_display, col_byte, col_utf16 }
             }
             Err(f) => {
                 let col_display = {
@@ -386,7 +388,9 @@ impl SourceMap {
                         f.non_narrow_chars[0..end_width_idx].into_iter().map(|x| x.width()).sum();
                     chpos.0 - end_width_idx + non_narrow
                 };
-                Loc { file: f, line: 0, col: chpos, col_display }
+                let col_byte = (pos - f.start_pos).to_usize();
+                let col_utf16 = chpos.0 + f.astral_chars_between(f.start_pos, pos);
+                Loc { file: f, line: 0, col: chpos, col_display, col_byte, col_utf16 }
             }
         }
     }
@@ -108,6 +108,10 @@ symbols! {
     // As well as the symbols listed, there are symbols for the the strings
     // "0", "1", ..., "9", which are accessible via `sym::integer`.
     Symbols {
+        CoerceUnsized,
+        DispatchFromDyn,
+        SmartPointer,
+        Unsize,
         aarch64_target_feature,
         abi,
         abi_amdgpu_kernel,
@@ -141,6 +145,7 @@ symbols! {
         any,
         arbitrary_enum_discriminant,
         arbitrary_self_types,
+        arith_offset,
         Arguments,
         ArgumentV1,
         arm_target_feature,
@@ -175,8 +180,11 @@ symbols! {
         bswap,
         bitreverse,
         C,
+        cache_line,
         caller_location,
         cdylib,
+        ceilf32,
+        ceilf64,
         cfg,
         cfg_attr,
         cfg_attr_multi,
@@ -203,8 +211,12 @@ symbols! {
         concat_idents,
         conservative_impl_trait,
         console,
+        const_allocate,
         const_compare_raw_pointers,
         const_constructor,
+        const_deallocate,
+        const_eval_limit,
+        const_eval_stack_limit,
         const_extern_fn,
         const_fn,
         const_fn_union,
@@ -226,6 +238,8 @@ symbols! {
         convert,
         Copy,
         copy_closures,
+        copysignf32,
+        copysignf64,
         core,
         core_intrinsics,
         crate_id,
@@ -258,7 +272,9 @@ symbols! {
         deprecated,
         deref,
         deref_mut,
+        deref_patterns,
         derive,
+        derive_smart_pointer,
         diagnostic,
         direct,
         doc,
@@ -312,11 +328,17 @@ symbols! {
         f16c_target_feature,
         f32,
         f64,
+        fabsf32,
+        fabsf64,
         feature,
         ffi_returns_twice,
         field,
         field_init_shorthand,
         file,
+        floorf32,
+        floorf64,
+        fmaf32,
+        fmaf64,
         fmt,
         fmt_internals,
         fn_must_use,
@@ -417,6 +439,7 @@ symbols! {
         LintPass,
         lint_reasons,
         literal,
+        loaded_from_disk,
         local_inner_macros,
         log_syntax,
         loop_break_value,
@@ -437,9 +460,14 @@ symbols! {
         masked,
         match_beginning_vert,
         match_default_bindings,
+        maxnumf32,
+        maxnumf64,
         may_dangle,
         maybe_uninit_uninit,
         maybe_uninit_zeroed,
+        mem_align_of_val,
+        mem_min_align_of_val,
+        mem_size_of_val,
         mem_uninitialized,
         mem_zeroed,
         member_constraints,
@@ -447,7 +475,11 @@ symbols! {
         meta,
         min_align_of,
         min_const_fn,
+        min_exhaustive_patterns,
+        min_specialization,
         min_const_unsafe_fn,
+        minnumf32,
+        minnumf64,
         mips_target_feature,
         mmx_target_feature,
         module,
@@ -460,6 +492,8 @@ symbols! {
         naked,
         naked_functions,
         name,
+        nearbyintf32,
+        nearbyintf64,
         needs_allocator,
         needs_drop,
         needs_panic_runtime,
@@ -491,11 +525,13 @@ symbols! {
         not,
         note,
         object_safe_for_dispatch,
+        offset,
         Ok,
         omit_gdb_pretty_printer_section,
         on,
         on_unimplemented,
         oom,
+        op,
         ops,
         optimize,
         optimize_attribute,
@@ -513,6 +549,7 @@ symbols! {
         packed,
         panic,
         panic_handler,
+        panic_if_uninhabited,
         panic_impl,
         panic_implementation,
         panic_runtime,
@@ -534,6 +571,7 @@ symbols! {
         plugin_registrar,
         plugins,
         Poll,
+        pointee,
         poll_with_tls_context,
         powerpc_target_feature,
         precise_pointer_size_matching,
@@ -554,6 +592,7 @@ symbols! {
         proc_macro_non_items,
         proc_macro_path_invoc,
         profiler_runtime,
+        ptr_guaranteed_cmp,
         ptr_offset_from,
         pub_restricted,
         pushpop_unsafe,
@@ -580,8 +619,10 @@ symbols! {
         repr,
         repr128,
         repr_align,
+        repr_align_cache_line,
         repr_align_enum,
         repr_packed,
+        repr_scalable,
         repr_simd,
         repr_transparent,
         re_rebalance_coherence,
@@ -589,9 +630,14 @@ symbols! {
         Result,
         Return,
         rhs,
+        rintf32,
+        rintf64,
+        riscv_target_feature,
         rlib,
         rotate_left,
         rotate_right,
+        roundf32,
+        roundf64,
         rt,
         rtm_target_feature,
         rust,
@@ -613,6 +659,7 @@ symbols! {
         rustc_conversion_suggestion,
         rustc_def_path,
         rustc_deprecated,
+        rustc_deprecated_safe,
         rustc_diagnostic_item,
         rustc_diagnostic_macros,
         rustc_dirty,
@@ -631,6 +678,7 @@ symbols! {
         rustc_mir,
         rustc_nonnull_optimization_guaranteed,
         rustc_object_lifetime_default,
+        rustc_on_misuse,
         rustc_on_unimplemented,
         rustc_outlives,
         rustc_paren_sugar,
@@ -645,13 +693,16 @@ symbols! {
         rustc_proc_macro_decls,
         rustc_promotable,
         rustc_regions,
+        rustc_specialization_trait,
         rustc_stable,
         rustc_std_internal_symbol,
+        rustc_strict_coherence,
         rustc_symbol_name,
         rustc_synthetic,
         rustc_reservation_impl,
         rustc_test_marker,
         rustc_then_this_would_need,
+        rustc_unsafe_specialization_marker,
         rustc_variance,
         rustfmt,
         rust_eh_personality,
@@ -663,14 +714,57 @@ symbols! {
         saturating_add,
         saturating_sub,
         _Self,
+        scalable,
         self_in_typedefs,
         self_struct_ctor,
         send_trait,
         should_panic,
         simd,
+        simd_add,
+        simd_and,
+        simd_bitmask,
+        simd_cast,
+        simd_ceil,
+        simd_div,
+        simd_eq,
         simd_extract,
+        simd_fabs,
         simd_ffi,
+        simd_floor,
+        simd_fma,
+        simd_fmax,
+        simd_fmin,
+        simd_fsqrt,
+        simd_ge,
+        simd_gt,
         simd_insert,
+        simd_le,
+        simd_lt,
+        simd_mul,
+        simd_ne,
+        simd_or,
+        simd_reduce_add_ordered,
+        simd_reduce_add_unordered,
+        simd_reduce_all,
+        simd_reduce_and,
+        simd_reduce_any,
+        simd_reduce_max,
+        simd_reduce_max_nanless,
+        simd_reduce_min,
+        simd_reduce_min_nanless,
+        simd_reduce_mul_ordered,
+        simd_reduce_mul_unordered,
+        simd_reduce_or,
+        simd_reduce_xor,
+        simd_rem,
+        simd_saturating_add,
+        simd_saturating_sub,
+        simd_select,
+        simd_select_bitmask,
+        simd_shl,
+        simd_shr,
+        simd_sub,
+        simd_xor,
         since,
         size,
         size_of,
@@ -681,6 +775,8 @@ symbols! {
         specialization,
         speed,
         spotlight,
+        sqrtf32,
+        sqrtf64,
         sse4a_target_feature,
         stable,
         staged_api,
@@ -731,6 +827,8 @@ symbols! {
         transparent_unions,
         trivial_bounds,
         Try,
+        truncf32,
+        truncf64,
         try_blocks,
         try_trait,
         tt,
@@ -752,8 +850,13 @@ symbols! {
         u64,
         u8,
         unboxed_closures,
+        unchecked_add,
+        unchecked_div,
+        unchecked_mul,
+        unchecked_rem,
         unchecked_shl,
         unchecked_shr,
+        unchecked_sub,
         underscore_const_names,
         underscore_imports,
         underscore_lifetimes,
@@ -762,6 +865,7 @@ symbols! {
         unmarked_api,
         unreachable_code,
         unrestricted_attribute_tokens,
+        unsafe_block_in_unsafe_fn,
         unsafe_no_drop_flag,
         unsized_locals,
         unsized_tuple_coercion,
@@ -777,6 +881,7 @@ symbols! {
         v1,
         val,
         var,
+        variant_count,
         vec,
         Vec,
         vis,
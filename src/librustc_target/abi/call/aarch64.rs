@@ -61,6 +61,8 @@ where
     Ty: TyLayoutMethods<'a, C> + Copy,
     C: LayoutOf<Ty = Ty, TyLayout = TyLayout<'a, Ty>> + HasDataLayout,
 {
+    // Scalable vectors are not aggregates, they are passed in the `z` registers of SVE or
+    // by reference, which LLVM takes care of.
     if !arg.layout.is_aggregate() {
         arg.extend_integer_width_to(32);
         return;
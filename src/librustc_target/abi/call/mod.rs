@@ -245,7 +245,9 @@ impl HomogeneousAggregate {
 impl<'a, Ty> TyLayout<'a, Ty> {
     fn is_aggregate(&self) -> bool {
         match self.abi {
-            Abi::Uninhabited | Abi::Scalar(_) | Abi::Vector { .. } => false,
+            Abi::Uninhabited | Abi::Scalar(_) | Abi::Vector { .. } | Abi::ScalableVector { .. } => {
+                false
+            }
             Abi::ScalarPair(..) | Abi::Aggregate { .. } => true,
         }
     }
@@ -266,7 +268,8 @@ impl<'a, Ty> TyLayout<'a, Ty> {
         C: LayoutOf<Ty = Ty, TyLayout = Self>,
     {
         match self.abi {
-            Abi::Uninhabited => HomogeneousAggregate::Heterogeneous,
+            // Scalable vectors have no fixed size, so they are never part of an aggregate.
+            Abi::Uninhabited | Abi::ScalableVector { .. } => HomogeneousAggregate::Heterogeneous,
 
             // The primitive for this algorithm.
             Abi::Scalar(ref scalar) => {
@@ -4,6 +4,11 @@
 use crate::abi::call::{ArgAbi, FnAbi};
 
 fn classify_ret<Ty>(arg: &mut ArgAbi<'_, Ty>, xlen: u64) {
+    // Scalable vectors are passed in the vector registers, which LLVM takes care of.
+    if arg.layout.abi.is_scalable_vector() {
+        return;
+    }
+
     // "Scalars wider than 2✕XLEN are passed by reference and are replaced in
     // the argument list with the address."
     // "Aggregates larger than 2✕XLEN bits are passed by reference and are
@@ -20,6 +25,11 @@ fn classify_ret<Ty>(arg: &mut ArgAbi<'_, Ty>, xlen: u64) {
 }
 
 fn classify_arg<Ty>(arg: &mut ArgAbi<'_, Ty>, xlen: u64) {
+    // Scalable vectors are passed in the vector registers, which LLVM takes care of.
+    if arg.layout.abi.is_scalable_vector() {
+        return;
+    }
+
     // "Scalars wider than 2✕XLEN are passed by reference and are replaced in
     // the argument list with the address."
     // "Aggregates larger than 2✕XLEN bits are passed by reference and are
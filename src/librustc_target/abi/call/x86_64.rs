@@ -56,6 +56,9 @@ where
 
             Abi::Vector { .. } => Class::Sse,
 
+            // Scalable vectors are rejected on x86, this is only a fallback.
+            Abi::ScalableVector { .. } => return Err(Memory),
+
             Abi::ScalarPair(..) | Abi::Aggregate { .. } => match layout.variants {
                 abi::Variants::Single { .. } => {
                     for i in 0..layout.fields.count() {
@@ -18,6 +18,8 @@ pub fn compute_abi_info<Ty>(fn_abi: &mut FnAbi<'_, Ty>) {
                 // FIXME(eddyb) there should be a size cap here
                 // (probably what clang calls "illegal vectors").
             }
+            // Scalable vectors are rejected on x86, this is only a fallback.
+            Abi::ScalableVector { .. } => a.make_indirect(),
             Abi::Scalar(_) => {
                 if a.layout.size.bytes() > 8 {
                     a.make_indirect();
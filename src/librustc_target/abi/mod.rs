@@ -726,6 +726,12 @@ pub enum Abi {
         element: Scalar,
         count: u64,
     },
+    /// A vector whose number of elements is a runtime multiple of `elt`, such as the vectors of
+    /// AArch64 SVE and RISC-V V. The size of the layout is the minimum size of the vector.
+    ScalableVector {
+        element: Scalar,
+        elt: u64,
+    },
     Aggregate {
         /// If true, the size is exact, otherwise it's only a lower bound.
         sized: bool,
@@ -736,7 +742,11 @@ impl Abi {
     /// Returns `true` if the layout corresponds to an unsized type.
     pub fn is_unsized(&self) -> bool {
         match *self {
-            Abi::Uninhabited | Abi::Scalar(_) | Abi::ScalarPair(..) | Abi::Vector { .. } => false,
+            Abi::Uninhabited
+            | Abi::Scalar(_)
+            | Abi::ScalarPair(..)
+            | Abi::Vector { .. }
+            | Abi::ScalableVector { .. } => false,
             Abi::Aggregate { sized } => !sized,
         }
     }
@@ -767,6 +777,14 @@ impl Abi {
             _ => false,
         }
     }
+
+    /// Returns `true` if this is a scalable vector type
+    pub fn is_scalable_vector(&self) -> bool {
+        match *self {
+            Abi::ScalableVector { .. } => true,
+            _ => false,
+        }
+    }
 }
 
 rustc_index::newtype_index! {
@@ -942,6 +960,9 @@ pub enum PointerKind {
 
     /// `Box<T>`, unlike `UniqueBorrowed`, it also has `noalias` on returns.
     UniqueOwned,
+
+    /// `Box<T>` where `T` contains an `UnsafePinned`, like `UniqueOwned` but never `noalias`.
+    UniqueOwnedAliasable,
 }
 
 #[derive(Copy, Clone)]
@@ -994,7 +1015,10 @@ impl<'a, Ty> TyLayout<'a, Ty> {
     /// Returns `true` if the type is a ZST and not unsized.
     pub fn is_zst(&self) -> bool {
         match self.abi {
-            Abi::Scalar(_) | Abi::ScalarPair(..) | Abi::Vector { .. } => false,
+            Abi::Scalar(_)
+            | Abi::ScalarPair(..)
+            | Abi::Vector { .. }
+            | Abi::ScalableVector { .. } => false,
             Abi::Uninhabited => self.size.bytes() == 0,
             Abi::Aggregate { sized } => sized && self.size.bytes() == 0,
         }
@@ -18,6 +18,7 @@ extern crate log;
 
 pub mod abi;
 pub mod spec;
+pub mod target_features;
 
 /// Requirements for a `StableHashingContext` to be used in this crate.
 /// This is a hack to allow using the `HashStable_Generic` derive macro
@@ -3,6 +3,7 @@ use crate::spec::{LinkerFlavor, Target, TargetOptions, TargetResult};
 pub fn target() -> TargetResult {
     let mut base = super::linux_musl_base::opts();
     base.max_atomic_width = Some(128);
+    base.supports_scalable_vectors = true;
 
     Ok(Target {
         llvm_target: "aarch64-unknown-linux-musl".to_string(),
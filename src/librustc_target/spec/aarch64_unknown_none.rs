@@ -17,6 +17,7 @@ pub fn target() -> Result<Target, String> {
         disable_redzone: true,
         linker_is_gnu: true,
         max_atomic_width: Some(128),
+        supports_scalable_vectors: true,
         panic_strategy: PanicStrategy::Abort,
         abi_blacklist: super::arm_base::abi_blacklist(),
         ..Default::default()
@@ -35,17 +35,25 @@ pub fn opts() -> TargetOptions {
     }
 }
 
-fn macos_deployment_target() -> (u32, u32) {
-    let deployment_target = env::var("MACOSX_DEPLOYMENT_TARGET").ok();
-    let version = deployment_target
+/// Returns the minimum macOS version requested with `MACOSX_DEPLOYMENT_TARGET`, 10.7 by default.
+pub fn macos_deployment_target() -> (u32, u32) {
+    deployment_target("MACOSX_DEPLOYMENT_TARGET").unwrap_or((10, 7))
+}
+
+/// Returns the minimum iOS version requested with `IPHONEOS_DEPLOYMENT_TARGET`, 7.0 by default.
+pub fn ios_deployment_target() -> (u32, u32) {
+    deployment_target("IPHONEOS_DEPLOYMENT_TARGET").unwrap_or((7, 0))
+}
+
+fn deployment_target(var_name: &str) -> Option<(u32, u32)> {
+    let deployment_target = env::var(var_name).ok();
+    deployment_target
         .as_ref()
         .and_then(|s| {
             let mut i = s.splitn(2, '.');
             i.next().and_then(|a| i.next().map(|b| (a, b)))
         })
-        .and_then(|(a, b)| a.parse::<u32>().and_then(|a| b.parse::<u32>().map(|b| (a, b))).ok());
-
-    version.unwrap_or((10, 7))
+        .and_then(|(a, b)| a.parse::<u32>().and_then(|a| b.parse::<u32>().map(|b| (a, b))).ok())
 }
 
 pub fn macos_llvm_target(arch: &str) -> String {
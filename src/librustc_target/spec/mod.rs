@@ -34,6 +34,7 @@
 //! the target's settings, though `target-feature` and `link-args` will *add*
 //! to the list specified by the target, rather than replace.
 
+use crate::abi::Align;
 use crate::spec::abi::{lookup as lookup_abi, Abi};
 use rustc_serialize::json::{Json, ToJson};
 use std::collections::BTreeMap;
@@ -74,6 +75,8 @@ mod windows_msvc_base;
 mod windows_uwp_base;
 mod windows_uwp_msvc_base;
 
+pub use apple_base::{ios_deployment_target, macos_deployment_target};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum LinkerFlavor {
     Em,
@@ -782,6 +785,15 @@ pub struct TargetOptions {
     /// wasm32 where the whole program either has simd or not.
     pub simd_types_indirect: bool,
 
+    /// Whether the target has scalable vector registers, like AArch64 with SVE and RISC-V with
+    /// the V extension, so that `#[repr(simd, scalable(N))]` types can be defined for it.
+    /// Whether the registers are used depends on the enabled target features.
+    pub supports_scalable_vectors: bool,
+
+    /// The size in bytes of the cache lines of the processors of the target, which
+    /// `#[repr(align(cache_line))]` aligns types to. Defaults to 64.
+    pub cache_line_size: u64,
+
     /// Pass a list of symbol which should be exported in the dylib to the linker.
     pub limit_rdylib_exports: bool,
 
@@ -890,6 +902,8 @@ impl Default for TargetOptions {
             emit_debug_gdb_scripts: true,
             requires_uwtable: false,
             simd_types_indirect: true,
+            supports_scalable_vectors: false,
+            cache_line_size: 64,
             limit_rdylib_exports: true,
             override_export_symbols: None,
             merge_functions: MergeFunctions::Aliases,
@@ -995,6 +1009,12 @@ impl Target {
                     .map(|o| o.as_boolean()
                          .map(|s| base.options.$key_name = s));
             } );
+            ($key_name:ident, u64) => ( {
+                let name = (stringify!($key_name)).replace("_", "-");
+                obj.find(&name[..])
+                    .map(|o| o.as_u64()
+                         .map(|s| base.options.$key_name = s));
+            } );
             ($key_name:ident, Option<u64>) => ( {
                 let name = (stringify!($key_name)).replace("_", "-");
                 obj.find(&name[..])
@@ -1204,6 +1224,8 @@ impl Target {
         key!(emit_debug_gdb_scripts, bool);
         key!(requires_uwtable, bool);
         key!(simd_types_indirect, bool);
+        key!(supports_scalable_vectors, bool);
+        key!(cache_line_size, u64);
         key!(limit_rdylib_exports, bool);
         key!(override_export_symbols, opt_list);
         key!(merge_functions, MergeFunctions)?;
@@ -1233,6 +1255,17 @@ impl Target {
             }
         }
 
+        // `#[repr(align(cache_line))]` turns the cache line size into an `Align`, so it has to
+        // be a valid one.
+        let cache_line_size = base.options.cache_line_size;
+        let cache_line_align = match cache_line_size {
+            0 => Err(format!("`{}` is not a power of 2", cache_line_size)),
+            _ => Align::from_bytes(cache_line_size),
+        };
+        if let Err(err) = cache_line_align {
+            return Err(format!("Invalid cache line size in target specification: {}", err));
+        }
+
         Ok(base)
     }
 
@@ -1432,6 +1465,8 @@ impl ToJson for Target {
         target_option_val!(emit_debug_gdb_scripts);
         target_option_val!(requires_uwtable);
         target_option_val!(simd_types_indirect);
+        target_option_val!(supports_scalable_vectors);
+        target_option_val!(cache_line_size);
         target_option_val!(limit_rdylib_exports);
         target_option_val!(override_export_symbols);
         target_option_val!(merge_functions);
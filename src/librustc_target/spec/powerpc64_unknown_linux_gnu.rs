@@ -5,6 +5,7 @@ pub fn target() -> TargetResult {
     base.cpu = "ppc64".to_string();
     base.pre_link_args.get_mut(&LinkerFlavor::Gcc).unwrap().push("-m64".to_string());
     base.max_atomic_width = Some(64);
+    base.cache_line_size = 128;
 
     // ld.so in at least RHEL6 on ppc64 has a bug related to BIND_NOW, so only enable partial RELRO
     // for now. https://github.com/rust-lang/rust/pull/43170#issuecomment-315411474
@@ -5,6 +5,7 @@ pub fn target() -> TargetResult {
     base.cpu = "ppc64".to_string();
     base.pre_link_args.get_mut(&LinkerFlavor::Gcc).unwrap().push("-m64".to_string());
     base.max_atomic_width = Some(64);
+    base.cache_line_size = 128;
 
     Ok(Target {
         llvm_target: "powerpc64-unknown-linux-gnu".to_string(),
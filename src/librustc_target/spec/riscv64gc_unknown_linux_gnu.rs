@@ -19,6 +19,7 @@ pub fn target() -> TargetResult {
             features: "+m,+a,+f,+d,+c".to_string(),
             llvm_abiname: "lp64d".to_string(),
             max_atomic_width: Some(64),
+            supports_scalable_vectors: true,
             ..super::linux_base::opts()
         },
     })
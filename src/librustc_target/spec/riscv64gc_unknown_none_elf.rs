@@ -17,6 +17,7 @@ pub fn target() -> TargetResult {
             linker: Some("rust-lld".to_string()),
             cpu: "generic-rv64".to_string(),
             max_atomic_width: Some(64),
+            supports_scalable_vectors: true,
             atomic_cas: true,
             features: "+m,+a,+f,+d,+c".to_string(),
             executables: true,
@@ -10,6 +10,7 @@ pub fn target() -> TargetResult {
     base.features = "-vector".to_string();
     base.max_atomic_width = Some(64);
     base.min_global_align = Some(16);
+    base.cache_line_size = 256;
 
     Ok(Target {
         llvm_target: "s390x-unknown-linux-gnu".to_string(),
@@ -0,0 +1,277 @@
+//! The target features known to rustc, which can be used with `#[target_feature]`,
+//! `cfg(target_feature)` and `-C target-feature`.
+//!
+//! Features are named the way Rust names them, which is not always the way the codegen backend
+//! does. Unstable features come with the feature gate that allows using them.
+
+use rustc_span::symbol::{sym, Symbol};
+
+const ARM_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("aclass", Some(sym::arm_target_feature)),
+    ("mclass", Some(sym::arm_target_feature)),
+    ("rclass", Some(sym::arm_target_feature)),
+    ("dsp", Some(sym::arm_target_feature)),
+    ("neon", Some(sym::arm_target_feature)),
+    ("crc", Some(sym::arm_target_feature)),
+    ("crypto", Some(sym::arm_target_feature)),
+    ("v5te", Some(sym::arm_target_feature)),
+    ("v6", Some(sym::arm_target_feature)),
+    ("v6k", Some(sym::arm_target_feature)),
+    ("v6t2", Some(sym::arm_target_feature)),
+    ("v7", Some(sym::arm_target_feature)),
+    ("v8", Some(sym::arm_target_feature)),
+    ("vfp2", Some(sym::arm_target_feature)),
+    ("vfp3", Some(sym::arm_target_feature)),
+    ("vfp4", Some(sym::arm_target_feature)),
+];
+
+const AARCH64_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("fp", Some(sym::aarch64_target_feature)),
+    ("neon", Some(sym::aarch64_target_feature)),
+    ("sve", Some(sym::aarch64_target_feature)),
+    ("crc", Some(sym::aarch64_target_feature)),
+    ("crypto", Some(sym::aarch64_target_feature)),
+    ("ras", Some(sym::aarch64_target_feature)),
+    ("lse", Some(sym::aarch64_target_feature)),
+    ("rdm", Some(sym::aarch64_target_feature)),
+    ("fp16", Some(sym::aarch64_target_feature)),
+    ("rcpc", Some(sym::aarch64_target_feature)),
+    ("dotprod", Some(sym::aarch64_target_feature)),
+    ("v8.1a", Some(sym::aarch64_target_feature)),
+    ("v8.2a", Some(sym::aarch64_target_feature)),
+    ("v8.3a", Some(sym::aarch64_target_feature)),
+];
+
+const X86_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("adx", Some(sym::adx_target_feature)),
+    ("aes", None),
+    ("avx", None),
+    ("avx2", None),
+    ("avx512bw", Some(sym::avx512_target_feature)),
+    ("avx512cd", Some(sym::avx512_target_feature)),
+    ("avx512dq", Some(sym::avx512_target_feature)),
+    ("avx512er", Some(sym::avx512_target_feature)),
+    ("avx512f", Some(sym::avx512_target_feature)),
+    ("avx512ifma", Some(sym::avx512_target_feature)),
+    ("avx512pf", Some(sym::avx512_target_feature)),
+    ("avx512vbmi", Some(sym::avx512_target_feature)),
+    ("avx512vl", Some(sym::avx512_target_feature)),
+    ("avx512vpopcntdq", Some(sym::avx512_target_feature)),
+    ("bmi1", None),
+    ("bmi2", None),
+    ("cmpxchg16b", Some(sym::cmpxchg16b_target_feature)),
+    ("f16c", Some(sym::f16c_target_feature)),
+    ("fma", None),
+    ("fxsr", None),
+    ("lzcnt", None),
+    ("mmx", Some(sym::mmx_target_feature)),
+    ("movbe", Some(sym::movbe_target_feature)),
+    ("pclmulqdq", None),
+    ("popcnt", None),
+    ("rdrand", None),
+    ("rdseed", None),
+    ("rtm", Some(sym::rtm_target_feature)),
+    ("sha", None),
+    ("sse", None),
+    ("sse2", None),
+    ("sse3", None),
+    ("sse4.1", None),
+    ("sse4.2", None),
+    ("sse4a", Some(sym::sse4a_target_feature)),
+    ("ssse3", None),
+    ("tbm", Some(sym::tbm_target_feature)),
+    ("xsave", None),
+    ("xsavec", None),
+    ("xsaveopt", None),
+    ("xsaves", None),
+];
+
+const HEXAGON_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("hvx", Some(sym::hexagon_target_feature)),
+    ("hvx-length128b", Some(sym::hexagon_target_feature)),
+];
+
+const POWERPC_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("altivec", Some(sym::powerpc_target_feature)),
+    ("power8-altivec", Some(sym::powerpc_target_feature)),
+    ("power9-altivec", Some(sym::powerpc_target_feature)),
+    ("power8-vector", Some(sym::powerpc_target_feature)),
+    ("power9-vector", Some(sym::powerpc_target_feature)),
+    ("vsx", Some(sym::powerpc_target_feature)),
+];
+
+const MIPS_WHITELIST: &[(&str, Option<Symbol>)] =
+    &[("fp64", Some(sym::mips_target_feature)), ("msa", Some(sym::mips_target_feature))];
+
+const RISCV_WHITELIST: &[(&str, Option<Symbol>)] = &[
+    ("m", Some(sym::riscv_target_feature)),
+    ("a", Some(sym::riscv_target_feature)),
+    ("f", Some(sym::riscv_target_feature)),
+    ("d", Some(sym::riscv_target_feature)),
+    ("c", Some(sym::riscv_target_feature)),
+    ("v", Some(sym::riscv_target_feature)),
+    ("zba", Some(sym::riscv_target_feature)),
+    ("zbb", Some(sym::riscv_target_feature)),
+    ("zbc", Some(sym::riscv_target_feature)),
+    ("zbs", Some(sym::riscv_target_feature)),
+    ("zbkb", Some(sym::riscv_target_feature)),
+    ("zbkc", Some(sym::riscv_target_feature)),
+    ("zbkx", Some(sym::riscv_target_feature)),
+    ("zkn", Some(sym::riscv_target_feature)),
+    ("zknd", Some(sym::riscv_target_feature)),
+    ("zkne", Some(sym::riscv_target_feature)),
+    ("zknh", Some(sym::riscv_target_feature)),
+    ("zks", Some(sym::riscv_target_feature)),
+    ("zksed", Some(sym::riscv_target_feature)),
+    ("zksh", Some(sym::riscv_target_feature)),
+    ("zkr", Some(sym::riscv_target_feature)),
+    ("zkt", Some(sym::riscv_target_feature)),
+];
+
+const WASM_WHITELIST: &[(&str, Option<Symbol>)] =
+    &[("simd128", Some(sym::wasm_target_feature)), ("atomics", Some(sym::wasm_target_feature))];
+
+/// When rustdoc is running, provide a list of all known features so that all their respective
+/// primitives may be documented.
+///
+/// IMPORTANT: If you're adding another whitelist to the above lists, make sure to add it to this
+/// iterator!
+pub fn all_known_features() -> impl Iterator<Item = (&'static str, Option<Symbol>)> {
+    ARM_WHITELIST
+        .iter()
+        .cloned()
+        .chain(AARCH64_WHITELIST.iter().cloned())
+        .chain(X86_WHITELIST.iter().cloned())
+        .chain(HEXAGON_WHITELIST.iter().cloned())
+        .chain(POWERPC_WHITELIST.iter().cloned())
+        .chain(MIPS_WHITELIST.iter().cloned())
+        .chain(RISCV_WHITELIST.iter().cloned())
+        .chain(WASM_WHITELIST.iter().cloned())
+}
+
+/// Returns the features known to rustc for the architecture `arch`.
+pub fn supported_target_features(arch: &str) -> &'static [(&'static str, Option<Symbol>)] {
+    match arch {
+        "arm" => ARM_WHITELIST,
+        "aarch64" => AARCH64_WHITELIST,
+        "x86" | "x86_64" => X86_WHITELIST,
+        "hexagon" => HEXAGON_WHITELIST,
+        "mips" | "mips64" => MIPS_WHITELIST,
+        "powerpc" | "powerpc64" => POWERPC_WHITELIST,
+        "riscv32" | "riscv64" => RISCV_WHITELIST,
+        "wasm32" => WASM_WHITELIST,
+        _ => &[],
+    }
+}
+
+/// Returns the features that `feature` directly depends on. Enabling `feature` enables them,
+/// and disabling any of them disables `feature`.
+pub fn implied_target_features(arch: &str, feature: &str) -> &'static [&'static str] {
+    match (arch, feature) {
+        ("arm", "v6") => &["v5te"],
+        ("arm", "v6k") => &["v6"],
+        ("arm", "v6t2") => &["v6k"],
+        ("arm", "v7") => &["v6t2"],
+        ("arm", "v8") => &["v7"],
+        ("arm", "vfp3") => &["vfp2"],
+        ("arm", "vfp4") => &["vfp3"],
+        ("arm", "neon") => &["vfp3"],
+        ("arm", "crypto") => &["neon"],
+
+        ("aarch64", "neon") | ("aarch64", "fp16") => &["fp"],
+        ("aarch64", "sve") => &["fp16"],
+        ("aarch64", "crypto") => &["neon"],
+        ("aarch64", "v8.1a") => &["crc", "lse", "rdm"],
+        ("aarch64", "v8.2a") => &["v8.1a", "ras"],
+        ("aarch64", "v8.3a") => &["v8.2a", "rcpc"],
+
+        ("x86", f) | ("x86_64", f) => match f {
+            "sse2" => &["sse"],
+            "sse3" => &["sse2"],
+            "ssse3" => &["sse3"],
+            "sse4.1" => &["ssse3"],
+            "sse4.2" => &["sse4.1"],
+            "sse4a" => &["sse3"],
+            "avx" => &["sse4.2"],
+            "avx2" | "fma" | "f16c" => &["avx"],
+            "avx512f" => &["avx2", "fma", "f16c"],
+            "avx512bw" | "avx512cd" | "avx512dq" | "avx512er" | "avx512ifma" | "avx512pf"
+            | "avx512vbmi" | "avx512vl" | "avx512vpopcntdq" => &["avx512f"],
+            "aes" | "pclmulqdq" | "sha" => &["sse2"],
+            "xsavec" | "xsaveopt" | "xsaves" => &["xsave"],
+            _ => &[],
+        },
+
+        ("hexagon", "hvx-length128b") => &["hvx"],
+
+        ("mips", "msa") | ("mips64", "msa") => &["fp64"],
+
+        ("powerpc", f) | ("powerpc64", f) => match f {
+            "vsx" | "power8-altivec" => &["altivec"],
+            "power9-altivec" => &["power8-altivec"],
+            "power8-vector" => &["vsx", "power8-altivec"],
+            "power9-vector" => &["power8-vector", "power9-altivec"],
+            _ => &[],
+        },
+
+        ("riscv32", f) | ("riscv64", f) => match f {
+            "d" => &["f"],
+            "v" => &["d"],
+            "zkn" => &["zbkb", "zbkc", "zbkx", "zkne", "zknd", "zknh"],
+            "zks" => &["zbkb", "zbkc", "zbkx", "zksed", "zksh"],
+            _ => &[],
+        },
+
+        _ => &[],
+    }
+}
+
+/// Returns the family of extensions that `feature` belongs to, which `--print target-features`
+/// groups the features by.
+pub fn target_feature_family(arch: &str, feature: &str) -> &'static str {
+    match (arch, feature) {
+        ("arm", f) => match f {
+            "aclass" | "mclass" | "rclass" => "architecture profiles",
+            "v5te" | "v6" | "v6k" | "v6t2" | "v7" | "v8" => "architecture versions",
+            "vfp2" | "vfp3" | "vfp4" => "floating point",
+            "neon" | "dsp" => "SIMD",
+            "crc" | "crypto" => "cryptography and checksums",
+            _ => "other",
+        },
+
+        ("aarch64", f) => match f {
+            "v8.1a" | "v8.2a" | "v8.3a" => "architecture versions",
+            "fp" | "fp16" => "floating point",
+            "neon" | "sve" | "rdm" | "dotprod" => "SIMD",
+            "crc" | "crypto" => "cryptography and checksums",
+            "lse" | "rcpc" | "ras" => "atomics and reliability",
+            _ => "other",
+        },
+
+        ("x86", f) | ("x86_64", f) => match f {
+            "mmx" | "sse" | "sse2" | "sse3" | "ssse3" | "sse4.1" | "sse4.2" | "sse4a" => "SSE",
+            "avx" | "avx2" | "fma" | "f16c" => "AVX",
+            f if f.starts_with("avx512") => "AVX-512",
+            "adx" | "bmi1" | "bmi2" | "lzcnt" | "movbe" | "popcnt" | "tbm" => "bit manipulation",
+            "aes" | "pclmulqdq" | "rdrand" | "rdseed" | "sha" => "cryptography and randomness",
+            "cmpxchg16b" | "rtm" => "atomics and transactional memory",
+            "fxsr" | "xsave" | "xsavec" | "xsaveopt" | "xsaves" => "processor state saving",
+            _ => "other",
+        },
+
+        ("hexagon", _) | ("powerpc", _) | ("powerpc64", _) | ("wasm32", "simd128") => "SIMD",
+        ("mips", "msa") | ("mips64", "msa") => "SIMD",
+        ("mips", "fp64") | ("mips64", "fp64") => "floating point",
+        ("wasm32", "atomics") => "atomics",
+
+        ("riscv32", f) | ("riscv64", f) => match f {
+            "m" | "a" | "f" | "d" | "c" => "standard extensions",
+            "v" => "vector",
+            f if f.starts_with("zb") => "bit manipulation",
+            f if f.starts_with("zk") => "scalar cryptography",
+            _ => "other",
+        },
+
+        _ => "other",
+    }
+}
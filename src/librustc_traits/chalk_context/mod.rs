@@ -602,6 +602,11 @@ crate fn provide(p: &mut Providers<'_>) {
     *p = Providers { evaluate_goal, ..*p };
 }
 
+/// Solves `goal` in a new `Forest`. The results are cached by the `evaluate_goal` query, whose
+/// dependencies invalidate them in incremental compilation.
+// FIXME: the answers to the subgoals are only cached in the `Forest`, so each query solves them
+// again. Sharing them needs a `Forest` that outlives the queries and records the dependencies of
+// each of its tables, like the `EvaluationCache` of the old trait solver does.
 crate fn evaluate_goal<'tcx>(
     tcx: TyCtxt<'tcx>,
     goal: ChalkCanonicalGoal<'tcx>,
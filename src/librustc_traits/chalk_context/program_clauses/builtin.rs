@@ -212,6 +212,139 @@ crate fn assemble_builtin_sized_impls<'tcx>(
     }
 }
 
+crate fn assemble_builtin_auto_trait_impls<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    auto_trait_def_id: DefId,
+    ty: Ty<'tcx>,
+    clauses: &mut Vec<Clause<'tcx>>,
+) {
+    let mut push_builtin_impl = |ty: Ty<'tcx>, nested: &[GenericArg<'tcx>]| {
+        let clause = builtin_impl_clause(tcx, ty, nested, auto_trait_def_id);
+        // Bind innermost bound vars that may exist in `ty` and `nested`.
+        clauses.push(Clause::ForAll(ty::Binder::bind(clause)));
+    };
+
+    // Like in the old trait solver, an explicit (positive or negative) impl of the auto trait
+    // replaces the builtin one, e.g. `impl<T: ?Sized> !Send for *const T`.
+    let mut has_explicit_impl = false;
+    if !ty.is_ty_var() {
+        tcx.for_each_relevant_impl(auto_trait_def_id, ty, |_| has_explicit_impl = true);
+    }
+    if has_explicit_impl {
+        return;
+    }
+
+    match &ty.kind {
+        // Non parametric types.
+        ty::Bool
+        | ty::Char
+        | ty::Int(..)
+        | ty::Uint(..)
+        | ty::Float(..)
+        | ty::Str
+        | ty::Never
+        | ty::Infer(ty::IntVar(_))
+        | ty::Infer(ty::FloatVar(_))
+        | ty::Error => push_builtin_impl(ty, &[]),
+
+        ty::FnPtr(fn_ptr) => {
+            let fn_ptr = fn_ptr.skip_binder();
+            let fn_ptr = generic_types::fn_ptr(
+                tcx,
+                fn_ptr.inputs_and_output.len(),
+                fn_ptr.c_variadic,
+                fn_ptr.unsafety,
+                fn_ptr.abi,
+            );
+            push_builtin_impl(fn_ptr, &[]);
+        }
+        &ty::FnDef(def_id, ..) => {
+            push_builtin_impl(generic_types::fn_def(tcx, def_id), &[]);
+        }
+
+        // These implement the auto trait if their element types do.
+        &ty::Array(_, length) => {
+            let element_ty = generic_types::bound(tcx, 0);
+            push_builtin_impl(
+                tcx.mk_ty(ty::Array(element_ty, length)),
+                &[GenericArg::from(element_ty)],
+            );
+        }
+        ty::Slice(_) => {
+            let element_ty = generic_types::bound(tcx, 0);
+            push_builtin_impl(tcx.mk_slice(element_ty), &[GenericArg::from(element_ty)]);
+        }
+        ty::RawPtr(ptr) => {
+            let ptr_ty = generic_types::raw_ptr(tcx, ptr.mutbl);
+            let pointee_ty = ptr_ty.builtin_deref(true).unwrap().ty;
+            push_builtin_impl(ptr_ty, &[GenericArg::from(pointee_ty)]);
+        }
+        &ty::Ref(_, _, mutbl) => {
+            let ref_ty = generic_types::ref_ty(tcx, mutbl);
+            let referent_ty = ref_ty.builtin_deref(true).unwrap().ty;
+            push_builtin_impl(ref_ty, &[GenericArg::from(referent_ty)]);
+        }
+        &ty::Tuple(type_list) => {
+            let type_list = generic_types::type_list(tcx, type_list.len());
+            push_builtin_impl(tcx.mk_ty(ty::Tuple(type_list)), &**type_list);
+        }
+        &ty::Closure(def_id, ..) => {
+            let closure_ty = generic_types::closure(tcx, def_id);
+            let upvar_tys: Vec<_> = match &closure_ty.kind {
+                ty::Closure(_, substs) => substs
+                    .as_closure()
+                    .upvar_tys(def_id, tcx)
+                    .map(|ty| GenericArg::from(ty))
+                    .collect(),
+                _ => bug!(),
+            };
+            push_builtin_impl(closure_ty, &upvar_tys);
+        }
+
+        // Structs, enums and unions implement the auto trait if all their fields do. The goal is
+        // coinductive, so this also holds for recursive types.
+        &ty::Adt(adt_def, _) => {
+            let substs = InternalSubsts::bound_vars_for_item(tcx, adt_def.did);
+            let adt = tcx.mk_ty(ty::Adt(adt_def, substs));
+            let nested: Vec<_> = if adt_def.is_phantom_data() {
+                substs.types().map(GenericArg::from).collect()
+            } else {
+                adt_def.all_fields().map(|field| GenericArg::from(field.ty(tcx, substs))).collect()
+            };
+            push_builtin_impl(adt, &nested);
+        }
+
+        // Artificially trigger an ambiguity by adding two possible types to
+        // unify against.
+        ty::Infer(ty::TyVar(_)) => {
+            push_builtin_impl(tcx.types.i32, &[]);
+            push_builtin_impl(tcx.types.f32, &[]);
+        }
+
+        ty::Projection(_projection_ty) => {
+            // FIXME: add builtin impls from the associated type values found in
+            // trait impls of `projection_ty.trait_ref(tcx)`.
+        }
+
+        // FIXME: add builtin impls for the hidden type of `impl Trait`, and for the upvars and
+        // witness of generators.
+        ty::Opaque(..) | ty::Generator(..) => (),
+
+        // The auto trait bound can only come from the environment or from explicit impls.
+        ty::Param(..)
+        | ty::Placeholder(..)
+        | ty::UnnormalizedProjection(..)
+        | ty::Dynamic(..)
+        | ty::Foreign(..) => (),
+
+        ty::Bound(..)
+        | ty::GeneratorWitness(..)
+        | ty::Infer(ty::FreshTy(_))
+        | ty::Infer(ty::FreshIntTy(_))
+        | ty::Infer(ty::FreshFloatTy(_)) => bug!("unexpected type {:?}", ty),
+    }
+}
+
 crate fn assemble_builtin_copy_clone_impls<'tcx>(
     tcx: TyCtxt<'tcx>,
     trait_def_id: DefId,
@@ -98,11 +98,19 @@ impl ChalkInferenceContext<'cx, 'tcx> {
                     );
                 }
 
+                if self.infcx.tcx.trait_is_auto(trait_predicate.def_id()) {
+                    assemble_builtin_auto_trait_impls(
+                        self.infcx.tcx,
+                        trait_predicate.def_id(),
+                        trait_predicate.self_ty(),
+                        &mut clauses,
+                    );
+                }
+
                 // FIXME: we need to add special rules for other builtin impls:
                 // * `Generator`
                 // * `FnOnce` / `FnMut` / `Fn`
                 // * trait objects
-                // * auto traits
 
                 // Rule `Implemented-From-Env` will be computed from the environment.
                 clauses
@@ -357,6 +357,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                         {
                             tcx.sess.parse_sess.expr_parentheses_needed(&mut err, *sp, None);
                         }
+                        if let Some(note) = self.on_misuse_note(oprnd_t, "deref", None).note {
+                            err.note(&note);
+                        }
                         err.emit();
                         oprnd_t = tcx.types.err;
                     }
@@ -1785,6 +1788,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                             );
                         }
                     }
+                    if let Some(note) = self.on_misuse_note(base_t, "index", None).note {
+                        err.note(&note);
+                    }
                     err.emit();
                     self.tcx.types.err
                 }
@@ -608,10 +608,24 @@ impl<'a, 'tcx> ProbeContext<'a, 'tcx> {
 
                     self.assemble_inherent_candidates_from_object(generalized_self_ty);
                     self.assemble_inherent_impl_candidates_for_type(p.def_id());
+
+                    let lang_def_id = lang_items.error_alloc_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+
+                    let lang_def_id = lang_items.error_send_alloc_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+
+                    let lang_def_id = lang_items.error_send_sync_alloc_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
                 }
             }
             ty::Adt(def, _) => {
                 self.assemble_inherent_impl_candidates_for_type(def.did);
+
+                if Some(def.did) == lang_items.backtrace_type() {
+                    let lang_def_id = lang_items.backtrace_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+                }
             }
             ty::Foreign(did) => {
                 self.assemble_inherent_impl_candidates_for_type(did);
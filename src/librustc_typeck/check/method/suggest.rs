@@ -578,6 +578,14 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     }
                 }
 
+                if is_method {
+                    let self_ty = actual.peel_refs();
+                    let note = self.on_misuse_note(self_ty, "method", Some(item_name.name));
+                    if let Some(note) = note.note {
+                        err.note(&note);
+                    }
+                }
+
                 let mut fallback_span = true;
                 let msg = "remove this method call";
                 if item_name.as_str() == "as_str" && actual.peel_refs().is_str() {
@@ -1524,9 +1524,13 @@ fn check_struct(tcx: TyCtxt<'_>, id: hir::HirId, span: Span) {
     if def.repr.simd() {
         check_simd(tcx, span, def_id);
     }
+    if def.repr.scalable() {
+        check_scalable_vector(tcx, span, def_id);
+    }
 
     check_transparent(tcx, span, def_id);
     check_packed(tcx, span, def_id);
+    check_scalable_vector_fields(tcx, def_id);
 }
 
 fn check_union(tcx: TyCtxt<'_>, id: hir::HirId, span: Span) {
@@ -1537,6 +1541,7 @@ fn check_union(tcx: TyCtxt<'_>, id: hir::HirId, span: Span) {
     check_transparent(tcx, span, def_id);
     check_union_fields(tcx, span, def_id);
     check_packed(tcx, span, def_id);
+    check_scalable_vector_fields(tcx, def_id);
 }
 
 /// When the `#![feature(untagged_unions)]` gate is active,
@@ -1705,6 +1710,7 @@ pub fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, it: &'tcx hir::Item<'tcx>) {
         }
         hir::ItemKind::Enum(ref enum_definition, _) => {
             check_enum(tcx, it.span, &enum_definition.variants, it.hir_id);
+            check_on_misuse(tcx, it);
         }
         hir::ItemKind::Fn(..) => {} // entirely within check_item_body
         hir::ItemKind::Impl { ref items, .. } => {
@@ -1730,6 +1736,7 @@ pub fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, it: &'tcx hir::Item<'tcx>) {
         }
         hir::ItemKind::Struct(..) => {
             check_struct(tcx, it.hir_id, it.span);
+            check_on_misuse(tcx, it);
         }
         hir::ItemKind::Union(..) => {
             check_union(tcx, it.hir_id, it.span);
@@ -1841,6 +1848,12 @@ fn check_on_unimplemented(tcx: TyCtxt<'_>, trait_def_id: DefId, item: &hir::Item
     let _ = traits::OnUnimplementedDirective::of_item(tcx, trait_def_id, item_def_id);
 }
 
+fn check_on_misuse(tcx: TyCtxt<'_>, item: &hir::Item<'_>) {
+    let item_def_id = tcx.hir().local_def_id(item.hir_id);
+    // an error would be reported if this fails.
+    let _ = traits::OnUnimplementedDirective::of_type(tcx, item_def_id);
+}
+
 fn report_forbidden_specialization(
     tcx: TyCtxt<'_>,
     impl_item: &hir::ImplItem<'_>,
@@ -2276,6 +2289,51 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: DefId) {
     }
 }
 
+/// Checks a `#[repr(simd, scalable(N))]` type, which must have a single field giving the
+/// element type of the vector.
+fn check_scalable_vector(tcx: TyCtxt<'_>, sp: Span, def_id: DefId) {
+    let def = tcx.adt_def(def_id);
+    if !def.repr.simd() {
+        tcx.sess.span_err(sp, "`repr(scalable)` can only be used with `repr(simd)`");
+    } else if def.non_enum_variant().fields.len() != 1 {
+        tcx.sess
+            .struct_span_err(sp, "scalable vectors must have a single field")
+            .note("the field is the element type of the vector")
+            .emit();
+    }
+    if !tcx.sess.target.target.options.supports_scalable_vectors {
+        tcx.sess.span_err(sp, "scalable vectors are not supported by the target");
+    }
+}
+
+/// Checks that the fields of a type are not scalable vectors, or arrays or tuples of them,
+/// since their size is only known at runtime.
+fn check_scalable_vector_fields(tcx: TyCtxt<'_>, def_id: DefId) {
+    fn is_scalable<'tcx>(ty: Ty<'tcx>) -> bool {
+        match ty.kind {
+            ty::Adt(def, _) => def.repr.scalable(),
+            ty::Array(ty, _) | ty::Slice(ty) => is_scalable(ty),
+            ty::Tuple(tys) => tys.types().any(is_scalable),
+            _ => false,
+        }
+    }
+
+    let def = tcx.adt_def(def_id);
+    if def.repr.scalable() {
+        return;
+    }
+    for field in def.all_fields() {
+        let field_ty = tcx.type_of(field.did);
+        if is_scalable(field_ty) {
+            let field_span = tcx.hir().span_if_local(field.did).unwrap();
+            tcx.sess.span_err(
+                field_span,
+                &format!("scalable vector type `{}` cannot be used in a field", field_ty),
+            );
+        }
+    }
+}
+
 fn check_packed(tcx: TyCtxt<'_>, sp: Span, def_id: DefId) {
     let repr = tcx.adt_def(def_id).repr;
     if repr.packed() {
@@ -2587,6 +2645,7 @@ pub fn check_enum<'tcx>(
 
     check_representable(tcx, sp, def_id);
     check_transparent(tcx, sp, def_id);
+    check_scalable_vector_fields(tcx, def_id);
 }
 
 fn report_unexpected_variant_res(tcx: TyCtxt<'_>, res: Res, span: Span, qpath: &QPath<'_>) {
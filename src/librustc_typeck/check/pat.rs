@@ -1,7 +1,7 @@
 use crate::check::FnCtxt;
 use rustc::infer;
 use rustc::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
-use rustc::traits::Pattern;
+use rustc::traits::{self, Pattern};
 use rustc::ty::subst::GenericArg;
 use rustc::ty::{self, BindingMode, Ty, TypeFoldable};
 use rustc_data_structures::fx::FxHashMap;
@@ -132,7 +132,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
 
         let ty = match pat.kind {
             PatKind::Wild => expected,
-            PatKind::Lit(lt) => self.check_pat_lit(pat.span, lt, expected, ti),
+            PatKind::Lit(lt) => self.check_pat_lit(pat, lt, expected, ti),
             PatKind::Range(lhs, rhs, _) => self.check_pat_range(pat.span, lhs, rhs, expected, ti),
             PatKind::Binding(ba, var_id, _, sub) => {
                 self.check_pat_ident(pat, ba, var_id, sub, expected, def_bm, ti)
@@ -225,7 +225,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         def_bm: BindingMode,
         is_non_ref_pat: bool,
     ) -> (Ty<'tcx>, BindingMode) {
-        if is_non_ref_pat {
+        if is_non_ref_pat || self.is_deref_pattern_str_lit(pat, expected) {
             debug!("pattern is non reference pattern");
             self.peel_off_references(pat, expected, def_bm)
         } else {
@@ -284,9 +284,58 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Is `ty` a smart pointer that deref patterns can match through?
+    fn is_deref_pure(&self, span: Span, ty: Ty<'tcx>) -> bool {
+        match (ty.kind, self.tcx.lang_items().deref_pure_trait()) {
+            (ty::Adt(..), Some(deref_pure)) => traits::type_known_to_meet_bound_modulo_regions(
+                self,
+                self.param_env,
+                ty,
+                deref_pure,
+                span,
+            ),
+            _ => false,
+        }
+    }
+
+    /// With `#![feature(deref_patterns)]`, returns the type that a pattern against a smart
+    /// pointer of type `ty` is matched against, i.e., `<ty as Deref>::Target`.
+    fn deref_pattern_target(&self, span: Span, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        if !self.tcx.features().deref_patterns || !self.is_deref_pure(span, ty) {
+            return None;
+        }
+        let mut autoderef = self.autoderef(span, ty);
+        let target = autoderef.nth(1).map(|(target, _)| target);
+        autoderef.finalize(self);
+        target
+    }
+
+    /// Is `pat` a string literal that is matched through a smart pointer, e.g.,
+    /// `"foo"` against a `String`?
+    fn is_deref_pattern_str_lit(&self, pat: &'tcx Pat<'tcx>, expected: Ty<'tcx>) -> bool {
+        let is_str_lit = match pat.kind {
+            PatKind::Lit(&hir::Expr { kind: hir::ExprKind::Lit(ref lit), .. }) => match lit.node {
+                ast::LitKind::Str(..) => true,
+                _ => false,
+            },
+            _ => false,
+        };
+        if !self.tcx.features().deref_patterns || !is_str_lit {
+            return false;
+        }
+        let mut ty = self.resolve_vars_with_obligations(&expected);
+        while let ty::Ref(_, inner_ty, _) = ty.kind {
+            ty = inner_ty;
+        }
+        self.is_deref_pure(pat.span, ty)
+    }
+
     /// Peel off as many immediately nested `& mut?` from the expected type as possible
     /// and return the new expected type and binding default binding mode.
     /// The adjustments vector, if non-empty is stored in a table.
+    ///
+    /// With `#![feature(deref_patterns)]`, smart pointers such as `Box<T>` and `Rc<T>` are
+    /// peeled off as well, so that the pattern is matched against their pointee.
     fn peel_off_references(
         &self,
         pat: &'tcx Pat<'tcx>,
@@ -304,24 +353,46 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         //
         // See the examples in `ui/match-defbm*.rs`.
         let mut pat_adjustments = vec![];
-        while let ty::Ref(_, inner_ty, inner_mutability) = expected.kind {
-            debug!("inspecting {:?}", expected);
+        loop {
+            while let ty::Ref(_, inner_ty, inner_mutability) = expected.kind {
+                debug!("inspecting {:?}", expected);
+
+                debug!("current discriminant is Ref, inserting implicit deref");
+                // Preserve the reference type. We'll need it later during HAIR lowering.
+                pat_adjustments.push(expected);
+
+                expected = inner_ty;
+                def_bm = ty::BindByReference(match def_bm {
+                    // If default binding mode is by value, make it `ref` or `ref mut`
+                    // (depending on whether we observe `&` or `&mut`).
+                    ty::BindByValue(_) |
+                    // When `ref mut`, stay a `ref mut` (on `&mut`) or downgrade to `ref` (on `&`).
+                    ty::BindByReference(hir::Mutability::Mut) => inner_mutability,
+                    // Once a `ref`, always a `ref`.
+                    // This is because a `& &mut` cannot mutate the underlying value.
+                    ty::BindByReference(m @ hir::Mutability::Not) => m,
+                });
+            }
 
-            debug!("current discriminant is Ref, inserting implicit deref");
-            // Preserve the reference type. We'll need it later during HAIR lowering.
+            // A `box` pattern matches the box itself.
+            if let PatKind::Box(_) = pat.kind {
+                if expected.is_box() {
+                    break;
+                }
+            }
+            let target = match self.deref_pattern_target(pat.span, expected) {
+                Some(target) => target,
+                None => break,
+            };
+            debug!("current discriminant is a smart pointer, inserting implicit deref");
+            // Like references, smart pointers are kept in the adjustments for HAIR lowering.
+            // Except for `Box`, the pointee is only accessible through `Deref::deref`, so
+            // bindings in the pattern are by reference.
+            if !expected.is_box() {
+                def_bm = ty::BindByReference(hir::Mutability::Not);
+            }
             pat_adjustments.push(expected);
-
-            expected = inner_ty;
-            def_bm = ty::BindByReference(match def_bm {
-                // If default binding mode is by value, make it `ref` or `ref mut`
-                // (depending on whether we observe `&` or `&mut`).
-                ty::BindByValue(_) |
-                // When `ref mut`, stay a `ref mut` (on `&mut`) or downgrade to `ref` (on `&`).
-                ty::BindByReference(hir::Mutability::Mut) => inner_mutability,
-                // Once a `ref`, always a `ref`.
-                // This is because a `& &mut` cannot mutate the underlying value.
-                ty::BindByReference(m @ hir::Mutability::Not) => m,
-            });
+            expected = self.resolve_vars_with_obligations(&target);
         }
 
         if pat_adjustments.len() > 0 {
@@ -334,11 +405,12 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
 
     fn check_pat_lit(
         &self,
-        span: Span,
+        pat: &'tcx Pat<'tcx>,
         lt: &hir::Expr<'tcx>,
         expected: Ty<'tcx>,
         ti: TopInfo<'tcx>,
     ) -> Ty<'tcx> {
+        let span = pat.span;
         // We've already computed the type above (when checking for a non-ref pat),
         // so avoid computing it again.
         let ty = self.node_ty(lt.hir_id);
@@ -357,6 +429,14 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     }
                 }
             }
+
+            // With deref patterns, a string literal matched through a smart pointer is
+            // compared against the `str` that the pointer derefs to.
+            if let ast::LitKind::Str(..) = lt.node {
+                if self.is_deref_pattern_str(pat, expected) {
+                    pat_ty = expected;
+                }
+            }
         }
 
         // Somewhat surprising: in this case, the subtyping relation goes the
@@ -385,6 +465,22 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         pat_ty
     }
 
+    /// Is `expected` the `str` that a string literal pattern is matched against after deref
+    /// patterns peeled off a smart pointer other than `Box`?
+    fn is_deref_pattern_str(&self, pat: &'tcx Pat<'tcx>, expected: Ty<'tcx>) -> bool {
+        if !self.resolve_vars_with_obligations(&expected).is_str() {
+            return false;
+        }
+        let tables = self.inh.tables.borrow();
+        match tables.pat_adjustments().get(pat.hir_id).and_then(|adjustments| adjustments.last()) {
+            Some(ty) => match ty.kind {
+                ty::Adt(adt_def, _) => !adt_def.is_box(),
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
     fn check_pat_range(
         &self,
         span: Span,
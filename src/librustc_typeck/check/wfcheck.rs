@@ -96,9 +96,13 @@ pub fn check_item_well_formed(tcx: TyCtxt<'_>, def_id: DefId) {
         // won't be allowed unless there's an *explicit* implementation of `Send`
         // for `T`
         hir::ItemKind::Impl { defaultness, ref of_trait, ref self_ty, .. } => {
-            let is_auto = tcx
-                .impl_trait_ref(tcx.hir().local_def_id(item.hir_id))
-                .map_or(false, |trait_ref| tcx.trait_is_auto(trait_ref.def_id));
+            let trait_ref = tcx.impl_trait_ref(tcx.hir().local_def_id(item.hir_id));
+            let is_auto = trait_ref.map_or(false, |trait_ref| tcx.trait_is_auto(trait_ref.def_id));
+            // The negative impls of `#[rustc_strict_coherence]` traits are used by coherence.
+            let allows_negative = is_auto
+                || trait_ref.map_or(false, |trait_ref| {
+                    tcx.has_attr(trait_ref.def_id, sym::rustc_strict_coherence)
+                });
             let polarity = tcx.impl_polarity(def_id);
             if let (hir::Defaultness::Default { .. }, true) = (defaultness, is_auto) {
                 tcx.sess.span_err(item.span, "impls of auto traits cannot be default");
@@ -109,7 +113,7 @@ pub fn check_item_well_formed(tcx: TyCtxt<'_>, def_id: DefId) {
                 }
                 ty::ImplPolarity::Negative => {
                     // FIXME(#27579): what amount of WF checking do we need for neg impls?
-                    if of_trait.is_some() && !is_auto {
+                    if of_trait.is_some() && !allows_negative {
                         struct_span_err!(
                             tcx.sess,
                             item.span,
@@ -811,15 +815,15 @@ fn check_method_receiver<'fcx, 'tcx>(
         fcx.tcx.liberate_late_bound_regions(method.def_id, &ty::Binder::bind(receiver_ty));
 
     if fcx.tcx.features().arbitrary_self_types {
-        if !receiver_is_valid(fcx, span, receiver_ty, self_ty, true) {
+        if receiver_is_valid(fcx, span, receiver_ty, self_ty, true).is_err() {
             // Report error; `arbitrary_self_types` was enabled.
             e0307(fcx, span, receiver_ty);
         }
     } else {
-        if !receiver_is_valid(fcx, span, receiver_ty, self_ty, false) {
-            if receiver_is_valid(fcx, span, receiver_ty, self_ty, true) {
+        if let Err(invalid) = receiver_is_valid(fcx, span, receiver_ty, self_ty, false) {
+            if receiver_is_valid(fcx, span, receiver_ty, self_ty, true).is_ok() {
                 // Report error; would have worked with `arbitrary_self_types`.
-                feature_err(
+                let mut err = feature_err(
                     &fcx.tcx.sess.parse_sess,
                     sym::arbitrary_self_types,
                     span,
@@ -828,9 +832,17 @@ fn check_method_receiver<'fcx, 'tcx>(
                             the `arbitrary_self_types` feature",
                         receiver_ty,
                     ),
-                )
-                .help(HELP_FOR_SELF_TYPE)
-                .emit();
+                );
+                match invalid {
+                    InvalidReceiver::NotReceiver(ty) => {
+                        err.note(&format!("`{}` does not implement the `Receiver` trait", ty));
+                    }
+                    // Only raw pointers are dereferenced with the feature and not without it.
+                    InvalidReceiver::NoDerefToSelf => {
+                        err.note("raw pointers do not implement the `Receiver` trait");
+                    }
+                }
+                err.help(HELP_FOR_SELF_TYPE).emit();
             } else {
                 // Report error; would not have worked with `arbitrary_self_types`.
                 e0307(fcx, span, receiver_ty);
@@ -852,13 +864,21 @@ fn e0307(fcx: &FnCtxt<'fcx, 'tcx>, span: Span, receiver_ty: Ty<'_>) {
     .emit();
 }
 
-/// Returns whether `receiver_ty` would be considered a valid receiver type for `self_ty`. If
+/// Why a type cannot be used as the type of `self`, see `receiver_is_valid`.
+enum InvalidReceiver<'tcx> {
+    /// The type does not dereference to `Self`.
+    NoDerefToSelf,
+    /// The type dereferences to `Self` through this type, which does not implement `Receiver`.
+    NotReceiver(Ty<'tcx>),
+}
+
+/// Checks whether `receiver_ty` would be considered a valid receiver type for `self_ty`. If
 /// `arbitrary_self_types` is enabled, `receiver_ty` must transitively deref to `self_ty`, possibly
 /// through a `*const/mut T` raw pointer. If the feature is not enabled, the requirements are more
-/// strict: `receiver_ty` must implement `Receiver` and directly implement
-/// `Deref<Target = self_ty>`.
+/// strict: `receiver_ty` and each type it derefs to on the way to `self_ty` must implement
+/// `Receiver`.
 ///
-/// N.B., there are cases this function returns `true` but causes an error to be emitted,
+/// N.B., there are cases this function returns `Ok` but causes an error to be emitted,
 /// particularly when `receiver_ty` derefs to a type that is the same as `self_ty` but has the
 /// wrong lifetime. Be careful of this if you are calling this function speculatively.
 fn receiver_is_valid<'fcx, 'tcx>(
@@ -867,7 +887,7 @@ fn receiver_is_valid<'fcx, 'tcx>(
     receiver_ty: Ty<'tcx>,
     self_ty: Ty<'tcx>,
     arbitrary_self_types_enabled: bool,
-) -> bool {
+) -> Result<(), InvalidReceiver<'tcx>> {
     let cause = fcx.cause(span, traits::ObligationCauseCode::MethodReceiver);
 
     let can_eq_self = |ty| fcx.infcx.can_eq(fcx.param_env, self_ty, ty).is_ok();
@@ -877,7 +897,7 @@ fn receiver_is_valid<'fcx, 'tcx>(
         if let Some(mut err) = fcx.demand_eqtype_with_origin(&cause, self_ty, receiver_ty) {
             err.emit();
         }
-        return true;
+        return Ok(());
     }
 
     let mut autoderef = fcx.autoderef(span, receiver_ty);
@@ -921,14 +941,18 @@ fn receiver_is_valid<'fcx, 'tcx>(
                         potential_self_ty,
                     )
                 {
-                    return false;
+                    return Err(InvalidReceiver::NotReceiver(potential_self_ty));
                 }
             }
         } else {
             debug!("receiver_is_valid: type `{:?}` does not deref to `{:?}`", receiver_ty, self_ty);
             // If he receiver already has errors reported due to it, consider it valid to avoid
             // unnecessary errors (#58712).
-            return receiver_ty.references_error();
+            return if receiver_ty.references_error() {
+                Ok(())
+            } else {
+                Err(InvalidReceiver::NoDerefToSelf)
+            };
         }
     }
 
@@ -936,10 +960,10 @@ fn receiver_is_valid<'fcx, 'tcx>(
     if !arbitrary_self_types_enabled
         && !receiver_is_implemented(fcx, receiver_trait_def_id, cause.clone(), receiver_ty)
     {
-        return false;
+        return Err(InvalidReceiver::NotReceiver(receiver_ty));
     }
 
-    true
+    Ok(())
 }
 
 fn receiver_is_implemented(
@@ -54,13 +54,26 @@ impl ItemLikeVisitor<'v> for InherentCollect<'tcx> {
         let lang_items = self.tcx.lang_items();
         match self_ty.kind {
             ty::Adt(def, _) => {
-                self.check_def_id(item, def.did);
+                // The methods of `Backtrace` are defined by `std`, which can capture one, outside
+                // of the crate of the type.
+                if Some(def_id) != lang_items.backtrace_impl() {
+                    self.check_def_id(item, def.did);
+                }
             }
             ty::Foreign(did) => {
                 self.check_def_id(item, did);
             }
             ty::Dynamic(ref data, ..) if data.principal_def_id().is_some() => {
-                self.check_def_id(item, data.principal_def_id().unwrap());
+                // The methods of `Box<dyn Error>` are defined by `alloc`, outside of the crate
+                // of the `Error` trait.
+                let error_alloc_impls = [
+                    lang_items.error_alloc_impl(),
+                    lang_items.error_send_alloc_impl(),
+                    lang_items.error_send_sync_alloc_impl(),
+                ];
+                if !error_alloc_impls.contains(&Some(def_id)) {
+                    self.check_def_id(item, data.principal_def_id().unwrap());
+                }
             }
             ty::Bool => {
                 self.check_primitive_impl(
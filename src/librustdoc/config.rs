@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::fmt;
+use std::fs;
 use std::path::PathBuf;
 
 use getopts;
@@ -188,7 +189,8 @@ pub struct RenderOptions {
     pub themes: Vec<PathBuf>,
     /// If present, CSS file that contains rules to add to the default CSS.
     pub extension_css: Option<PathBuf>,
-    /// A map of crate names to the URL to use instead of querying the crate's `html_root_url`.
+    /// A map of crate names to the URL to use instead of querying the crate's `html_root_url`,
+    /// from the `--extern-html-root-urls-file` maps and the `--extern-html-root-url` arguments.
     pub extern_html_root_urls: BTreeMap<String, String>,
     /// If present, suffix added to CSS/JavaScript files when referencing them in generated pages.
     pub resource_suffix: String,
@@ -327,7 +329,7 @@ impl Options {
         let extern_html_root_urls = match parse_extern_html_roots(&matches) {
             Ok(ex) => ex,
             Err(err) => {
-                diag.struct_err(err).emit();
+                diag.struct_err(&err).emit();
                 return Err(1);
             }
         };
@@ -593,13 +595,26 @@ fn check_deprecated_options(matches: &getopts::Matches, diag: &rustc_errors::Han
     }
 }
 
-/// Extracts `--extern-html-root-url` arguments from `matches` and returns a map of crate names to
-/// the given URLs. If an `--extern-html-root-url` argument was ill-formed, returns an error
-/// describing the issue.
-fn parse_extern_html_roots(
-    matches: &getopts::Matches,
-) -> Result<BTreeMap<String, String>, &'static str> {
+/// Extracts the `--extern-html-root-urls-file` maps and the `--extern-html-root-url` arguments from
+/// `matches` and returns a map of crate names to the given URLs, where the arguments override the
+/// maps. If a map couldn't be read or an `--extern-html-root-url` argument was ill-formed, returns
+/// an error describing the issue.
+fn parse_extern_html_roots(matches: &getopts::Matches) -> Result<BTreeMap<String, String>, String> {
     let mut externs = BTreeMap::new();
+    // The maps are JSON objects of crate names to URLs, written by the build system which knows
+    // where the documentation of each dependency ends up.
+    for path in &matches.opt_strs("extern-html-root-urls-file") {
+        let contents = fs::read_to_string(path).map_err(|err| {
+            format!("couldn't read --extern-html-root-urls-file {}: {}", path, err)
+        })?;
+        let map: BTreeMap<String, String> = serde_json::from_str(&contents).map_err(|err| {
+            format!(
+                "--extern-html-root-urls-file {} is not a map of crate names to URLs: {}",
+                path, err
+            )
+        })?;
+        externs.extend(map);
+    }
     for arg in &matches.opt_strs("extern-html-root-url") {
         let mut parts = arg.splitn(2, '=');
         let name = parts.next().ok_or("--extern-html-root-url must not be empty")?;
@@ -155,6 +155,14 @@ fn opts() -> Vec<RustcOptGroup> {
         unstable("extern-html-root-url", |o| {
             o.optmulti("", "extern-html-root-url", "base URL to use for dependencies", "NAME=URL")
         }),
+        unstable("extern-html-root-urls-file", |o| {
+            o.optmulti(
+                "",
+                "extern-html-root-urls-file",
+                "JSON map of crate names to the base URL to use for their documentation",
+                "PATH",
+            )
+        }),
         stable("plugin-path", |o| o.optmulti("", "plugin-path", "removed", "DIR")),
         stable("C", |o| {
             o.optmulti("C", "codegen", "pass a codegen option to rustc", "OPT[=VALUE]")
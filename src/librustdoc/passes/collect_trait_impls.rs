@@ -62,6 +62,10 @@ pub fn collect_trait_impls(krate: Crate, cx: &DocContext<'_>) -> Crate {
         lang_items.slice_u8_alloc_impl(),
         lang_items.const_ptr_impl(),
         lang_items.mut_ptr_impl(),
+        lang_items.error_alloc_impl(),
+        lang_items.error_send_alloc_impl(),
+        lang_items.error_send_sync_alloc_impl(),
+        lang_items.backtrace_impl(),
     ];
 
     for def_id in primitive_impls.iter().filter_map(|&def_id| def_id) {
@@ -562,6 +562,7 @@ impl<'a, 'tcx> RustdocVisitor<'a, 'tcx> {
                 unsafety,
                 polarity,
                 defaultness,
+                constness: _,
                 ref generics,
                 ref of_trait,
                 self_ty,
@@ -91,10 +91,17 @@
 // `Backtrace`, but that's a relatively small price to pay relative to capturing
 // a backtrace or actually symbolizing it.
 
+#[cfg(not(bootstrap))]
+use core::backtrace::BacktraceVTable;
+
+use crate::borrow::Cow;
+use crate::cell::UnsafeCell;
 use crate::env;
+use crate::ffi::c_void;
 use crate::fmt;
+use crate::path::{Path, PathBuf};
 use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
-use crate::sync::Mutex;
+use crate::sync::Once;
 use crate::sys_common::backtrace::{lock, output_filename};
 use crate::vec::Vec;
 use backtrace::BytesOrWideString;
@@ -106,7 +113,18 @@ use backtrace_rs as backtrace;
 /// previous point in time. In some instances the `Backtrace` type may
 /// internally be empty due to configuration. For more information see
 /// `Backtrace::capture`.
+#[cfg(bootstrap)]
 pub struct Backtrace {
+    raw: RawBacktrace,
+}
+
+// The type is defined in `core` so that the `Error` trait can return it, and this module
+// provides its methods.
+#[cfg(not(bootstrap))]
+pub use core::backtrace::Backtrace;
+
+/// The capture that a `Backtrace` owns.
+struct RawBacktrace {
     inner: Inner,
 }
 
@@ -129,26 +147,72 @@ pub enum BacktraceStatus {
 enum Inner {
     Unsupported,
     Disabled,
-    Captured(Mutex<Capture>),
+    Captured(LazilyResolvedCapture),
 }
 
 struct Capture {
     actual_start: usize,
-    resolved: bool,
     frames: Vec<BacktraceFrame>,
 }
 
 fn _assert_send_sync() {
     fn _assert<T: Send + Sync>() {}
     _assert::<Backtrace>();
+    _assert::<RawBacktrace>();
+}
+
+#[cfg(bootstrap)]
+fn wrap(raw: RawBacktrace) -> Backtrace {
+    Backtrace { raw }
+}
+
+#[cfg(bootstrap)]
+fn raw(backtrace: &Backtrace) -> &RawBacktrace {
+    &backtrace.raw
+}
+
+#[cfg(not(bootstrap))]
+static RAW_BACKTRACE_VTABLE: BacktraceVTable =
+    BacktraceVTable { fmt: fmt_raw_backtrace, drop: drop_raw_backtrace };
+
+#[cfg(not(bootstrap))]
+unsafe fn fmt_raw_backtrace(data: *mut (), fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&*(data as *const RawBacktrace), fmt)
 }
 
-struct BacktraceFrame {
+#[cfg(not(bootstrap))]
+unsafe fn drop_raw_backtrace(data: *mut ()) {
+    drop(Box::from_raw(data as *mut RawBacktrace))
+}
+
+#[cfg(not(bootstrap))]
+fn wrap(raw: RawBacktrace) -> Backtrace {
+    let data = Box::into_raw(Box::new(raw)) as *mut ();
+    // `RawBacktrace` is `Send` and `Sync`, and the box is only freed by `drop_raw_backtrace`.
+    unsafe { Backtrace::from_raw_parts(data, &RAW_BACKTRACE_VTABLE) }
+}
+
+#[cfg(not(bootstrap))]
+fn raw(backtrace: &Backtrace) -> &RawBacktrace {
+    // Only `wrap` creates backtraces, so they all own a `RawBacktrace`.
+    unsafe { &*(backtrace.as_raw() as *const RawBacktrace) }
+}
+
+/// A single frame of a backtrace.
+///
+/// The frames of a backtrace are returned by `Backtrace::frames`, with the
+/// symbols that were found for their instruction pointer. A frame has no
+/// symbols if no debug information was found for it, and more than one if
+/// functions were inlined into it.
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceFrame {
     frame: backtrace::Frame,
     symbols: Vec<BacktraceSymbol>,
 }
 
-struct BacktraceSymbol {
+/// A symbol of a frame of a backtrace, as returned by `BacktraceFrame::symbols`.
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -159,6 +223,91 @@ enum BytesOrWide {
     Wide(Vec<u16>),
 }
 
+impl BacktraceFrame {
+    /// Returns the current instruction pointer of this frame.
+    ///
+    /// This is the address of the next instruction to execute in the frame,
+    /// which is usually just after the call of the next frame.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the symbols found for the instruction pointer of this frame,
+    /// the innermost inlined function first.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, if it is known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| backtrace::SymbolName::new(b).to_string())
+    }
+
+    /// Returns the path of the source file that this symbol is defined in, if
+    /// it is known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(|b| b.to_path().into_owned())
+    }
+
+    /// Returns the line in `filename` that the frame was executing, if it is
+    /// known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+}
+
+impl BytesOrWide {
+    fn to_path(&self) -> Cow<'_, Path> {
+        match self {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(bytes) => {
+                use crate::os::unix::prelude::*;
+                Path::new(crate::ffi::OsStr::from_bytes(bytes)).into()
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(bytes) => {
+                Path::new(crate::str::from_utf8(bytes).unwrap_or("<unknown>")).into()
+            }
+            #[cfg(windows)]
+            BytesOrWide::Wide(wide) => {
+                use crate::os::windows::prelude::*;
+                Cow::Owned(crate::ffi::OsString::from_wide(wide).into())
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(_wide) => Path::new("<unknown>").into(),
+        }
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("BacktraceFrame")
+            .field("ip", &self.ip())
+            .field("symbols", &self.symbols)
+            .finish()
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Debug for BacktraceSymbol {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("BacktraceSymbol")
+            .field("name", &self.name())
+            .field("filename", &self.filename.as_ref().map(|b| b.to_path()))
+            .field("lineno", &self.lineno)
+            .finish()
+    }
+}
+
+#[cfg_attr(not(bootstrap), lang = "backtrace_impl")]
 impl Backtrace {
     /// Returns whether backtrace captures are enabled through environment
     /// variables.
@@ -202,7 +351,7 @@ impl Backtrace {
     #[inline(never)] // want to make sure there's a frame here to remove
     pub fn capture() -> Backtrace {
         if !Backtrace::enabled() {
-            return Backtrace { inner: Inner::Disabled };
+            return wrap(RawBacktrace { inner: Inner::Disabled });
         }
         Backtrace::create(Backtrace::capture as usize)
     }
@@ -244,42 +393,77 @@ impl Backtrace {
         let inner = if frames.len() == 0 {
             Inner::Unsupported
         } else {
-            Inner::Captured(Mutex::new(Capture {
+            Inner::Captured(LazilyResolvedCapture::new(Capture {
                 actual_start: actual_start.unwrap_or(0),
                 frames,
-                resolved: false,
             }))
         };
 
-        Backtrace { inner }
+        wrap(RawBacktrace { inner })
     }
 
     /// Returns the status of this backtrace, indicating whether this backtrace
     /// request was unsupported, disabled, or a stack trace was actually
     /// captured.
     pub fn status(&self) -> BacktraceStatus {
-        match self.inner {
+        match raw(self).inner {
             Inner::Unsupported => BacktraceStatus::Unsupported,
             Inner::Disabled => BacktraceStatus::Disabled,
             Inner::Captured(_) => BacktraceStatus::Captured,
         }
     }
+
+    /// Returns all the frames of this backtrace, resolving their symbols if
+    /// that was not done yet.
+    ///
+    /// Unlike the `Display` implementation, this includes the frames of the
+    /// functions that captured the backtrace. This returns an empty slice if no
+    /// backtrace was captured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    ///
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         println!("{:?} {:?}:{:?}", symbol.name(), symbol.filename(), symbol.lineno());
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn frames(&self) -> &[BacktraceFrame] {
+        match &raw(self).inner {
+            Inner::Captured(c) => &c.force().frames,
+            _ => &[],
+        }
+    }
 }
 
+#[cfg(bootstrap)]
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self, fmt)
+        fmt::Debug::fmt(&self.raw, fmt)
     }
 }
 
+#[cfg(bootstrap)]
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut capture = match &self.inner {
+        fmt::Debug::fmt(&self.raw, fmt)
+    }
+}
+
+impl fmt::Debug for RawBacktrace {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("unsupported backtrace"),
             Inner::Disabled => return fmt.write_str("disabled backtrace"),
-            Inner::Captured(c) => c.lock().unwrap(),
+            Inner::Captured(c) => c.force(),
         };
-        capture.resolve();
 
         let full = fmt.alternate();
         let (frames, style) = if full {
@@ -322,14 +506,36 @@ impl fmt::Debug for Backtrace {
     }
 }
 
+/// A capture whose symbols are resolved the first time they are needed.
+struct LazilyResolvedCapture {
+    sync: Once,
+    capture: UnsafeCell<Capture>,
+}
+
+impl LazilyResolvedCapture {
+    fn new(capture: Capture) -> LazilyResolvedCapture {
+        LazilyResolvedCapture { sync: Once::new(), capture: UnsafeCell::new(capture) }
+    }
+
+    fn force(&self) -> &Capture {
+        self.sync.call_once(|| {
+            // The capture is only mutated here, before any reference to it is
+            // handed out, and `Once` makes the other threads wait for the end
+            // of the resolution.
+            unsafe { (*self.capture.get()).resolve() };
+        });
+
+        // The capture is not mutated anymore once resolved.
+        unsafe { &*self.capture.get() }
+    }
+}
+
+// The capture is only mutated while the `Once` is running, which excludes any
+// other access to it.
+unsafe impl Sync for LazilyResolvedCapture where Capture: Sync {}
+
 impl Capture {
     fn resolve(&mut self) {
-        // If we're already resolved, nothing to do!
-        if self.resolved {
-            return;
-        }
-        self.resolved = true;
-
         // Use the global backtrace lock to synchronize this as it's a
         // requirement of the `backtrace` crate, and then actually resolve
         // everything.
@@ -198,6 +198,14 @@ use crate::sys;
 /// ```
 
 #[derive(Clone)]
+#[cfg_attr(
+    not(bootstrap),
+    rustc_on_misuse(on(
+        op = "index",
+        note = "`{Self}` is indexed by a reference to any borrowed form of its key type `{K}`, \
+                such as `&{K}`"
+    ))
+)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct HashMap<K, V, S = RandomState> {
     base: base::HashMap<K, V, S>,
@@ -229,6 +229,22 @@ fn initial_buffer_size(file: &File) -> usize {
     file.metadata().map(|m| m.len() as usize + 1).unwrap_or(0)
 }
 
+/// Returns the length of `file` for `Seek::stream_len`. The length of a regular file is in its
+/// metadata, which avoids moving the seek position; other files, such as block devices, only
+/// report their length through a seek to their end.
+fn stream_len(mut file: &File) -> io::Result<u64> {
+    let metadata = file.metadata()?;
+    if metadata.is_file() {
+        return Ok(metadata.len());
+    }
+    let old_pos = file.seek(SeekFrom::Current(0))?;
+    let len = file.seek(SeekFrom::End(0))?;
+    if old_pos != len {
+        file.seek(SeekFrom::Start(old_pos))?;
+    }
+    Ok(len)
+}
+
 /// Read the entire contents of a file into a bytes vector.
 ///
 /// This is a convenience function for using [`File::open`] and [`read_to_end`]
@@ -683,6 +699,10 @@ impl Seek for File {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.inner.seek(pos)
     }
+
+    fn stream_len(&mut self) -> io::Result<u64> {
+        stream_len(self)
+    }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for &File {
@@ -718,6 +738,10 @@ impl Seek for &File {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.inner.seek(pos)
     }
+
+    fn stream_len(&mut self) -> io::Result<u64> {
+        stream_len(self)
+    }
 }
 
 impl OpenOptions {
@@ -2415,6 +2439,22 @@ mod tests {
         assert_eq!(tell_pos_post_read, message.len() as u64);
     }
 
+    #[test]
+    fn file_test_io_stream_len() {
+        let message = "ten-four";
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_rt_io_file_test_stream_len.txt");
+        {
+            let mut rw_stream = check!(File::create(filename));
+            check!(rw_stream.write(message.as_bytes()));
+        }
+        let mut read_stream = check!(File::open(filename));
+        check!(read_stream.seek(SeekFrom::Start(4)));
+        assert_eq!(check!(read_stream.stream_len()), message.len() as u64);
+        assert_eq!(check!((&read_stream).stream_len()), message.len() as u64);
+        assert_eq!(check!(read_stream.stream_position()), 4);
+    }
+
     #[test]
     fn file_test_io_seek_and_write() {
         let initial_msg = "food-is-yummy";
@@ -2871,6 +2911,27 @@ mod tests {
         assert!(!Path::new("test/unicode-bogus-path-각丁ー再见").exists());
     }
 
+    #[test]
+    fn path_try_exists() {
+        let tmpdir = tmpdir();
+        let file = tmpdir.join("file");
+        assert_eq!(check!(file.try_exists()), false);
+        check!(File::create(&file));
+        assert_eq!(check!(file.try_exists()), true);
+        assert_eq!(check!(tmpdir.path().try_exists()), true);
+        assert_eq!(check!(tmpdir.join("missing").join("file").try_exists()), false);
+
+        // A broken symbolic link is reported as an error, not as a missing path.
+        if got_symlink_permission(&tmpdir) {
+            let link = tmpdir.join("link");
+            check!(symlink_file(&"missing", &link));
+            assert!(!link.exists());
+            assert_eq!(link.try_exists().unwrap_err().kind(), ErrorKind::NotFound);
+            check!(symlink_file(&"file", &tmpdir.join("link-to-file")));
+            assert_eq!(check!(tmpdir.join("link-to-file").try_exists()), true);
+        }
+    }
+
     #[test]
     fn copy_file_does_not_exist() {
         let from = Path::new("test/nonexistent-bogus-path");
@@ -211,31 +211,6 @@ impl<R> BufReader<R> {
     }
 }
 
-impl<R: Seek> BufReader<R> {
-    /// Seeks relative to the current position. If the new position lies within the buffer,
-    /// the buffer will not be flushed, allowing for more efficient seeks.
-    /// This method does not return the location of the underlying reader, so the caller
-    /// must track this information themselves if it is required.
-    #[unstable(feature = "bufreader_seek_relative", issue = "31100")]
-    pub fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
-        let pos = self.pos as u64;
-        if offset < 0 {
-            if let Some(new_pos) = pos.checked_sub((-offset) as u64) {
-                self.pos = new_pos as usize;
-                return Ok(());
-            }
-        } else {
-            if let Some(new_pos) = pos.checked_add(offset as u64) {
-                if new_pos <= self.cap as u64 {
-                    self.pos = new_pos as usize;
-                    return Ok(());
-                }
-            }
-        }
-        self.seek(SeekFrom::Current(offset)).map(drop)
-    }
-}
-
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<R: Read> Read for BufReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
@@ -330,7 +305,7 @@ impl<R: Seek> Seek for BufReader<R> {
     /// `Err`, the underlying reader will be left at the same position it would
     /// have if you called `seek` with `SeekFrom::Current(0)`.
     ///
-    /// [`BufReader::seek_relative`]: struct.BufReader.html#method.seek_relative
+    /// [`BufReader::seek_relative`]: trait.Seek.html#method.seek_relative
     /// [`std::io::Seek`]: trait.Seek.html
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         let result: u64;
@@ -356,6 +331,44 @@ impl<R: Seek> Seek for BufReader<R> {
         self.discard_buffer();
         Ok(result)
     }
+
+    /// Returns the length of the underlying reader, without discarding the internal buffer.
+    fn stream_len(&mut self) -> io::Result<u64> {
+        self.inner.stream_len()
+    }
+
+    /// Returns the current seek position from the start of the stream, without discarding the
+    /// internal buffer.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let remainder = (self.cap - self.pos) as u64;
+        self.inner.stream_position().map(|pos| {
+            pos.checked_sub(remainder).expect(
+                "overflow when subtracting remaining buffer size from inner stream position",
+            )
+        })
+    }
+
+    /// Seeks relative to the current position. If the new position lies within the buffer,
+    /// the buffer will not be flushed, allowing for more efficient seeks.
+    /// This method does not return the location of the underlying reader, so the caller
+    /// must track this information themselves if it is required.
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        let pos = self.pos as u64;
+        if offset < 0 {
+            if let Some(new_pos) = pos.checked_sub((-offset) as u64) {
+                self.pos = new_pos as usize;
+                return Ok(());
+            }
+        } else {
+            if let Some(new_pos) = pos.checked_add(offset as u64) {
+                if new_pos <= self.cap as u64 {
+                    self.pos = new_pos as usize;
+                    return Ok(());
+                }
+            }
+        }
+        self.seek(SeekFrom::Current(offset)).map(drop)
+    }
 }
 
 /// Wraps a writer and buffers its output.
@@ -756,6 +769,11 @@ impl<W: Send + fmt::Debug> error::Error for IntoInnerError<W> {
     fn description(&self) -> &str {
         error::Error::description(self.error())
     }
+
+    #[cfg(not(bootstrap))]
+    fn provide<'a>(&'a self, demand: &mut error::Demand<'a>) {
+        error::Error::provide(self.error(), demand)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1162,6 +1180,20 @@ mod tests {
         assert_eq!(reader.fill_buf().ok(), Some(&[2, 3][..]));
     }
 
+    #[test]
+    fn test_buffered_reader_stream_position() {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(4, io::Cursor::new(inner));
+
+        assert_eq!(reader.fill_buf().ok(), Some(&[5, 6, 7, 0][..]));
+        reader.consume(1);
+        assert_eq!(reader.stream_position().ok(), Some(1));
+        assert_eq!(reader.stream_len().ok(), Some(8));
+        // Neither discards the buffer.
+        assert_eq!(reader.buffer(), &[6, 7, 0][..]);
+        assert_eq!(reader.get_ref().position(), 4);
+    }
+
     #[test]
     fn test_buffered_reader_invalidated_after_read() {
         let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
@@ -217,21 +217,8 @@ where
             SeekFrom::End(n) => (self.inner.as_ref().len() as u64, n),
             SeekFrom::Current(n) => (self.pos, n),
         };
-        let new_pos = if offset >= 0 {
-            base_pos.checked_add(offset as u64)
-        } else {
-            base_pos.checked_sub((offset.wrapping_neg()) as u64)
-        };
-        match new_pos {
-            Some(n) => {
-                self.pos = n;
-                Ok(self.pos)
-            }
-            None => Err(Error::new(
-                ErrorKind::InvalidInput,
-                "invalid seek to a negative or overflowing position",
-            )),
-        }
+        self.pos = offset_position(base_pos, offset)?;
+        Ok(self.pos)
     }
 
     fn stream_len(&mut self) -> io::Result<u64> {
@@ -241,6 +228,23 @@ where
     fn stream_position(&mut self) -> io::Result<u64> {
         Ok(self.pos)
     }
+
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        self.pos = offset_position(self.pos, offset)?;
+        Ok(())
+    }
+}
+
+/// Returns the position `offset` bytes after `base_pos`, for seeking a cursor.
+fn offset_position(base_pos: u64, offset: i64) -> io::Result<u64> {
+    let new_pos = if offset >= 0 {
+        base_pos.checked_add(offset as u64)
+    } else {
+        base_pos.checked_sub((offset.wrapping_neg()) as u64)
+    };
+    new_pos.ok_or_else(|| {
+        Error::new(ErrorKind::InvalidInput, "invalid seek to a negative or overflowing position")
+    })
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -888,6 +892,22 @@ mod tests {
         assert!(r.seek(SeekFrom::End(-2)).is_err());
     }
 
+    #[test]
+    fn seek_relative_and_stream_len() {
+        let buf = [0; 8];
+        let mut r = Cursor::new(&buf[..]);
+        assert!(r.seek_relative(5).is_ok());
+        assert_eq!(r.position(), 5);
+        assert!(r.seek_relative(-2).is_ok());
+        assert_eq!(r.position(), 3);
+        assert!(r.seek_relative(-4).is_err());
+        assert_eq!(r.position(), 3);
+        assert!(r.seek_relative(10).is_ok());
+        assert_eq!(r.position(), 13);
+        assert_eq!(r.stream_len().unwrap(), 8);
+        assert_eq!(r.stream_position().unwrap(), 13);
+    }
+
     #[test]
     fn test_seekable_mem_writer() {
         let mut writer = Cursor::new(Vec::<u8>::new());
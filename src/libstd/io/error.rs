@@ -251,6 +251,36 @@ impl Error {
         Self::_new(kind, error.into())
     }
 
+    /// Creates a new I/O error from an arbitrary error payload.
+    ///
+    /// This function is used to generically create I/O errors which do not
+    /// originate from the OS itself. It is a shortcut for [`Error::new`]
+    /// with [`ErrorKind::Other`].
+    ///
+    /// [`Error::new`]: #method.new
+    /// [`ErrorKind::Other`]: enum.ErrorKind.html#variant.Other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_error_other)]
+    ///
+    /// use std::io::Error;
+    ///
+    /// // errors can be created from strings
+    /// let custom_error = Error::other("oh no!");
+    ///
+    /// // errors can also be created from other errors
+    /// let custom_error2 = Error::other(custom_error);
+    /// ```
+    #[unstable(feature = "io_error_other", issue = "none")]
+    pub fn other<E>(error: E) -> Error
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self::_new(ErrorKind::Other, error.into())
+    }
+
     fn _new(kind: ErrorKind, error: Box<dyn error::Error + Send + Sync>) -> Error {
         Error { repr: Repr::Custom(Box::new(Custom { kind, error })) }
     }
@@ -475,6 +505,60 @@ impl Error {
         }
     }
 
+    /// Attempts to downcast the inner error to `E`.
+    ///
+    /// If this `Error` was constructed via `new` or `other` with an error of
+    /// type `E`, then this function returns that error. Otherwise, the
+    /// `Error` is returned unchanged, so that it can still be handled or
+    /// propagated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_error_other)]
+    /// #![feature(io_error_downcast)]
+    ///
+    /// use std::fmt;
+    /// use std::io;
+    ///
+    /// #[derive(Debug)]
+    /// enum LibError {
+    ///     BadInput,
+    /// }
+    ///
+    /// impl fmt::Display for LibError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("bad input")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for LibError {}
+    ///
+    /// let err = io::Error::other(LibError::BadInput);
+    /// match err.downcast::<LibError>() {
+    ///     Ok(LibError::BadInput) => println!("the library rejected its input"),
+    ///     Err(err) => println!("I/O error: {}", err),
+    /// }
+    ///
+    /// // Errors that don't contain a `LibError` are returned as is.
+    /// let err = io::Error::from(io::ErrorKind::NotFound);
+    /// let err = err.downcast::<LibError>().unwrap_err();
+    /// assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    /// ```
+    #[unstable(feature = "io_error_downcast", issue = "none")]
+    pub fn downcast<E>(self) -> result::Result<E, Error>
+    where
+        E: error::Error + Send + Sync + 'static,
+    {
+        match self.repr {
+            Repr::Custom(c) if c.error.is::<E>() => match c.error.downcast::<E>() {
+                Ok(error) => Ok(*error),
+                Err(_) => unreachable!(),
+            },
+            repr => Err(Error { repr }),
+        }
+    }
+
     /// Returns the corresponding `ErrorKind` for this error.
     ///
     /// # Examples
@@ -558,6 +642,14 @@ impl error::Error for Error {
             Repr::Custom(ref c) => c.error.source(),
         }
     }
+
+    #[cfg(not(bootstrap))]
+    fn provide<'a>(&'a self, demand: &mut error::Demand<'a>) {
+        demand.provide_value(self.kind());
+        if let Repr::Custom(ref c) = self.repr {
+            c.error.provide(demand);
+        }
+    }
 }
 
 fn _assert_error_is_sync_send() {
@@ -620,4 +712,67 @@ mod test {
         let extracted = err.into_inner().unwrap();
         extracted.downcast::<TestError>().unwrap();
     }
+
+    #[test]
+    fn test_other_and_downcast() {
+        #[derive(Debug, PartialEq)]
+        struct TestError(u32);
+
+        impl fmt::Display for TestError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "test error {}", self.0)
+            }
+        }
+
+        impl error::Error for TestError {}
+
+        let err = Error::other(TestError(1));
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(err.to_string(), "test error 1");
+        assert_eq!(err.downcast::<TestError>().unwrap(), TestError(1));
+
+        // Errors that don't contain the type are given back.
+        let err = Error::other("oh no!").downcast::<TestError>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(err.to_string(), "oh no!");
+
+        let err = Error::new(ErrorKind::Interrupted, TestError(2));
+        let err = err.downcast::<fmt::Error>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert_eq!(err.downcast::<TestError>().unwrap(), TestError(2));
+
+        let err = Error::from(ErrorKind::NotFound).downcast::<TestError>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        let err = Error::from_raw_os_error(6).downcast::<TestError>().unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(6));
+    }
+
+    #[test]
+    fn test_provide() {
+        #[derive(Debug)]
+        struct StatusError(u16);
+
+        impl fmt::Display for StatusError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "status {}", self.0)
+            }
+        }
+
+        impl error::Error for StatusError {
+            fn provide<'a>(&'a self, demand: &mut error::Demand<'a>) {
+                demand.provide_value(self.0).provide_value(ErrorKind::Interrupted);
+            }
+        }
+
+        let err = Error::new(ErrorKind::Other, StatusError(404));
+        let err: &(dyn error::Error + 'static) = &err;
+        assert_eq!(err.request_value::<ErrorKind>(), Some(ErrorKind::Other));
+        assert_eq!(err.request_value::<u16>(), Some(404));
+        assert_eq!(err.request_value::<u32>(), None);
+
+        let err = Error::from(ErrorKind::NotFound);
+        let err: &(dyn error::Error + 'static) = &err;
+        assert_eq!(err.request_value::<ErrorKind>(), Some(ErrorKind::NotFound));
+        assert_eq!(err.request_value::<u16>(), None);
+    }
 }
@@ -1589,6 +1589,39 @@ pub trait Seek {
     fn stream_position(&mut self) -> Result<u64> {
         self.seek(SeekFrom::Current(0))
     }
+
+    /// Seeks relative to the current position.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Current(offset))`, but doesn't return the new
+    /// position, which lets implementations avoid the work of computing it. In particular,
+    /// [`BufReader`] keeps its internal buffer when the new position lies within it, where
+    /// `seek` always discards it.
+    ///
+    /// [`BufReader`]: struct.BufReader.html
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(bufreader_seek_relative)]
+    /// use std::{
+    ///     io::{self, BufRead, BufReader, Seek},
+    ///     fs::File,
+    /// };
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut f = BufReader::new(File::open("foo.txt")?);
+    ///
+    ///     // Skip the four-byte header without discarding what was already read.
+    ///     f.fill_buf()?;
+    ///     f.seek_relative(4)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "bufreader_seek_relative", issue = "31100")]
+    fn seek_relative(&mut self, offset: i64) -> Result<()> {
+        self.seek(SeekFrom::Current(offset))?;
+        Ok(())
+    }
 }
 
 /// Enumeration of possible methods to seek within an I/O object.
@@ -238,6 +238,7 @@
 #![feature(array_error_internals)]
 #![feature(asm)]
 #![feature(associated_type_bounds)]
+#![cfg_attr(not(bootstrap), feature(backtrace, backtrace_internals))]
 #![feature(box_syntax)]
 #![feature(c_variadic)]
 #![feature(cfg_target_has_atomic)]
@@ -257,6 +258,8 @@
 #![feature(doc_masked)]
 #![feature(doc_spotlight)]
 #![feature(dropck_eyepatch)]
+#![cfg_attr(not(bootstrap), feature(error_generic_member_access))]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
 #![feature(duration_constants)]
 #![feature(exact_size_is_empty)]
 #![feature(exhaustive_patterns)]
@@ -284,6 +287,7 @@
 #![feature(optin_builtin_traits)]
 #![feature(panic_info_message)]
 #![feature(panic_internals)]
+#![feature(panic_payload_as_str)]
 #![feature(panic_unwind)]
 #![feature(prelude_import)]
 #![feature(ptr_internals)]
@@ -509,6 +513,13 @@ mod std_detect;
 #[cfg(not(test))]
 pub use std_detect::detect;
 
+// Run-time detection of the RISC-V target features, which `std_detect` does not support yet.
+// FIXME(#48556): temporary, remove this module once `std_detect` detects the RISC-V features.
+#[macro_use]
+#[doc(hidden)]
+#[unstable(feature = "stdsimd", issue = "48556")]
+pub mod riscv_detect;
+
 // Re-export macros defined in libcore.
 #[stable(feature = "rust1", since = "1.0.0")]
 #[allow(deprecated, deprecated_in_future)]
@@ -24,6 +24,9 @@ pub use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
 )]
 pub use core::num::IntErrorKind;
 
+#[unstable(feature = "num_decimal", issue = "none")]
+pub use core::num::{Decimal, DecimalBuffer};
+
 #[cfg(test)]
 use crate::fmt;
 #[cfg(test)]
@@ -70,3 +70,4 @@ pub mod vxworks;
 pub mod wasi;
 
 pub mod raw;
+pub mod unload;
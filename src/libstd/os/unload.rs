@@ -0,0 +1,106 @@
+//! Checks of whether a library built by Rust can be unloaded.
+//!
+//! Unloading a library while the process still uses its code makes the process crash later on.
+//! This happens in particular after the library registered destructors for its thread-local
+//! variables, which run when the threads exit, whether the library is still loaded or not.
+//!
+//! The cdylibs built with `-Z unload-info` export the symbol [`UNLOAD_INFO_SYMBOL`]. Programs
+//! loading them, such as the hosts of plugins, can look it up with `dlsym` or `GetProcAddress`
+//! and read it with [`UnloadInfo::from_symbol`] to refuse to unload a library that is not safe
+//! to unload.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! #![feature(dylib_unload_info)]
+//! use std::os::unload::UnloadInfo;
+//!
+//! # let symbol: *const u8 = std::ptr::null();
+//! // `symbol` is the address of `UNLOAD_INFO_SYMBOL` in the library.
+//! let safe_to_unload = unsafe { UnloadInfo::from_symbol(symbol) }
+//!     .map_or(false, |info| info.is_safe_to_unload());
+//! ```
+//!
+//! [`UNLOAD_INFO_SYMBOL`]: constant.UNLOAD_INFO_SYMBOL.html
+//! [`UnloadInfo::from_symbol`]: struct.UnloadInfo.html#method.from_symbol
+
+#![unstable(feature = "dylib_unload_info", issue = "none")]
+
+use crate::fmt;
+use crate::sync::atomic::{AtomicUsize, Ordering};
+
+/// The name of the symbol of the unload information in the libraries.
+pub const UNLOAD_INFO_SYMBOL: &str = "__rust_unload_info";
+
+/// The version of the layout of `UnloadInfo` that this module can read.
+const UNLOAD_INFO_VERSION: u32 = 1;
+
+/// Set in `flags` if a crate linked into the library has statics with destructors.
+const STATIC_DTORS: u32 = 1 << 0;
+
+/// The unload information of a library, which tells whether it can be unloaded.
+///
+/// The compiler writes it when building the library, with the exception of the number of
+/// thread-local destructors, which is kept by the copy of the standard library in the library.
+#[repr(C)]
+pub struct UnloadInfo {
+    version: u32,
+    flags: u32,
+    tls_dtors: *const AtomicUsize,
+}
+
+impl UnloadInfo {
+    /// Reads the unload information of a library from the address of its
+    /// [`UNLOAD_INFO_SYMBOL`].
+    ///
+    /// Returns `None` if `symbol` is null, which is what looking up the symbol in a library built
+    /// without unload information gives, or if the information comes from a version of Rust
+    /// whose layout of it this version does not know.
+    ///
+    /// # Safety
+    ///
+    /// `symbol` must be null or the address of the `UNLOAD_INFO_SYMBOL` of a library, which must
+    /// stay loaded for `'a`.
+    ///
+    /// [`UNLOAD_INFO_SYMBOL`]: constant.UNLOAD_INFO_SYMBOL.html
+    pub unsafe fn from_symbol<'a>(symbol: *const u8) -> Option<&'a UnloadInfo> {
+        let info = (symbol as *const UnloadInfo).as_ref()?;
+        if info.version == UNLOAD_INFO_VERSION { Some(info) } else { None }
+    }
+
+    /// Returns the number of destructors of thread-local variables that the library registered.
+    ///
+    /// This is always zero for libraries that do not contain the standard library.
+    pub fn registered_tls_dtors(&self) -> usize {
+        match unsafe { self.tls_dtors.as_ref() } {
+            Some(count) => count.load(Ordering::SeqCst),
+            None => 0,
+        }
+    }
+
+    /// Whether a crate linked into the library has statics whose types have destructors.
+    ///
+    /// These destructors never run, so unloading the library leaks what the statics own.
+    pub fn has_static_dtors(&self) -> bool {
+        self.flags & STATIC_DTORS != 0
+    }
+
+    /// Whether the library can be unloaded without leaking or crashing the process later on.
+    ///
+    /// This only remains true until the library registers a thread-local destructor, i.e. until
+    /// it next runs code that uses a thread-local variable whose type has a destructor. The
+    /// program must make sure that no thread runs the code of the library when it checks this
+    /// and unloads the library.
+    pub fn is_safe_to_unload(&self) -> bool {
+        !self.has_static_dtors() && self.registered_tls_dtors() == 0
+    }
+}
+
+impl fmt::Debug for UnloadInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnloadInfo")
+            .field("registered_tls_dtors", &self.registered_tls_dtors())
+            .field("has_static_dtors", &self.has_static_dtors())
+            .finish()
+    }
+}
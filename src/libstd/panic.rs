@@ -20,6 +20,9 @@ use crate::thread::Result;
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub use crate::panicking::{set_hook, take_hook};
 
+#[unstable(feature = "panic_update_hook", issue = "none")]
+pub use crate::panicking::update_hook;
+
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub use core::panic::{Location, PanicInfo};
 
@@ -425,3 +428,34 @@ pub fn catch_unwind<F: FnOnce() -> R + UnwindSafe, R>(f: F) -> Result<R> {
 pub fn resume_unwind(payload: Box<dyn Any + Send>) -> ! {
     panicking::rust_panic_without_hook(payload)
 }
+
+/// Invokes a closure, aborting the process if it unwinds.
+///
+/// The panic hook still runs when the closure panics, so the message of the panic is reported
+/// as usual before the process aborts. No unwinding happens out of `abort_unwind`, which makes
+/// it suitable for the code that must not unwind, such as the functions called from C or the
+/// destructors of guards whose cleanup must complete.
+///
+/// Unlike with [`catch_unwind`], the closure is called directly, and there is no cost when it
+/// does not panic.
+///
+/// [`catch_unwind`]: ./fn.catch_unwind.html
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(abort_unwind)]
+/// use std::panic;
+///
+/// extern "C" fn callback() {
+///     panic::abort_unwind(|| {
+///         // The process aborts before the panic could unwind into the C caller.
+///         panic!("oh no!");
+///     })
+/// }
+/// ```
+#[unstable(feature = "abort_unwind", issue = "none")]
+#[unwind(aborts)]
+pub fn abort_unwind<F: FnOnce() -> R, R>(f: F) -> R {
+    f()
+}
@@ -53,6 +53,10 @@ extern "C" {
     /// on liballoc, and thus cannot use `Box`.
     #[unwind(allowed)]
     fn __rust_start_panic(payload: usize) -> u32;
+
+    /// Whether `__rust_start_panic` unwinds, which it does not with the runtime of
+    /// `-C panic=abort`.
+    fn __rust_panic_can_unwind() -> bool;
 }
 
 /// This function is called by the panic runtime if FFI code catches a Rust
@@ -173,6 +177,64 @@ pub fn take_hook() -> Box<dyn Fn(&PanicInfo<'_>) + 'static + Sync + Send> {
     }
 }
 
+/// Atomically changes the panic hook, to a hook built from the current one.
+///
+/// *See also the functions [`set_hook`] and [`take_hook`].*
+///
+/// [`set_hook`]: ./fn.set_hook.html
+/// [`take_hook`]: ./fn.take_hook.html
+///
+/// The new hook is `hook_fn`, which is called with the previous hook and the `PanicInfo` of
+/// the panic. The previous hook is the default hook if no custom hook was registered.
+///
+/// Unlike calling `take_hook` and then `set_hook`, there is no window in which the panics of
+/// other threads run with the default hook, or in which another thread can replace the hook
+/// and have its hook dropped. This lets libraries layer their behavior on top of the hook of
+/// the application.
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+///
+/// # Examples
+///
+/// The following will print the custom message, and then the normal output of panic.
+///
+/// ```should_panic
+/// #![feature(panic_update_hook)]
+/// use std::panic;
+///
+/// panic::update_hook(|prev, info| {
+///     println!("Print custom message and execute panic handler as usual");
+///     prev(info);
+/// });
+///
+/// panic!("Custom and then normal");
+/// ```
+#[unstable(feature = "panic_update_hook", issue = "none")]
+pub fn update_hook<F>(hook_fn: F)
+where
+    F: Fn(&(dyn Fn(&PanicInfo<'_>) + Send + Sync + 'static), &PanicInfo<'_>)
+        + Sync
+        + Send
+        + 'static,
+{
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    unsafe {
+        HOOK_LOCK.write();
+        let prev: Box<dyn Fn(&PanicInfo<'_>) + 'static + Sync + Send> = match HOOK {
+            Hook::Default => Box::new(default_hook),
+            Hook::Custom(ptr) => Box::from_raw(ptr),
+        };
+        let hook = Box::new(move |info: &PanicInfo<'_>| hook_fn(&*prev, info));
+        HOOK = Hook::Custom(Box::into_raw(hook));
+        HOOK_LOCK.write_unlock();
+    }
+}
+
 fn default_hook(info: &PanicInfo<'_>) {
     // If this is a double panic, make sure that we print a backtrace
     // for this panic. Otherwise only print it if logging is enabled.
@@ -185,13 +247,7 @@ fn default_hook(info: &PanicInfo<'_>) {
     // The current implementation always returns `Some`.
     let location = info.location().unwrap();
 
-    let msg = match info.payload().downcast_ref::<&'static str>() {
-        Some(s) => *s,
-        None => match info.payload().downcast_ref::<String>() {
-            Some(s) => &s[..],
-            None => "Box<Any>",
-        },
-    };
+    let msg = info.payload_as_str().unwrap_or("Box<Any>");
     let thread = thread_info::current_thread();
     let name = thread.as_ref().and_then(|t| t.name()).unwrap_or("<unnamed>");
 
@@ -330,7 +386,8 @@ pub fn begin_panic_fmt(msg: &fmt::Arguments<'_>) -> ! {
         unsafe { intrinsics::abort() }
     }
 
-    let info = PanicInfo::internal_constructor(Some(msg), Location::caller());
+    let location = Location::caller();
+    let info = PanicInfo::internal_constructor(Some(msg), location, location.can_unwind());
     begin_panic_handler(&info)
 }
 
@@ -396,6 +453,15 @@ pub fn begin_panic<M: Any + Send>(msg: M, #[cfg(bootstrap)] _: &(&str, u32, u32)
         unsafe { intrinsics::abort() }
     }
 
+    // A string payload is also the message of the panic, so that panic hooks always find the
+    // message of the panics given a string in `PanicInfo::message`.
+    if let Some(&s) = (&msg as &dyn Any).downcast_ref::<&'static str>() {
+        rust_panic_with_hook(
+            &mut PanicPayload::new(msg),
+            Some(&format_args!("{}", s)),
+            Location::caller(),
+        );
+    }
     rust_panic_with_hook(&mut PanicPayload::new(msg), None, Location::caller());
 
     struct PanicPayload<A> {
@@ -457,7 +523,10 @@ fn rust_panic_with_hook(
     }
 
     unsafe {
-        let mut info = PanicInfo::internal_constructor(message, location);
+        // A panic while already panicking aborts the process after the hook runs, and so does a
+        // panic that the call site or the panic runtime cannot unwind from.
+        let can_unwind = panics <= 1 && location.can_unwind() && __rust_panic_can_unwind();
+        let mut info = PanicInfo::internal_constructor(message, location, can_unwind);
         HOOK_LOCK.read();
         match HOOK {
             // Some platforms (like wasm) know that printing to stderr won't ever actually
@@ -468,11 +537,11 @@ fn rust_panic_with_hook(
             // formatting.)
             Hook::Default if panic_output().is_none() => {}
             Hook::Default => {
-                info.set_payload(payload.get());
+                set_payload(&mut info, payload.get());
                 default_hook(&info);
             }
             Hook::Custom(ptr) => {
-                info.set_payload(payload.get());
+                set_payload(&mut info, payload.get());
                 (*ptr)(&info);
             }
         };
@@ -494,6 +563,15 @@ fn rust_panic_with_hook(
     rust_panic(payload)
 }
 
+/// Sets the payload of the `info` passed to the panic hook, along with its string if it is a
+/// `String`, which libcore cannot downcast to itself.
+fn set_payload<'a>(info: &mut PanicInfo<'a>, payload: &'a (dyn Any + Send)) {
+    info.set_payload(payload);
+    if let Some(s) = payload.downcast_ref::<String>() {
+        info.set_payload_str(s);
+    }
+}
+
 /// This is the entry point for `resume_unwind`.
 /// It just forwards the payload to the panic runtime.
 pub fn rust_panic_without_hook(payload: Box<dyn Any + Send>) -> ! {
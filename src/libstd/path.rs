@@ -2469,6 +2469,47 @@ impl Path {
         fs::metadata(self).is_ok()
     }
 
+    /// Returns `Ok(true)` if the path points at an existing entity, and
+    /// `Ok(false)` if nothing exists at the path.
+    ///
+    /// This function will traverse symbolic links to query information about the
+    /// destination file. As opposed to [`exists`], it doesn't silently ignore the
+    /// errors unrelated to the path not existing: it returns `Err(_)` in case of a
+    /// permission error on one of the parent directories, or of a broken symbolic
+    /// link, for which the error is the [`NotFound`] error of its destination.
+    ///
+    /// Note that this doesn't prevent time-of-check to time-of-use bugs: the
+    /// entity may have been created or removed by the time this function returns.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(path_try_exists)]
+    ///
+    /// use std::path::Path;
+    /// assert!(!Path::new("does_not_exist.txt").try_exists().expect("can't check existence"));
+    /// assert!(Path::new("/root/secret_file.txt").try_exists().is_err());
+    /// ```
+    ///
+    /// [`exists`]: #method.exists
+    /// [`NotFound`]: ../io/enum.ErrorKind.html#variant.NotFound
+    #[unstable(feature = "path_try_exists", issue = "none")]
+    pub fn try_exists(&self) -> io::Result<bool> {
+        match fs::metadata(self) {
+            Ok(_) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                // The path may be a symbolic link whose destination is missing, in which case
+                // the error is reported instead.
+                match fs::symlink_metadata(self) {
+                    Ok(_) => Err(error),
+                    Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     /// Returns `true` if the path exists on disk and is pointing at a regular file.
     ///
     /// This function will traverse symbolic links to query information about the
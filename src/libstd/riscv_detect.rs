@@ -0,0 +1,223 @@
+//! Run-time detection of the RISC-V target features, see `is_riscv_feature_detected!`.
+//!
+//! `std_detect` does not know about RISC-V yet, so the detection lives here until it does. The
+//! features are read from the ISA string of `/proc/cpuinfo` on Linux, like `rv64imafdc_zba`.
+//! Where that file does not exist, only the features enabled at compile time are detected.
+//!
+//! FIXME(#48556): this module is temporary. Move the detection into `std_detect` once it
+//! supports RISC-V, re-export `is_riscv_feature_detected!` from there and remove this module.
+
+// The detection is only used on RISC-V targets.
+#![allow(dead_code)]
+
+use crate::sync::atomic::{AtomicU32, Ordering};
+
+/// Checks at run time whether a RISC-V target feature is enabled.
+///
+/// The feature names are those of `#[target_feature]`. A feature that `cfg(target_feature)`
+/// reports as enabled at compile time is always detected.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(stdsimd)]
+///
+/// #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+/// {
+///     if is_riscv_feature_detected!("zbb") {
+///         println!("the `zbb` extension is available");
+///     }
+/// }
+/// ```
+#[macro_export]
+#[unstable(feature = "stdsimd", issue = "48556")]
+#[allow_internal_unstable(stdsimd)]
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+macro_rules! is_riscv_feature_detected {
+    ("m") => {
+        cfg!(target_feature = "m") || $crate::riscv_detect::check_for("m")
+    };
+    ("a") => {
+        cfg!(target_feature = "a") || $crate::riscv_detect::check_for("a")
+    };
+    ("f") => {
+        cfg!(target_feature = "f") || $crate::riscv_detect::check_for("f")
+    };
+    ("d") => {
+        cfg!(target_feature = "d") || $crate::riscv_detect::check_for("d")
+    };
+    ("c") => {
+        cfg!(target_feature = "c") || $crate::riscv_detect::check_for("c")
+    };
+    ("v") => {
+        cfg!(target_feature = "v") || $crate::riscv_detect::check_for("v")
+    };
+    ("zba") => {
+        cfg!(target_feature = "zba") || $crate::riscv_detect::check_for("zba")
+    };
+    ("zbb") => {
+        cfg!(target_feature = "zbb") || $crate::riscv_detect::check_for("zbb")
+    };
+    ("zbc") => {
+        cfg!(target_feature = "zbc") || $crate::riscv_detect::check_for("zbc")
+    };
+    ("zbs") => {
+        cfg!(target_feature = "zbs") || $crate::riscv_detect::check_for("zbs")
+    };
+    ("zbkb") => {
+        cfg!(target_feature = "zbkb") || $crate::riscv_detect::check_for("zbkb")
+    };
+    ("zbkc") => {
+        cfg!(target_feature = "zbkc") || $crate::riscv_detect::check_for("zbkc")
+    };
+    ("zbkx") => {
+        cfg!(target_feature = "zbkx") || $crate::riscv_detect::check_for("zbkx")
+    };
+    ("zkn") => {
+        cfg!(target_feature = "zkn") || $crate::riscv_detect::check_for("zkn")
+    };
+    ("zknd") => {
+        cfg!(target_feature = "zknd") || $crate::riscv_detect::check_for("zknd")
+    };
+    ("zkne") => {
+        cfg!(target_feature = "zkne") || $crate::riscv_detect::check_for("zkne")
+    };
+    ("zknh") => {
+        cfg!(target_feature = "zknh") || $crate::riscv_detect::check_for("zknh")
+    };
+    ("zks") => {
+        cfg!(target_feature = "zks") || $crate::riscv_detect::check_for("zks")
+    };
+    ("zksed") => {
+        cfg!(target_feature = "zksed") || $crate::riscv_detect::check_for("zksed")
+    };
+    ("zksh") => {
+        cfg!(target_feature = "zksh") || $crate::riscv_detect::check_for("zksh")
+    };
+    ("zkr") => {
+        cfg!(target_feature = "zkr") || $crate::riscv_detect::check_for("zkr")
+    };
+    ("zkt") => {
+        cfg!(target_feature = "zkt") || $crate::riscv_detect::check_for("zkt")
+    };
+    ($t:tt,) => {
+        is_riscv_feature_detected!($t)
+    };
+    ($t:tt) => {
+        compile_error!(concat!("unknown RISC-V target feature: ", $t))
+    };
+}
+
+/// Checks at run time whether a RISC-V target feature is enabled.
+///
+/// This macro can only be used on RISC-V targets.
+#[macro_export]
+#[unstable(feature = "stdsimd", issue = "48556")]
+#[cfg(not(any(target_arch = "riscv32", target_arch = "riscv64")))]
+macro_rules! is_riscv_feature_detected {
+    ($t:tt $(,)?) => {
+        compile_error!("`is_riscv_feature_detected` can only be used on RISC-V targets")
+    };
+}
+
+/// The features that can be detected, the bits of `CACHE` are in this order.
+const FEATURES: [&str; 22] = [
+    "m", "a", "f", "d", "c", "v", "zba", "zbb", "zbc", "zbs", "zbkb", "zbkc", "zbkx", "zkn",
+    "zknd", "zkne", "zknh", "zks", "zksed", "zksh", "zkr", "zkt",
+];
+
+/// The features implied by a feature, as in `rustc_target::target_features`. A feature comes
+/// before the features it is implied by, so that one pass applies all implications.
+const IMPLIED: [(&str, &[&str]); 4] = [
+    ("v", &["d"]),
+    ("d", &["f"]),
+    ("zkn", &["zbkb", "zbkc", "zbkx", "zkne", "zknd", "zknh"]),
+    ("zks", &["zbkb", "zbkc", "zbkx", "zksed", "zksh"]),
+];
+
+/// Set once the features were detected.
+const INITIALIZED: u32 = 1 << 31;
+
+/// The detected features, or 0 if they were not detected yet.
+static CACHE: AtomicU32 = AtomicU32::new(0);
+
+/// Checks whether `feature`, one of `FEATURES`, was detected.
+#[doc(hidden)]
+pub fn check_for(feature: &str) -> bool {
+    let mut cache = CACHE.load(Ordering::Relaxed);
+    if cache == 0 {
+        cache = detect() | INITIALIZED;
+        CACHE.store(cache, Ordering::Relaxed);
+    }
+    cache & bit(feature) != 0
+}
+
+/// The bit of `feature` in `CACHE`, or 0 if it is not one of `FEATURES`.
+fn bit(feature: &str) -> u32 {
+    FEATURES.iter().position(|&known| known == feature).map_or(0, |i| 1 << i)
+}
+
+#[cfg(feature = "std_detect_file_io")]
+fn detect() -> u32 {
+    let cpuinfo = match crate::fs::read_to_string("/proc/cpuinfo") {
+        Ok(cpuinfo) => cpuinfo,
+        Err(_) => return 0,
+    };
+    let isa = cpuinfo.lines().find_map(|line| {
+        let mut parts = line.splitn(2, ':');
+        if parts.next()?.trim() == "isa" { parts.next() } else { None }
+    });
+    isa.map_or(0, |isa| parse_isa(isa.trim()))
+}
+
+#[cfg(not(feature = "std_detect_file_io"))]
+fn detect() -> u32 {
+    0
+}
+
+/// Returns the features of the ISA string `isa`, like `rv64imafdc_zba_zbb`: single-letter
+/// extensions, followed by multi-letter extensions separated by underscores.
+fn parse_isa(isa: &str) -> u32 {
+    if !isa.starts_with("rv32") && !isa.starts_with("rv64") {
+        return 0;
+    }
+    let mut extensions = isa[4..].split('_');
+    let mut features = 0;
+    for letter in extensions.next().unwrap_or("").chars() {
+        // `g` stands for `imafd`.
+        let letters = if letter == 'g' { "imafd".chars().collect() } else { vec![letter] };
+        for letter in letters {
+            features |= bit(letter.encode_utf8(&mut [0; 4]));
+        }
+    }
+    for extension in extensions {
+        features |= bit(extension);
+    }
+    for &(feature, implied) in IMPLIED.iter() {
+        if features & bit(feature) != 0 {
+            for &implied in implied {
+                features |= bit(implied);
+            }
+        }
+    }
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bit, parse_isa};
+
+    #[test]
+    fn isa_strings() {
+        let f = |features: &[&str]| features.iter().fold(0, |all, &feature| all | bit(feature));
+        assert_eq!(parse_isa("rv64imafdc"), f(&["m", "a", "f", "d", "c"]));
+        assert_eq!(parse_isa("rv64gc"), f(&["m", "a", "f", "d", "c"]));
+        assert_eq!(parse_isa("rv32imv_zba_zbb"), f(&["m", "v", "d", "f", "zba", "zbb"]));
+        assert_eq!(
+            parse_isa("rv64i_zkn_zkt"),
+            f(&["zkn", "zbkb", "zbkc", "zbkx", "zkne", "zknd", "zknh", "zkt"]),
+        );
+        assert_eq!(parse_isa("rv64i_xfoo"), 0);
+        assert_eq!(parse_isa("x86_64"), 0);
+    }
+}
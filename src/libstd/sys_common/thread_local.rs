@@ -53,6 +53,20 @@ use crate::sync::atomic::{self, AtomicUsize, Ordering};
 use crate::sys::thread_local as imp;
 use crate::sys_common::mutex::Mutex;
 
+/// The number of thread-local destructors that this copy of the standard library registered.
+///
+/// The library containing the copy must not be unloaded when it is not zero, as the destructors
+/// still run when the threads exit. Programs loading the library read it through the unload
+/// information of `-Z unload-info`, see `std::os::unload`.
+#[rustc_std_internal_symbol]
+#[allow(non_upper_case_globals)]
+pub static __rust_tls_dtors_registered: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts a thread-local destructor in `__rust_tls_dtors_registered`.
+pub fn count_tls_dtor() {
+    __rust_tls_dtors_registered.fetch_add(1, Ordering::Relaxed);
+}
+
 /// A type for TLS keys that are statically allocated.
 ///
 /// This type is entirely `unsafe` to use as it does not protect against
@@ -146,6 +160,10 @@ impl StaticKey {
     }
 
     unsafe fn lazy_init(&self) -> usize {
+        if self.dtor.is_some() {
+            count_tls_dtor();
+        }
+
         // Currently the Windows implementation of TLS is pretty hairy, and
         // it greatly simplifies creation if we just synchronize everything.
         //
@@ -209,6 +227,9 @@ impl Key {
     /// scope.
     #[inline]
     pub fn new(dtor: Option<unsafe extern "C" fn(*mut u8)>) -> Key {
+        if dtor.is_some() {
+            count_tls_dtor();
+        }
         Key { key: unsafe { imp::create(dtor) } }
     }
 
@@ -363,6 +363,7 @@ pub mod fast {
     use crate::fmt;
     use crate::mem;
     use crate::sys::fast_thread_local::register_dtor;
+    use crate::sys_common::thread_local::count_tls_dtor;
 
     #[derive(Copy, Clone)]
     enum DtorState {
@@ -434,6 +435,7 @@ pub mod fast {
             match self.dtor_state.get() {
                 DtorState::Unregistered => {
                     // dtor registration happens before initialization.
+                    count_tls_dtor();
                     register_dtor(self as *const _ as *mut u8, destroy_value::<T>);
                     self.dtor_state.set(DtorState::Registered);
                     true
@@ -820,6 +820,9 @@ pub enum ReprAttr {
     ReprSimd,
     ReprTransparent,
     ReprAlign(u32),
+    /// `repr(align(cache_line))`, aligning to the cache line size of the target.
+    ReprAlignCacheLine,
+    ReprScalable(u32),
 }
 
 #[derive(Eq, PartialEq, Debug, RustcEncodable, RustcDecodable, Copy, Clone, HashStable_Generic)]
@@ -845,8 +848,9 @@ impl IntType {
 /// Valid repr contents: any of the primitive integral type names (see
 /// `int_type_of_word`, below) to specify enum discriminant type; `C`, to use
 /// the same discriminant size that the corresponding C enum would or C
-/// structure layout, `packed` to remove padding, and `transparent` to elegate representation
-/// concerns to the only non-ZST field.
+/// structure layout, `packed` to remove padding, `transparent` to elegate representation
+/// concerns to the only non-ZST field, and `scalable` to make a `simd` type a vector whose
+/// length is a multiple of its argument only known at runtime.
 pub fn find_repr_attrs(sess: &ParseSess, attr: &Attribute) -> Vec<ReprAttr> {
     use ReprAttr::*;
 
@@ -913,6 +917,23 @@ pub fn find_repr_attrs(sess: &ParseSess, attr: &Attribute) -> Vec<ReprAttr> {
                             Ok(literal) => acc.push(ReprPacked(literal)),
                             Err(message) => literal_error = Some(message),
                         };
+                    } else if name == sym::scalable {
+                        recognised = true;
+                        match value.kind {
+                            ast::LitKind::Int(literal, ast::LitIntType::Unsuffixed)
+                                if literal.is_power_of_two()
+                                    && literal <= u32::max_value() as u128 =>
+                            {
+                                acc.push(ReprScalable(literal as u32))
+                            }
+                            _ => {
+                                diagnostic.span_err(
+                                    item.span(),
+                                    "invalid `repr(scalable)` attribute: \
+                                     not an unsuffixed power of two",
+                                );
+                            }
+                        }
                     }
                     if let Some(literal_error) = literal_error {
                         struct_span_err!(
@@ -927,6 +948,15 @@ pub fn find_repr_attrs(sess: &ParseSess, attr: &Attribute) -> Vec<ReprAttr> {
                 } else {
                     if let Some(meta_item) = item.meta_item() {
                         if meta_item.check_name(sym::align) {
+                            if let MetaItemKind::List(ref list) = meta_item.kind {
+                                if list.len() == 1
+                                    && list[0].is_word()
+                                    && list[0].check_name(sym::cache_line)
+                                {
+                                    recognised = true;
+                                    acc.push(ReprAlignCacheLine);
+                                }
+                            }
                             if let MetaItemKind::NameValue(ref value) = meta_item.kind {
                                 recognised = true;
                                 let mut err = struct_span_err!(
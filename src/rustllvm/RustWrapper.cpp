@@ -1024,6 +1024,17 @@ extern "C" LLVMTypeRef LLVMRustArrayType(LLVMTypeRef ElementTy,
   return wrap(ArrayType::get(unwrap(ElementTy), ElementCount));
 }
 
+// There is no C API for the scalable vector types yet.
+extern "C" LLVMTypeRef LLVMRustScalableVectorType(LLVMTypeRef ElementTy,
+                                                  unsigned MinElementCount) {
+#if LLVM_VERSION_GE(9, 0)
+  return wrap(VectorType::get(unwrap(ElementTy),
+                              ElementCount(MinElementCount, true)));
+#else
+  report_fatal_error("scalable vectors require LLVM 9 or later");
+#endif
+}
+
 DEFINE_SIMPLE_CONVERSION_FUNCTIONS(Twine, LLVMTwineRef)
 
 extern "C" void LLVMRustWriteTwineToString(LLVMTwineRef T, RustStringRef Str) {
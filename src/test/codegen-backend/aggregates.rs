@@ -0,0 +1,59 @@
+// run-pass
+
+// Structs, enums with and without niches, arrays and slices, passed by value and by reference.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: i64,
+    y: i8,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Empty,
+    Dot(Point),
+    Line(Point, Point),
+    Named(String),
+}
+
+#[inline(never)]
+fn mirror(p: Point) -> Point {
+    Point { x: -p.x, y: -p.y }
+}
+
+#[inline(never)]
+fn points(shape: &Shape) -> usize {
+    match shape {
+        Shape::Empty | Shape::Named(_) => 0,
+        Shape::Dot(_) => 1,
+        Shape::Line(..) => 2,
+    }
+}
+
+#[inline(never)]
+fn sum(values: &[u32]) -> u32 {
+    values.iter().sum()
+}
+
+fn main() {
+    let p = Point { x: 3, y: -4 };
+    assert_eq!(mirror(p), Point { x: -3, y: 4 });
+
+    let shapes =
+        [Shape::Empty, Shape::Dot(p), Shape::Line(p, mirror(p)), Shape::Named("a".into())];
+    assert_eq!(shapes.iter().map(points).sum::<usize>(), 3);
+    assert_eq!(shapes[3], Shape::Named(String::from("a")));
+
+    let some: Option<&Point> = Some(&p);
+    assert_eq!(std::mem::size_of_val(&some), std::mem::size_of::<&Point>());
+    assert_eq!(some.map(|p| p.x), Some(3));
+
+    let mut values = [0u32; 100];
+    for (i, v) in values.iter_mut().enumerate() {
+        *v = i as u32 * 700;
+    }
+    assert_eq!(sum(&values), 3465000);
+    assert_eq!(sum(&values[10..20]), 101500);
+    let (a, b) = values.split_at(50);
+    assert_eq!(a.len() + b.len(), 100);
+}
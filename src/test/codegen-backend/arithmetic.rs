@@ -0,0 +1,39 @@
+// run-pass
+// compile-flags: -C overflow-checks=on
+
+// The integer and float operations, including the checked and wrapping ones and the 128-bit
+// integers, which backends often lower to library calls.
+
+#[inline(never)]
+fn black_box<T>(x: T) -> T {
+    x
+}
+
+fn main() {
+    let a = black_box(7i32);
+    let b = black_box(-3i32);
+    assert_eq!(a + b, 4);
+    assert_eq!(a * b, -21);
+    assert_eq!(a / b, -2);
+    assert_eq!(a % b, 1);
+    assert_eq!(a << 2, 28);
+    assert_eq!(b >> 1, -2);
+    assert_eq!((b as u32) >> 28, 15);
+    assert_eq!(a.checked_add(i32::max_value()), None);
+    assert_eq!(a.wrapping_mul(i32::max_value()), 2147483641);
+    assert_eq!(black_box(u8::max_value()).overflowing_add(1), (0, true));
+
+    let x = black_box(0x1234_5678_9abc_def0_1234_5678_9abc_def0u128);
+    let y = black_box(0x10u128);
+    assert_eq!(x / y, 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+    assert_eq!(x % y, 0);
+    assert_eq!(x.count_ones(), 64);
+    assert_eq!((x as i128).wrapping_neg() as u128, x.wrapping_neg());
+    assert_eq!(black_box(-1i128) as u64, u64::max_value());
+
+    let f = black_box(2.5f64);
+    assert_eq!(f * 2.0, 5.0);
+    assert_eq!(f as i32, 2);
+    assert!(black_box(std::f64::NAN) != black_box(std::f64::NAN));
+    assert_eq!((f as f32).sqrt(), 1.5811388);
+}
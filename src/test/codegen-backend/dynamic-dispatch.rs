@@ -0,0 +1,68 @@
+// run-pass
+
+// Closures, function pointers, trait objects and generic code instantiated at several types.
+
+use std::fmt::Debug;
+
+trait Animal {
+    fn name(&self) -> String;
+    fn legs(&self) -> u32 {
+        4
+    }
+}
+
+struct Dog;
+struct Bird(&'static str);
+
+impl Animal for Dog {
+    fn name(&self) -> String {
+        "dog".to_string()
+    }
+}
+
+impl Animal for Bird {
+    fn name(&self) -> String {
+        self.0.to_string()
+    }
+    fn legs(&self) -> u32 {
+        2
+    }
+}
+
+fn describe<T: Debug>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+fn apply(f: fn(u32) -> u32, x: u32) -> u32 {
+    f(x)
+}
+
+fn main() {
+    let animals: Vec<Box<dyn Animal>> = vec![Box::new(Dog), Box::new(Bird("robin"))];
+    let names: Vec<_> = animals.iter().map(|a| a.name()).collect();
+    assert_eq!(names, ["dog", "robin"]);
+    assert_eq!(animals.iter().map(|a| a.legs()).sum::<u32>(), 6);
+
+    assert_eq!(describe(1u8), "1");
+    assert_eq!(describe("str"), "\"str\"");
+    assert_eq!(describe((1, 'c', [2.5f32])), "(1, 'c', [2.5])");
+
+    let offset = 10;
+    let add = move |x: u32| x + offset;
+    let mut calls = 0;
+    let mut count = |x: u32| {
+        calls += 1;
+        x
+    };
+    assert_eq!(add(count(5)), 15);
+    assert_eq!(calls, 1);
+
+    fn double(x: u32) -> u32 {
+        x * 2
+    }
+    assert_eq!(apply(double, 21), 42);
+    assert_eq!(apply(|x| x + 1, 41), 42);
+
+    let boxed: Box<dyn Fn(&str) -> usize> = Box::new(|s| s.len());
+    assert_eq!(boxed("four"), 4);
+}
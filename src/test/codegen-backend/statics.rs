@@ -0,0 +1,43 @@
+// run-pass
+// ignore-emscripten no threads support
+
+// Statics, thread locals, atomics and the allocator.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+static GREETING: &str = "hello";
+static mut TABLE: [u8; 4] = [1, 2, 3, 4];
+
+thread_local!(static LOCAL: Cell<u32> = Cell::new(1));
+
+fn main() {
+    assert_eq!(GREETING.len(), 5);
+    unsafe {
+        TABLE[0] = 9;
+        assert_eq!(TABLE, [9, 2, 3, 4]);
+    }
+
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                COUNTER.fetch_add(i, Ordering::SeqCst);
+                LOCAL.with(|l| l.set(l.get() + i as u32));
+                LOCAL.with(|l| l.get())
+            })
+        })
+        .collect();
+    let locals: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+    assert_eq!(locals, [1, 2, 3, 4]);
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 6);
+    assert_eq!(LOCAL.with(|l| l.get()), 1);
+
+    let mut map = HashMap::new();
+    for i in 0..1000 {
+        map.insert(i, vec![i; i % 7]);
+    }
+    assert_eq!(map.values().map(Vec::len).sum::<usize>(), 2997);
+}
@@ -0,0 +1,31 @@
+// run-pass
+
+// Panics unwind through the generated frames and run the destructors of their locals.
+
+use std::cell::Cell;
+use std::panic;
+
+struct Guard<'a>(&'a Cell<u32>);
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[inline(never)]
+fn fail(drops: &Cell<u32>, depth: u32) {
+    let _guard = Guard(drops);
+    if depth == 0 {
+        panic!("bottom");
+    }
+    fail(drops, depth - 1);
+}
+
+fn main() {
+    panic::set_hook(Box::new(|_| {}));
+    let drops = Cell::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| fail(&drops, 3)));
+    assert_eq!(*result.unwrap_err().downcast::<&str>().unwrap(), "bottom");
+    assert_eq!(drops.get(), 4);
+}
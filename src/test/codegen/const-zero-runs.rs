@@ -0,0 +1,21 @@
+// Checks that long runs of zero bytes in constants are emitted as `zeroinitializer`, rather than
+// byte by byte.
+
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#[repr(C)]
+pub struct Big {
+    a: u8,
+    zeros: [u8; 1024],
+    b: u8,
+}
+
+// CHECK: @BIG = constant <{ [1 x i8], [1024 x i8], [1 x i8] }> <{ [1 x i8] c"\01", [1024 x i8] zeroinitializer, [1 x i8] c"\02" }>
+#[no_mangle]
+pub static BIG: Big = Big { a: 1, zeros: [0; 1024], b: 2 };
+
+// CHECK: @SMALL = constant <{ [4 x i8] }> <{ [4 x i8] c"\01\00\00\02" }>
+#[no_mangle]
+pub static SMALL: [u8; 4] = [1, 0, 0, 2];
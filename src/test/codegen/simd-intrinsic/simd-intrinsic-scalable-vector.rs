@@ -0,0 +1,31 @@
+// only-aarch64
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![allow(incomplete_features)]
+#![feature(repr_simd, repr_scalable, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd, scalable(4))]
+#[derive(Copy, Clone)]
+pub struct svfloat32_t(f32);
+
+extern "platform-intrinsic" {
+    fn simd_splat<T, U>(x: U) -> T;
+    fn simd_add<T>(x: T, y: T) -> T;
+}
+
+// CHECK-LABEL: @splat
+#[no_mangle]
+pub unsafe fn splat(x: f32) -> svfloat32_t {
+    // CHECK: shufflevector <vscale x 4 x float> {{.*}}, <vscale x 4 x i32> zeroinitializer
+    simd_splat(x)
+}
+
+// CHECK-LABEL: @add(<vscale x 4 x float> %a, <vscale x 4 x float> %b)
+#[no_mangle]
+pub unsafe fn add(a: svfloat32_t, b: svfloat32_t) -> svfloat32_t {
+    // CHECK: fadd <vscale x 4 x float> %a, %b
+    simd_add(a, b)
+}
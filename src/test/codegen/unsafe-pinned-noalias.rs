@@ -0,0 +1,41 @@
+// Checks that the unique pointers to an `UnsafePinned` do not get `noalias`, even where the
+// other unique pointers do.
+
+// compile-flags: -C no-prepopulate-passes -Z mutable-noalias=yes
+// ignore-tidy-linelength
+
+#![crate_type = "lib"]
+#![feature(unsafe_pinned)]
+
+use std::pin::UnsafePinned;
+
+pub struct Node {
+    _value: UnsafePinned<i32>,
+    _next: *mut Node,
+}
+
+// CHECK: @mutable_borrow(i32* noalias align 4 dereferenceable(4) %_1)
+#[no_mangle]
+pub fn mutable_borrow(_: &mut i32) {
+}
+
+// CHECK: @mutable_unsafe_pinned(i32* align 4 dereferenceable(4) %_1)
+#[no_mangle]
+pub fn mutable_unsafe_pinned(_: &mut UnsafePinned<i32>) {
+}
+
+// CHECK: @mutable_node(%Node* align {{[0-9]+}} dereferenceable({{[0-9]+}}) %_1)
+#[no_mangle]
+pub fn mutable_node(_: &mut Node) {
+}
+
+// CHECK: @box_unsafe_pinned(i32* nonnull align 4 %_1)
+#[no_mangle]
+pub fn box_unsafe_pinned(_: Box<UnsafePinned<i32>>) {
+}
+
+// Shared references are unaffected.
+// CHECK: @shared_unsafe_pinned(i32* noalias readonly align 4 dereferenceable(4) %_1)
+#[no_mangle]
+pub fn shared_unsafe_pinned(_: &UnsafePinned<i32>) {
+}
@@ -0,0 +1,47 @@
+// Checks that the cached results of evaluating globals, including the unvalidated ones that
+// the evaluation of other statics reads, are loaded from disk rather than recomputed when
+// something they do not depend on changes.
+
+// revisions: rpass1 rpass2
+// compile-flags: -Z query-dep-graph
+
+#![feature(rustc_attrs)]
+
+// `SECOND` changes, so its evaluation is redone and reads the cached result of `TABLE`.
+#[rustc_clean(cfg = "rpass2", loaded_from_disk = "const_eval_raw,const_eval_validated")]
+pub static TABLE: [u32; 4] = [1, 2, 3, 4];
+
+#[cfg(rpass1)]
+pub static SECOND: u32 = TABLE[1];
+
+#[cfg(rpass2)]
+pub static SECOND: u32 = TABLE[1] * 1;
+
+#[rustc_clean(cfg = "rpass2", loaded_from_disk = "const_eval_validated")]
+pub static REFS: (&u32, &[u32; 4]) = (&SECOND, &TABLE);
+
+#[rustc_clean(label = "optimized_mir", cfg = "rpass2")]
+pub const SUM: u32 = sum(&[1, 2, 3, 4]);
+
+pub const fn sum(values: &[u32; 4]) -> u32 {
+    values[0] + values[1] + values[2] + values[3]
+}
+
+#[cfg(rpass1)]
+fn unrelated() -> u32 {
+    1
+}
+
+#[cfg(rpass2)]
+#[rustc_dirty(label = "optimized_mir", cfg = "rpass2")]
+fn unrelated() -> u32 {
+    2
+}
+
+fn main() {
+    assert_eq!(SECOND, 2);
+    assert_eq!(*REFS.0, 2);
+    assert_eq!(REFS.1[3], 4);
+    assert_eq!(SUM, 10);
+    assert!(unrelated() > 0);
+}
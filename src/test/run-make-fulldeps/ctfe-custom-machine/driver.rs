@@ -0,0 +1,180 @@
+#![feature(never_type, rustc_private)]
+
+#[macro_use]
+extern crate rustc;
+extern crate rustc_data_structures;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_mir;
+extern crate rustc_span;
+
+use rustc::mir;
+use rustc::ty::{self, Ty, TyCtxt};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_driver::Compilation;
+use rustc_hir::def_id::DefId;
+use rustc_interface::{interface, Queries};
+use rustc_mir::const_eval::eval_global_using_machine;
+use rustc_mir::interpret::{
+    AllocId, Allocation, AssertMessage, GlobalId, ImmTy, InterpCx, InterpResult, Machine, Memory,
+    MemoryKind, OpTy, PlaceTy, Pointer, Scalar,
+};
+use rustc_span::Span;
+use std::borrow::Cow;
+use std::env;
+
+/// A machine that only knows how to call const fns, and counts the terminators it executes.
+struct CountingMachine {
+    terminators: usize,
+}
+
+impl<'mir, 'tcx> Machine<'mir, 'tcx> for CountingMachine {
+    type MemoryKinds = !;
+    type PointerTag = ();
+    type ExtraFnVal = !;
+    type FrameExtra = ();
+    type MemoryExtra = ();
+    type AllocExtra = ();
+    type MemoryMap = FxHashMap<AllocId, (MemoryKind<!>, Allocation)>;
+
+    const STATIC_KIND: Option<!> = None;
+    const CHECK_ALIGN: bool = true;
+
+    fn enforce_validity(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
+        false
+    }
+
+    fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        ecx.machine.terminators += 1;
+        Ok(())
+    }
+
+    fn find_mir_or_eval_fn(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _span: Span,
+        instance: ty::Instance<'tcx>,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
+        _unwind: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx, Option<&'mir mir::Body<'tcx>>> {
+        Ok(Some(*ecx.load_mir(instance.def, None)?))
+    }
+
+    fn call_extra_fn(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        fn_val: !,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
+        _unwind: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        match fn_val {}
+    }
+
+    fn call_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _span: Span,
+        instance: ty::Instance<'tcx>,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(PlaceTy<'tcx>, mir::BasicBlock)>,
+        _unwind: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("calling intrinsic `{}`", instance)
+    }
+
+    fn assert_panic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _span: Span,
+        msg: &AssertMessage<'tcx>,
+        _unwind: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("assertion failed: {:?}", msg)
+    }
+
+    fn find_foreign_static(
+        _tcx: TyCtxt<'tcx>,
+        _def_id: DefId,
+    ) -> InterpResult<'tcx, Cow<'tcx, Allocation>> {
+        throw_unsup!(ReadForeignStatic)
+    }
+
+    fn binary_ptr_op(
+        _ecx: &InterpCx<'mir, 'tcx, Self>,
+        _bin_op: mir::BinOp,
+        _left: ImmTy<'tcx>,
+        _right: ImmTy<'tcx>,
+    ) -> InterpResult<'tcx, (Scalar, bool, Ty<'tcx>)> {
+        throw_unsup_format!("pointer arithmetic or comparison")
+    }
+
+    fn box_alloc(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _dest: PlaceTy<'tcx>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("`box` expression")
+    }
+
+    fn init_allocation_extra<'b>(
+        _memory_extra: &(),
+        _id: AllocId,
+        alloc: Cow<'b, Allocation>,
+        _kind: Option<MemoryKind<!>>,
+    ) -> (Cow<'b, Allocation>, ()) {
+        (alloc, ())
+    }
+
+    fn tag_static_base_pointer(_memory_extra: &(), _id: AllocId) {}
+
+    fn stack_push(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
+    fn ptr_to_int(_mem: &Memory<'mir, 'tcx, Self>, _ptr: Pointer) -> InterpResult<'tcx, u64> {
+        throw_unsup!(ReadPointerAsBytes)
+    }
+}
+
+/// Evaluates `FOO` of `input.rs` with a `CountingMachine` after the analysis of the crate.
+struct EvalFoo {
+    evaluated: bool,
+}
+
+impl rustc_driver::Callbacks for EvalFoo {
+    fn after_analysis<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries.global_ctxt().unwrap().peek_mut().enter(|tcx| {
+            let item = tcx.hir().krate().items.values().find(|item| item.ident.as_str() == "FOO");
+            let def_id = tcx.hir().local_def_id(item.unwrap().hir_id);
+            let gid = GlobalId { instance: ty::Instance::mono(tcx, def_id), promoted: None };
+            let machine = CountingMachine { terminators: 0 };
+            let (ecx, res) =
+                eval_global_using_machine(tcx, ty::ParamEnv::reveal_all().and(gid), machine, ());
+            let place = res.unwrap();
+            assert_eq!(ecx.read_scalar(place.into()).unwrap().to_u32().unwrap(), 42);
+            // At least the call to `double` and the returns of both bodies.
+            assert!(ecx.machine.terminators >= 3, "{}", ecx.machine.terminators);
+        });
+        self.evaluated = true;
+        Compilation::Stop
+    }
+}
+
+fn main() {
+    let sysroot = env::args().nth(1).expect("expected the sysroot");
+    let args: Vec<String> = vec![
+        "driver".into(),
+        "--crate-type=lib".into(),
+        "--sysroot".into(),
+        sysroot,
+        "input.rs".into(),
+    ];
+    let mut callbacks = EvalFoo { evaluated: false };
+    rustc_driver::catch_fatal_errors(|| {
+        rustc_driver::run_compiler(&args, &mut callbacks, None, None).ok();
+    })
+    .ok();
+    assert!(callbacks.evaluated);
+}
@@ -0,0 +1,5 @@
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+pub const FOO: u32 = double(21);
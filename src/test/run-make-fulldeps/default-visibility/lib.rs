@@ -0,0 +1,12 @@
+// The Rust functions get the visibility selected with `-Z default-visibility`, the
+// `#[no_mangle]` functions always keep the default visibility.
+
+#[inline(never)]
+pub fn public_rust_function() -> u32 {
+    42
+}
+
+#[no_mangle]
+pub extern "C" fn public_c_function() -> u32 {
+    public_rust_function()
+}
@@ -0,0 +1,6 @@
+extern crate lib;
+
+fn main() {
+    assert_eq!(lib::public_c_function(), 42);
+    assert_eq!(lib::public_rust_function(), 42);
+}
@@ -0,0 +1,8 @@
+pub mod tables {
+    #[repr(C)]
+    pub struct Padded(pub u8, pub u16);
+
+    pub static TABLE: [u8; 4] = [1, 2, 3, 4];
+    pub static REF: &u8 = &TABLE[1];
+    pub static PADDED: Padded = Padded(1, 2);
+}
@@ -0,0 +1,18 @@
+#![feature(never_type, test)]
+#![cfg_attr(all(), feature(box_syntax))]
+#![feature(min_const_fn)]
+#![feature(rustc_attrs)]
+
+extern crate test;
+
+fn diverge() -> ! {
+    panic!()
+}
+
+fn main() {
+    let x = box 1;
+    test::black_box(x);
+    if false {
+        diverge()
+    }
+}
@@ -0,0 +1,7 @@
+#![crate_type = "rlib"]
+
+extern crate crate_a;
+
+pub fn b() {
+    crate_a::a();
+}
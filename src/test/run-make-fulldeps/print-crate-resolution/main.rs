@@ -0,0 +1,5 @@
+extern crate crate_b;
+
+fn main() {
+    crate_b::b();
+}
@@ -0,0 +1,10 @@
+#![crate_type = "dylib"]
+
+#[link(name = "m")]
+extern "C" {
+    fn cos(x: f64) -> f64;
+}
+
+pub fn one() -> f64 {
+    unsafe { cos(0.0) }
+}
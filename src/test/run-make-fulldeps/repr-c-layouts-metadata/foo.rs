@@ -0,0 +1,18 @@
+#![crate_type = "rlib"]
+
+#[repr(C)]
+pub struct Pair {
+    pub a: u8,
+    pub b: u32,
+}
+
+#[repr(C)]
+pub union Word {
+    pub bytes: [u8; 4],
+    pub value: u32,
+}
+
+pub struct NotReprC(pub u8, pub u32);
+
+#[repr(C)]
+pub struct Generic<T>(pub T);
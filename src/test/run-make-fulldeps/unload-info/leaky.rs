@@ -0,0 +1 @@
+pub static NAMES: Vec<String> = Vec::new();
@@ -0,0 +1,35 @@
+#![feature(dylib_unload_info, rustc_private)]
+
+extern crate rustc_metadata;
+
+use rustc_metadata::dynamic_lib::DynamicLibrary;
+use std::os::unload::{UnloadInfo, UNLOAD_INFO_SYMBOL};
+use std::path::Path;
+
+fn unload_info(lib: &DynamicLibrary) -> &UnloadInfo {
+    unsafe {
+        let symbol = lib.symbol::<u8>(UNLOAD_INFO_SYMBOL).unwrap();
+        UnloadInfo::from_symbol(symbol).unwrap()
+    }
+}
+
+pub fn main() {
+    let plugin = DynamicLibrary::open(Some(Path::new("libplugin.so"))).unwrap();
+    let info = unload_info(&plugin);
+    assert!(!info.has_static_dtors());
+    assert_eq!(info.registered_tls_dtors(), 0);
+    assert!(info.is_safe_to_unload());
+
+    // Using the thread-local variable of the plugin registers its destructor.
+    unsafe {
+        let remember_name = plugin.symbol::<u8>("remember_name").unwrap();
+        let remember_name: extern "C" fn() = std::mem::transmute(remember_name);
+        remember_name();
+    }
+    assert!(info.registered_tls_dtors() > 0);
+    assert!(!info.is_safe_to_unload());
+
+    let leaky = DynamicLibrary::open(Some(Path::new("libleaky.so"))).unwrap();
+    assert!(unload_info(&leaky).has_static_dtors());
+    assert!(!unload_info(&leaky).is_safe_to_unload());
+}
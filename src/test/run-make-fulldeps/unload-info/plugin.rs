@@ -0,0 +1,8 @@
+use std::cell::RefCell;
+
+thread_local!(static NAMES: RefCell<Vec<String>> = RefCell::new(Vec::new()));
+
+#[no_mangle]
+pub extern "C" fn remember_name() {
+    NAMES.with(|names| names.borrow_mut().push(String::from("plugin")));
+}
@@ -0,0 +1,17 @@
+#[used]
+static UNREFERENCED: u32 = 1;
+
+#[used]
+static REFERENCED: u32 = 2;
+
+#[no_mangle]
+pub extern "C" fn unreferenced_fn() {}
+
+#[no_mangle]
+pub extern "C" fn referenced_fn() -> u32 {
+    REFERENCED
+}
+
+fn main() {
+    println!("{}", referenced_fn());
+}
@@ -0,0 +1,15 @@
+// compile-flags:-Z unstable-options
+// compile-flags:--extern-html-root-urls-file {{src-base}}/auxiliary/extern-html-root-urls.json
+// compile-flags:--extern-html-root-url alloc=https://example.com/alloc/0.2.0
+
+// The map gives the location of `core`, and the `--extern-html-root-url` flag overrides the one
+// of `alloc`.
+
+// @has extern_html_root_urls_file/index.html
+// @has - '//a/@href' 'https://example.com/core/0.1.0/core/iter/index.html'
+// @has - '//a/@href' 'https://example.com/alloc/0.2.0/alloc/vec/index.html'
+// @!has - '//a/@href' 'https://example.com/alloc/0.1.0/alloc/vec/index.html'
+#[doc(no_inline)]
+pub use std::iter;
+#[doc(no_inline)]
+pub use std::vec;
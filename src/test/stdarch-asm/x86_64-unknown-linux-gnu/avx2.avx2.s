@@ -0,0 +1,4 @@
+add_epi32:
+    vpaddd %ymm1, %ymm0, %ymm0
+    retq
+
@@ -0,0 +1,10 @@
+// The AVX2 intrinsics, which are only checked when AVX2 is enabled.
+
+// features: +avx2
+
+use std::arch::x86_64::*;
+
+#[no_mangle]
+pub unsafe extern "C" fn add_epi32(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_add_epi32(a, b)
+}
@@ -0,0 +1,8 @@
+add_epi32:
+    vpaddd %xmm1, %xmm0, %xmm0
+    retq
+
+mul_ps:
+    vmulps %xmm1, %xmm0, %xmm0
+    retq
+
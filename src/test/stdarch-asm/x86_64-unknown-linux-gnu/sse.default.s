@@ -0,0 +1,8 @@
+add_epi32:
+    paddd %xmm1, %xmm0
+    retq
+
+mul_ps:
+    mulps %xmm1, %xmm0
+    retq
+
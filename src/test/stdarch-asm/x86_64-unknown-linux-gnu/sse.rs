@@ -0,0 +1,17 @@
+// The SSE intrinsics, which are lowered to their legacy encoding by default and to their VEX
+// encoding with AVX.
+
+// features:
+// features: +avx
+
+use std::arch::x86_64::*;
+
+#[no_mangle]
+pub unsafe extern "C" fn add_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_add_epi32(a, b)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mul_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_mul_ps(a, b)
+}
@@ -0,0 +1,40 @@
+// run-pass
+// ignore-cross-compile
+// ignore-stage1
+
+#![feature(rustc_private)]
+
+extern crate rustc;
+extern crate rustc_target;
+
+use rustc::mir::interpret::{AllocId, Allocation, Pointer};
+use rustc::ty::layout::{Align, Size};
+use rustc_target::abi::TargetDataLayout;
+
+fn main() {
+    let dl = TargetDataLayout::default();
+    let align = Align::from_bytes(1).unwrap();
+
+    // The bytes of a new allocation are not stored, even when they are read.
+    let mut alloc: Allocation = Allocation::undef(Size::from_bytes(1 << 20), align);
+    assert!(alloc.is_zeroed());
+    assert_eq!(alloc.inspect_with_undef_and_ptr_outside_interpreter(0..16), &[0; 16]);
+    let bytes = alloc.inspect_with_undef_and_ptr_outside_interpreter(0..alloc.len());
+    assert!(bytes.iter().all(|&byte| byte == 0));
+    assert!(alloc.is_zeroed());
+
+    // They are stored once they are written to.
+    let ptr = Pointer::new(AllocId(0), Size::from_bytes(8));
+    alloc.get_bytes_mut(&dl, ptr, Size::from_bytes(1)).unwrap()[0] = 1;
+    assert!(!alloc.is_zeroed());
+    assert_eq!(alloc.inspect_with_undef_and_ptr_outside_interpreter(7..10), &[0, 1, 0]);
+    alloc.compact();
+    assert!(!alloc.is_zeroed());
+
+    // Compacting stops storing bytes that are all zero.
+    let mut alloc: Allocation = Allocation::from_byte_aligned_bytes(vec![0; 100]);
+    assert!(!alloc.is_zeroed());
+    alloc.compact();
+    assert!(alloc.is_zeroed());
+    assert_eq!(alloc.inspect_with_undef_and_ptr_outside_interpreter(98..100), &[0, 0]);
+}
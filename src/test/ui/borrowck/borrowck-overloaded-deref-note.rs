@@ -0,0 +1,39 @@
+// Check that borrows made through an overloaded `Deref` or `DerefMut` impl
+// point at the impl.
+
+use std::ops::{Deref, DerefMut};
+
+struct MyBox<T>(T);
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn disjoint_fields(p: &mut MyBox<Point>) {
+    let x = &mut p.x;
+    let y = &mut p.y; //~ ERROR cannot borrow `*p` as mutable more than once at a time
+    drop(y);
+    drop(x);
+}
+
+fn assign_while_borrowed(mut p: MyBox<Point>) {
+    let x = &p.x;
+    p = MyBox(Point { x: 0, y: 0 }); //~ ERROR cannot assign to `p` because it is borrowed
+    drop(x);
+}
+
+fn main() {}
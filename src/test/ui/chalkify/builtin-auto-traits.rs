@@ -0,0 +1,34 @@
+// check-pass
+// compile-flags: -Z chalk
+
+// Test that auto traits are implemented for builtin types and for types whose fields implement
+// them, including recursive types.
+
+struct List<T> {
+    value: T,
+    next: Option<Box<List<T>>>,
+}
+
+enum Tree {
+    Leaf(u32),
+    Node(Vec<Tree>),
+}
+
+struct Pair<'a>(&'a str, [u8; 4]);
+
+fn test_send<T: Send>(_: T) {}
+
+fn test_sync<T: Sync>(_: T) {}
+
+fn test_send_sync<T: Send + Sync>(arg: T) {
+    test_send(&arg);
+    test_sync(arg);
+}
+
+fn main() {
+    test_send_sync(1u8);
+    test_send_sync((true, 'a', 1.5));
+    test_send_sync(Pair("a", [0; 4]));
+    test_send_sync(List { value: 0, next: None });
+    test_send_sync(Tree::Node(vec![Tree::Leaf(0)]));
+}
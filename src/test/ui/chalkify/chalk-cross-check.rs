@@ -0,0 +1,27 @@
+// check-pass
+// compile-flags: -Z chalk -Z chalk-cross-check
+
+// Test that the old trait solver agrees with Chalk about the obligations of this crate, so that
+// `-Z chalk-cross-check` warns about no discrepancy.
+
+trait Foo {}
+
+impl Foo for i32 {}
+
+impl<T: Foo> Foo for Option<T> {}
+
+trait Bar: Foo {}
+
+impl Bar for i32 {}
+
+fn only_foo<T: Foo>(_x: T) {}
+
+fn only_bar<T: Bar>(x: T) {
+    only_foo(x)
+}
+
+fn main() {
+    only_foo(0i32);
+    only_foo(Some(Some(0i32)));
+    only_bar(0i32);
+}
@@ -0,0 +1,7 @@
+#![feature(optin_builtin_traits, rustc_attrs)]
+
+#[rustc_strict_coherence]
+pub trait Remote {}
+
+impl !Remote for &str {}
+impl Remote for u32 {}
@@ -0,0 +1,16 @@
+// Tests that a negative impl of an upstream `#[rustc_strict_coherence]` trait lets coherence
+// rely on the trait not being implemented for the types that it covers, but not for others.
+
+// aux-build:coherence_strict_lib.rs
+
+extern crate coherence_strict_lib;
+
+use coherence_strict_lib::Remote;
+
+trait Foo {}
+impl<T: Remote> Foo for T {}
+impl Foo for &str {}
+impl Foo for &u8 {}
+//~^ ERROR E0119
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![feature(const_generics)]
+//~^ WARN the feature `const_generics` is incomplete and may cause the compiler to crash
+
+#[derive(PartialEq, Eq)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[derive(PartialEq, Eq)]
+enum Shape {
+    Dot,
+    Line(u8),
+}
+
+struct ByPoint<const P: Point>;
+struct ByShape<const S: Shape>;
+struct ByRef<const R: &'static (u8, &'static [u16])>;
+
+pub fn main() {
+    let _: ByPoint<{ Point { x: 1, y: 2 } }> = ByPoint::<{ Point { x: 2, y: 1 } }>;
+    //~^ ERROR mismatched types
+    let _: ByShape<{ Shape::Dot }> = ByShape::<{ Shape::Line(3) }>;
+    //~^ ERROR mismatched types
+    let _: ByRef<{ &(1, &[2, 3]) }> = ByRef::<{ &(1, &[3, 2]) }>;
+    //~^ ERROR mismatched types
+}
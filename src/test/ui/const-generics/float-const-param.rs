@@ -0,0 +1,45 @@
+// run-pass
+
+#![feature(const_generics, const_compare_raw_pointers)]
+//~^ WARN the feature `const_generics` is incomplete and may cause the compiler to crash
+
+// Float and raw pointer const arguments are compared by their bits, so `0.0` and `-0.0` are
+// different arguments, while the same NaN is the same argument.
+
+struct Config<const F: f64>;
+
+impl<const F: f64> Config<F> {
+    fn value(&self) -> f64 {
+        F
+    }
+}
+
+trait Name {
+    fn name() -> &'static str;
+}
+
+impl Name for Config<{ 0.0 }> {
+    fn name() -> &'static str {
+        "zero"
+    }
+}
+
+impl Name for Config<{ -0.0 }> {
+    fn name() -> &'static str {
+        "negative zero"
+    }
+}
+
+fn address<const P: *const u8>() -> usize {
+    P as usize
+}
+
+fn main() {
+    let half: Config<{ 1.0 / 2.0 }> = Config::<0.5>;
+    assert_eq!(half.value(), 0.5);
+    assert_eq!(Config::<{ std::f64::INFINITY }>.value(), std::f64::INFINITY);
+    assert!(Config::<{ 0.0 / 0.0 }>.value().is_nan());
+    assert_eq!(<Config<{ 0.0 }>>::name(), "zero");
+    assert_eq!(<Config<{ -0.0 }>>::name(), "negative zero");
+    assert_eq!(address::<{ 0x10 as *const u8 }>(), 0x10);
+}
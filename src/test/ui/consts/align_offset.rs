@@ -0,0 +1,49 @@
+// run-pass
+#![feature(const_align_offset)]
+#![feature(const_ptr_offset)]
+
+// Pointers into allocations can be aligned up to the alignment of their allocation.
+const ALIGNED: usize = {
+    let data = [0u32; 4];
+    data.as_ptr().align_offset(4)
+};
+
+const UNALIGNED_U8: usize = unsafe {
+    let data = [0u32; 4];
+    (data.as_ptr() as *const u8).add(1).align_offset(4)
+};
+
+const UNALIGNED_U16: usize = unsafe {
+    let data = [0u32; 4];
+    (data.as_ptr() as *const u16).add(1).align_offset(4)
+};
+
+// The address of the allocation is not known, so alignments above the alignment of the
+// allocation are never reached.
+const OVER_ALIGNED: usize = {
+    let data = [0u32; 4];
+    data.as_ptr().align_offset(8)
+};
+
+// Integer addresses are aligned exactly like at runtime.
+const INT_ALIGNED: usize = (16 as *const u64).align_offset(8);
+const INT_U16: usize = (12 as *const u16).align_offset(8);
+const INT_STRIDE_3: usize = (13 as *const [u8; 3]).align_offset(8);
+const INT_IMPOSSIBLE: usize = (13 as *const u16).align_offset(4);
+const INT_ZST: usize = (13 as *const ()).align_offset(4);
+
+fn main() {
+    assert_eq!(ALIGNED, 0);
+    assert_eq!(UNALIGNED_U8, 3);
+    assert_eq!(UNALIGNED_U16, 1);
+    assert_eq!(OVER_ALIGNED, usize::max_value());
+
+    assert_eq!(INT_ALIGNED, (16 as *const u64).align_offset(8));
+    assert_eq!(INT_U16, (12 as *const u16).align_offset(8));
+    assert_eq!(INT_STRIDE_3, (13 as *const [u8; 3]).align_offset(8));
+    assert_eq!(INT_IMPOSSIBLE, (13 as *const u16).align_offset(4));
+    assert_eq!(INT_ZST, (13 as *const ()).align_offset(4));
+    assert_eq!(INT_U16, 2);
+    assert_eq!(INT_STRIDE_3, 1);
+    assert_eq!(INT_IMPOSSIBLE, usize::max_value());
+}
@@ -0,0 +1,28 @@
+// Checks that `-Z audit-const-target-dependence` reports the constants whose value depends on
+// the layout of the types for the target or on its byte order.
+
+// build-pass
+// compile-flags: -Z audit-const-target-dependence
+
+#![feature(const_transmute)]
+#![allow(dead_code)]
+
+use std::mem;
+
+const PTR_SIZE: usize = mem::size_of::<*const u8>();
+//~^ WARN the value of `PTR_SIZE` may depend on the target
+
+const PAIR_SIZE: usize = mem::size_of::<(u8, u64)>();
+//~^ WARN the value of `PAIR_SIZE` may depend on the target
+
+const U64_SIZE: usize = mem::size_of::<u64>();
+
+const BYTES: u32 = unsafe { mem::transmute::<[u8; 4], u32>([1, 2, 3, 4]) };
+//~^ WARN the value of `BYTES` may depend on the target
+
+const BITS: u32 = unsafe { mem::transmute::<f32, u32>(1.0) };
+
+#[allow(target_dependent_const)]
+const ALLOWED: usize = mem::align_of::<u64>();
+
+fn main() {}
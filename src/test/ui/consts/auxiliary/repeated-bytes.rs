@@ -0,0 +1,11 @@
+pub const ZEROS: &[u8] = &[0; 100];
+
+pub const PATTERN: &[u16] = &[1, 2, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 3];
+
+#[inline]
+pub fn mixed() -> &'static [u8; 40] {
+    &[
+        1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 4, 5,
+    ]
+}
@@ -0,0 +1,15 @@
+// compile-flags: -Z const-float-strict
+#![feature(core_intrinsics, const_float_intrinsics)]
+
+use std::intrinsics::{copysignf64, sqrtf64};
+
+const ROOT: f64 = unsafe { sqrtf64(2.0) };
+const NEG_ZERO: f64 = unsafe { copysignf64(0.0, -1.0) };
+
+const NAN: f64 = 0.0 / 0.0;
+//~^ ERROR any use of this value will cause an error
+
+const ROOT_NAN: f64 = unsafe { sqrtf64(-1.0) };
+//~^ ERROR any use of this value will cause an error
+
+fn main() {}
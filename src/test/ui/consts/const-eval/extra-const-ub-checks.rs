@@ -0,0 +1,18 @@
+// compile-flags: -Z extra-const-ub-checks
+#![feature(const_raw_ptr_deref, const_transmute)]
+
+use std::mem::transmute;
+
+const UNALIGNED_READ: u16 = unsafe { *(&[0u8; 4][1] as *const u8 as *const u16) };
+//~^ ERROR any use of this value will cause an error
+
+const INVALID_BOOL_COPY: () = unsafe {
+    let _b: bool = transmute(3u8);
+    //~^ ERROR any use of this value will cause an error
+};
+
+const VALID_COPY: bool = unsafe { transmute(1u8) };
+
+fn main() {
+    assert!(VALID_COPY);
+}
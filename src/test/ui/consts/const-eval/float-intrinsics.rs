@@ -0,0 +1,45 @@
+// run-pass
+#![feature(core_intrinsics, const_float_intrinsics, const_if_match, const_loop)]
+
+use std::intrinsics::*;
+
+const FLOOR: f64 = unsafe { floorf64(-1.5) };
+const CEIL: f32 = unsafe { ceilf32(1.25) };
+const TRUNC: f64 = unsafe { truncf64(-2.75) };
+const ROUND: f64 = unsafe { roundf64(2.5) };
+const RINT: f64 = unsafe { rintf64(2.5) };
+const NEARBYINT: f32 = unsafe { nearbyintf32(3.5) };
+const FMA: f64 = unsafe { fmaf64(0.1, 10.0, -1.0) };
+const ABS: f32 = unsafe { fabsf32(-0.0) };
+const COPYSIGN: f64 = unsafe { copysignf64(3.0, -0.0) };
+const MIN: f64 = unsafe { minnumf64(std::f64::NAN, 1.0) };
+const MAX: f32 = unsafe { maxnumf32(-1.0, 2.0) };
+const SQRT: f64 = unsafe { sqrtf64(2.0) };
+
+// A table computed at compile time.
+const SQUARES: [f64; 4] = {
+    let mut table = [0.0; 4];
+    let mut i = 0;
+    while i < 4 {
+        table[i] = unsafe { sqrtf64((i * i) as f64) };
+        i += 1;
+    }
+    table
+};
+
+fn main() {
+    assert_eq!(FLOOR, -2.0);
+    assert_eq!(CEIL, 2.0);
+    assert_eq!(TRUNC, -2.0);
+    assert_eq!(ROUND, 3.0);
+    assert_eq!(RINT, 2.0);
+    assert_eq!(NEARBYINT, 4.0);
+    assert_eq!(FMA, unsafe { fmaf64(0.1, 10.0, -1.0) });
+    assert_ne!(FMA, 0.0);
+    assert_eq!(ABS.to_bits(), 0);
+    assert_eq!(COPYSIGN, -3.0);
+    assert_eq!(MIN, 1.0);
+    assert_eq!(MAX, 2.0);
+    assert_eq!(SQRT, 2.0f64.sqrt());
+    assert_eq!(SQUARES, [0.0, 1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,14 @@
+// The transcendental functions and `powi` are computed by the math library of the platform at
+// runtime, so they cannot be evaluated at compile time.
+
+#![feature(core_intrinsics, const_float_intrinsics)]
+
+use std::intrinsics::{powif64, sinf64};
+
+const SINE: f64 = unsafe { sinf64(1.0) };
+//~^ ERROR calls in constants are limited to constant functions
+
+const POWI: f64 = unsafe { powif64(3.0, 4) };
+//~^ ERROR calls in constants are limited to constant functions
+
+fn main() {}
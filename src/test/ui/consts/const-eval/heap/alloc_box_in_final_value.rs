@@ -0,0 +1,8 @@
+#![feature(box_syntax)]
+#![feature(const_heap)]
+
+// Every use of the constant would free the interned memory when dropping its copy of the box.
+const BOX: Box<i32> = box 42;
+//~^ ERROR it is undefined behavior to use this value
+
+fn main() {}
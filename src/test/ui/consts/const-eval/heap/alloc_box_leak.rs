@@ -0,0 +1,41 @@
+// run-pass
+// `box` expressions allocate on the compile-time heap, and `Box::leak` turns that memory into
+// `'static` memory of the final value.
+#![feature(box_syntax)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+#![feature(const_loop)]
+#![feature(const_if_match)]
+#![feature(const_fn)]
+#![feature(const_fn_union)]
+
+const FOO: &i32 = Box::leak(box 42);
+
+static BAR: &[u8] = Box::leak(box [1, 2, 3]);
+
+const SQUARES: &[usize] = {
+    let squares: &mut [usize; 4] = Box::leak(box [0; 4]);
+    let mut i = 0;
+    while i < squares.len() {
+        squares[i] = i * i;
+        i += 1;
+    }
+    squares
+};
+
+const fn to_str(bytes: &'static [u8]) -> &'static str {
+    union Repr {
+        bytes: &'static [u8],
+        s: &'static str,
+    }
+    unsafe { Repr { bytes }.s }
+}
+
+const GREETING: &str = to_str(Box::leak(box *b"hello"));
+
+fn main() {
+    assert_eq!(*FOO, 42);
+    assert_eq!(BAR, [1, 2, 3]);
+    assert_eq!(SQUARES, [0, 1, 4, 9]);
+    assert_eq!(GREETING, "hello");
+}
@@ -0,0 +1,23 @@
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_fn)]
+use std::intrinsics;
+
+const fn local_ptr() -> *const i32 {
+    let x = 42;
+    &x
+}
+
+const LOCAL: *const i32 = local_ptr(); //~ ERROR any use of this value will cause an error
+
+const fn heap_ptr() -> *const u8 {
+    unsafe {
+        let ptr = intrinsics::const_allocate(4, 4);
+        intrinsics::const_deallocate(ptr, 4, 4);
+        ptr
+    }
+}
+
+const HEAP: *const u8 = heap_ptr(); //~ ERROR any use of this value will cause an error
+
+fn main() {}
@@ -0,0 +1,29 @@
+// run-pass
+// Heap allocations that are only reachable through raw pointers get interned together with the
+// value of the constant.
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_raw_ptr_deref)]
+#![feature(const_mut_refs)]
+#![feature(const_fn)]
+use std::intrinsics;
+
+struct RawBox {
+    ptr: *const u32,
+}
+
+const BOX: RawBox = RawBox::new(7);
+
+impl RawBox {
+    const fn new(val: u32) -> RawBox {
+        unsafe {
+            let ptr = intrinsics::const_allocate(4, 4) as *mut u32;
+            *ptr = val;
+            RawBox { ptr }
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(unsafe { *BOX.ptr }, 7);
+}
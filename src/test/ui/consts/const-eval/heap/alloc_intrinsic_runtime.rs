@@ -0,0 +1,10 @@
+// run-pass
+#![feature(core_intrinsics)]
+use std::intrinsics;
+
+fn main() {
+    // The compile-time heap does not exist at runtime.
+    let ptr = unsafe { intrinsics::const_allocate(4, 4) };
+    assert!(ptr.is_null());
+    unsafe { intrinsics::const_deallocate(ptr, 4, 4) };
+}
@@ -0,0 +1,23 @@
+// run-pass
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_raw_ptr_deref)]
+#![feature(const_mut_refs)]
+#![feature(const_fn)]
+use std::intrinsics;
+
+const FOO: i32 = foo();
+
+const fn foo() -> i32 {
+    unsafe {
+        let ptr = intrinsics::const_allocate(4, 4) as *mut i32;
+        *ptr = 42;
+        let val = *ptr;
+        intrinsics::const_deallocate(ptr as *mut u8, 4, 4);
+        val
+    }
+}
+
+fn main() {
+    assert_eq!(FOO, 42)
+}
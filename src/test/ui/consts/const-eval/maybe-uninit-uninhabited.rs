@@ -0,0 +1,17 @@
+// FIXME: missing sysroot spans (#53081)
+// ignore-i586-unknown-linux-gnu
+// ignore-i586-unknown-linux-musl
+// ignore-i686-unknown-linux-musl
+
+#![feature(const_maybe_uninit_assume_init)]
+
+use std::mem::MaybeUninit;
+
+enum Void {}
+
+pub const UNINHABITED: Void = unsafe {
+    //~^ NOTE
+    MaybeUninit::<Void>::uninit().assume_init()
+};
+
+fn main() {}
@@ -0,0 +1,43 @@
+// run-pass
+
+#![feature(const_maybe_uninit_as_ptr)]
+#![feature(const_maybe_uninit_assume_init)]
+#![feature(const_mut_refs)]
+#![feature(const_raw_ptr_deref)]
+
+use std::mem::MaybeUninit;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Padded(u8, u32);
+
+const UNINIT: MaybeUninit<u32> = MaybeUninit::uninit();
+
+const fn init_field_by_field() -> Padded {
+    let mut padded = MaybeUninit::<Padded>::uninit();
+    unsafe {
+        let ptr = padded.as_mut_ptr();
+        (*ptr).0 = 1;
+        (*ptr).1 = 2;
+        padded.assume_init()
+    }
+}
+
+const PADDED: Padded = init_field_by_field();
+
+const PARTIAL: [MaybeUninit<u8>; 4] = {
+    let mut arr = [MaybeUninit::<u8>::uninit(); 4];
+    unsafe {
+        *arr[0].as_mut_ptr() = 1;
+        *arr[2].as_mut_ptr() = 3;
+    }
+    arr
+};
+
+const READ_BACK: u8 = unsafe { *PARTIAL[2].as_ptr() };
+
+fn main() {
+    let _ = UNINIT;
+    assert_eq!(PADDED, Padded(1, 2));
+    assert_eq!(unsafe { *PARTIAL[0].as_ptr() }, 1);
+    assert_eq!(READ_BACK, 3);
+}
@@ -0,0 +1,87 @@
+// run-pass
+#![feature(const_fn)]
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.33.7")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct i32x4(i32, i32, i32, i32);
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct u8x4(u8, u8, u8, u8);
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct f32x4(f32, f32, f32, f32);
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct i32x2(i32, i32);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_add<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_mul<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_shr<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_lt<T, U>(x: T, y: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_select<M, T>(m: M, a: T, b: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_bitmask<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_cast<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_saturating_add<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_add_ordered<T, U>(x: T, acc: U) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_max<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_any<T>(x: T) -> bool;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_fmin<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_fsqrt<T>(x: T) -> T;
+}
+
+const A: i32x4 = i32x4(1, -2, 3, i32::max_value());
+const B: i32x4 = i32x4(10, 20, -30, 1);
+
+const ADD: i32x4 = unsafe { simd_add(A, B) };
+const MUL: i32x4 = unsafe { simd_mul(A, B) };
+const SHR: i32x4 = unsafe { simd_shr(B, i32x4(1, 2, 3, 0)) };
+const LT: i32x4 = unsafe { simd_lt(A, B) };
+const SELECT: i32x4 = unsafe { simd_select(LT, A, B) };
+const BITMASK: u8 = unsafe { simd_bitmask(LT) };
+const CAST: u8x4 = unsafe { simd_cast(A) };
+const SATURATING: i32x4 = unsafe { simd_saturating_add(A, B) };
+const SHUFFLE: i32x2 = unsafe { simd_shuffle2(A, B, [3, 4]) };
+const SUM: i32 = unsafe { simd_reduce_add_ordered(B, 100) };
+const MAX: i32 = unsafe { simd_reduce_max(A) };
+const ANY: bool = unsafe { simd_reduce_any(i32x4(0, 0, 1, 0)) };
+const FMIN: f32x4 =
+    unsafe { simd_fmin(f32x4(1.0, 2.0, 0.0 / 0.0, 4.0), f32x4(2.0, 1.0, 3.0, 4.0)) };
+const FSQRT: f32x4 = unsafe { simd_fsqrt(f32x4(1.0, 4.0, 9.0, 2.25)) };
+
+fn main() {
+    assert_eq!(ADD, i32x4(11, 18, -27, i32::min_value()));
+    assert_eq!(MUL, i32x4(10, -40, -90, i32::max_value()));
+    assert_eq!(SHR, i32x4(5, 5, -4, 1));
+    assert_eq!(LT, i32x4(-1, -1, 0, 0));
+    assert_eq!(SELECT, i32x4(1, -2, -30, 1));
+    assert_eq!(BITMASK, 0b0011);
+    assert_eq!(CAST, u8x4(1, 254, 3, 255));
+    assert_eq!(SATURATING, i32x4(11, 18, -27, i32::max_value()));
+    assert_eq!(SHUFFLE, i32x2(i32::max_value(), 10));
+    assert_eq!(SUM, 101);
+    assert_eq!(MAX, i32::max_value());
+    assert!(ANY);
+    assert_eq!(FMIN, f32x4(1.0, 1.0, 3.0, 4.0));
+    assert_eq!(FSQRT, f32x4(1.0, 2.0, 3.0, 1.5));
+}
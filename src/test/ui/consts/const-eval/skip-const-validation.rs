@@ -0,0 +1,9 @@
+// check-pass
+// compile-flags: -Z skip-const-validation
+
+#![allow(dead_code)]
+
+const BAD_BOOL: bool = unsafe { std::mem::transmute(3u8) };
+//~^ WARN the value of this item was not validated
+
+fn main() {}
@@ -0,0 +1,16 @@
+// compile-flags: -Z const-strict-provenance
+#![feature(const_raw_ptr_to_usize_cast)]
+
+const fn make_ptr(addr: usize) -> *const u8 {
+    addr as *const u8
+    //~^ ERROR any use of this value will cause an error
+}
+
+const INT_TO_PTR: *const u8 = make_ptr(4);
+
+const PTR_TO_INT: usize = unsafe { &0u8 as *const u8 as usize };
+//~^ ERROR any use of this value will cause an error
+
+const NULL: *const u8 = std::ptr::null();
+
+fn main() {}
@@ -0,0 +1,50 @@
+// run-pass
+
+#![feature(const_transmute)]
+
+use std::mem::{size_of, transmute};
+
+#[cfg(target_pointer_width = "64")]
+type Halves = (u32, u32);
+#[cfg(target_pointer_width = "32")]
+type Halves = (u16, u16);
+
+const REF: &i32 = &42;
+const STR: &str = "hello";
+
+// A reference survives a trip through an array of bytes.
+const FROM_BYTES: &i32 = unsafe {
+    let bytes: [u8; size_of::<&i32>()] = transmute(REF);
+    transmute(bytes)
+};
+
+// Fat pointers can be taken apart into their words and put back together.
+const FROM_WORDS: &str = unsafe {
+    let words: [usize; 2] = transmute(STR);
+    transmute(words)
+};
+
+// Copying a pair whose fields each hold a part of a pointer keeps the pointer intact.
+const fn pass_halves(halves: Halves) -> Halves {
+    halves
+}
+
+const FROM_HALVES: &i32 = unsafe {
+    let halves: Halves = transmute(REF);
+    transmute(pass_halves(halves))
+};
+
+// Overwriting a pointer with an integer discards the pointer, and the memory holds a plain
+// integer afterwards.
+const DISCARDED: [usize; 2] = unsafe {
+    let mut words: [usize; 2] = transmute(STR);
+    words[0] = 7;
+    words
+};
+
+fn main() {
+    assert_eq!(*FROM_BYTES, 42);
+    assert_eq!(FROM_WORDS, "hello");
+    assert_eq!(*FROM_HALVES, 42);
+    assert_eq!(DISCARDED, [7, 5]);
+}
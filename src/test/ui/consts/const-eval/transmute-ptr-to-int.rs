@@ -0,0 +1,15 @@
+#![feature(const_transmute)]
+
+use std::mem::{size_of, transmute};
+
+type Bytes = [u8; size_of::<&'static i32>()];
+
+const REF: &i32 = &42;
+
+const ADDR_PLUS_ONE: usize = unsafe { transmute::<&i32, usize>(REF) + 1 };
+//~^ ERROR any use of this value will cause an error
+
+const FIRST_BYTE: u8 = unsafe { transmute::<&i32, Bytes>(REF)[0] };
+//~^ ERROR any use of this value will cause an error
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![feature(core_intrinsics)]
+#![feature(core_intrinsics, const_int_unchecked_arith)]
 
 use std::intrinsics;
 
@@ -117,4 +117,13 @@ const SHR_I64_NEG_RANDOM: i64 = unsafe { intrinsics::unchecked_shr(5_i64, -30) }
 const SHR_I128_NEG_RANDOM: i128 = unsafe { intrinsics::unchecked_shr(5_i128, -93) };
 //~^ ERROR any use of this value will cause an error
 
+// unchecked arithmetic whose result overflows:
+
+const ADD_U8_OVERFLOW: u8 = unsafe { intrinsics::unchecked_add(255_u8, 1) };
+//~^ ERROR any use of this value will cause an error
+const SUB_I32_OVERFLOW: i32 = unsafe { intrinsics::unchecked_sub(i32::min_value(), 1) };
+//~^ ERROR any use of this value will cause an error
+const MUL_U64_OVERFLOW: u64 = unsafe { intrinsics::unchecked_mul(u64::max_value(), 2) };
+//~^ ERROR any use of this value will cause an error
+
 fn main() {}
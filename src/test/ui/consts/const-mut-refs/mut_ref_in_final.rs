@@ -0,0 +1,19 @@
+#![feature(const_mut_refs)]
+
+// Mutable references to zero-sized pointees are fine in the final value of a constant, as
+// nothing can be mutated through them.
+const EMPTY: &mut [u8] = &mut [];
+const UNIT: &mut () = &mut ();
+
+const A: &mut i32 = &mut 42;
+//~^ ERROR it is undefined behavior to use this value
+
+const B: (u8, &mut [u8; 2]) = (0, &mut [1, 2]);
+//~^ ERROR it is undefined behavior to use this value
+
+// Mutable references behind shared ones in statics are effectively immutable.
+static C: &mut i32 = &mut 42;
+
+fn main() {
+    let _ = (EMPTY, UNIT, &C);
+}
@@ -0,0 +1,21 @@
+// `-Z const-eval-limit` takes precedence over `#[const_eval_limit]`, and `0` disables the limit.
+
+// check-pass
+// compile-flags: -Z const-eval-limit=0
+
+#![feature(const_eval_limit)]
+#![feature(const_loop, const_if_match)]
+#![const_eval_limit = "500"]
+
+const X: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        x += 1;
+    }
+
+    x
+};
+
+fn main() {
+    assert_eq!(X, 1000);
+}
@@ -0,0 +1,13 @@
+// Check that the values of `#[const_eval_limit]` must be non-negative integers.
+
+#![feature(const_eval_limit)]
+#![const_eval_limit = "-1"]
+//~^ ERROR `#[const_eval_limit]` must be a non-negative integer
+
+#[const_eval_limit = "a lot"]
+//~^ ERROR `#[const_eval_limit]` must be a non-negative integer
+const X: usize = 1;
+
+fn main() {
+    let _: [u8; X] = [0];
+}
@@ -0,0 +1,21 @@
+// A `#[const_eval_limit]` on an item takes precedence over the limit of the crate.
+
+// check-pass
+
+#![feature(const_eval_limit)]
+#![feature(const_loop, const_if_match)]
+#![const_eval_limit = "500"]
+
+#[const_eval_limit = "100000"]
+const X: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        x += 1;
+    }
+
+    x
+};
+
+fn main() {
+    assert_eq!(X, 1000);
+}
@@ -0,0 +1,15 @@
+#![feature(const_eval_limit)]
+#![feature(const_loop, const_if_match)]
+#![const_eval_limit = "500"]
+
+const X: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        //~^ ERROR any use of this value will cause an error
+        x += 1;
+    }
+
+    x
+};
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Check that a long-running constant evaluation is linted, and that its progress is reported
+// until it finishes or hits the step limit.
+
+#![feature(const_eval_limit, const_loop)]
+#![const_eval_limit = "300000"]
+
+const X: () = loop {};
+//~^ WARNING evaluation of `X` is taking a long time
+//~| ERROR any use of this value will cause an error
+
+// Allowing the lint silences all the reports. `Y` takes more than 300000 steps, so it needs a
+// limit of its own.
+#[allow(long_running_const_eval)]
+#[const_eval_limit = "0"]
+const Y: usize = {
+    let mut y = 0;
+    while y != 100_000 {
+        y += 1;
+    }
+    y
+};
+
+fn main() {}
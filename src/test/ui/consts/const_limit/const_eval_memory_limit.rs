@@ -0,0 +1,15 @@
+// compile-flags: -Z const-eval-memory-limit=1024
+
+const SMALL: u8 = {
+    let a = [0u8; 512];
+    a[0]
+};
+
+const BIG: u8 = {
+    let a = [0u8; 512];
+    let b = [1u8; 1024];
+    //~^ ERROR any use of this value will cause an error
+    a[0] + b[0]
+};
+
+fn main() {}
@@ -0,0 +1,12 @@
+// compile-flags: -Z const-eval-stack-limit=10
+
+#![feature(const_if_match)]
+
+const fn depth(n: u32) -> u32 {
+    if n == 0 { 0 } else { depth(n - 1) + 1 }
+    //~^ ERROR could not evaluate static initializer
+}
+
+static X: u32 = depth(100);
+
+fn main() {}
@@ -0,0 +1,18 @@
+// A `#[const_eval_stack_limit]` on an item raises the maximum number of stack frames of its
+// evaluation above the recursion limit of the crate.
+
+// check-pass
+
+#![feature(const_eval_limit)]
+#![feature(const_if_match)]
+
+const fn depth(n: u32) -> u32 {
+    if n == 0 { 0 } else { depth(n - 1) + 1 }
+}
+
+#[const_eval_stack_limit = "300"]
+const X: u32 = depth(200);
+
+fn main() {
+    assert_eq!(X, 200);
+}
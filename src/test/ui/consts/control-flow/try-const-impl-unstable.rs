@@ -0,0 +1,16 @@
+// The const impls of `Try` are unstable.
+
+#![feature(const_if_match)]
+#![feature(const_trait_impl)]
+#![allow(incomplete_features)]
+
+const fn opt() -> Option<i32> {
+    let x = Some(2);
+    x?;
+    //~^ ERROR `std::option::<impl std::ops::Try for std::option::Option<T>>::into_result` is not
+    //~| ERROR `std::convert::<impl std::convert::From<T> for T>::from` is not yet stable
+    //~| ERROR `std::option::<impl std::ops::Try for std::option::Option<T>>::from_error` is not
+    None
+}
+
+fn main() {}
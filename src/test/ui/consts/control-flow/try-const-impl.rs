@@ -0,0 +1,38 @@
+// The `?` operator can be used in const fns, through the const impls of `Try` for `Option` and
+// `Result`.
+
+// run-pass
+
+#![feature(const_if_match)]
+#![feature(const_trait_impl)]
+#![feature(const_try)]
+#![allow(incomplete_features)]
+
+const fn add_opt(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+    Some(a? + b?)
+}
+
+type Res = Result<i32, &'static str>;
+
+const fn add_res(a: Res, b: Res) -> Res {
+    Ok(a? + b?)
+}
+
+const fn first<T: Copy>(a: Option<T>) -> Option<T> {
+    let x = a?;
+    Some(x)
+}
+
+const SOME: Option<i32> = add_opt(Some(1), Some(2));
+const NONE: Option<i32> = add_opt(Some(1), None);
+const OK: Res = add_res(Ok(1), Ok(2));
+const ERR: Res = add_res(Ok(1), Err("b"));
+const FIRST: Option<u8> = first(Some(3));
+
+fn main() {
+    assert_eq!(SOME, Some(3));
+    assert_eq!(NONE, None);
+    assert_eq!(OK, Ok(3));
+    assert_eq!(ERR, Err("b"));
+    assert_eq!(FIRST, Some(3));
+}
@@ -1,5 +1,5 @@
-// The `?` operator is still not const-evaluatable because it calls `From::from` on the error
-// variant.
+// The `?` operator calls the methods of `Try` and `From`, so it also requires
+// `#![feature(const_trait_impl)]`.
 
 #![feature(const_if_match)]
 
@@ -0,0 +1,15 @@
+// Checks that `-Z ctfe-profile` reports the evaluation of each constant.
+
+// compile-flags: -Z ctfe-profile
+// build-pass
+// ignore-pass
+// normalize-stdout-test "\d+\.\d{3}ms" -> "$$TIME"
+// normalize-stdout-test "\d+ steps" -> "$$STEPS steps"
+
+#![crate_type = "lib"]
+
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+pub const FOO: u32 = double(21);
@@ -0,0 +1,12 @@
+// Checks that `-Z print-ctfe-stats` reports the memory interned by the constants of the crate.
+
+// compile-flags: -Z print-ctfe-stats
+// build-pass
+// ignore-pass
+// normalize-stdout-test "\d+ requests" -> "$$REQUESTS requests"
+// normalize-stdout-test "\d+\.\d% cache hits" -> "$$HIT_RATE cache hits"
+
+#![crate_type = "lib"]
+
+pub const SMALL: u8 = 1;
+pub const LARGE: [u32; 4] = [1, 2, 3, 4];
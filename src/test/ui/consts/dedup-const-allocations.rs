@@ -0,0 +1,31 @@
+// run-pass
+
+// Constants with identical nested allocations share them, which must not be observable apart
+// from the addresses of those allocations.
+
+struct Table<T>(T);
+
+impl<T> Table<T> {
+    const ROWS: &'static [&'static [u8]] = &[&[1, 2, 3], &[4, 5], &[1, 2, 3]];
+}
+
+const A: &[&[u8]] = &[&[1, 2, 3], &[4, 5]];
+const B: &[&[u8]] = &[&[1, 2, 3], &[4, 5]];
+const C: &[&[u8]] = &[&[1, 2, 3], &[4, 6]];
+
+static S: &[&[u8]] = &[&[1, 2, 3], &[4, 5]];
+static mut M: &mut [u8] = &mut [1, 2, 3];
+
+fn main() {
+    assert_eq!(A, B);
+    assert_eq!(A, S);
+    assert_ne!(A, C);
+    assert_eq!(C[1], &[4, 6]);
+    assert_eq!(Table::<u8>::ROWS, Table::<u16>::ROWS);
+    assert_eq!(Table::<u32>::ROWS[2], A[0]);
+    unsafe {
+        M[0] = 7;
+        assert_eq!(M, &[7, 2, 3]);
+    }
+    assert_eq!(A[0], &[1, 2, 3]);
+}
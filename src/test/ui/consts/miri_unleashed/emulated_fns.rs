@@ -0,0 +1,22 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+
+// Checks that const evaluation emulates some functions of the C library.
+
+extern "C" {
+    fn memcmp(a: *const u8, b: *const u8, n: usize) -> i32;
+    fn strlen(s: *const u8) -> usize;
+}
+
+const LESS: i32 = unsafe { memcmp(b"abc".as_ptr(), b"abd".as_ptr(), 3) };
+//~^ WARN skipping const checks
+const EQUAL: i32 = unsafe { memcmp(b"abc".as_ptr(), b"abd".as_ptr(), 2) };
+//~^ WARN skipping const checks
+const LEN: usize = unsafe { strlen(b"hello\0".as_ptr()) };
+//~^ WARN skipping const checks
+
+fn main() {
+    assert_eq!(LESS, -1);
+    assert_eq!(EQUAL, 0);
+    assert_eq!(LEN, 5);
+}
@@ -0,0 +1,74 @@
+// run-pass
+#![feature(const_if_match)]
+#![feature(const_loop)]
+#![feature(const_ptr_offset)]
+#![feature(const_ptr_offset_from)]
+#![feature(const_raw_ptr_deref)]
+#![feature(const_raw_ptr_to_usize_cast)]
+#![feature(ptr_offset_from)]
+
+#[repr(C)]
+struct Struct {
+    a: u32,
+    b: u32,
+    c: u32,
+}
+const S: Struct = Struct { a: 0, b: 0, c: 0 };
+
+// Pointers cannot be compared in const contexts, so `offset_from` is used to check that two
+// pointers are equal.
+
+const OFFSET_NO_CHANGE: isize = unsafe {
+    let s = S;
+    let p1 = &s.b as *const u32;
+    let p2 = p1.offset(2).offset(-2);
+    p1.offset_from(p2)
+};
+
+const ADD_SUB: isize = unsafe {
+    let s = S;
+    let p1 = &s.a as *const u32;
+    let p2 = p1.add(3).sub(1);
+    p2.offset_from(p1)
+};
+
+const OFFSET_MUT: isize = unsafe {
+    let data = [0u8; 4];
+    let p1 = data.as_ptr() as *mut u8;
+    let p2 = p1.add(4).offset(-1);
+    p2.offset_from(p1)
+};
+
+// Wrapping arithmetic may leave the allocation, as long as the pointer is not used out of
+// bounds.
+const WRAPPING_OUT_OF_BOUNDS: isize = unsafe {
+    let s = S;
+    let p1 = &s.c as *const u32;
+    let p2 = p1.wrapping_add(100).wrapping_sub(101);
+    p1.offset_from(p2)
+};
+
+const WRAPPING_INT: usize = (16 as *const u16).wrapping_offset(-4) as usize;
+
+// A slice iterator written without casting any pointer to `usize`.
+const fn sum(s: &[u32]) -> u32 {
+    let mut p = s.as_ptr();
+    let end = unsafe { p.add(s.len()) };
+    let mut sum = 0;
+    while unsafe { end.offset_from(p) } > 0 {
+        sum += unsafe { *p };
+        p = unsafe { p.add(1) };
+    }
+    sum
+}
+
+const SUM: u32 = sum(&[1, 2, 3, 4]);
+
+fn main() {
+    assert_eq!(OFFSET_NO_CHANGE, 0);
+    assert_eq!(ADD_SUB, 2);
+    assert_eq!(OFFSET_MUT, 3);
+    assert_eq!(WRAPPING_OUT_OF_BOUNDS, 1);
+    assert_eq!(WRAPPING_INT, 8);
+    assert_eq!(SUM, 10);
+}
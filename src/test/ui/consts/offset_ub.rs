@@ -0,0 +1,12 @@
+// FIXME: missing sysroot spans (#53081)
+// ignore-i586-unknown-linux-gnu
+// ignore-i586-unknown-linux-musl
+// ignore-i686-unknown-linux-musl
+
+#![feature(const_ptr_offset)]
+
+pub const NULL: *const u8 = unsafe { (0 as *const u8).offset(0) }; //~ NOTE
+pub const NOT_PTR: *const u8 = unsafe { (42 as *const u8).offset(1) }; //~ NOTE
+pub const OVERFLOW: *const u16 = unsafe { [0u16; 1].as_ptr().offset(isize::max_value()) }; //~ NOTE
+
+fn main() {}
@@ -0,0 +1,36 @@
+// run-pass
+#![feature(const_raw_ptr_comparison)]
+
+use std::ptr;
+
+const FOO: i32 = 42;
+
+// Integer addresses can always be compared.
+const INT_EQ: Option<bool> = (4 as *const i32).guaranteed_eq(4 as *const i32);
+const INT_NE: Option<bool> = (4 as *const i32).guaranteed_ne(8 as *const i32);
+
+// An in-bounds pointer is never null.
+const NOT_NULL: Option<bool> = (&FOO as *const i32).guaranteed_eq(ptr::null());
+const NOT_NULL_MUT: Option<bool> =
+    ptr::null_mut::<i32>().guaranteed_ne(&FOO as *const i32 as *mut i32);
+
+// Whether a pointer has a given address, or where two allocations are placed relative to each
+// other, is not known at compile time.
+const PTR_INT: Option<bool> = (&FOO as *const i32).guaranteed_eq(4 as *const i32);
+const PTR_PTR: Option<bool> = (&FOO as *const i32).guaranteed_eq(&FOO as *const i32);
+
+fn main() {
+    assert_eq!(INT_EQ, Some(true));
+    assert_eq!(INT_NE, Some(true));
+    assert_eq!(NOT_NULL, Some(false));
+    assert_eq!(NOT_NULL_MUT, Some(true));
+    assert_eq!(PTR_INT, None);
+    assert_eq!(PTR_PTR, None);
+
+    // At runtime the result is always known.
+    let x = 0;
+    let p = &x as *const i32;
+    assert_eq!(p.guaranteed_eq(p), Some(true));
+    assert_eq!(p.guaranteed_ne(p), Some(false));
+    assert_eq!(p.guaranteed_eq(ptr::null()), Some(false));
+}
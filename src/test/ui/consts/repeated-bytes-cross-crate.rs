@@ -0,0 +1,22 @@
+// run-pass
+// aux-build:repeated-bytes.rs
+
+// Allocations with runs of equal bytes are serialized in a compact form, check that they are
+// restored correctly.
+
+extern crate repeated_bytes;
+
+use repeated_bytes::{mixed, PATTERN, ZEROS};
+
+fn main() {
+    assert_eq!(ZEROS.len(), 100);
+    assert!(ZEROS.iter().all(|&b| b == 0));
+    assert_eq!(PATTERN.len(), 21);
+    assert_eq!(PATTERN[..2], [1, 2]);
+    assert!(PATTERN[2..20].iter().all(|&x| x == 7));
+    assert_eq!(PATTERN[20], 3);
+    let mixed = mixed();
+    assert_eq!(mixed[..3], [1, 2, 3]);
+    assert!(mixed[3..38].iter().all(|&b| b == 0));
+    assert_eq!(mixed[38..], [4, 5]);
+}
@@ -0,0 +1,33 @@
+#![feature(derive_smart_pointer)]
+
+use std::marker::SmartPointer;
+
+#[derive(SmartPointer)]
+enum NotStruct<'a, T: ?Sized> { Pointer(&'a T) }
+//~^ ERROR `SmartPointer` can only be derived on `struct`s
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct NoGenerics<'a>(&'a u8);
+//~^ ERROR `SmartPointer` can only be derived on `struct`s that are generic over a type
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct AmbiguousPointee<'a, T1: ?Sized, T2: ?Sized>((&'a T1, &'a T2));
+//~^ ERROR exactly one generic type parameter must be marked as `#[pointee]`
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct TooManyPointees<'a, #[pointee] A: ?Sized, #[pointee] B: ?Sized>((&'a A, &'a B));
+//~^ ERROR only one type parameter can be marked as `#[pointee]`
+
+#[derive(SmartPointer)]
+struct NotTransparent<'a, T: ?Sized>(&'a T);
+//~^ ERROR `SmartPointer` can only be derived on `#[repr(transparent)]` structs
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct NotMaybeSized<'a, T>(&'a T);
+//~^ ERROR `SmartPointer` requires the `#[pointee]` type parameter to be `?Sized`
+
+fn main() {}
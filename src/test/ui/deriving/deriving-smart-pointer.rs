@@ -0,0 +1,48 @@
+// run-pass
+#![feature(derive_smart_pointer, arbitrary_self_types)]
+
+use std::marker::SmartPointer;
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct MyPointer<'a, #[pointee] T: ?Sized> {
+    ptr: &'a T,
+}
+
+#[derive(SmartPointer)]
+#[repr(transparent)]
+struct MyBox<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> std::ops::Deref for MyPointer<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.ptr
+    }
+}
+
+trait MyTrait {
+    fn by_pointer(self: MyPointer<'_, Self>) -> usize;
+    fn by_ref(&self) -> usize;
+}
+
+impl<T> MyTrait for [T; 3] {
+    fn by_pointer(self: MyPointer<'_, Self>) -> usize {
+        self.len()
+    }
+    fn by_ref(&self) -> usize {
+        self.len()
+    }
+}
+
+fn main() {
+    let array = [1, 2, 3];
+    let slice: MyPointer<'_, [i32]> = MyPointer { ptr: &array };
+    assert_eq!(slice.len(), 3);
+
+    let object: MyPointer<'_, dyn MyTrait> = MyPointer { ptr: &array };
+    assert_eq!(object.by_ref(), 3);
+    assert_eq!(object.by_pointer(), 3);
+
+    let boxed: MyBox<dyn MyTrait> = MyBox(Box::new([4, 5, 6]));
+    assert_eq!(boxed.0.by_ref(), 3);
+}
@@ -0,0 +1,49 @@
+// run-pass
+// Tests that the errors still return their backtrace through the `Error` trait of `core`, and
+// that the `Backtrace` of `core` is the one that `std` captures.
+
+// ignore-emscripten no backtrace support
+
+#![feature(backtrace, error_in_core)]
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+struct TracedError {
+    backtrace: Backtrace,
+}
+
+impl fmt::Display for TracedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("traced error")
+    }
+}
+
+impl Error for TracedError {
+    fn backtrace(&self) -> Option<&core::backtrace::Backtrace> {
+        Some(&self.backtrace)
+    }
+}
+
+#[derive(Debug)]
+struct UntracedError;
+
+impl fmt::Display for UntracedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("untraced error")
+    }
+}
+
+impl Error for UntracedError {}
+
+fn main() {
+    let error: Box<dyn Error> = Box::new(TracedError { backtrace: Backtrace::force_capture() });
+    let backtrace = error.backtrace().unwrap();
+    assert_ne!(backtrace.status(), BacktraceStatus::Disabled);
+    assert_eq!(format!("{}", backtrace), format!("{:?}", backtrace));
+
+    let error: &dyn core::error::Error = &UntracedError;
+    assert!(error.backtrace().is_none());
+}
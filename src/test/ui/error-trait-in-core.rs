@@ -0,0 +1,32 @@
+// run-pass
+// Tests that the `Error` trait of `core` is the one of `std`, and that the errors and the strings
+// still convert to `Box<dyn Error>`.
+
+#![feature(error_in_core)]
+
+use std::fmt;
+
+#[derive(Debug)]
+struct CoreError;
+
+impl fmt::Display for CoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("core error")
+    }
+}
+
+impl core::error::Error for CoreError {}
+
+fn main() {
+    let boxed: Box<dyn std::error::Error> = CoreError.into();
+    assert!(boxed.is::<CoreError>());
+    assert_eq!(boxed.to_string(), "core error");
+    assert!(boxed.downcast::<CoreError>().is_ok());
+
+    let boxed: Box<dyn std::error::Error + Send + Sync> = "str error".into();
+    assert_eq!(boxed.to_string(), "str error");
+    assert!(boxed.downcast::<CoreError>().is_err());
+
+    let error: &dyn core::error::Error = &CoreError;
+    assert!(error.downcast_ref::<CoreError>().is_some());
+}
@@ -1,17 +1,31 @@
-// run-pass
+// build-fail
+// Extern types have no size or alignment known to Rust, so `size_of_val` and `align_of_val`
+// reject them, also when the type is only known after monomorphization.
 #![feature(extern_types)]
+#![allow(dead_code)]
 
-use std::mem::{size_of_val, align_of_val};
+use std::mem::{align_of_val, size_of_val};
 
-extern {
+extern "C" {
     type A;
 }
 
+struct Tail {
+    len: usize,
+    data: A,
+}
+
+fn generic_size<T: ?Sized>(x: &T) -> usize {
+    size_of_val(x) //~ ERROR cannot compute the size of a value of type `A`
+}
+
 fn main() {
-    let x: &A = unsafe {
-        &*(1usize as *const A)
-    };
+    let x: &A = unsafe { &*(8usize as *const A) };
+    let y: &Tail = unsafe { &*(8usize as *const Tail) };
 
-    assert_eq!(size_of_val(x), 0);
-    assert_eq!(align_of_val(x), 1);
+    size_of_val(x); //~ ERROR cannot compute the size of a value of type `A`
+    align_of_val(x); //~ ERROR cannot compute the alignment of a value of type `A`
+    size_of_val(y); //~ ERROR cannot compute the size of a value of type `Tail`
+    generic_size(x);
+    generic_size(&0u8);
 }
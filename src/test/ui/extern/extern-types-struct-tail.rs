@@ -0,0 +1,32 @@
+// run-pass
+// Structs can end in an extern type, to model C structs with a known header followed by data
+// that Rust must not know about. References to them are thin, and the header can be used.
+#![feature(extern_types)]
+
+use std::mem::size_of;
+
+extern "C" {
+    type Opaque;
+}
+
+#[repr(C)]
+struct Header {
+    len: u32,
+    data: Opaque,
+}
+
+fn header_len(header: &Header) -> u32 {
+    header.len
+}
+
+fn data_ptr(header: &Header) -> *const Opaque {
+    &header.data
+}
+
+fn main() {
+    let storage = [7u32, 1, 2, 3];
+    let header = unsafe { &*(storage.as_ptr() as *const Header) };
+    assert_eq!(size_of::<&Header>(), size_of::<usize>());
+    assert_eq!(header_len(header), 7);
+    assert_eq!(data_ptr(header) as usize, &storage[1] as *const u32 as usize);
+}
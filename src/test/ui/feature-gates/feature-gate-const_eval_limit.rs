@@ -0,0 +1,7 @@
+#![const_eval_limit = "42"] //~ ERROR the `#[const_eval_limit]` attribute is an experimental feature
+
+#[const_eval_stack_limit = "42"]
+//~^ ERROR the `#[const_eval_stack_limit]` attribute is an experimental feature
+const X: u32 = 0;
+
+fn main() {}
@@ -0,0 +1,9 @@
+// Without `#![feature(deref_patterns)]`, patterns do not match through smart pointers.
+
+fn main() {
+    let s = String::from("foo");
+    match s {
+        "foo" => {} //~ ERROR mismatched types
+        _ => {}
+    }
+}
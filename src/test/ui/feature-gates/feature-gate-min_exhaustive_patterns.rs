@@ -0,0 +1,12 @@
+#![feature(never_type)]
+
+// An uninhabited field makes a struct uninhabited where the field is visible.
+pub struct Void {
+    _priv: !,
+}
+
+fn empty_match(x: Void) -> ! {
+    match x {} //~ ERROR non-exhaustive patterns
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+// only-aarch64
+
+#![feature(repr_simd)]
+
+#[repr(simd, scalable(4))] //~ ERROR scalable vector types are experimental
+struct Foo(f32);
+
+fn main() {}
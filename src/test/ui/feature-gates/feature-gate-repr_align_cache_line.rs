@@ -0,0 +1,4 @@
+#[repr(align(cache_line))] //~ ERROR `#[repr(align(cache_line))]` is experimental
+struct Padded(u8);
+
+fn main() {}
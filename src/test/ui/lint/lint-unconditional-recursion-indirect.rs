@@ -0,0 +1,103 @@
+// Recursion through other functions that always call back the function they were called from.
+
+#![deny(unconditional_recursion)]
+#![allow(dead_code)]
+
+use std::ops::Deref;
+
+// Mutual recursion.
+fn ping() { //~ ERROR function cannot return without recursing
+    pong();
+}
+
+fn pong() { //~ ERROR function cannot return without recursing
+    ping();
+}
+
+// Recursion through a default method, which every implementation has to override one of.
+trait Shape {
+    fn area(&self) -> f64 {
+        self.scaled_area(1.0)
+    }
+
+    fn scaled_area(&self, scale: f64) -> f64 {
+        self.area() * scale
+    }
+}
+
+struct Circle(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 { //~ ERROR function cannot return without recursing
+        self.scaled_area(self.0 * self.0)
+    }
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn scaled_area(&self, scale: f64) -> f64 {
+        self.0 * self.0 * scale
+    }
+}
+
+// Recursion through a `Deref` impl.
+struct Wrapper(String);
+
+impl Deref for Wrapper {
+    type Target = str;
+
+    fn deref(&self) -> &str { //~ ERROR function cannot return without recursing
+        self.as_str()
+    }
+}
+
+impl Wrapper {
+    fn as_str(&self) -> &str { //~ ERROR function cannot return without recursing
+        self
+    }
+}
+
+// Recursion through closures.
+fn closure() { //~ ERROR function cannot return without recursing
+    let f = || closure();
+    f();
+}
+
+fn captured_fn_item() { //~ ERROR function cannot return without recursing
+    let g = captured_fn_item;
+    let f = || g();
+    f();
+}
+
+fn captured_fn_ptr() { //~ ERROR function cannot return without recursing
+    let g: fn() = captured_fn_ptr;
+    let f = move || g();
+    f();
+}
+
+// The other function does not always call back.
+fn conditional(n: u32) -> u32 {
+    conditional_helper(n)
+}
+
+fn conditional_helper(n: u32) -> u32 {
+    if n == 0 { 0 } else { conditional(n - 1) }
+}
+
+fn early_return(n: u32) -> u32 {
+    early_return_helper(n)
+}
+
+fn early_return_helper(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    early_return(n - 1)
+}
+
+fn closure_not_called() {
+    let _f = || closure_not_called();
+}
+
+fn main() {}
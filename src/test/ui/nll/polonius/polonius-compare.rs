@@ -0,0 +1,32 @@
+// Check that `-Z polonius=compare` borrow checks with NLL, and warns about the borrow conflicts
+// that only one of NLL and Polonius reports.
+// ignore-compare-mode-nll
+// ignore-compare-mode-polonius
+// ignore-compare-mode-polonius-compare
+// compile-flags: -Z borrowck=mir -Z polonius=compare
+
+struct Map {}
+
+impl Map {
+    fn get(&self) -> Option<&String> {
+        None
+    }
+    fn set(&mut self, _v: String) {}
+}
+
+fn get_or_set(map: &mut Map) -> &String {
+    loop {
+        match map.get() {
+            Some(v) => {
+                return v;
+            }
+            None => {
+                map.set(String::new());
+                //~^ WARNING borrow checker discrepancy
+                //~| ERROR cannot borrow `*map` as mutable because it is also borrowed as immutable
+            }
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// Check that `-Z polonius=next` accepts returning a reference out of a conditional loan that is
+// followed by other mutable borrows on the paths where it is not returned, which NLL rejects.
+// check-pass
+// ignore-compare-mode-nll
+// compile-flags: -Z borrowck=mir -Z polonius=next
+
+use std::collections::HashMap;
+
+fn get_default<'r>(map: &'r mut HashMap<u32, String>, key: u32) -> &'r mut String {
+    match map.get_mut(&key) {
+        Some(value) => value,
+        None => {
+            map.insert(key, String::new());
+            map.get_mut(&key).unwrap()
+        }
+    }
+}
+
+fn main() {
+    let mut map = HashMap::new();
+    get_default(&mut map, 0).push('a');
+}
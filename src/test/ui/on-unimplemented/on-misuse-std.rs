@@ -0,0 +1,23 @@
+// ignore-tidy-linelength
+
+// Test the notes that the `#[rustc_on_misuse]` attributes of the standard library add to the
+// errors about the indexing of maps and about the collections inside of an `Option`.
+
+use std::collections::HashMap;
+
+fn main() {
+    let map: HashMap<String, u32> = HashMap::new();
+    let key = "key";
+    map[&key];
+    //~^ ERROR the trait bound `std::string::String: std::borrow::Borrow<&str>` is not satisfied
+
+    let values: Option<Vec<u32>> = None;
+    values.first();
+    //~^ ERROR no method named `first` found
+    values[0];
+    //~^ ERROR cannot index into a value of type `std::option::Option<std::vec::Vec<u32>>`
+
+    let boxed: Option<Box<u32>> = None;
+    *boxed;
+    //~^ ERROR type `std::option::Option<std::boxed::Box<u32>>` cannot be dereferenced
+}
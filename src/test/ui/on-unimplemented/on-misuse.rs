@@ -0,0 +1,23 @@
+// Test that the `#[rustc_on_misuse]` attribute of a type adds notes to the errors about the
+// operations that the type does not support.
+
+#![feature(rustc_attrs)]
+
+#[rustc_on_misuse(
+    on(op = "index", note = "`{Self}` is indexed with `Wrapper::get`"),
+    on(op = "deref", note = "`{Self}` cannot be dereferenced to its `{T}`"),
+    on(all(op = "method", from_method = "unwrapped"), note = "use `{Wrapper}::into_inner`")
+)]
+struct Wrapper<T>(T);
+
+fn main() {
+    let w = Wrapper(vec![1u8]);
+    w[0];
+    //~^ ERROR cannot index into a value of type `Wrapper<std::vec::Vec<u8>>`
+    *w;
+    //~^ ERROR type `Wrapper<std::vec::Vec<u8>>` cannot be dereferenced
+    w.unwrapped();
+    //~^ ERROR no method named `unwrapped` found
+    w.unwrapped_ref();
+    //~^ ERROR no method named `unwrapped_ref` found
+}
@@ -0,0 +1,46 @@
+// Or-patterns nested in many columns used to make exhaustiveness checking take exponential time,
+// as every combination of their alternatives was checked separately.
+
+#![feature(or_patterns)]
+#![allow(incomplete_features)]
+#![deny(unreachable_patterns)]
+
+enum E {
+    A,
+    B,
+    C,
+}
+use E::*;
+
+type T = (
+    E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E,
+    E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E,
+);
+
+fn value() -> T {
+    unimplemented!()
+}
+
+fn main() {
+    // Get the fatal error out of the way
+    match (0,) {
+        (0 | _,) => {}
+        //~^ ERROR or-patterns are not fully implemented yet
+    }
+
+    match value() {
+        (
+            A | B, A | B, A | B, A | B, A | B, A | B, A | B, A | B,
+            A | B, A | B, A | B, A | B, A | B, A | B, A | B, A | B,
+            A | B, A | B, A | B, A | B, A | B, A | B, A | B, A | B,
+            A | B, A | B, A | B, A | B, A | B, A | B, A | B, A | B,
+        ) => {}
+        (
+            C | A, C | A, C | A, C | A, C | A, C | A, C | A, C | A,
+            C | A, C | A, C | A, C | A, C | A, C | A, C | A, C | A,
+            C | A, C | A, C | A, C | A, C | A, C | A, C | A, C | A,
+            C | A, C | A, C | A, C | A, C | A, C | A, C | A, C | A,
+        ) => {}
+        _ => {}
+    }
+}
@@ -0,0 +1,44 @@
+// run-pass
+// Checks that `panic::abort_unwind` aborts the process when the closure panics, after the panic
+// hook reported the message.
+
+// ignore-cloudabi no env and process
+// ignore-emscripten no processes
+// ignore-sgx no processes
+// ignore-wasm32-bare compiled with panic=abort by default
+
+#![allow(unused_must_use)]
+#![feature(abort_unwind)]
+
+use std::io::prelude::*;
+use std::io;
+use std::process::{Command, Stdio};
+use std::{env, panic, str};
+
+fn test() {
+    let _ = panic::catch_unwind(|| {
+        panic::abort_unwind(|| panic!("Test"));
+    });
+    // The process should have aborted by now.
+    io::stdout().write(b"This should never be printed.\n");
+    let _ = io::stdout().flush();
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 && args[1] == "test" {
+        return test();
+    }
+
+    assert_eq!(panic::abort_unwind(|| 1 + 1), 2);
+
+    let output = Command::new(&args[0])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .arg("test")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(str::from_utf8(&output.stderr).unwrap().contains("'Test'"));
+}
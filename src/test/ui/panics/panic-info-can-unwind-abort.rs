@@ -0,0 +1,28 @@
+// run-pass
+// Checks that `PanicInfo::can_unwind` is `false` under `-C panic=abort`.
+
+// compile-flags:-C panic=abort
+// no-prefer-dynamic
+// ignore-cloudabi no env and process
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(panic_can_unwind)]
+
+use std::process::Command;
+use std::{env, panic, str};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 && args[1] == "test" {
+        panic::set_hook(Box::new(|info| {
+            eprintln!("can_unwind: {}", info.can_unwind());
+        }));
+        panic!("abort");
+    }
+
+    let output = Command::new(&args[0]).arg("test").output().unwrap();
+    assert!(!output.status.success());
+    let stderr = str::from_utf8(&output.stderr).unwrap();
+    assert!(stderr.starts_with("can_unwind: false\n"), "{}", stderr);
+}
@@ -0,0 +1,66 @@
+// run-pass
+// Checks that `PanicInfo::can_unwind` is `true` for a panic and `false` for a panic in a
+// destructor that runs while unwinding from another one, which aborts the process, and for a
+// panic in a function with `#[unwind(aborts)]`.
+
+// ignore-cloudabi no env and process
+// ignore-emscripten no processes
+// ignore-sgx no processes
+// ignore-wasm32-bare compiled with panic=abort by default
+
+#![feature(panic_can_unwind, unwind_attributes)]
+
+use std::process::Command;
+use std::{env, panic};
+
+struct PanicOnDrop;
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        panic!("second");
+    }
+}
+
+fn set_hook() {
+    panic::set_hook(Box::new(|info| {
+        eprintln!("can_unwind: {}", info.can_unwind());
+    }));
+}
+
+fn test() {
+    set_hook();
+    let _guard = PanicOnDrop;
+    panic!("first");
+}
+
+#[unwind(aborts)]
+extern "Rust" fn panic_in_aborting_fn() {
+    panic!("aborts");
+}
+
+fn test_aborts() {
+    set_hook();
+    let _ = panic::catch_unwind(|| panic_in_aborting_fn());
+}
+
+fn run(mode: &str) -> String {
+    let me = env::args().next().unwrap();
+    let output = Command::new(me).arg(mode).output().unwrap();
+    assert!(!output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 && args[1] == "test" {
+        return test();
+    }
+    if args.len() > 1 && args[1] == "aborts" {
+        return test_aborts();
+    }
+
+    let stderr = run("test");
+    assert!(stderr.starts_with("can_unwind: true\ncan_unwind: false\n"), "{}", stderr);
+    let stderr = run("aborts");
+    assert!(stderr.starts_with("can_unwind: false\n"), "{}", stderr);
+}
@@ -0,0 +1,34 @@
+// run-pass
+// Checks that `PanicInfo::message` returns the message of the panics given a string literal or a
+// formatting string, and only these.
+
+// ignore-emscripten no threads support
+
+#![feature(panic_info_message)]
+
+extern crate core;
+
+use std::cell::RefCell;
+use std::panic;
+
+thread_local! {
+    static MESSAGE: RefCell<Option<String>> = RefCell::new(None);
+}
+
+fn message_of(f: fn()) -> Option<String> {
+    let _ = panic::catch_unwind(f);
+    MESSAGE.with(|m| m.borrow_mut().take())
+}
+
+fn main() {
+    panic::set_hook(Box::new(|info| {
+        let message = info.message().map(|m| m.to_string());
+        MESSAGE.with(|m| *m.borrow_mut() = message);
+    }));
+
+    assert_eq!(message_of(|| panic!("literal")).as_deref(), Some("literal"));
+    assert_eq!(message_of(|| panic!("formatted {}", 1)).as_deref(), Some("formatted 1"));
+    assert_eq!(message_of(|| core::panic!("core {}", 2)).as_deref(), Some("core 2"));
+    assert_eq!(message_of(|| panic!(3)), None);
+    assert_eq!(message_of(|| panic!(String::from("owned"))), None);
+}
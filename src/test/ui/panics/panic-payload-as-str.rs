@@ -0,0 +1,27 @@
+// run-pass
+// ignore-wasm32-bare compiled with panic=abort by default
+
+#![feature(panic_payload_as_str)]
+
+use std::cell::RefCell;
+use std::panic;
+
+thread_local! {
+    static PAYLOADS: RefCell<Vec<Option<String>>> = RefCell::new(Vec::new());
+}
+
+fn main() {
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload_as_str().map(str::to_owned);
+        PAYLOADS.with(|payloads| payloads.borrow_mut().push(payload));
+    }));
+
+    let _ = panic::catch_unwind(|| panic!("static"));
+    let _ = panic::catch_unwind(|| panic!("formatted {}", 1));
+    let _ = panic::catch_unwind(|| panic!(String::from("owned")));
+    let _ = panic::catch_unwind(|| panic!(1));
+
+    let expected =
+        [Some("static".to_owned()), Some("formatted 1".to_owned()), Some("owned".to_owned()), None];
+    PAYLOADS.with(|payloads| assert_eq!(*payloads.borrow(), expected));
+}
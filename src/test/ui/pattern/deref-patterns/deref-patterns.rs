@@ -0,0 +1,60 @@
+// run-pass
+#![feature(deref_patterns)]
+
+use std::rc::Rc;
+
+fn unbox(b: Box<Option<i32>>) -> i32 {
+    match b {
+        Some(x) => x,
+        None => 0,
+    }
+}
+
+fn rc_ref(rc: &Rc<Option<String>>) -> usize {
+    match rc {
+        Some(s) => s.len(),
+        None => 0,
+    }
+}
+
+fn is_foo(s: String) -> bool {
+    match s {
+        "foo" => true,
+        _ => false,
+    }
+}
+
+fn first(v: Option<String>) -> u8 {
+    match v {
+        Some("a") => 1,
+        Some("b") => 2,
+        Some(_) => 3,
+        None => 4,
+    }
+}
+
+fn pair(rc: Rc<(i32, Box<i32>)>) -> i32 {
+    match rc {
+        (0, _) => 0,
+        (x, y) => x + **y,
+    }
+}
+
+fn main() {
+    assert_eq!(unbox(Box::new(Some(3))), 3);
+    assert_eq!(unbox(Box::new(None)), 0);
+
+    assert_eq!(rc_ref(&Rc::new(Some("abc".to_string()))), 3);
+    assert_eq!(rc_ref(&Rc::new(None)), 0);
+
+    assert!(is_foo("foo".to_string()));
+    assert!(!is_foo("bar".to_string()));
+
+    assert_eq!(first(Some("a".to_string())), 1);
+    assert_eq!(first(Some("b".to_string())), 2);
+    assert_eq!(first(Some("c".to_string())), 3);
+    assert_eq!(first(None), 4);
+
+    assert_eq!(pair(Rc::new((0, Box::new(1)))), 0);
+    assert_eq!(pair(Rc::new((2, Box::new(3)))), 5);
+}
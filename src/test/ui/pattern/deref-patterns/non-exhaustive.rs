@@ -0,0 +1,11 @@
+#![feature(deref_patterns)]
+
+use std::rc::Rc;
+
+fn main() {
+    let rc = Rc::new(true);
+    match rc {
+        //~^ ERROR non-exhaustive patterns: `false` not covered
+        true => {}
+    }
+}
@@ -0,0 +1,39 @@
+#![feature(exclusive_range_pattern)]
+#![allow(illegal_floating_point_literal_pattern)]
+#![deny(unreachable_patterns)]
+
+use std::f64::{INFINITY, NEG_INFINITY};
+
+fn main() {
+    let x: f64 = 0.0;
+
+    // Ranges covered by several ranges together.
+    match x {
+        0.0..=1.0 => {}
+        1.0..=2.0 => {}
+        0.5..=1.5 => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+
+    // `-0.0` is equal to `0.0`.
+    match x {
+        0.0 => {}
+        -0.0 => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+    match x {
+        -1.0..0.0 => {}
+        0.0 => {}
+        -1.0..=0.0 => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+
+    // NaN is not covered by any range.
+    match x {
+        NEG_INFINITY..=INFINITY => {}
+        _ => {}
+    }
+    match x { //~ ERROR non-exhaustive patterns: `_` not covered
+        NEG_INFINITY..=INFINITY => {}
+    }
+}
@@ -0,0 +1,20 @@
+#![feature(never_type)]
+#![feature(min_exhaustive_patterns)]
+
+mod private {
+    pub struct Void {
+        _priv: !,
+    }
+
+    // The field is visible here, so `Void` is known to be uninhabited.
+    pub fn empty_match_inside(x: Void) -> ! {
+        match x {}
+    }
+}
+
+// The field is private, so `Void` may not be known to be uninhabited here.
+fn empty_match_outside(x: private::Void) -> ! {
+    match x {} //~ ERROR non-exhaustive patterns
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// The number of witnesses of non-exhaustiveness grows exponentially with the number of columns
+// here, so only some of them are computed.
+
+enum Letter {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+}
+use Letter::*;
+
+fn main() {
+    let x = (A, A, A, A);
+    match x {
+        //~^ ERROR non-exhaustive patterns
+        (A, _, _, _) => {}
+        (_, A, _, _) => {}
+        (_, _, A, _) => {}
+        (_, _, _, A) => {}
+    }
+}
@@ -0,0 +1,28 @@
+// run-pass
+#![feature(cache_line_align, repr_align_cache_line)]
+
+use std::mem;
+
+#[repr(align(cache_line))]
+struct Padded(u8);
+
+#[repr(C, align(cache_line))]
+struct Pair {
+    a: Padded,
+    b: Padded,
+}
+
+fn main() {
+    assert!(mem::CACHE_LINE_ALIGN.is_power_of_two());
+    assert_eq!(mem::align_of::<Padded>(), mem::CACHE_LINE_ALIGN);
+    assert_eq!(mem::size_of::<Padded>(), mem::CACHE_LINE_ALIGN);
+    assert_eq!(mem::size_of::<Pair>(), 2 * mem::CACHE_LINE_ALIGN);
+
+    if cfg!(any(target_arch = "powerpc64", all(target_arch = "aarch64", target_os = "ios"))) {
+        assert_eq!(mem::CACHE_LINE_ALIGN, 128);
+    } else if cfg!(target_arch = "s390x") {
+        assert_eq!(mem::CACHE_LINE_ALIGN, 256);
+    } else if cfg!(target_arch = "x86_64") {
+        assert_eq!(mem::CACHE_LINE_ALIGN, 64);
+    }
+}
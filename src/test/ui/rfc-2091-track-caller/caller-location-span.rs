@@ -0,0 +1,51 @@
+// run-pass
+
+#![feature(track_caller, panic_location_span, const_fn)]
+
+use std::panic::Location;
+
+const SOURCE: &str = include_str!("caller-location-span.rs");
+
+#[inline(never)]
+#[track_caller]
+fn codegen_caller_loc(_: u32) -> &'static Location<'static> {
+    Location::caller()
+}
+
+#[track_caller]
+const fn const_caller_loc(_: u32) -> &'static Location<'static> {
+    Location::caller()
+}
+
+macro_rules! caller_location_from_macro {
+    ($e:expr) => (codegen_caller_loc($e));
+}
+
+fn source_of(loc: &Location<'_>) -> &'static str {
+    let offset = loc.byte_offset().unwrap() as usize;
+    &SOURCE[offset..offset + loc.byte_len().unwrap() as usize]
+}
+
+const CONST_LOC: &Location<'static> = const_caller_loc(4);
+
+fn main() {
+    let loc = codegen_caller_loc(1 + 2);
+    assert_eq!(loc.column(), 15);
+    assert_eq!(loc.column_end(), Some(40));
+    assert_eq!(source_of(loc), "codegen_caller_loc(1 + 2)");
+
+    // The location of a call that spans several lines has no end column.
+    let loc = codegen_caller_loc(
+        3,
+    );
+    assert_eq!(loc.column_end(), None);
+    assert_eq!(source_of(loc), "codegen_caller_loc(\n        3,\n    )");
+
+    // A call in a macro is located at the macro invocation.
+    let loc = caller_location_from_macro!(5);
+    assert_eq!(source_of(loc), "caller_location_from_macro!(5)");
+
+    assert_eq!(CONST_LOC.column(), 39);
+    assert_eq!(CONST_LOC.column_end(), Some(58));
+    assert_eq!(source_of(CONST_LOC), "const_caller_loc(4)");
+}
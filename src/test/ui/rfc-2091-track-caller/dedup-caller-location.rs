@@ -0,0 +1,39 @@
+// run-pass
+
+#![feature(const_fn, track_caller)]
+
+use std::panic::Location;
+
+#[track_caller]
+const fn tracked() -> &'static Location<'static> {
+    Location::caller()
+}
+
+#[track_caller]
+fn runtime_tracked() -> &'static Location<'static> {
+    Location::caller()
+}
+
+// Both calls are attributed to the invocation of the macro, so they have the same location.
+macro_rules! const_pair {
+    () => {
+        (tracked(), tracked())
+    };
+}
+
+macro_rules! runtime_pair {
+    () => {
+        (runtime_tracked(), runtime_tracked())
+    };
+}
+
+const PAIR: (&Location, &Location) = const_pair!();
+
+fn main() {
+    assert_eq!((PAIR.0.line(), PAIR.0.column()), (PAIR.1.line(), PAIR.1.column()));
+    assert!(std::ptr::eq(PAIR.0, PAIR.1));
+
+    let (a, b) = runtime_pair!();
+    assert_eq!((a.line(), a.column()), (b.line(), b.column()));
+    assert!(std::ptr::eq(a, b));
+}
@@ -0,0 +1,14 @@
+// edition:2018
+
+#![feature(generators, stmt_expr_attributes, track_caller)]
+
+fn main() {
+    let _ = #[track_caller] async {};
+    //~^ ERROR `#[track_caller]` may not be used on generators or async blocks
+
+    let _ = #[track_caller] || yield;
+    //~^ ERROR `#[track_caller]` may not be used on generators or async blocks
+
+    let _ = #[track_caller] 1;
+    //~^ ERROR attribute should be applied to function
+}
@@ -0,0 +1,47 @@
+// run-pass
+
+#![feature(stmt_expr_attributes)]
+#![feature(track_caller)]
+
+use std::panic::Location;
+
+type Loc = &'static Location<'static>;
+
+fn call_once<F: FnOnce() -> Loc>(f: F) -> Loc {
+    f()
+}
+
+#[track_caller]
+fn tracked_call_once<F: FnOnce() -> Loc>(f: F) -> Loc {
+    f()
+}
+
+fn main() {
+    let closure = #[track_caller] || Location::caller();
+
+    let loc = closure();
+    assert_eq!(loc.file(), file!());
+    assert_eq!(loc.line(), 22);
+    assert_eq!(loc.column(), 15);
+
+    // Called through `FnOnce`, the shim forwards the location of its own caller.
+    let loc = call_once(closure);
+    assert_eq!(loc.file(), file!());
+    assert_eq!(loc.line(), 11);
+    assert_eq!(loc.column(), 5);
+
+    let loc = tracked_call_once(closure);
+    assert_eq!(loc.file(), file!());
+    assert_eq!(loc.line(), 33);
+    assert_eq!(loc.column(), 15);
+
+    let with_arg = #[track_caller] |x: u32| (x, Location::caller());
+    let (x, loc) = with_arg(7);
+    assert_eq!(x, 7);
+    assert_eq!(loc.line(), 39);
+    assert_eq!(loc.column(), 20);
+
+    // A `fn` pointer cannot pass the caller location, but calling through one still works.
+    let fn_ptr: fn() -> Loc = closure;
+    fn_ptr();
+}
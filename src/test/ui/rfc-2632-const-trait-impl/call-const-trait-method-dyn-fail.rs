@@ -0,0 +1,27 @@
+// Methods of non-const trait impls cannot be called through trait objects during const
+// evaluation.
+
+#![feature(const_trait_impl)]
+#![feature(const_fn)]
+#![allow(incomplete_features)]
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+const fn area(shape: &dyn Shape) -> u32 {
+    shape.area()
+    //~^ ERROR any use of this value will cause an error
+}
+
+const SQUARE: u32 = area(&Square(3));
+
+fn main() {}
@@ -0,0 +1,42 @@
+// Methods of const trait impls can be called through trait objects during const evaluation.
+
+// run-pass
+
+#![feature(const_trait_impl)]
+#![feature(const_fn)]
+#![allow(incomplete_features)]
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+struct Rect(u32, u32);
+
+impl const Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+impl const Shape for Rect {
+    fn area(&self) -> u32 {
+        self.0 * self.1
+    }
+}
+
+const fn area(shape: &dyn Shape) -> u32 {
+    shape.area()
+}
+
+const SQUARE: u32 = area(&Square(3));
+const RECT: u32 = area(&Rect(2, 5));
+const OBJECT: &dyn Shape = &Rect(3, 4);
+const OBJECT_AREA: u32 = OBJECT.area();
+
+fn main() {
+    assert_eq!(SQUARE, 9);
+    assert_eq!(RECT, 10);
+    assert_eq!(OBJECT_AREA, 12);
+    assert_eq!(area(&Square(4)), 16);
+}
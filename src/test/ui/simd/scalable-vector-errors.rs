@@ -0,0 +1,31 @@
+// only-aarch64
+
+#![allow(incomplete_features)]
+#![feature(repr_simd, repr_scalable)]
+
+#[repr(simd, scalable(4))]
+struct Sv(f32);
+
+#[repr(scalable(4))]
+struct NotSimd(f32); //~ ERROR `repr(scalable)` can only be used with `repr(simd)`
+
+#[repr(simd, scalable(4))]
+struct TwoFields(f32, f32); //~ ERROR scalable vectors must have a single field
+
+#[repr(simd, scalable(3))] //~ ERROR invalid `repr(scalable)` attribute
+struct NotPowerOfTwo(f32);
+
+#[repr(scalable(4))] //~ ERROR attribute should be applied to struct
+enum Enum { A }
+
+struct Field {
+    v: Sv, //~ ERROR scalable vector type `Sv` cannot be used in a field
+}
+
+struct Tuple((Sv, u8)); //~ ERROR scalable vector type `(Sv, u8)` cannot be used in a field
+
+enum Variant {
+    A([Sv; 2]), //~ ERROR scalable vector type `[Sv; 2]` cannot be used in a field
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+// only-x86_64
+
+#![allow(incomplete_features)]
+#![feature(repr_simd, repr_scalable)]
+
+#[repr(simd, scalable(4))]
+struct Sv(f32); //~ ERROR scalable vectors are not supported by the target
+
+fn main() {}
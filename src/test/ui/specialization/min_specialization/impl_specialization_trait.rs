@@ -0,0 +1,19 @@
+// Check that specialization traits can't be implemented without a feature.
+
+// gate-test-min_specialization
+
+#![feature(rustc_attrs)]
+
+#[rustc_specialization_trait]
+trait SpecTrait {
+    fn method(&self);
+}
+
+struct A {}
+
+impl SpecTrait for A {
+    //~^ ERROR implementing `rustc_specialization_trait` traits is unstable
+    fn method(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Test that specializing on the same parameter twice isn't allowed.
+
+#![feature(min_specialization)]
+
+trait X {
+    fn f();
+}
+
+impl<T> X for T {
+    default fn f() {}
+}
+
+impl<T> X for (T, T) {}
+//~^ ERROR specializing impl repeats parameter `T`
+
+fn main() {}
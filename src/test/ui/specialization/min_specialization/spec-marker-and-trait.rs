@@ -0,0 +1,34 @@
+// check-pass
+
+// Test that specializing on the well-formed predicates of the trait and
+// self-type of an impl is allowed, as well as specializing on traits marked
+// with `rustc_unsafe_specialization_marker` or `rustc_specialization_trait`.
+
+#![feature(min_specialization)]
+#![feature(rustc_attrs)]
+
+#[rustc_unsafe_specialization_marker]
+trait SpecMarker {}
+
+#[rustc_specialization_trait]
+trait SpecTrait {
+    fn g(&self);
+}
+
+trait X {
+    fn f(&self);
+}
+
+impl<T> X for T {
+    default fn f(&self) {}
+}
+
+impl<T: SpecMarker> X for Vec<T> {
+    fn f(&self) {}
+}
+
+impl<T: SpecTrait> X for Option<T> {
+    fn f(&self) {}
+}
+
+fn main() {}
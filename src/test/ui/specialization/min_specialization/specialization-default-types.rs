@@ -0,0 +1,15 @@
+// Test that `min_specialization` only allows specializing functions.
+
+#![feature(min_specialization)]
+
+trait Tr {
+    type Ty;
+    fn f();
+}
+
+impl<T> Tr for T {
+    default type Ty = u8; //~ ERROR specialization is unstable
+    default fn f() {}
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Test that `rustc_specialization_trait` requires always applicable impls.
+
+#![feature(min_specialization)]
+#![feature(rustc_attrs)]
+
+#[rustc_specialization_trait]
+trait SpecTrait {
+    fn g(&self);
+}
+
+impl SpecTrait for &'static u8 {
+    //~^ ERROR cannot specialize on `'static` lifetime
+    fn g(&self) {}
+}
+
+impl<T> SpecTrait for (T, T) {
+    //~^ ERROR specializing impl repeats parameter `T`
+    fn g(&self) {}
+}
+
+impl<T: Clone> SpecTrait for [T] {
+    //~^ ERROR cannot specialize on trait `std::clone::Clone`
+    fn g(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Test that directly specializing on `'static` is not allowed.
+
+#![feature(min_specialization)]
+
+trait X {
+    fn f();
+}
+
+impl<T> X for &'_ T {
+    default fn f() {}
+}
+
+impl X for &'static u8 {}
+//~^ ERROR cannot specialize on `'static` lifetime
+
+fn main() {}
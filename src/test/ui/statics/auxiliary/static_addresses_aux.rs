@@ -0,0 +1,17 @@
+pub struct Entry {
+    pub name: &'static str,
+    pub next: Option<&'static Entry>,
+}
+
+// Private, but reachable through the value of `SECOND`.
+static FIRST: Entry = Entry { name: "first", next: None };
+
+pub static SECOND: Entry = Entry { name: "second", next: Some(&FIRST) };
+
+pub static HEAD: &Entry = &SECOND;
+
+fn hook() -> &'static str {
+    "hook"
+}
+
+pub static HOOK: fn() -> &'static str = hook;
@@ -0,0 +1,23 @@
+// run-pass
+// aux-build:static_addresses_aux.rs
+
+// Statics can point to the statics of other crates, including the ones that they only reach
+// by reading the value of another static.
+
+extern crate static_addresses_aux as aux;
+
+use aux::Entry;
+
+static THIRD: Entry = Entry { name: "third", next: Some(aux::HEAD) };
+static TAIL: Option<&Entry> = aux::SECOND.next;
+static HOOK: fn() -> &'static str = aux::HOOK;
+
+fn main() {
+    assert!(std::ptr::eq(THIRD.next.unwrap(), &aux::SECOND));
+
+    let tail = TAIL.unwrap();
+    assert_eq!(tail.name, "first");
+    assert!(std::ptr::eq(tail, aux::SECOND.next.unwrap()));
+
+    assert_eq!(HOOK(), "hook");
+}
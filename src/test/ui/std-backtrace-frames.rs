@@ -0,0 +1,32 @@
+// run-pass
+// ignore-android FIXME #17520
+// ignore-cloudabi no support for backtraces
+// ignore-emscripten no support for backtraces
+// ignore-openbsd no support for libbacktrace without filename
+// ignore-sgx no support for backtraces
+// ignore-msvc see #62897 and `backtrace-debuginfo.rs` test
+// compile-flags:-g
+
+#![feature(backtrace, backtrace_frames)]
+
+use std::backtrace::Backtrace;
+
+#[inline(never)]
+fn capture() -> Backtrace {
+    Backtrace::force_capture()
+}
+
+fn main() {
+    let backtrace = capture();
+    let symbols = backtrace.frames().iter().flat_map(|frame| frame.symbols());
+    let capture = symbols
+        .filter(|symbol| symbol.name().map_or(false, |name| name.contains("frames::capture")))
+        .next()
+        .expect("no symbol for `capture`");
+    let filename = capture.filename().expect("no filename for `capture`");
+    assert!(filename.ends_with("std-backtrace-frames.rs"), "{}", filename.display());
+    assert_eq!(capture.lineno(), Some(16));
+
+    // Resolving the frames doesn't change the rendering.
+    assert!(backtrace.to_string().contains("frames::capture"));
+}
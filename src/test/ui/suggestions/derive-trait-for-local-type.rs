@@ -0,0 +1,47 @@
+// Suggest deriving the trait of an unsatisfied bound on the local types that can derive it.
+
+#![allow(dead_code)]
+
+fn needs_clone<T: Clone>() {}
+fn needs_copy<T: Copy>() {}
+fn needs_ord<T: Ord>() {}
+fn needs_default<T: Default>() {}
+
+struct NoDerives;
+
+#[derive(Debug)]
+struct SomeDerives(u8);
+
+#[derive(PartialEq)]
+enum Comparable {
+    A,
+    B(i32),
+}
+
+mod inner {
+    pub struct Inner {
+        pub x: u8,
+    }
+}
+
+// These cannot derive the traits.
+struct HasClosure(Box<dyn Fn()>);
+
+enum Choice {
+    A,
+}
+
+fn main() {
+    needs_clone::<NoDerives>();
+    //~^ ERROR the trait bound `NoDerives: std::clone::Clone` is not satisfied
+    needs_copy::<SomeDerives>();
+    //~^ ERROR the trait bound `SomeDerives: std::marker::Copy` is not satisfied
+    needs_ord::<Comparable>();
+    //~^ ERROR the trait bound `Comparable: std::cmp::Ord` is not satisfied
+    needs_default::<inner::Inner>();
+    //~^ ERROR the trait bound `inner::Inner: std::default::Default` is not satisfied
+    needs_clone::<HasClosure>();
+    //~^ ERROR the trait bound `HasClosure: std::clone::Clone` is not satisfied
+    needs_default::<Choice>();
+    //~^ ERROR the trait bound `Choice: std::default::Default` is not satisfied
+}
@@ -0,0 +1,40 @@
+// edition:2018
+
+// Suggest naming the elided lifetime in the return type of an `async fn` when elision fails,
+// tying it to the reference in `self` if that is not used for elision, or else to the
+// references among the arguments.
+
+use std::pin::Pin;
+
+struct Struct {}
+
+type Alias = Struct;
+
+async fn two_args(x: &u32, y: &u32) -> &u32 {
+    //~^ ERROR missing lifetime specifier
+    loop {}
+}
+
+impl Struct {
+    async fn ref_alias(self: &Alias) -> &u32 {
+        //~^ ERROR missing lifetime specifier
+        loop {}
+    }
+
+    async fn box_ref_alias(self: Box<&Alias>) -> &u32 {
+        //~^ ERROR missing lifetime specifier
+        loop {}
+    }
+
+    async fn pin_ref_mut_alias(self: Pin<&mut Alias>) -> &u32 {
+        //~^ ERROR missing lifetime specifier
+        loop {}
+    }
+
+    async fn generic<T>(self: &Alias, t: T) -> &u32 {
+        //~^ ERROR missing lifetime specifier
+        loop {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+// Checks the warnings about contradictory and redundant `-C target-feature` entries.
+
+// check-pass
+// only-x86_64
+// compile-flags: -C target-feature=+avx2,-avx,+sse2,sse3
+
+fn main() {}
@@ -0,0 +1,4 @@
+// compile-flags: -Z time-limit=0
+// normalize-stderr-test "computing `\w+`: .*" -> "computing `QUERY`: DESCRIPTION"
+
+fn main() {}
@@ -0,0 +1,11 @@
+// edition:2018
+
+#![feature(rustc_attrs)]
+
+#[rustc_deprecated_safe = "2018"]
+unsafe fn set_var(_key: &str, _value: &str) {}
+
+fn main() {
+    set_var("KEY", "value");
+    //~^ ERROR call to unsafe function is unsafe and requires unsafe function or block
+}
@@ -0,0 +1,13 @@
+// check-pass
+// run-rustfix
+
+#![feature(rustc_attrs)]
+
+#[rustc_deprecated_safe = "2018"]
+unsafe fn set_var(_key: &str, _value: &str) {}
+
+fn main() {
+    set_var("KEY", "value");
+    //~^ WARN call to unsafe function `set_var` without an unsafe block is deprecated
+    unsafe { set_var("KEY", "value") };
+}
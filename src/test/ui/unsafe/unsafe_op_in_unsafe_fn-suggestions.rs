@@ -0,0 +1,42 @@
+// run-rustfix
+
+#![feature(unsafe_block_in_unsafe_fn)]
+#![deny(unsafe_op_in_unsafe_fn)]
+#![deny(unused_unsafe)]
+#![allow(dead_code)]
+
+unsafe fn unsf() {}
+
+unsafe fn statement() {
+    unsf(); // this comment stays where it is
+    //~^ ERROR call to unsafe function is unsafe and requires unsafe block
+}
+
+unsafe fn initializer(p: *const u32) -> u32 {
+    let x = *p + 1;
+    //~^ ERROR dereference of raw pointer is unsafe and requires unsafe block
+    x
+}
+
+unsafe fn tail(p: *const u32) -> u32 {
+    *p
+    //~^ ERROR dereference of raw pointer is unsafe and requires unsafe block
+}
+
+unsafe fn several(p: *mut u32) {
+    *p = /* keep me */ *p + 1;
+    //~^ ERROR dereference of raw pointer is unsafe and requires unsafe block
+    //~| ERROR dereference of raw pointer is unsafe and requires unsafe block
+}
+
+unsafe fn closure(p: *const u32) -> u32 {
+    let f = || *p;
+    //~^ ERROR dereference of raw pointer is unsafe and requires unsafe block
+    f()
+}
+
+unsafe fn already_unsafe(p: *const u32) -> u32 {
+    unsafe { *p }
+}
+
+fn main() {}
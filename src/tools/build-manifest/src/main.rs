@@ -443,6 +443,7 @@ impl Builder {
         package("cargo", HOSTS);
         package("rust-mingw", MINGW);
         package("rust-std", TARGETS);
+        package("rust-std-debug-assertions", TARGETS);
         package("rust-docs", DOCS_TARGETS);
         package("rust-src", &["*"]);
         package("rls-preview", HOSTS);
@@ -567,6 +568,9 @@ impl Builder {
                 .filter(|&&target| target != host)
                 .map(|target| Component::from_str("rust-std", target)),
         );
+        extensions.extend(
+            TARGETS.iter().map(|target| Component::from_str("rust-std-debug-assertions", target)),
+        );
         extensions.extend(HOSTS.iter().map(|target| Component::from_str("rustc-dev", target)));
         extensions.push(Component::from_str("rust-src", "*"));
 
@@ -136,23 +136,52 @@ pub enum FailMode {
 pub enum CompareMode {
     Nll,
     Polonius,
+    /// Borrow checks with NLL, but warns wherever Polonius would report different loan errors.
+    PoloniusCompare,
+    /// Solves traits with Chalk, and warns wherever the old trait solver disagrees with it.
+    Chalk,
+    /// A compare mode registered in the `compare-modes` of the bootstrap config, with its name
+    /// and the extra rustc flags it passes.
+    Custom(String, Vec<String>),
 }
 
 impl CompareMode {
-    pub(crate) fn to_str(&self) -> &'static str {
+    pub(crate) fn to_str(&self) -> &str {
         match *self {
             CompareMode::Nll => "nll",
             CompareMode::Polonius => "polonius",
+            CompareMode::PoloniusCompare => "polonius-compare",
+            CompareMode::Chalk => "chalk",
+            CompareMode::Custom(ref name, _) => name,
         }
     }
 
-    pub fn parse(s: String) -> CompareMode {
+    /// Returns the compare mode named `s`. `flags`, the value of `--compare-mode-flags`, makes
+    /// it a custom mode passing these flags to rustc.
+    pub fn parse(s: String, flags: Option<String>) -> CompareMode {
+        if let Some(flags) = flags {
+            let flags = flags.split_whitespace().map(String::from).collect();
+            return CompareMode::Custom(s, flags);
+        }
         match s.as_str() {
             "nll" => CompareMode::Nll,
             "polonius" => CompareMode::Polonius,
+            "polonius-compare" => CompareMode::PoloniusCompare,
+            "chalk" => CompareMode::Chalk,
             x => panic!("unknown --compare-mode option: {}", x),
         }
     }
+
+    /// The rustc flags passed in this compare mode.
+    pub fn rustc_flags(&self) -> Vec<&str> {
+        match *self {
+            CompareMode::Nll => vec!["-Zborrowck=mir"],
+            CompareMode::Polonius => vec!["-Zpolonius", "-Zborrowck=mir"],
+            CompareMode::PoloniusCompare => vec!["-Zpolonius=compare", "-Zborrowck=mir"],
+            CompareMode::Chalk => vec!["-Zchalk", "-Zchalk-cross-check"],
+            CompareMode::Custom(_, ref flags) => flags.iter().map(|s| &s[..]).collect(),
+        }
+    }
 }
 
 /// Configuration for compiletest
@@ -298,6 +327,11 @@ pub struct Config {
     /// created in `/<build_base>/rustfix_missing_coverage.txt`
     pub rustfix_coverage: bool,
 
+    /// If set, UI tests are compiled with both `rustc_path` and this compiler instead of being
+    /// checked against their expected output, and the tests whose rendered diagnostics differ
+    /// are listed with a diff in `/<build_base>/diagnostics_baseline_diff.txt`.
+    pub baseline_rustc_path: Option<PathBuf>,
+
     // Configuration for various run-make tests frobbing things like C compilers
     // or querying about various LLVM component information.
     pub cc: String,
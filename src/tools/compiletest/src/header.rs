@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 
 use log::*;
 
-use crate::common::{self, CompareMode, Config, FailMode, Mode, PassMode};
+use crate::common::{self, Config, FailMode, Mode, PassMode};
 use crate::extract_gdb_version;
 use crate::util;
 
@@ -893,8 +893,7 @@ impl Config {
                 name == self.stage_id.split('-').next().unwrap() || // stage
                 (self.target != self.host && name == "cross-compile") ||
                 match self.compare_mode {
-                    Some(CompareMode::Nll) => name == "compare-mode-nll",
-                    Some(CompareMode::Polonius) => name == "compare-mode-polonius",
+                    Some(ref mode) => name == format!("compare-mode-{}", mode.to_str()),
                     None => false,
                 } ||
                 (cfg!(debug_assertions) && name == "debug")
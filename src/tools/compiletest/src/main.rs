@@ -127,12 +127,25 @@ pub fn parse_config(args: Vec<String>) -> Config {
             "mode describing what file the actual ui output will be compared to",
             "COMPARE MODE",
         )
+        .optopt(
+            "",
+            "compare-mode-flags",
+            "extra rustc flags making `--compare-mode` a custom compare mode",
+            "FLAGS",
+        )
         .optflag(
             "",
             "rustfix-coverage",
             "enable this to generate a Rustfix coverage file, which is saved in \
                 `./<build_base>/rustfix_missing_coverage.txt`",
         )
+        .optopt(
+            "",
+            "baseline-rustc-path",
+            "compare the diagnostics of the ui tests with the ones of this compiler, \
+             writing their differences to `./<build_base>/diagnostics_baseline_diff.txt`",
+            "PATH",
+        )
         .optflag("h", "help", "show this message");
 
     let (argv0, args_) = args.split_first().unwrap();
@@ -231,8 +244,11 @@ pub fn parse_config(args: Vec<String>) -> Config {
         quiet: matches.opt_present("quiet"),
         color,
         remote_test_client: matches.opt_str("remote-test-client").map(PathBuf::from),
-        compare_mode: matches.opt_str("compare-mode").map(CompareMode::parse),
+        compare_mode: matches
+            .opt_str("compare-mode")
+            .map(|mode| CompareMode::parse(mode, matches.opt_str("compare-mode-flags"))),
         rustfix_coverage: matches.opt_present("rustfix-coverage"),
+        baseline_rustc_path: matches.opt_str("baseline-rustc-path").map(PathBuf::from),
 
         cc: matches.opt_str("cc").unwrap(),
         cxx: matches.opt_str("cxx").unwrap(),
@@ -366,6 +382,17 @@ pub fn run_tests(config: &Config) {
         }
     }
 
+    // Likewise, the report of the differences with the baseline compiler is
+    // created by the tests whose diagnostics differ.
+    if config.baseline_rustc_path.is_some() {
+        let diff_file_path = config.build_base.join("diagnostics_baseline_diff.txt");
+        if diff_file_path.exists() {
+            if let Err(e) = fs::remove_file(&diff_file_path) {
+                panic!("Could not delete {} due to {}", diff_file_path.display(), e)
+            }
+        }
+    }
+
     let opts = test_opts(config);
     let tests = make_tests(config);
     // sadly osx needs some file descriptor limits raised for running tests in
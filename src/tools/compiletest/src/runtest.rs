@@ -1919,14 +1919,8 @@ impl<'test> TestCx<'test> {
             }
         }
 
-        match self.config.compare_mode {
-            Some(CompareMode::Nll) => {
-                rustc.args(&["-Zborrowck=mir"]);
-            }
-            Some(CompareMode::Polonius) => {
-                rustc.args(&["-Zpolonius", "-Zborrowck=mir"]);
-            }
-            None => {}
+        if let Some(ref mode) = self.config.compare_mode {
+            rustc.args(&mode.rustc_flags());
         }
 
         if self.props.force_host {
@@ -2839,6 +2833,10 @@ impl<'test> TestCx<'test> {
     }
 
     fn run_ui_test(&self) {
+        if let Some(ref baseline_rustc) = self.config.baseline_rustc_path {
+            return self.run_ui_baseline_comparison(baseline_rustc);
+        }
+
         if let Some(FailMode::Build) = self.props.fail_mode {
             // Make sure a build-fail test cannot fail due to failing analysis (e.g. typeck).
             let pm = Some(PassMode::Check);
@@ -2859,7 +2857,10 @@ impl<'test> TestCx<'test> {
 
         let expected_fixed = self.load_expected_output(UI_FIXED);
 
-        let modes_to_prune = vec![CompareMode::Nll];
+        let mut modes_to_prune = vec![CompareMode::Nll];
+        if let Some(ref mode @ CompareMode::Custom(..)) = self.config.compare_mode {
+            modes_to_prune.push(mode.clone());
+        }
         self.prune_duplicate_outputs(&modes_to_prune);
 
         let mut errors = self.load_compare_outputs(&proc_res, TestOutput::Compile, explicit);
@@ -3011,6 +3012,64 @@ impl<'test> TestCx<'test> {
         }
     }
 
+    /// Compiles the test with both the compiler being tested and the baseline compiler, and
+    /// appends the differences between the diagnostics they render to the report in the build
+    /// directory. The test itself only fails if it cannot be compiled at all.
+    fn run_ui_baseline_comparison(&self, baseline_rustc: &Path) {
+        let mut baseline_config = self.config.clone();
+        baseline_config.rustc_path = baseline_rustc.to_path_buf();
+        baseline_config.build_base = self.config.build_base.join("baseline");
+        let baseline_cx = TestCx { config: &baseline_config, ..*self };
+        create_dir_all(&baseline_cx.output_base_dir()).unwrap();
+
+        let explicit = self.props.compile_flags.iter().any(|s| s.contains("--error-format"));
+        let rendered_diagnostics = |cx: &TestCx<'_>| {
+            let emit_metadata = cx.should_emit_metadata(cx.pass_mode());
+            let proc_res = cx.compile_test(WillExecute::No, emit_metadata);
+            let stderr = if explicit {
+                proc_res.stderr.clone()
+            } else {
+                json::extract_rendered(&proc_res.stderr)
+            };
+            cx.normalize_output(&stderr, &cx.props.normalize_stderr)
+        };
+        let actual = rendered_diagnostics(self);
+        let baseline = rendered_diagnostics(&baseline_cx);
+        if actual == baseline {
+            return;
+        }
+
+        let mut report = format!("diff of the diagnostics of {}:\n", self.testpaths.file.display());
+        for result in make_diff(&baseline, &actual, 3) {
+            let mut line_number = result.line_number;
+            for line in result.lines {
+                match line {
+                    DiffLine::Expected(e) => {
+                        report.push_str(&format!("-\t{}\n", e));
+                        line_number += 1;
+                    }
+                    DiffLine::Context(c) => {
+                        report.push_str(&format!("{}\t{}\n", line_number, c));
+                        line_number += 1;
+                    }
+                    DiffLine::Resulting(r) => report.push_str(&format!("+\t{}\n", r)),
+                }
+            }
+            report.push('\n');
+        }
+
+        // The tests run in parallel, so the whole entry is written at once.
+        let diff_file_path = self.config.build_base.join("diagnostics_baseline_diff.txt");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&diff_file_path)
+            .expect("could not create or open file");
+        if let Err(_) = file.write_all(report.as_bytes()) {
+            panic!("couldn't write to {}", diff_file_path.display());
+        }
+    }
+
     fn run_mir_opt_test(&self) {
         let proc_res = self.compile_test(WillExecute::Yes, EmitMetadata::No);
 
@@ -3291,7 +3350,9 @@ impl<'test> TestCx<'test> {
             expected_output_path(&self.testpaths, self.revision, &self.config.compare_mode, kind);
 
         if !path.exists() {
-            if let Some(CompareMode::Polonius) = self.config.compare_mode {
+            if let Some(CompareMode::Polonius) | Some(CompareMode::PoloniusCompare) =
+                self.config.compare_mode
+            {
                 path = expected_output_path(
                     &self.testpaths,
                     self.revision,
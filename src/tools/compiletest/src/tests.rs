@@ -49,3 +49,14 @@ fn is_test_test() {
     assert_eq!(false, is_test(&OsString::from("#a_dog_gif")));
     assert_eq!(false, is_test(&OsString::from("~a_temp_file")));
 }
+
+#[test]
+fn test_parse_custom_compare_mode() {
+    let mode = CompareMode::parse("next-solver".to_string(), Some("-Zchalk  -Zfoo=bar".into()));
+    assert_eq!(mode.to_str(), "next-solver");
+    assert_eq!(mode.rustc_flags(), vec!["-Zchalk", "-Zfoo=bar"]);
+
+    let mode = CompareMode::parse("polonius".to_string(), None);
+    assert_eq!(mode, CompareMode::Polonius);
+    assert_eq!(mode.rustc_flags(), vec!["-Zpolonius", "-Zborrowck=mir"]);
+}
@@ -18,6 +18,8 @@ use std::thread;
 use std::time::Duration;
 
 const REMOTE_ADDR_ENV: &str = "TEST_DEVICE_ADDR";
+const QEMU_KERNEL_ENV: &str = "TEST_QEMU_KERNEL";
+const QEMU_INITRD_ENV: &str = "TEST_QEMU_INITRD";
 
 macro_rules! t {
     ($e:expr) => {
@@ -52,8 +54,7 @@ fn spawn_emulator(target: &str, server: &Path, tmpdir: &Path, rootfs: Option<Pat
     } else if target.contains("android") {
         start_android_emulator(server);
     } else {
-        let rootfs = rootfs.as_ref().expect("need rootfs on non-android");
-        start_qemu_emulator(target, rootfs, server, tmpdir);
+        start_qemu_emulator(target, rootfs.as_ref().map(|p| &**p), server, tmpdir);
     }
 
     // Wait for the emulator to come online
@@ -92,26 +93,31 @@ fn start_android_emulator(server: &Path) {
     Command::new("adb").arg("shell").arg("/data/tmp/testd").spawn().unwrap();
 }
 
-fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path) {
-    // Generate a new rootfs image now that we've updated the test server
-    // executable. This is the equivalent of:
-    //
-    //      find $rootfs -print 0 | cpio --null -o --format=newc > rootfs.img
-    t!(fs::copy(server, rootfs.join("testd")));
+fn start_qemu_emulator(target: &str, rootfs: Option<&Path>, server: &Path, tmpdir: &Path) {
     let rootfs_img = tmpdir.join("rootfs.img");
-    let mut cmd = Command::new("cpio");
-    cmd.arg("--null")
-        .arg("-o")
-        .arg("--format=newc")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .current_dir(rootfs);
-    let mut child = t!(cmd.spawn());
-    let mut stdin = child.stdin.take().unwrap();
-    let rootfs = rootfs.to_path_buf();
-    thread::spawn(move || add_files(&mut stdin, &rootfs, &rootfs));
-    t!(io::copy(&mut child.stdout.take().unwrap(), &mut t!(File::create(&rootfs_img))));
-    assert!(t!(child.wait()).success());
+    match (rootfs, env::var_os(QEMU_INITRD_ENV)) {
+        // Generate a new rootfs image now that we've updated the test server
+        // executable.
+        (Some(rootfs), _) => {
+            t!(fs::copy(server, rootfs.join("testd")));
+            write_cpio(rootfs, &mut t!(File::create(&rootfs_img)));
+        }
+        // Provision the configured initrd with the test server. The kernel
+        // unpacks concatenated cpio archives in order, so appending one that
+        // only holds `testd` adds it to the image.
+        (None, Some(initrd)) => {
+            let provision = tmpdir.join("qemu-provision");
+            let _ = fs::remove_dir_all(&provision);
+            t!(fs::create_dir_all(&provision));
+            t!(fs::copy(server, provision.join("testd")));
+            t!(fs::copy(&initrd, &rootfs_img));
+            write_cpio(&provision, &mut t!(fs::OpenOptions::new().append(true).open(&rootfs_img)));
+        }
+        (None, None) => panic!("need a rootfs or an initrd to start QEMU for: {}", target),
+    }
+    let kernel = |default: &str| {
+        env::var_os(QEMU_KERNEL_ENV).map(PathBuf::from).unwrap_or_else(|| PathBuf::from(default))
+    };
 
     // Start up the emulator, in the background
     match target {
@@ -122,7 +128,7 @@ fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path
                 .arg("-m")
                 .arg("1024")
                 .arg("-kernel")
-                .arg("/tmp/zImage")
+                .arg(kernel("/tmp/zImage"))
                 .arg("-initrd")
                 .arg(&rootfs_img)
                 .arg("-dtb")
@@ -143,7 +149,7 @@ fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path
                 .arg("-m")
                 .arg("1024")
                 .arg("-kernel")
-                .arg("/tmp/Image")
+                .arg(kernel("/tmp/Image"))
                 .arg("-initrd")
                 .arg(&rootfs_img)
                 .arg("-append")
@@ -155,8 +161,47 @@ fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path
                 .arg("virtio-net-device,netdev=net0,mac=00:00:00:00:00:00");
             t!(cmd.spawn());
         }
-        _ => panic!("cannot start emulator for: {}" < target),
+        "riscv64gc-unknown-linux-gnu" => {
+            let kernel = env::var_os(QEMU_KERNEL_ENV).expect("need a kernel to start QEMU");
+            let mut cmd = Command::new("qemu-system-riscv64");
+            cmd.arg("-machine")
+                .arg("virt")
+                .arg("-m")
+                .arg("1024")
+                .arg("-kernel")
+                .arg(kernel)
+                .arg("-initrd")
+                .arg(&rootfs_img)
+                .arg("-append")
+                .arg("console=ttyS0 root=/dev/ram rdinit=/sbin/init init=/sbin/init")
+                .arg("-nographic")
+                .arg("-netdev")
+                .arg("user,id=net0,hostfwd=tcp::12345-:12345")
+                .arg("-device")
+                .arg("virtio-net-device,netdev=net0,mac=00:00:00:00:00:00");
+            t!(cmd.spawn());
+        }
+        _ => panic!("cannot start emulator for: {}", target),
     }
+}
+
+/// Writes an archive of the files in `dir` to `out`. This is the equivalent of:
+///
+///      find $dir -print 0 | cpio --null -o --format=newc > out
+fn write_cpio(dir: &Path, out: &mut File) {
+    let mut cmd = Command::new("cpio");
+    cmd.arg("--null")
+        .arg("-o")
+        .arg("--format=newc")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .current_dir(dir);
+    let mut child = t!(cmd.spawn());
+    let mut stdin = child.stdin.take().unwrap();
+    let dir = dir.to_path_buf();
+    thread::spawn(move || add_files(&mut stdin, &dir, &dir));
+    t!(io::copy(&mut child.stdout.take().unwrap(), out));
+    assert!(t!(child.wait()).success());
 
     fn add_files(w: &mut dyn Write, root: &Path, cur: &Path) {
         for entry in t!(cur.read_dir()) {
@@ -0,0 +1,197 @@
+//! The constant evaluator target: generates a crate of constants from the input, and evaluates
+//! them.
+//!
+//! The input drives a small generator of integer expressions, local bindings, casts and
+//! comparisons, which the compiler lowers to MIR before the evaluator runs it. Overflows and
+//! divisions by zero are compilation errors rather than failures.
+
+use std::fmt::Write;
+use std::io;
+
+use rustc::session::config::{Input, Options as SessionOptions};
+use rustc::session::DiagnosticOutput;
+use rustc_hir as hir;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_interface::interface;
+use rustc_span::source_map::FileName;
+
+use crate::Options;
+
+const TYPES: &[&str] =
+    &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+/// The maximum number of constants generated from one input.
+const MAX_CONSTS: usize = 8;
+
+/// The maximum nesting of the generated expressions.
+const MAX_DEPTH: u32 = 4;
+
+pub fn run(data: &[u8], options: &Options) -> String {
+    let src = Generator { data, locals: Vec::new() }.generate();
+    let mut described = src.clone();
+    described.push_str(&evaluate(src, options));
+    described
+}
+
+/// Compiles `src`, and describes the values of its constants.
+fn evaluate(src: String, options: &Options) -> String {
+    let mut opts = SessionOptions::default();
+    opts.maybe_sysroot = options.sysroot.clone();
+
+    let config = interface::Config {
+        opts,
+        crate_cfg: Default::default(),
+        input: Input::Str { name: FileName::Custom("fuzz".to_owned()), input: src },
+        input_path: None,
+        output_file: None,
+        output_dir: None,
+        file_loader: None,
+        diagnostic_output: DiagnosticOutput::Raw(Box::new(io::sink())),
+        stderr: None,
+        crate_name: Some("fuzz".to_owned()),
+        lint_caps: Default::default(),
+        register_lints: None,
+        override_queries: None,
+        registry: rustc_driver::diagnostics_registry(),
+    };
+
+    let described = rustc_driver::catch_fatal_errors(|| {
+        interface::run_compiler(config, |compiler| {
+            compiler.enter(|queries| {
+                queries.global_ctxt()?.peek_mut().enter(|tcx| {
+                    tcx.analysis(LOCAL_CRATE)?;
+                    let mut described = String::new();
+                    for item in tcx.hir().krate().items.values() {
+                        if let hir::ItemKind::Const(..) = item.kind {
+                            let def_id = tcx.hir().local_def_id(item.hir_id);
+                            let value = tcx.const_eval_poly(def_id);
+                            writeln!(described, "{} = {:?}", item.ident, value).unwrap();
+                        }
+                    }
+                    Ok(described)
+                })
+            })
+        })
+    });
+    match described {
+        Ok(Ok(described)) => described,
+        Ok(Err(_)) => "error".to_owned(),
+        Err(_) => "fatal error".to_owned(),
+    }
+}
+
+/// Generates Rust source from the bytes of the input, reading zeros once they run out.
+struct Generator<'a> {
+    data: &'a [u8],
+    /// The names and types of the local bindings in scope.
+    locals: Vec<(String, &'static str)>,
+}
+
+impl Generator<'_> {
+    fn generate(mut self) -> String {
+        let mut src = String::from("#![crate_type = \"lib\"]\n");
+        let mut count = 0;
+        while !self.data.is_empty() && count < MAX_CONSTS {
+            let ty = self.ty();
+            let value = self.expr(ty, MAX_DEPTH);
+            writeln!(src, "pub const C{}: {} = {};", count, ty, value).unwrap();
+            count += 1;
+        }
+        src
+    }
+
+    fn byte(&mut self) -> u8 {
+        match self.data.split_first() {
+            Some((&b, rest)) => {
+                self.data = rest;
+                b
+            }
+            None => 0,
+        }
+    }
+
+    fn ty(&mut self) -> &'static str {
+        TYPES[self.byte() as usize % TYPES.len()]
+    }
+
+    fn expr(&mut self, ty: &'static str, depth: u32) -> String {
+        if depth == 0 || self.data.is_empty() {
+            return self.leaf(ty);
+        }
+        let depth = depth - 1;
+        match self.byte() % 7 {
+            0 => self.leaf(ty),
+            1 => {
+                let op = ["+", "-", "*", "/", "%", "&", "|", "^"][self.byte() as usize % 8];
+                let lhs = self.expr(ty, depth);
+                let rhs = self.expr(ty, depth);
+                format!("({} {} {})", lhs, op, rhs)
+            }
+            2 => {
+                // The shift amount can be of any integer type.
+                let op = if self.byte() % 2 == 0 { "<<" } else { ">>" };
+                let rhs_ty = self.ty();
+                let lhs = self.expr(ty, depth);
+                let rhs = self.expr(rhs_ty, depth);
+                format!("({} {} {})", lhs, op, rhs)
+            }
+            3 => {
+                let op = if ty.starts_with('i') && self.byte() % 2 == 0 { "-" } else { "!" };
+                format!("({}{})", op, self.expr(ty, depth))
+            }
+            4 => {
+                let from = self.ty();
+                format!("({} as {})", self.expr(from, depth), ty)
+            }
+            5 => {
+                let op = ["==", "!=", "<", "<=", ">", ">="][self.byte() as usize % 6];
+                let operand_ty = self.ty();
+                let lhs = self.expr(operand_ty, depth);
+                let rhs = self.expr(operand_ty, depth);
+                format!("(({} {} {}) as {})", lhs, op, rhs, ty)
+            }
+            _ => {
+                let name = format!("x{}", self.locals.len());
+                let value_ty = self.ty();
+                let value = self.expr(value_ty, depth);
+                self.locals.push((name.clone(), value_ty));
+                let body = self.expr(ty, depth);
+                self.locals.pop();
+                format!("{{ let {}: {} = {}; {} }}", name, value_ty, value, body)
+            }
+        }
+    }
+
+    /// A local binding of type `ty`, or a literal.
+    fn leaf(&mut self, ty: &'static str) -> String {
+        let b = self.byte();
+        if b % 4 == 0 {
+            let locals: Vec<_> = self.locals.iter().filter(|(_, t)| *t == ty).collect();
+            if !locals.is_empty() {
+                return locals[b as usize / 4 % locals.len()].0.clone();
+            }
+        }
+        if b % 2 == 0 {
+            // A small value, which makes for more interesting arithmetic.
+            return format!("{}{}", b / 2, ty);
+        }
+
+        // Any value of the type, on every target.
+        let bits = match ty {
+            "u8" | "i8" => 8,
+            "u16" | "i16" => 16,
+            "u32" | "i32" | "usize" | "isize" => 32,
+            "u64" | "i64" => 64,
+            _ => 128,
+        };
+        let bits = if ty.starts_with('i') { bits - 1 } else { bits };
+        let mut value = 0u128;
+        for _ in 0..(bits + 7) / 8 {
+            value = value << 8 | u128::from(self.byte());
+        }
+        if bits < 128 {
+            value &= (1 << bits) - 1;
+        }
+        format!("{}{}", value, ty)
+    }
+}
@@ -0,0 +1,63 @@
+//! The lexer target: tokenizes the input with `rustc_lexer`, and unescapes the quoted literals.
+
+use std::fmt::{self, Write};
+use std::ops::Range;
+use std::str;
+
+use rustc_lexer::unescape;
+use rustc_lexer::{LiteralKind, TokenKind};
+
+use crate::Options;
+
+pub fn run(data: &[u8], _: &Options) -> String {
+    let src = match str::from_utf8(data) {
+        Ok(src) => src,
+        Err(_) => return "not UTF-8".to_owned(),
+    };
+
+    let mut described = String::new();
+    let mut pos = 0;
+    if !src.is_empty() {
+        if let Some(shebang_len) = rustc_lexer::strip_shebang(src) {
+            writeln!(described, "shebang {}", shebang_len).unwrap();
+            pos = shebang_len;
+        }
+    }
+    for token in rustc_lexer::tokenize(&src[pos..]) {
+        assert!(token.len > 0, "empty token at {}", pos);
+        // This also checks that the token ends at a character boundary.
+        let text = &src[pos..pos + token.len];
+        write!(described, "{:?} {}", token.kind, token.len).unwrap();
+        if let TokenKind::Literal { kind, suffix_start } = token.kind {
+            describe_literal(&mut described, kind, &text[..suffix_start]);
+        }
+        described.push('\n');
+        pos += token.len;
+    }
+    assert_eq!(pos, src.len(), "the tokens do not cover the input");
+    described
+}
+
+/// Describes the unescaped contents of the literal `text`, if it is a terminated quoted literal.
+fn describe_literal(described: &mut String, kind: LiteralKind, text: &str) {
+    let mut describe = |range: Range<usize>, unit: &dyn fmt::Debug| {
+        write!(described, " {:?}:{:?}", range, unit).unwrap();
+    };
+    match kind {
+        LiteralKind::Char { terminated: true } => {
+            describe(0..text.len(), &unescape::unescape_char(&text[1..text.len() - 1]))
+        }
+        LiteralKind::Byte { terminated: true } => {
+            describe(0..text.len(), &unescape::unescape_byte(&text[2..text.len() - 1]))
+        }
+        LiteralKind::Str { terminated: true } => {
+            unescape::unescape_str(&text[1..text.len() - 1], &mut |range, c| describe(range, &c))
+        }
+        LiteralKind::ByteStr { terminated: true } => {
+            unescape::unescape_byte_str(&text[2..text.len() - 1], &mut |range, b| {
+                describe(range, &b)
+            })
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,221 @@
+//! Fuzz targets for the lexer, the parser and the constant evaluator of rustc.
+//!
+//! Each target takes arbitrary bytes, runs a part of the compiler on them, and describes what
+//! the compiler made of them. A target fails if the compiler panics with anything but a fatal
+//! error, which is an ICE, or if running it twice on the same input gives two different
+//! descriptions.
+//!
+//! By default, this runs every target on the inputs of a corpus directory, with a subdirectory
+//! per target, and on a few mutations of each of them. This is what `x.py test --fuzz-smoke`
+//! does:
+//!
+//! ```text
+//! rustc-fuzz [--sysroot <path>] [--mutations <count>] <corpus>
+//! ```
+//!
+//! With the `libfuzzer` feature, this instead exports `LLVMFuzzerTestOneInput`, and links
+//! against libFuzzer, which runs the target named by the `RUSTC_FUZZ_TARGET` environment
+//! variable. The sysroot of the constant evaluator is then read from `RUSTC_FUZZ_SYSROOT`.
+
+#![feature(rustc_private)]
+#![cfg_attr(feature = "libfuzzer", no_main)]
+
+extern crate rustc;
+extern crate rustc_data_structures;
+extern crate rustc_driver;
+extern crate rustc_errors;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_lexer;
+extern crate rustc_parse;
+extern crate rustc_session;
+extern crate rustc_span;
+extern crate syntax;
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+mod const_eval;
+mod lexer;
+mod parser;
+
+/// The configuration of the targets.
+#[derive(Default)]
+pub struct Options {
+    /// The sysroot that the constant evaluator compiles against.
+    pub sysroot: Option<PathBuf>,
+}
+
+/// A fuzz target, which describes what the compiler made of its input.
+type Target = fn(&[u8], &Options) -> String;
+
+const TARGETS: &[(&str, Target)] =
+    &[("lexer", lexer::run), ("parser", parser::run), ("const-eval", const_eval::run)];
+
+/// Runs `target` twice on `data`, and returns why it failed, if it did.
+fn check(target: Target, data: &[u8], options: &Options) -> Result<(), String> {
+    let run = || {
+        panic::catch_unwind(AssertUnwindSafe(|| target(data, options)))
+            .map_err(|payload| format!("the compiler panicked: {}", panic_message(&*payload)))
+    };
+    let first = run()?;
+    let second = run()?;
+    if first != second {
+        return Err(format!(
+            "the result is not deterministic:\n{}\n--- and then ---\n{}",
+            first, second
+        ));
+    }
+    Ok(())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "Box<Any>"
+    }
+}
+
+#[cfg(feature = "libfuzzer")]
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn LLVMFuzzerTestOneInput(data: *const u8, size: usize) -> i32 {
+    use std::env;
+
+    let data = if size == 0 { &[][..] } else { unsafe { std::slice::from_raw_parts(data, size) } };
+    let name = env::var("RUSTC_FUZZ_TARGET").expect("RUSTC_FUZZ_TARGET is not set");
+    let target = match TARGETS.iter().find(|&&(n, _)| n == name) {
+        Some(&(_, target)) => target,
+        None => panic!("unknown fuzz target `{}`", name),
+    };
+    let options = Options { sysroot: env::var_os("RUSTC_FUZZ_SYSROOT").map(PathBuf::from) };
+    if let Err(why) = check(target, data, &options) {
+        eprintln!("{}", why);
+        std::process::abort();
+    }
+    0
+}
+
+#[cfg(not(feature = "libfuzzer"))]
+fn main() {
+    smoke::main()
+}
+
+#[cfg(not(feature = "libfuzzer"))]
+mod smoke {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process;
+
+    use super::{check, Options, TARGETS};
+
+    pub fn main() {
+        let mut options = Options::default();
+        let mut mutations = 16;
+        let mut corpus = None;
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match &arg[..] {
+                "--sysroot" => options.sysroot = args.next().map(PathBuf::from),
+                "--mutations" => {
+                    mutations = args.next().and_then(|n| n.parse().ok()).unwrap_or_else(|| usage())
+                }
+                _ if corpus.is_none() => corpus = Some(PathBuf::from(arg)),
+                _ => usage(),
+            }
+        }
+        let corpus = corpus.unwrap_or_else(|| usage());
+
+        let mut failures = 0;
+        for &(name, target) in TARGETS {
+            let inputs = read_inputs(&corpus.join(name));
+            let mut runs = 0;
+            for (path, data) in &inputs {
+                for (i, input) in mutate(data, mutations).iter().enumerate() {
+                    runs += 1;
+                    if let Err(why) = check(target, input, &options) {
+                        failures += 1;
+                        eprintln!("{}: {} (run {}): {}", name, path.display(), i, why);
+                        eprintln!("input: {:?}", String::from_utf8_lossy(input));
+                    }
+                }
+            }
+            println!("{}: {} runs on {} inputs", name, runs, inputs.len());
+        }
+
+        if failures > 0 {
+            eprintln!("{} fuzz smoke runs failed", failures);
+            process::exit(1);
+        }
+    }
+
+    fn usage() -> ! {
+        eprintln!("usage: rustc-fuzz [--sysroot <path>] [--mutations <count>] <corpus>");
+        process::exit(2);
+    }
+
+    /// The inputs of the corpus of a target, sorted by path so that the runs are reproducible.
+    fn read_inputs(dir: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => panic!("failed to read the corpus `{}`: {}", dir.display(), e),
+        };
+        let mut inputs = entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                let data = fs::read(&path).unwrap();
+                (path, data)
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    }
+
+    /// The bytes that the mutations insert, which are the ones most likely to change the meaning
+    /// of Rust source.
+    const PUNCTUATION: &[u8] = b"{}()[]<>;,.:'\"\\#!$&*+-/=?@^|~0aZ_ \n";
+
+    /// The input itself, followed by `count` mutations of it. The mutations only depend on the
+    /// input, so that the failures can be reproduced.
+    fn mutate(data: &[u8], count: usize) -> Vec<Vec<u8>> {
+        let mut state = data.iter().fold(0x2545_f491_4f6c_dd1d_u64, |h, &b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+        let mut next = move || {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        };
+
+        let mut inputs = vec![data.to_vec()];
+        for _ in 0..count {
+            let mut input = data.to_vec();
+            for _ in 0..1 + next() % 4 {
+                let at = if input.is_empty() { 0 } else { next() % input.len() };
+                match next() % 4 {
+                    0 if !input.is_empty() => input[at] ^= 1 << (next() % 8),
+                    1 if !input.is_empty() => {
+                        input.remove(at);
+                    }
+                    2 if !input.is_empty() => {
+                        let end = at + next() % (input.len() - at + 1);
+                        let copied = input[at..end].to_vec();
+                        let to = next() % (input.len() + 1);
+                        input.splice(to..to, copied);
+                    }
+                    _ => input.insert(at, PUNCTUATION[next() % PUNCTUATION.len()]),
+                }
+            }
+            inputs.push(input);
+        }
+        inputs
+    }
+}
@@ -0,0 +1,42 @@
+//! The parser target: parses the input as a crate, and pretty-prints the items it contains.
+
+use std::str;
+
+use rustc_data_structures::sync::Lrc;
+use rustc_errors::emitter::SilentEmitter;
+use rustc_errors::Handler;
+use rustc_session::parse::ParseSess;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+use rustc_span::FileName;
+use syntax::print::pprust;
+
+use crate::Options;
+
+pub fn run(data: &[u8], _: &Options) -> String {
+    let src = match str::from_utf8(data) {
+        Ok(src) => src.to_owned(),
+        Err(_) => return "not UTF-8".to_owned(),
+    };
+
+    syntax::with_default_globals(|| {
+        let source_map = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        // The errors are emitted rather than cancelled, so that the handler sees them: it panics
+        // on the delayed bugs of a session without errors.
+        let handler = Handler::with_emitter(false, None, Box::new(SilentEmitter));
+        let sess = ParseSess::with_span_handler(handler, source_map);
+        let name = FileName::Custom("fuzz".to_owned());
+        let described = rustc_driver::catch_fatal_errors(|| {
+            match rustc_parse::parse_crate_from_source_str(name, src, &sess) {
+                Ok(krate) => {
+                    let items = krate.module.items.iter().map(|item| pprust::item_to_string(item));
+                    items.collect::<Vec<_>>().join("\n")
+                }
+                Err(mut err) => {
+                    err.emit();
+                    "parse error".to_owned()
+                }
+            }
+        });
+        described.unwrap_or_else(|_| "fatal error".to_owned())
+    })
+}